@@ -1,11 +1,21 @@
 use std::process::exit;
 
 fn main() {
+    // Only regenerate the parsers when a grammar changes
+    for grammar in [
+        "src/parser/pg.rustemo",
+        "src/parser/pgs.rustemo",
+        "src/parser/map.rustemo",
+    ] {
+        println!("cargo:rerun-if-changed={}", grammar);
+    }
+    // The actions files are maintained by hand, so rustemo only generates the parsers and
+    // new actions have to be added to them when a grammar changes
     // let mut settings = rustemo_compiler::Settings::new();
     let mut settings = rustemo_compiler::Settings::new()
         .notrace(true)
+        .actions(false)
         .in_source_tree();
-    settings.process_dir().unwrap();
     if std::env::var("CARGO_FEATURE_ARRAYS").is_ok() {
         settings = settings.generator_table_type(rustemo_compiler::GeneratorTableType::Arrays);
     }
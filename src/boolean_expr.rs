@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{collections::HashSet, fmt::Display};
 
 use crate::{pgs_error::PgsError, value::Value};
 
@@ -15,6 +15,14 @@ pub enum BooleanExpr {
     GreaterThanOrEqual(Value),
     LessThanOrEqual(Value),
     Regex(String),
+    Unique,
+    Sum(Box<BooleanExpr>),
+    Min(Box<BooleanExpr>),
+    Max(Box<BooleanExpr>),
+    All(Box<BooleanExpr>),
+    Any(Box<BooleanExpr>),
+    NoneOf(Box<BooleanExpr>),
+    Contains(Value),
 }
 
 impl BooleanExpr {
@@ -42,10 +50,152 @@ impl BooleanExpr {
             BooleanExpr::Regex(pattern) => value.regex_match(pattern),
             BooleanExpr::True => Ok(true),
             BooleanExpr::False => Ok(false),
+            BooleanExpr::Unique
+            | BooleanExpr::Sum(_)
+            | BooleanExpr::Min(_)
+            | BooleanExpr::Max(_)
+            | BooleanExpr::All(_)
+            | BooleanExpr::Any(_)
+            | BooleanExpr::NoneOf(_)
+            | BooleanExpr::Contains(_) => self.check_values(std::slice::from_ref(value)),
+        }
+    }
+
+    /// Checks the expression against the whole set of values of a property.
+    ///
+    /// Expressions without collection-level predicates hold when every value satisfies them,
+    /// which is the same behaviour as checking them value by value.
+    /// Over no values, `SUM` is 0, `ALL`, `NONE` and `UNIQUE` hold, `ANY` and `CONTAINS`
+    /// don't hold, and `MIN` and `MAX` don't hold because there is no minimum or maximum
+    /// to check, as `MIN` and `MAX` of no rows are null in SQL.
+    pub fn check_values(&self, values: &[Value]) -> Result<bool, PgsError> {
+        if !self.is_aggregate() {
+            return self.check_each(values);
+        }
+        match self {
+            BooleanExpr::And(a, b) => {
+                let check_a = a.check_values(values)?;
+                let check_b = b.check_values(values)?;
+                Ok(check_a && check_b)
+            }
+            BooleanExpr::Or(a, b) => {
+                let check_a = a.check_values(values)?;
+                let check_b = b.check_values(values)?;
+                Ok(check_a || check_b)
+            }
+            BooleanExpr::Not(expr) => {
+                let check = expr.check_values(values)?;
+                Ok(!check)
+            }
+            BooleanExpr::Unique => {
+                let mut seen = HashSet::new();
+                Ok(values.iter().all(|v| seen.insert(v)))
+            }
+            BooleanExpr::Sum(expr) => {
+                let sum = sum_values(values)?;
+                expr.check(&sum)
+            }
+            BooleanExpr::Min(expr) => match values.iter().min() {
+                Some(min) => expr.check(min),
+                None => Ok(false),
+            },
+            BooleanExpr::Max(expr) => match values.iter().max() {
+                Some(max) => expr.check(max),
+                None => Ok(false),
+            },
+            BooleanExpr::All(expr) => {
+                for value in values {
+                    if !expr.check(value)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            BooleanExpr::Any(expr) => {
+                for value in values {
+                    if expr.check(value)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            BooleanExpr::NoneOf(expr) => {
+                for value in values {
+                    if expr.check(value)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            BooleanExpr::Contains(v) => Ok(values.contains(v)),
+            BooleanExpr::True
+            | BooleanExpr::False
+            | BooleanExpr::Equals(_)
+            | BooleanExpr::GreaterThan(_)
+            | BooleanExpr::LessThan(_)
+            | BooleanExpr::GreaterThanOrEqual(_)
+            | BooleanExpr::LessThanOrEqual(_)
+            | BooleanExpr::Regex(_) => self.check_each(values),
+        }
+    }
+
+    /// Checks that every value satisfies the expression
+    fn check_each(&self, values: &[Value]) -> Result<bool, PgsError> {
+        for value in values {
+            if !self.check(value)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Returns true if the expression contains predicates over the whole set of values
+    pub fn is_aggregate(&self) -> bool {
+        match self {
+            BooleanExpr::And(a, b) | BooleanExpr::Or(a, b) => a.is_aggregate() || b.is_aggregate(),
+            BooleanExpr::Not(expr) => expr.is_aggregate(),
+            BooleanExpr::Unique
+            | BooleanExpr::Sum(_)
+            | BooleanExpr::Min(_)
+            | BooleanExpr::Max(_)
+            | BooleanExpr::All(_)
+            | BooleanExpr::Any(_)
+            | BooleanExpr::NoneOf(_)
+            | BooleanExpr::Contains(_) => true,
+            BooleanExpr::True
+            | BooleanExpr::False
+            | BooleanExpr::Equals(_)
+            | BooleanExpr::GreaterThan(_)
+            | BooleanExpr::LessThan(_)
+            | BooleanExpr::GreaterThanOrEqual(_)
+            | BooleanExpr::LessThanOrEqual(_)
+            | BooleanExpr::Regex(_) => false,
         }
     }
 }
 
+fn sum_values(values: &[Value]) -> Result<Value, PgsError> {
+    let mut sum: i32 = 0;
+    for value in values {
+        match value {
+            Value::Integer(i) => {
+                sum = sum.checked_add(*i).ok_or(PgsError::InvalidNumber(format!(
+                    "Overflow in SUM adding {}",
+                    i
+                )))?
+            }
+            _ => {
+                return Err(PgsError::TypeMismatch {
+                    operation: "SUM".into(),
+                    expected: "Integer".into(),
+                    found: format!("{:?}", value),
+                });
+            }
+        }
+    }
+    Ok(Value::Integer(sum))
+}
+
 impl Display for BooleanExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -60,6 +210,73 @@ impl Display for BooleanExpr {
             BooleanExpr::Regex(pattern) => write!(f, "(REGEX({})", pattern),
             BooleanExpr::True => write!(f, "TRUE"),
             BooleanExpr::False => write!(f, "FALSE"),
+            BooleanExpr::Unique => write!(f, "UNIQUE"),
+            BooleanExpr::Sum(expr) => write!(f, "SUM({})", expr),
+            BooleanExpr::Min(expr) => write!(f, "MIN({})", expr),
+            BooleanExpr::Max(expr) => write!(f, "MAX({})", expr),
+            BooleanExpr::All(expr) => write!(f, "ALL({})", expr),
+            BooleanExpr::Any(expr) => write!(f, "ANY({})", expr),
+            BooleanExpr::NoneOf(expr) => write!(f, "NONE({})", expr),
+            BooleanExpr::Contains(value) => write!(f, "CONTAINS({})", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positive() -> Box<BooleanExpr> {
+        Box::new(BooleanExpr::GreaterThan(Value::int(0)))
+    }
+
+    #[test]
+    fn test_aggregates_of_no_values() {
+        let holds = [
+            BooleanExpr::Unique,
+            BooleanExpr::Sum(Box::new(BooleanExpr::Equals(Value::int(0)))),
+            BooleanExpr::All(positive()),
+            BooleanExpr::NoneOf(positive()),
+        ];
+        for expr in holds {
+            assert!(expr.check_values(&[]).unwrap(), "{} should hold", expr);
         }
+        let fails = [
+            BooleanExpr::Min(positive()),
+            BooleanExpr::Max(positive()),
+            BooleanExpr::Min(Box::new(BooleanExpr::True)),
+            BooleanExpr::Any(positive()),
+            BooleanExpr::Contains(Value::int(1)),
+        ];
+        for expr in fails {
+            assert!(!expr.check_values(&[]).unwrap(), "{} should fail", expr);
+        }
+    }
+
+    #[test]
+    fn test_min_max() {
+        let values = [Value::int(3), Value::int(1), Value::int(7)];
+        let min = BooleanExpr::Min(Box::new(BooleanExpr::Equals(Value::int(1))));
+        let max = BooleanExpr::Max(Box::new(BooleanExpr::Equals(Value::int(7))));
+        assert!(min.check_values(&values).unwrap());
+        assert!(max.check_values(&values).unwrap());
+        assert!(
+            !BooleanExpr::Min(positive())
+                .check_values(&[Value::int(-1)])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_or_of_values_is_checked_per_value() {
+        let small_or_big = BooleanExpr::Or(
+            Box::new(BooleanExpr::LessThan(Value::int(5))),
+            Box::new(BooleanExpr::GreaterThan(Value::int(8))),
+        );
+        assert!(
+            small_or_big
+                .check_values(&[Value::int(1), Value::int(10)])
+                .unwrap()
+        );
     }
 }
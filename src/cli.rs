@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand, command};
+use clap::{Parser, Subcommand, ValueEnum, command};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about)]
@@ -21,11 +21,49 @@ pub enum Command {
     Pgs {
         #[arg(short, long, help = "Path to the schema file")]
         schema: String,
+        #[arg(
+            long = "schema-format",
+            value_enum,
+            default_value = "pgs",
+            help = "Format of the schema"
+        )]
+        schema_format: SchemaFormat,
+        #[arg(
+            short = 'I',
+            long = "import-path",
+            help = "Directory where schemas imported by name are looked up, it can be repeated"
+        )]
+        import_paths: Vec<String>,
+        #[arg(
+            long = "to",
+            value_enum,
+            help = "Translate the schema to another language instead of showing it"
+        )]
+        to: Option<SchemaExportFormat>,
     },
     #[command(name = "pg", about = "Process and validate property graphs")]
     Pg {
         #[arg(short, long, help = "Path to the property graph file")]
         graph: String,
+        #[arg(
+            long = "graph-format",
+            value_enum,
+            default_value = "pg",
+            help = "Format of the property graph"
+        )]
+        graph_format: GraphFormat,
+        #[arg(
+            long = "to",
+            value_enum,
+            help = "Serialize the property graph in another format instead of showing it"
+        )]
+        to: Option<GraphExportFormat>,
+        #[arg(
+            long = "base",
+            default_value = "http://example.org/",
+            help = "Base IRI of the nodes, labels and properties in RDF exports"
+        )]
+        base: String,
     },
     #[command(name = "map", about = "Process and validate type map associations")]
     TypeMap {
@@ -39,9 +77,87 @@ pub enum Command {
     Validate {
         #[arg(short, long, help = "Path to the property graph file")]
         graph: String,
+        #[arg(
+            long = "graph-format",
+            value_enum,
+            default_value = "pg",
+            help = "Format of the property graph"
+        )]
+        graph_format: GraphFormat,
         #[arg(short, long, help = "Path to the property graph schema file")]
         schema: String,
+        #[arg(
+            long = "schema-format",
+            value_enum,
+            default_value = "pgs",
+            help = "Format of the property graph schema"
+        )]
+        schema_format: SchemaFormat,
         #[arg(short, long, help = "Path to the type map associations file")]
         map: String,
+        #[arg(
+            short = 'I',
+            long = "import-path",
+            help = "Directory where schemas imported by name are looked up, it can be repeated"
+        )]
+        import_paths: Vec<String>,
     },
 }
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Property graph syntax used by this tool
+    Pg,
+    /// Node and relationship files of the Neo4j import tool, the path can be a file or a directory
+    Csv,
+    /// GraphML document
+    Graphml,
+    /// JSON Lines with a node or edge per line
+    Jsonl,
+    /// PG format of the pg-format tools
+    PgFormat,
+    /// PG-JSON document with nodes and edges arrays
+    PgJson,
+    /// YARS-PG node and edge statements
+    Yarspg,
+    /// Cypher script with CREATE and MERGE clauses
+    Cypher,
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Eq)]
+pub enum GraphExportFormat {
+    /// GraphML document
+    Graphml,
+    /// PG format of the pg-format tools
+    PgFormat,
+    /// PG-JSON document with nodes and edges arrays
+    PgJson,
+    /// YARS-PG node and edge statements
+    Yarspg,
+    /// Cypher CREATE statement
+    Cypher,
+    /// GQL INSERT statement
+    Gql,
+    /// RDF 1.2 Turtle with triple terms reified by the edges
+    Turtle,
+    /// RDF 1.2 N-Triples with triple terms reified by the edges
+    Ntriples,
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Eq)]
+pub enum SchemaFormat {
+    /// PG-Schema syntax used by this tool
+    Pgs,
+    /// ISO GQL CREATE GRAPH TYPE statements
+    Gql,
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Eq)]
+pub enum SchemaExportFormat {
+    /// Neo4j CREATE CONSTRAINT statements with a report of what could not be translated
+    Neo4j,
+    /// ISO GQL CREATE GRAPH TYPE statement named after the schema file
+    Gql,
+    /// JSON Schema document with the records of the node types in $defs
+    JsonSchema,
+}
@@ -1,13 +1,15 @@
 use std::fmt::Display;
 
 use either::Either;
+use itertools::Itertools;
 
 use crate::{
     edge::Edge, evidence::Evidence, formal_base_type::FormalBaseType,
-    label_property_spec::LabelPropertySpec, pgs::PropertyGraphSchema, pgs_error::PgsError,
+    label_property_spec::LabelPropertySpec, node_id::NodeId, pg::PropertyGraph,
+    pgs::PropertyGraphSchema, pgs_error::PgsError, type_name::TypeName,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EdgeType {
     pub source: LabelPropertySpec,
     pub edge: LabelPropertySpec,
@@ -26,12 +28,25 @@ impl EdgeType {
         }
     }
 
+    /// Renames the node types referenced by the endpoints and the edge
+    pub fn map_refs(&self, f: &impl Fn(&str) -> TypeName) -> Self {
+        EdgeType::new(
+            self.source.map_refs(f),
+            self.edge.map_refs(f),
+            self.target.map_refs(f),
+        )
+    }
+
+    /// Names of the node types referenced by the endpoints and the edge
+    pub fn refs(&self) -> Vec<&TypeName> {
+        [self.source.refs(), self.edge.refs(), self.target.refs()].concat()
+    }
+
     pub fn semantics(&self, schema: &PropertyGraphSchema) -> Result<EdgeSemantics, PgsError> {
-        // Placeholder for actual implementation
         Ok(EdgeSemantics {
-            source: FormalBaseType::new(),
-            edge: FormalBaseType::new(),
-            target: FormalBaseType::new(),
+            source: self.source.semantics(schema)?,
+            edge: self.edge.semantics(schema)?,
+            target: self.target.semantics(schema)?,
         })
     }
 }
@@ -53,13 +68,86 @@ pub struct EdgeSemantics {
 }
 
 impl EdgeSemantics {
+    /// Checks the labels and content of the edge and of its source and target nodes
     pub fn conforms_edge(
         &self,
         type_name: &str,
         edge: &Edge,
+        graph: &PropertyGraph,
     ) -> Either<Vec<PgsError>, Vec<Evidence>> {
-        // Placeholder for actual implementation
-        // This would check if the edge conforms to the semantics defined by this EdgeType
-        Either::Right(vec![]) // Return empty evidence for now
+        let results = [
+            self.edge.conforms(edge.labels(), edge.content()),
+            conforms_endpoint(type_name, "source", &self.source, &edge.source, graph),
+            conforms_endpoint(type_name, "target", &self.target, &edge.target, graph),
+        ];
+        let mut errors = Vec::new();
+        let mut evidences = Vec::new();
+        for result in results {
+            match result {
+                Either::Left(es) => errors.extend(es),
+                Either::Right(es) => evidences.extend(es),
+            }
+        }
+        if errors.is_empty() {
+            Either::Right(evidences)
+        } else {
+            Either::Left(errors)
+        }
+    }
+}
+
+fn conforms_endpoint(
+    type_name: &str,
+    endpoint: &str,
+    semantics: &FormalBaseType,
+    node_id: &NodeId,
+    graph: &PropertyGraph,
+) -> Either<Vec<PgsError>, Vec<Evidence>> {
+    let node_name = graph
+        .node_name(node_id)
+        .cloned()
+        .unwrap_or(node_id.to_string());
+    match graph.node(node_id) {
+        None => Either::Left(vec![PgsError::MissingEndpointNode {
+            type_name: type_name.to_string(),
+            endpoint: endpoint.to_string(),
+            node: node_name,
+        }]),
+        Some(node) => match semantics.conforms(node.labels(), node.content()) {
+            Either::Left(errors) => Either::Left(vec![PgsError::EndpointMismatch {
+                type_name: type_name.to_string(),
+                endpoint: endpoint.to_string(),
+                node: node_name,
+                errors: errors.iter().join(", "),
+            }]),
+            Either::Right(evidences) => Either::Right(evidences),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{edge_id::EdgeId, record_type::RecordType};
+
+    use super::*;
+
+    #[test]
+    fn test_missing_endpoint_node() {
+        let any_node = FormalBaseType::new()
+            .with_open()
+            .with_record_type(RecordType::empty().with_open());
+        let semantics = EdgeSemantics {
+            source: any_node.clone(),
+            edge: FormalBaseType::type_0(),
+            target: any_node,
+        };
+        let edge = Edge::new(EdgeId::new(0), NodeId::new(0), NodeId::new(1));
+        let result = semantics.conforms_edge("KnowsType", &edge, &PropertyGraph::new());
+        let errors = result.left().unwrap();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| matches!(
+            e,
+            PgsError::MissingEndpointNode { type_name, .. } if type_name == "KnowsType"
+        )));
     }
 }
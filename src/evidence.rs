@@ -14,6 +14,28 @@ pub enum Evidence {
         condition: String,
         values: String,
     },
+    DegreeConforms {
+        node: String,
+        direction: String,
+        edge_label: String,
+        count: usize,
+    },
+    UniqueKeyValues {
+        node: String,
+        keys: String,
+        values: String,
+    },
+    ReferenceConforms {
+        node: String,
+        key: String,
+        values: String,
+        type_name: String,
+    },
+    PathFound {
+        node: String,
+        target: String,
+        path: String,
+    },
 }
 
 impl Display for Evidence {
@@ -31,6 +53,30 @@ impl Display for Evidence {
             Evidence::ConditionPassed { condition, values } => {
                 write!(f, "Condition {condition} passed for values: {values}")
             }
+            Evidence::DegreeConforms {
+                node,
+                direction,
+                edge_label,
+                count,
+            } => write!(
+                f,
+                "Node {node} has {count} {direction} edges with label {edge_label}"
+            ),
+            Evidence::UniqueKeyValues { node, keys, values } => {
+                write!(f, "Node {node} has unique values {values} for {keys}")
+            }
+            Evidence::ReferenceConforms {
+                node,
+                key,
+                values,
+                type_name,
+            } => write!(
+                f,
+                "Node {node} refers with {key} to nodes {values} which conform to {type_name}"
+            ),
+            Evidence::PathFound { node, target, path } => {
+                write!(f, "Node {node} reaches {target} through {path}")
+            }
         }
     }
 }
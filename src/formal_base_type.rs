@@ -56,6 +56,11 @@ impl FormalBaseType {
         self
     }
 
+    /// Alternative record types of the content
+    pub fn content(&self) -> &HashSet<RecordType> {
+        &self.content
+    }
+
     /// Checks if the FormalBaseType conforms to the given labels and content.
     pub fn conforms(
         &self,
@@ -128,6 +133,36 @@ impl FormalBaseType {
         }
     }
 
+    /// Combines the semantics of the types extended by some type
+    pub fn try_combine(&self, other: &FormalBaseType) -> Result<Self, PgsError> {
+        let mut content = HashSet::new();
+        for record1 in &self.content {
+            for record2 in &other.content {
+                content.insert(record1.try_combine(record2)?);
+            }
+        }
+        Ok(FormalBaseType {
+            labels: self.labels.union(&other.labels).cloned().collect(),
+            open_labels: combine_openness(self.open_labels, other.open_labels),
+            content,
+        })
+    }
+
+    /// Semantics of a type that extends the parent, inheriting its labels and properties
+    pub fn extend(&self, parent: &FormalBaseType) -> Self {
+        let mut content = HashSet::new();
+        for record in &self.content {
+            for parent_record in &parent.content {
+                content.insert(record.extend(parent_record));
+            }
+        }
+        FormalBaseType {
+            labels: self.labels.union(&parent.labels).cloned().collect(),
+            open_labels: combine_openness(self.open_labels, parent.open_labels),
+            content,
+        }
+    }
+
     pub fn type_0() -> FormalBaseType {
         let mut content = HashSet::new();
         content.insert(RecordType::empty());
@@ -0,0 +1,550 @@
+use std::{cmp::Ordering, collections::HashMap, fmt::Display};
+
+use either::Either;
+use itertools::Itertools;
+
+use crate::{
+    card::Card,
+    edge::Edge,
+    evidence::Evidence,
+    key::Key,
+    node::Node,
+    path_expr::PathExpr,
+    pg::PropertyGraph,
+    pgs::PropertyGraphSchema,
+    pgs_error::PgsError,
+    type_name::{LabelName, TypeName},
+    validation_result::ResultAssociation,
+    value::Value,
+};
+
+/// Constraint over the nodes with some label which can't be checked looking only at each node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphConstraint {
+    name: String,
+    label: LabelName,
+    kind: ConstraintKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// Number of edges with some label that go out or come into each node
+    Degree {
+        direction: Direction,
+        edge_label: LabelName,
+        card: Card,
+    },
+    /// Nodes which have values for all the keys must not share them with other nodes
+    Unique { keys: Vec<Key> },
+    /// Every node must have values for all the keys and they must be unique
+    NodeKey { keys: Vec<Key> },
+    /// Values of the key must be names of nodes in the graph which conform to the node type
+    Reference { key: Key, type_name: TypeName },
+    /// Every node must reach some node with the target label through a path that matches the expression
+    Reaches {
+        target: LabelName,
+        path_expr: PathExpr,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Outgoing,
+    Incoming,
+}
+
+impl GraphConstraint {
+    pub fn new(name: &str, label: &str, kind: ConstraintKind) -> Self {
+        GraphConstraint {
+            name: name.to_string(),
+            label: label.to_string(),
+            kind,
+        }
+    }
+
+    pub fn degree(
+        name: &str,
+        label: &str,
+        direction: Direction,
+        edge_label: &str,
+        card: Card,
+    ) -> Self {
+        GraphConstraint::new(
+            name,
+            label,
+            ConstraintKind::Degree {
+                direction,
+                edge_label: edge_label.to_string(),
+                card,
+            },
+        )
+    }
+
+    pub fn unique(name: &str, label: &str, keys: Vec<Key>) -> Self {
+        GraphConstraint::new(name, label, ConstraintKind::Unique { keys })
+    }
+
+    pub fn node_key(name: &str, label: &str, keys: Vec<Key>) -> Self {
+        GraphConstraint::new(name, label, ConstraintKind::NodeKey { keys })
+    }
+
+    pub fn reference(name: &str, label: &str, key: Key, type_name: &str) -> Self {
+        GraphConstraint::new(
+            name,
+            label,
+            ConstraintKind::Reference {
+                key,
+                type_name: type_name.to_string(),
+            },
+        )
+    }
+
+    pub fn reaches(name: &str, label: &str, target: &str, path_expr: PathExpr) -> Self {
+        GraphConstraint::new(
+            name,
+            label,
+            ConstraintKind::Reaches {
+                target: target.to_string(),
+                path_expr,
+            },
+        )
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn label(&self) -> &LabelName {
+        &self.label
+    }
+
+    pub fn kind(&self) -> &ConstraintKind {
+        &self.kind
+    }
+
+    /// Prefixes the name of the constraint with a namespace and renames the node types it references
+    pub fn qualified(&self, namespace: &str, f: &impl Fn(&str) -> TypeName) -> Self {
+        let constraint = self.map_refs(f);
+        GraphConstraint::new(
+            &format!("{}::{}", namespace, self.name),
+            &self.label,
+            constraint.kind,
+        )
+    }
+
+    /// Name of the node type referenced by the constraint, if any
+    pub fn type_ref(&self) -> Option<&TypeName> {
+        match &self.kind {
+            ConstraintKind::Reference { type_name, .. } => Some(type_name),
+            _ => None,
+        }
+    }
+
+    /// Renames the node type referenced by the constraint
+    pub fn map_refs(&self, f: &impl Fn(&str) -> TypeName) -> Self {
+        let kind = match &self.kind {
+            ConstraintKind::Reference { key, type_name } => ConstraintKind::Reference {
+                key: key.clone(),
+                type_name: f(type_name),
+            },
+            kind => kind.clone(),
+        };
+        GraphConstraint::new(&self.name, &self.label, kind)
+    }
+
+    /// Checks the constraint against every node with the label, returning one result association per node
+    ///
+    /// Nodes without values for some of the keys of a UNIQUE constraint
+    /// or without values for the key of a REFERENCES constraint are not reported
+    pub fn validate(
+        &self,
+        schema: &PropertyGraphSchema,
+        graph: &PropertyGraph,
+    ) -> Vec<ResultAssociation> {
+        let key_nodes = self.key_values_nodes(graph);
+        let mut results = Vec::new();
+        for node in graph.nodes_with_label(&self.label) {
+            let name = node_name(graph, node);
+            let result: Either<Vec<PgsError>, Vec<Evidence>> = match &self.kind {
+                ConstraintKind::Degree {
+                    direction,
+                    edge_label,
+                    card,
+                } => {
+                    let edges: Box<dyn Iterator<Item = &Edge>> = match direction {
+                        Direction::Outgoing => Box::new(graph.out_edges(&node.id)),
+                        Direction::Incoming => Box::new(graph.in_edges(&node.id)),
+                    };
+                    let count = edges
+                        .filter(|edge| edge.labels().contains(edge_label))
+                        .count();
+                    if card.contains(count) {
+                        Either::Right(vec![Evidence::DegreeConforms {
+                            node: name.clone(),
+                            direction: direction.to_string(),
+                            edge_label: edge_label.clone(),
+                            count,
+                        }])
+                    } else {
+                        Either::Left(vec![PgsError::DegreeMismatch {
+                            node: name.clone(),
+                            direction: direction.to_string(),
+                            edge_label: edge_label.clone(),
+                            expected: card.clone(),
+                            count,
+                        }])
+                    }
+                }
+                ConstraintKind::Unique { keys } | ConstraintKind::NodeKey { keys } => {
+                    match key_values(node, keys) {
+                        None if matches!(self.kind, ConstraintKind::Unique { .. }) => continue,
+                        None => Either::Left(vec![PgsError::MissingKeyValues {
+                            node: name.clone(),
+                            keys: show_keys(keys),
+                        }]),
+                        Some(values) => {
+                            let others = key_nodes[&values]
+                                .iter()
+                                .filter(|other| other.id != node.id)
+                                .map(|other| node_name(graph, other))
+                                .sorted()
+                                .collect::<Vec<_>>();
+                            if others.is_empty() {
+                                Either::Right(vec![Evidence::UniqueKeyValues {
+                                    node: name.clone(),
+                                    keys: show_keys(keys),
+                                    values: show_values(&values),
+                                }])
+                            } else {
+                                Either::Left(vec![PgsError::DuplicateKeyValues {
+                                    node: name.clone(),
+                                    keys: show_keys(keys),
+                                    values: show_values(&values),
+                                    others: others.iter().join(", "),
+                                }])
+                            }
+                        }
+                    }
+                }
+                ConstraintKind::Reference { key, type_name } => {
+                    let values = match node.content().get(key) {
+                        Some(values) if !values.is_empty() => values,
+                        _ => continue,
+                    };
+                    let errors = values
+                        .iter()
+                        .filter_map(|value| {
+                            check_reference(schema, graph, &name, key, value, type_name).err()
+                        })
+                        .collect::<Vec<_>>();
+                    if errors.is_empty() {
+                        Either::Right(vec![Evidence::ReferenceConforms {
+                            node: name.clone(),
+                            key: key.to_string(),
+                            values: values.iter().join(", "),
+                            type_name: type_name.clone(),
+                        }])
+                    } else {
+                        Either::Left(errors)
+                    }
+                }
+                ConstraintKind::Reaches { target, path_expr } => {
+                    match path_expr.reaches(graph, &node.id, target) {
+                        Ok(path) => Either::Right(vec![Evidence::PathFound {
+                            node: name.clone(),
+                            target: target.clone(),
+                            path: path.to_string(),
+                        }]),
+                        Err(partial_path) => Either::Left(vec![PgsError::NotReachable {
+                            node: name.clone(),
+                            target: target.clone(),
+                            path_expr: path_expr.to_string(),
+                            partial_path: partial_path.to_string(),
+                        }]),
+                    }
+                }
+            };
+            results.push(ResultAssociation {
+                node_id: name,
+                type_name: self.name.clone(),
+                conforms: result.is_right(),
+                details: result,
+            });
+        }
+        results.sort_by(|a1, a2| a1.node_id.cmp(&a2.node_id));
+        results
+    }
+
+    /// Nodes with the label grouped by the values of the keys
+    fn key_values_nodes<'a>(
+        &self,
+        graph: &'a PropertyGraph,
+    ) -> HashMap<Vec<Vec<Value>>, Vec<&'a Node>> {
+        let mut nodes: HashMap<Vec<Vec<Value>>, Vec<&Node>> = HashMap::new();
+        if let ConstraintKind::Unique { keys } | ConstraintKind::NodeKey { keys } = &self.kind {
+            for node in graph.nodes_with_label(&self.label) {
+                if let Some(values) = key_values(node, keys) {
+                    nodes.entry(values).or_default().push(node);
+                }
+            }
+        }
+        nodes
+    }
+}
+
+/// Checks that the value is the name of a node which conforms to the type or a subtype,
+/// where only string values can be names of nodes
+fn check_reference(
+    schema: &PropertyGraphSchema,
+    graph: &PropertyGraph,
+    name: &str,
+    key: &Key,
+    value: &Value,
+    type_name: &TypeName,
+) -> Result<(), PgsError> {
+    let target = match value {
+        Value::String(node_name) => graph.get_node_by_label(node_name).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| PgsError::DanglingReference {
+        node: name.to_string(),
+        key: key.to_string(),
+        value: value.to_string(),
+    })?;
+    match schema.conforms_node_or_subtype(type_name, target) {
+        Either::Right(_) => Ok(()),
+        Either::Left(errors) => Err(PgsError::ReferenceTypeMismatch {
+            node: name.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+            type_name: type_name.clone(),
+            errors: errors.iter().join(", "),
+        }),
+    }
+}
+
+fn node_name(graph: &PropertyGraph, node: &Node) -> String {
+    graph
+        .node_name(&node.id)
+        .cloned()
+        .unwrap_or(node.id.to_string())
+}
+
+/// Values of the node for each key, or None if some key has no values.
+///
+/// The values of a key are sorted without repetitions, so nodes with the same values
+/// in another order have the same key values.
+fn key_values(node: &Node, keys: &[Key]) -> Option<Vec<Vec<Value>>> {
+    keys.iter()
+        .map(|key| {
+            node.content()
+                .get(key)
+                .filter(|values| !values.is_empty())
+                .map(|values| {
+                    values
+                        .iter()
+                        .sorted_by(|v1, v2| total_cmp(v1, v2))
+                        .dedup()
+                        .cloned()
+                        .collect()
+                })
+        })
+        .collect()
+}
+
+/// Total order of values by their type and then by their value, as `Ord for Value`
+/// considers equal the values of different types, booleans and records
+fn total_cmp(v1: &Value, v2: &Value) -> Ordering {
+    match (v1, v2) {
+        (Value::Bool(b1), Value::Bool(b2)) => b1.cmp(b2),
+        (Value::Record(r1), Value::Record(r2)) => r1
+            .iter()
+            .zip(r2.iter())
+            .map(|((k1, vs1), (k2, vs2))| k1.cmp(k2).then_with(|| values_cmp(vs1, vs2)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| r1.iter().count().cmp(&r2.iter().count())),
+        _ if type_rank(v1) == type_rank(v2) => v1.cmp(v2),
+        _ => type_rank(v1).cmp(&type_rank(v2)),
+    }
+}
+
+fn values_cmp(vs1: &[Value], vs2: &[Value]) -> Ordering {
+    vs1.iter()
+        .zip(vs2)
+        .map(|(v1, v2)| total_cmp(v1, v2))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| vs1.len().cmp(&vs2.len()))
+}
+
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::String(_) => 0,
+        Value::Integer(_) => 1,
+        Value::Date(_) => 2,
+        Value::Bool(_) => 3,
+        Value::Record(_) => 4,
+    }
+}
+
+fn show_keys(keys: &[Key]) -> String {
+    format!("({})", keys.iter().join(", "))
+}
+
+fn show_values(values: &[Vec<Value>]) -> String {
+    format!(
+        "({})",
+        values.iter().map(|vs| vs.iter().join(" ")).join(", ")
+    )
+}
+
+impl Display for GraphConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} FOR (:{}) ", self.name, self.label)?;
+        match &self.kind {
+            ConstraintKind::Degree {
+                direction,
+                edge_label,
+                card,
+            } => write!(f, "{} :{} {}", direction, edge_label, card),
+            ConstraintKind::Unique { keys } => write!(f, "UNIQUE {}", show_keys(keys)),
+            ConstraintKind::NodeKey { keys } => write!(f, "KEY {}", show_keys(keys)),
+            ConstraintKind::Reference { key, type_name } => {
+                write!(f, "{} REFERENCES @{}", key, type_name)
+            }
+            ConstraintKind::Reaches { target, path_expr } => {
+                write!(f, "REACHES (:{}) VIA {}", target, path_expr)
+            }
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Outgoing => write!(f, "OUTGOING"),
+            Direction::Incoming => write!(f, "INCOMING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::record::Record;
+
+    fn graph(people: &[(&str, Record)]) -> PropertyGraph {
+        let mut graph = PropertyGraph::new();
+        for (name, record) in people {
+            graph
+                .add_node(
+                    name.to_string(),
+                    HashSet::from(["Person".to_string()]),
+                    record.clone(),
+                )
+                .unwrap();
+        }
+        graph
+    }
+
+    fn conforming(constraint: &GraphConstraint, graph: &PropertyGraph) -> Vec<(String, bool)> {
+        constraint
+            .validate(&PropertyGraphSchema::new(), graph)
+            .into_iter()
+            .map(|result| (result.node_id, result.conforms))
+            .collect()
+    }
+
+    #[test]
+    fn test_unique_value_under_different_names() {
+        let email = Record::new().with_key_value("email", Value::str("a@example.org"));
+        let graph = graph(&[
+            ("alice", email.clone()),
+            ("alice_smith", email),
+            (
+                "bob",
+                Record::new().with_key_value("email", Value::str("b@example.org")),
+            ),
+        ]);
+        let unique = GraphConstraint::unique("UniqueEmail", "Person", vec![Key::new("email")]);
+        assert_eq!(
+            conforming(&unique, &graph),
+            vec![
+                ("alice".to_string(), false),
+                ("alice_smith".to_string(), false),
+                ("bob".to_string(), true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unique_values_in_other_order() {
+        let graph = graph(&[
+            (
+                "alice",
+                Record::new()
+                    .with_key_value("email", Value::str("a@example.org"))
+                    .with_key_value("email", Value::str("b@example.org")),
+            ),
+            (
+                "bob",
+                Record::new()
+                    .with_key_value("email", Value::str("b@example.org"))
+                    .with_key_value("email", Value::str("a@example.org")),
+            ),
+        ]);
+        let unique = GraphConstraint::unique("UniqueEmail", "Person", vec![Key::new("email")]);
+        assert_eq!(
+            conforming(&unique, &graph),
+            vec![("alice".to_string(), false), ("bob".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_unique_booleans_and_mixed_types() {
+        let graph = graph(&[
+            (
+                "alice",
+                Record::new()
+                    .with_key_value("flags", Value::true_())
+                    .with_key_value("flags", Value::false_()),
+            ),
+            (
+                "bob",
+                Record::new()
+                    .with_key_value("flags", Value::false_())
+                    .with_key_value("flags", Value::true_()),
+            ),
+            (
+                "carol",
+                Record::new()
+                    .with_key_value("flags", Value::true_())
+                    .with_key_value("flags", Value::true_()),
+            ),
+            (
+                "dave",
+                Record::new()
+                    .with_key_value("flags", Value::int(1))
+                    .with_key_value("flags", Value::str("1")),
+            ),
+            (
+                "erin",
+                Record::new()
+                    .with_key_value("flags", Value::str("1"))
+                    .with_key_value("flags", Value::int(1)),
+            ),
+        ]);
+        let unique = GraphConstraint::unique("UniqueFlags", "Person", vec![Key::new("flags")]);
+        assert_eq!(
+            conforming(&unique, &graph),
+            vec![
+                ("alice".to_string(), false),
+                ("bob".to_string(), false),
+                ("carol".to_string(), true),
+                ("dave".to_string(), false),
+                ("erin".to_string(), false)
+            ]
+        );
+    }
+}
@@ -65,14 +65,44 @@ impl LabelPropertySpec {
         LabelPropertySpec::Content(Box::new(label_property_spec), property_value_spec)
     }
 
+    /// Renames the node types referenced by this spec
+    pub fn map_refs(&self, f: &impl Fn(&str) -> TypeName) -> Self {
+        match self {
+            LabelPropertySpec::Ref(type_name) => LabelPropertySpec::Ref(f(type_name)),
+            LabelPropertySpec::Optional(spec) => LabelPropertySpec::optional(spec.map_refs(f)),
+            LabelPropertySpec::And(left, right) => {
+                LabelPropertySpec::and(left.map_refs(f), right.map_refs(f))
+            }
+            LabelPropertySpec::Or(left, right) => {
+                LabelPropertySpec::or(left.map_refs(f), right.map_refs(f))
+            }
+            LabelPropertySpec::Open(spec) => LabelPropertySpec::open(spec.map_refs(f)),
+            LabelPropertySpec::Content(spec, value_spec) => {
+                LabelPropertySpec::content(spec.map_refs(f), value_spec.clone())
+            }
+            LabelPropertySpec::Empty | LabelPropertySpec::Label(_) => self.clone(),
+        }
+    }
+
+    /// Names of the node types referenced by the spec
+    pub fn refs(&self) -> Vec<&TypeName> {
+        match self {
+            LabelPropertySpec::Ref(type_name) => vec![type_name],
+            LabelPropertySpec::Optional(spec)
+            | LabelPropertySpec::Open(spec)
+            | LabelPropertySpec::Content(spec, _) => spec.refs(),
+            LabelPropertySpec::And(left, right) | LabelPropertySpec::Or(left, right) => {
+                [left.refs(), right.refs()].concat()
+            }
+            LabelPropertySpec::Empty | LabelPropertySpec::Label(_) => Vec::new(),
+        }
+    }
+
     pub fn semantics(&self, schema: &PropertyGraphSchema) -> Result<FormalBaseType, PgsError> {
         match self {
             LabelPropertySpec::Empty => Ok(FormalBaseType::type_0()),
             LabelPropertySpec::Label(label) => Ok(FormalBaseType::from_label(label.clone())),
-            LabelPropertySpec::Ref(type_name) => {
-                let label_property_spec = schema.get_node_semantics(type_name)?;
-                label_property_spec.semantics(schema)
-            }
+            LabelPropertySpec::Ref(type_name) => schema.node_type_semantics(type_name),
             LabelPropertySpec::Optional(label_property_spec) => {
                 let base_type = label_property_spec.semantics(schema)?;
                 Ok(base_type.union(&FormalBaseType::type_0()))
@@ -154,4 +184,23 @@ mod tests {
         );
         assert_eq!(semantics, expected);
     }
+
+    #[test]
+    fn test_semantics_open_content_with_label() {
+        let graph = PropertyGraphSchema::new();
+        let name = PropertyValue::property(Key::new("name"), TypeSpec::string(Card::One));
+        let spec = LabelPropertySpec::content(
+            LabelPropertySpec::Label("Company".to_string()),
+            PropertyValueSpec::open(name),
+        );
+        let semantics = spec.semantics(&graph).unwrap();
+        let expected = FormalBaseType::new()
+            .with_label("Company")
+            .with_record_type(
+                RecordType::new()
+                    .with_key_value("name", ValueType::string(Card::One))
+                    .with_open(),
+            );
+        assert_eq!(semantics, expected);
+    }
 }
@@ -6,19 +6,23 @@ pub mod edge_id;
 pub mod edge_type;
 pub mod evidence;
 pub mod formal_base_type;
+pub mod graph_constraint;
 pub mod key;
 pub mod label_property_spec;
 pub mod node;
 pub mod node_id;
 pub mod parser;
+pub mod path_expr;
 pub mod pg;
 pub mod pgs;
 pub mod pgs_error;
 pub mod property_value_spec;
 pub mod record;
+pub mod record_expr;
 pub mod record_type;
 pub mod type_map;
 pub mod type_name;
 pub mod validation_result;
 pub mod value;
 pub mod value_type;
+pub mod writer;
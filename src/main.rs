@@ -1,7 +1,21 @@
 use anyhow::*;
 use clap::Parser;
-use pgschemapc::cli::{Cli, Command};
-use pgschemapc::parser::{map_builder::MapBuilder, pg_builder::PgBuilder, pgs_builder::PgsBuilder};
+use itertools::Itertools;
+use pgschemapc::cli::{
+    Cli, Command, GraphExportFormat, GraphFormat, SchemaExportFormat, SchemaFormat,
+};
+use pgschemapc::parser::{
+    csv_builder::CsvBuilder, cypher_builder::CypherBuilder, gql_builder::GqlBuilder,
+    graphml_builder::GraphmlBuilder, jsonl_builder::JsonlBuilder, map_builder::MapBuilder,
+    pg_builder::PgBuilder, pg_format_builder::PgFormatBuilder, pg_json_builder::PgJsonBuilder,
+    pgs_resolver::PgsResolver, yarspg_builder::YarsPgBuilder,
+};
+use pgschemapc::writer::{
+    cypher_writer::CypherWriter, gql_writer::GqlWriter, graphml_writer::GraphmlWriter,
+    json_schema_writer::JsonSchemaWriter, neo4j_constraint_writer::Neo4jConstraintWriter,
+    pg_format_writer::PgFormatWriter, pg_json_writer::PgJsonWriter, rdf_writer::RdfWriter,
+    yarspg_writer::YarsPgWriter,
+};
 use std::result::Result::Ok;
 
 // src/main.rs
@@ -16,25 +30,91 @@ fn main() -> Result<()> {
     let cli = Cli::parse_from(args);
 
     match &cli.command {
-        Some(Command::Pgs { schema }) => run_pgs(schema),
-        Some(Command::Pg { graph }) => run_pg(graph),
+        Some(Command::Pgs {
+            schema,
+            schema_format,
+            import_paths,
+            to,
+        }) => run_pgs(schema, schema_format, import_paths, to),
+        Some(Command::Pg {
+            graph,
+            graph_format,
+            to,
+            base,
+        }) => run_pg(graph, graph_format, to, base),
         Some(Command::TypeMap { map }) => run_map(map),
-        Some(Command::Validate { graph, schema, map }) => run_validate(graph, schema, map),
+        Some(Command::Validate {
+            graph,
+            graph_format,
+            schema,
+            schema_format,
+            map,
+            import_paths,
+        }) => run_validate(
+            graph,
+            graph_format,
+            schema,
+            schema_format,
+            map,
+            import_paths,
+        ),
         None => {
             bail!("Command not specified, type `--help` to see list of commands")
         }
     }
 }
 
-fn run_pgs(schema: &str) -> Result<()> {
-    let schema = get_schema(schema)?;
-    println!("Property graph schema: {}", schema);
+fn run_pgs(
+    schema_path: &str,
+    schema_format: &SchemaFormat,
+    import_paths: &[String],
+    to: &Option<SchemaExportFormat>,
+) -> Result<()> {
+    let schema = get_schema(schema_path, schema_format, import_paths)?;
+    match to {
+        None => println!("Property graph schema: {}", schema),
+        Some(SchemaExportFormat::Neo4j) => print!(
+            "{}",
+            Neo4jConstraintWriter::new().write_constraints(&schema)?
+        ),
+        Some(SchemaExportFormat::Gql) => {
+            let name = std::path::Path::new(schema_path)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            print!("{}", GqlWriter::new().write_graph_type(&name, &schema)?)
+        }
+        Some(SchemaExportFormat::JsonSchema) => {
+            println!("{}", JsonSchemaWriter::new().write_json_schema(&schema)?)
+        }
+    }
     Ok(())
 }
 
-fn run_pg(graph: &str) -> Result<()> {
-    let pg = get_graph(graph)?;
-    println!("Property graph: {}", pg);
+fn run_pg(
+    graph: &str,
+    graph_format: &GraphFormat,
+    to: &Option<GraphExportFormat>,
+    base: &str,
+) -> Result<()> {
+    let pg = get_graph(graph, graph_format)?;
+    match to {
+        None => println!("Property graph: {}", pg),
+        Some(GraphExportFormat::Graphml) => print!("{}", GraphmlWriter::new().write_graphml(&pg)?),
+        Some(GraphExportFormat::PgFormat) => {
+            print!("{}", PgFormatWriter::new().write_pg_format(&pg)?)
+        }
+        Some(GraphExportFormat::PgJson) => println!("{}", PgJsonWriter::new().write_pg_json(&pg)?),
+        Some(GraphExportFormat::Yarspg) => print!("{}", YarsPgWriter::new().write_yarspg(&pg)?),
+        Some(GraphExportFormat::Cypher) => print!("{}", CypherWriter::new().write_cypher(&pg)?),
+        Some(GraphExportFormat::Gql) => print!("{}", CypherWriter::new().write_gql(&pg)?),
+        Some(GraphExportFormat::Turtle) => {
+            print!("{}", RdfWriter::new().with_base(base).write_turtle(&pg)?)
+        }
+        Some(GraphExportFormat::Ntriples) => {
+            print!("{}", RdfWriter::new().with_base(base).write_ntriples(&pg)?)
+        }
+    }
     Ok(())
 }
 
@@ -44,38 +124,113 @@ fn run_map(map: &str) -> Result<()> {
     Ok(())
 }
 
-fn run_validate(graph_path: &str, schema_path: &str, map_path: &str) -> Result<()> {
-    let schema = get_schema(schema_path)?;
-    let graph = get_graph(graph_path)?;
+fn run_validate(
+    graph_path: &str,
+    graph_format: &GraphFormat,
+    schema_path: &str,
+    schema_format: &SchemaFormat,
+    map_path: &str,
+    import_paths: &[String],
+) -> Result<()> {
+    let schema = get_schema(schema_path, schema_format, import_paths)?;
+    let graph = get_graph(graph_path, graph_format)?;
     let map = get_map(map_path)?;
     let result = map.validate(&schema, &graph)?;
     println!("Validation result: {}", result);
     Ok(())
 }
 
-fn get_schema(path: &str) -> Result<pgschemapc::pgs::PropertyGraphSchema> {
+fn get_schema(
+    path: &str,
+    schema_format: &SchemaFormat,
+    import_paths: &[String],
+) -> Result<pgschemapc::pgs::PropertyGraphSchema> {
+    match schema_format {
+        SchemaFormat::Pgs => get_pgs_schema(path, import_paths),
+        SchemaFormat::Gql => get_gql_schema(path, import_paths),
+    }
+}
+
+fn get_pgs_schema(
+    path: &str,
+    import_paths: &[String],
+) -> Result<pgschemapc::pgs::PropertyGraphSchema> {
+    let mut resolver = import_paths
+        .iter()
+        .fold(PgsResolver::new(), |resolver, import_path| {
+            resolver.with_search_path(import_path)
+        });
+    let schema: pgschemapc::pgs::PropertyGraphSchema = match resolver.resolve_path(path) {
+        Ok(schema) => schema,
+        Err(e) => {
+            bail!("Failed to parse schema: {}", e);
+        }
+    };
+    Ok(schema)
+}
+
+fn get_gql_schema(
+    path: &str,
+    import_paths: &[String],
+) -> Result<pgschemapc::pgs::PropertyGraphSchema> {
+    if !import_paths.is_empty() {
+        bail!("GQL schemas don't import other schemas, --import-path only applies to PGS schemas");
+    }
     let schema_content = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read schema file: {}", path))?;
-    let schema: pgschemapc::pgs::PropertyGraphSchema =
-        match PgsBuilder::new().parse_pgs(schema_content.as_str()) {
-            Ok(schema) => schema,
-            Err(e) => {
-                bail!("Failed to parse schema: {}", e);
-            }
-        };
+    let schema = match GqlBuilder::new().parse_gql(schema_content.as_str()) {
+        Ok(schema) => schema,
+        Err(e) => {
+            bail!("Failed to parse schema: {}", e);
+        }
+    };
     Ok(schema)
 }
 
-fn get_graph(path: &str) -> Result<pgschemapc::pg::PropertyGraph> {
-    let graph_content = std::fs::read_to_string(path)
-        .with_context(|| format!("Failed to read graph file: {}", path))?;
-    let graph = match PgBuilder::new().parse_pg(graph_content.as_str()) {
-        Ok(graph) => graph,
+/// Reads and parses the graph at `path` using the builder selected by `--graph-format`
+fn get_graph(path: &str, graph_format: &GraphFormat) -> Result<pgschemapc::pg::PropertyGraph> {
+    let graph = match graph_format {
+        GraphFormat::Csv => CsvBuilder::new().parse_csv(&read_csv_files(path)?),
+        GraphFormat::Pg => PgBuilder::new().parse_pg(&read_graph_file(path)?),
+        GraphFormat::Graphml => GraphmlBuilder::new().parse_graphml(&read_graph_file(path)?),
+        GraphFormat::Jsonl => JsonlBuilder::new().parse_jsonl(&read_graph_file(path)?),
+        GraphFormat::PgFormat => PgFormatBuilder::new().parse_pg_format(&read_graph_file(path)?),
+        GraphFormat::PgJson => PgJsonBuilder::new().parse_pg_json(&read_graph_file(path)?),
+        GraphFormat::Yarspg => YarsPgBuilder::new().parse_yarspg(&read_graph_file(path)?),
+        GraphFormat::Cypher => CypherBuilder::new().parse_cypher(&read_graph_file(path)?),
+    };
+    match graph {
+        Ok(graph) => Ok(graph),
         Err(e) => {
             bail!("Failed to parse graph: {}", e);
         }
+    }
+}
+
+fn read_graph_file(path: &str) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read graph file: {}", path))
+}
+
+/// Reads a CSV file or all the CSV files in a directory
+fn read_csv_files(path: &str) -> Result<Vec<String>> {
+    let path = std::path::Path::new(path);
+    let files = if path.is_dir() {
+        std::fs::read_dir(path)
+            .with_context(|| format!("Failed to read graph directory: {}", path.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|file| file.extension().is_some_and(|ext| ext == "csv"))
+            .sorted()
+            .collect()
+    } else {
+        vec![path.to_path_buf()]
     };
-    Ok(graph)
+    files
+        .iter()
+        .map(|file| {
+            std::fs::read_to_string(file)
+                .with_context(|| format!("Failed to read graph file: {}", file.display()))
+        })
+        .collect()
 }
 
 fn get_map(path: &str) -> Result<pgschemapc::type_map::TypeMap> {
@@ -220,9 +375,296 @@ mod tests {
         );
     }
 
+    #[test]
+    fn collection() {
+        // It checks aggregate conditions over multi-valued properties
+        test_case(
+            "tests/collection.pg",
+            "tests/collection.pgs",
+            "tests/collection.map",
+            "tests/collection.result_map",
+        );
+    }
+
+    #[test]
+    fn list() {
+        // It checks ordered lists and multisets with repeated values and sets without them
+        test_case(
+            "tests/list.pg",
+            "tests/list.pgs",
+            "tests/list.map",
+            "tests/list.result_map",
+        );
+    }
+
+    #[test]
+    fn nested() {
+        // It checks nested records
+        test_case(
+            "tests/nested.pg",
+            "tests/nested.pgs",
+            "tests/nested.map",
+            "tests/nested.result_map",
+        );
+    }
+
+    #[test]
+    fn record_check() {
+        // It checks conditions between properties of a record
+        test_case(
+            "tests/record_check.pg",
+            "tests/record_check.pgs",
+            "tests/record_check.map",
+            "tests/record_check.result_map",
+        );
+    }
+
+    #[test]
+    fn edge_check() {
+        // It checks conditions between optional properties of edges
+        test_case(
+            "tests/edge_check.pg",
+            "tests/edge_check.pgs",
+            "tests/edge_check.map",
+            "tests/edge_check.result_map",
+        );
+    }
+
+    #[test]
+    fn conditional() {
+        // It checks conditional and exclusive properties
+        test_case(
+            "tests/conditional.pg",
+            "tests/conditional.pgs",
+            "tests/conditional.map",
+            "tests/conditional.result_map",
+        );
+    }
+
+    #[test]
+    fn degree() {
+        // It checks degree constraints over the edges of each node
+        test_case(
+            "tests/degree.pg",
+            "tests/degree.pgs",
+            "tests/degree.map",
+            "tests/degree.result_map",
+        );
+    }
+
+    #[test]
+    fn keys() {
+        // It checks unique values and keys across the nodes with a label
+        test_case(
+            "tests/keys.pg",
+            "tests/keys.pgs",
+            "tests/keys.map",
+            "tests/keys.result_map",
+        );
+    }
+
+    #[test]
+    fn references() {
+        // It checks property values that refer to other nodes of some type
+        test_case(
+            "tests/references.pg",
+            "tests/references.pgs",
+            "tests/references.map",
+            "tests/references.result_map",
+        );
+    }
+
+    #[test]
+    fn paths() {
+        // It checks that nodes reach other nodes through regular path expressions
+        test_case(
+            "tests/paths.pg",
+            "tests/paths.pgs",
+            "tests/paths.map",
+            "tests/paths.result_map",
+        );
+    }
+
+    #[test]
+    fn endpoints() {
+        // It checks the source and target nodes of edges against named node types
+        test_case(
+            "tests/endpoints.pg",
+            "tests/endpoints.pgs",
+            "tests/endpoints.map",
+            "tests/endpoints.result_map",
+        );
+    }
+
+    #[test]
+    fn inheritance() {
+        // It checks abstract node types and types that extend other types
+        test_case(
+            "tests/inheritance.pg",
+            "tests/inheritance.pgs",
+            "tests/inheritance.map",
+            "tests/inheritance.result_map",
+        );
+    }
+
+    #[test]
+    fn imports() {
+        // It checks schemas that import types from other schemas
+        test_case(
+            "tests/imports.pg",
+            "tests/imports.pgs",
+            "tests/imports.map",
+            "tests/imports.result_map",
+        );
+    }
+
+    #[test]
+    fn namespaces() {
+        // It checks types with the same name imported from schemas in different namespaces
+        test_case(
+            "tests/namespaces.pg",
+            "tests/namespaces.pgs",
+            "tests/namespaces.map",
+            "tests/namespaces.result_map",
+        );
+    }
+
+    #[test]
+    fn csv() {
+        // It checks property graphs loaded from Neo4j import CSV files
+        test_case_with_format(
+            "tests/csv",
+            &GraphFormat::Csv,
+            "tests/csv.pgs",
+            "tests/csv.map",
+            "tests/csv.result_map",
+        );
+    }
+
+    #[test]
+    fn graphml() {
+        // It checks property graphs loaded from GraphML documents
+        test_case_with_format(
+            "tests/graphml.graphml",
+            &GraphFormat::Graphml,
+            "tests/graphml.pgs",
+            "tests/graphml.map",
+            "tests/graphml.result_map",
+        );
+    }
+
+    #[test]
+    fn jsonl() {
+        // It checks property graphs loaded from JSON Lines
+        test_case_with_format(
+            "tests/jsonl.jsonl",
+            &GraphFormat::Jsonl,
+            "tests/jsonl.pgs",
+            "tests/jsonl.map",
+            "tests/jsonl.result_map",
+        );
+    }
+
+    #[test]
+    fn pg_format() {
+        // It checks property graphs loaded from the PG format
+        test_case_with_format(
+            "tests/pg_format.pgf",
+            &GraphFormat::PgFormat,
+            "tests/graph_formats.pgs",
+            "tests/graph_formats.map",
+            "tests/graph_formats.result_map",
+        );
+    }
+
+    #[test]
+    fn pg_json() {
+        // It checks property graphs loaded from PG-JSON
+        test_case_with_format(
+            "tests/pg_json.json",
+            &GraphFormat::PgJson,
+            "tests/graph_formats.pgs",
+            "tests/graph_formats.map",
+            "tests/graph_formats.result_map",
+        );
+    }
+
+    #[test]
+    fn yarspg() {
+        // It checks property graphs loaded from YARS-PG
+        test_case_with_format(
+            "tests/yarspg.yarspg",
+            &GraphFormat::Yarspg,
+            "tests/graph_formats.pgs",
+            "tests/graph_formats.map",
+            "tests/graph_formats.result_map",
+        );
+    }
+
+    #[test]
+    fn cypher() {
+        // It checks property graphs loaded from Cypher CREATE and MERGE scripts
+        test_case_with_format(
+            "tests/cypher.cypher",
+            &GraphFormat::Cypher,
+            "tests/graph_formats.pgs",
+            "tests/graph_formats.map",
+            "tests/graph_formats.result_map",
+        );
+    }
+
+    #[test]
+    fn gql() {
+        // It checks schemas loaded from ISO GQL graph types
+        test_case_with_formats(
+            "tests/pg_format.pgf",
+            &GraphFormat::PgFormat,
+            "tests/graph_formats.gql",
+            &SchemaFormat::Gql,
+            "tests/graph_formats.map",
+            "tests/graph_formats.result_map",
+        );
+    }
+
     fn test_case(pg_file: &str, pgs_file: &str, map_file: &str, expected_map_file: &str) {
-        let pg = get_graph(pg_file).expect(format!("Failed to parse: {pg_file})").as_str());
-        let pgs = get_schema(pgs_file).expect(format!("Failed to parse: {pgs_file})").as_str());
+        test_case_with_format(
+            pg_file,
+            &GraphFormat::Pg,
+            pgs_file,
+            map_file,
+            expected_map_file,
+        );
+    }
+
+    fn test_case_with_format(
+        pg_file: &str,
+        graph_format: &GraphFormat,
+        pgs_file: &str,
+        map_file: &str,
+        expected_map_file: &str,
+    ) {
+        test_case_with_formats(
+            pg_file,
+            graph_format,
+            pgs_file,
+            &SchemaFormat::Pgs,
+            map_file,
+            expected_map_file,
+        );
+    }
+
+    fn test_case_with_formats(
+        pg_file: &str,
+        graph_format: &GraphFormat,
+        pgs_file: &str,
+        schema_format: &SchemaFormat,
+        map_file: &str,
+        expected_map_file: &str,
+    ) {
+        let pg = get_graph(pg_file, graph_format)
+            .expect(format!("Failed to parse: {pg_file})").as_str());
+        let pgs = get_schema(pgs_file, schema_format, &[])
+            .expect(format!("Failed to parse: {pgs_file})").as_str());
         let type_map = get_map(map_file).expect(format!("Failed to parse: {map_file})").as_str());
         let expected_result = get_map(expected_map_file)
             .expect(format!("Failed to parse: {expected_map_file})").as_str());
@@ -0,0 +1,306 @@
+use std::collections::HashSet;
+
+use csv::{ReaderBuilder, StringRecord};
+
+use crate::{
+    key::Key, pg::PropertyGraph, pgs_error::PgsError, record::Record, type_name::LabelName,
+    value::Value,
+};
+
+/// Separator of labels and of the values of array columns
+const ARRAY_DELIMITER: char = ';';
+
+/// Builds property graphs from the CSV files used by the Neo4j import tool.
+///
+/// Node files have an `:ID` column and optionally a `:LABEL` column, relationship files
+/// have `:START_ID`, `:END_ID` and `:TYPE` columns. The other columns are properties
+/// declared as `name:type`, where the type is `string` by default and `type[]` declares
+/// arrays whose values are separated by `;`. Relationships get the names `e1`, `e2`, ...
+/// in the order they are read.
+pub struct CsvBuilder {}
+
+impl Default for CsvBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvBuilder {
+    pub fn new() -> Self {
+        CsvBuilder {}
+    }
+
+    /// Parses the contents of node and relationship files, all nodes are added before the relationships
+    pub fn parse_csv(&self, inputs: &[String]) -> Result<PropertyGraph, PgsError> {
+        let mut nodes = Vec::new();
+        let mut relationships = Vec::new();
+        for input in inputs {
+            let mut reader = ReaderBuilder::new().from_reader(input.as_bytes());
+            let header = reader.headers().map_err(read_error)?;
+            let columns = header
+                .iter()
+                .map(Column::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+            if columns.contains(&Column::StartId) {
+                relationships.push((columns, reader));
+            } else {
+                nodes.push((columns, reader));
+            }
+        }
+        let mut pg = PropertyGraph::new();
+        for (columns, mut reader) in nodes {
+            for row in reader.records() {
+                let row = row.map_err(read_error)?;
+                add_node(&columns, &row, &mut pg)?;
+            }
+        }
+        let mut edge_counter = 0;
+        for (columns, mut reader) in relationships {
+            for row in reader.records() {
+                let row = row.map_err(read_error)?;
+                edge_counter += 1;
+                add_edge(&columns, &row, format!("e{}", edge_counter), &mut pg)?;
+            }
+        }
+        Ok(pg)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Column {
+    /// Identifier of a node, which is also stored as a property if the column has a name
+    Id(Option<Key>),
+    Label,
+    StartId,
+    EndId,
+    Type,
+    Ignore,
+    Property {
+        key: Key,
+        csv_type: CsvType,
+        array: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CsvType {
+    String,
+    Integer,
+    Boolean,
+    Date,
+}
+
+impl Column {
+    fn parse(field: &str) -> Result<Column, PgsError> {
+        let (name, kind) = match field.rsplit_once(':') {
+            Some((name, kind)) => (name.trim(), kind.trim()),
+            None => (field.trim(), "string"),
+        };
+        // ID spaces, as in :ID(Person), are not distinguished
+        let kind = kind.split_once('(').map_or(kind, |(kind, _)| kind);
+        let (kind, array) = match kind.strip_suffix("[]") {
+            Some(kind) => (kind, true),
+            None => (kind, false),
+        };
+        let column = match kind.to_uppercase().as_str() {
+            "ID" if name.is_empty() => Column::Id(None),
+            "ID" => Column::Id(Some(Key::new(name))),
+            "LABEL" => Column::Label,
+            "START_ID" => Column::StartId,
+            "END_ID" => Column::EndId,
+            "TYPE" => Column::Type,
+            "IGNORE" => Column::Ignore,
+            other => {
+                let csv_type = match other {
+                    "STRING" | "CHAR" => CsvType::String,
+                    "INT" | "LONG" | "SHORT" | "BYTE" => CsvType::Integer,
+                    "BOOLEAN" => CsvType::Boolean,
+                    "DATE" => CsvType::Date,
+                    _ => {
+                        return Err(csv_error(
+                            1,
+                            format!("unsupported type {} in column {}", kind, field),
+                        ));
+                    }
+                };
+                Column::Property {
+                    key: Key::new(name),
+                    csv_type,
+                    array,
+                }
+            }
+        };
+        Ok(column)
+    }
+}
+
+fn add_node(
+    columns: &[Column],
+    row: &StringRecord,
+    pg: &mut PropertyGraph,
+) -> Result<(), PgsError> {
+    let line = line(row);
+    let mut id = None;
+    let mut labels = HashSet::new();
+    let mut record = Record::new();
+    for (column, field) in columns.iter().zip(row.iter()) {
+        match column {
+            Column::Id(key) => {
+                id = Some(field.to_string());
+                if let Some(key) = key {
+                    record.insert(key.clone(), Value::str(field));
+                }
+            }
+            Column::Label => labels.extend(split_labels(field)),
+            _ => add_property(column, field, line, &mut record)?,
+        }
+    }
+    let id = id.ok_or(csv_error(line, "missing :ID column"))?;
+    pg.add_node(id, labels, record)
+        .map_err(|e| csv_error(line, e))
+}
+
+fn add_edge(
+    columns: &[Column],
+    row: &StringRecord,
+    name: String,
+    pg: &mut PropertyGraph,
+) -> Result<(), PgsError> {
+    let line = line(row);
+    let mut source = None;
+    let mut target = None;
+    let mut labels = HashSet::new();
+    let mut record = Record::new();
+    for (column, field) in columns.iter().zip(row.iter()) {
+        match column {
+            Column::StartId => source = Some(field.to_string()),
+            Column::EndId => target = Some(field.to_string()),
+            Column::Type => labels.extend(split_labels(field)),
+            _ => add_property(column, field, line, &mut record)?,
+        }
+    }
+    let source = source.ok_or(csv_error(line, "missing :START_ID column"))?;
+    let target = target.ok_or(csv_error(line, "missing :END_ID column"))?;
+    pg.add_edge(name, source, labels, record, target)
+}
+
+/// Empty fields are properties without value
+fn add_property(
+    column: &Column,
+    field: &str,
+    line: u64,
+    record: &mut Record,
+) -> Result<(), PgsError> {
+    let Column::Property {
+        key,
+        csv_type,
+        array,
+    } = column
+    else {
+        return Ok(());
+    };
+    if field.is_empty() {
+        return Ok(());
+    }
+    let values: Vec<&str> = if *array {
+        field.split(ARRAY_DELIMITER).collect()
+    } else {
+        vec![field]
+    };
+    for value in values {
+        record.insert(key.clone(), get_value(csv_type, value, line)?);
+    }
+    Ok(())
+}
+
+fn get_value(csv_type: &CsvType, field: &str, line: u64) -> Result<Value, PgsError> {
+    match csv_type {
+        CsvType::String => Ok(Value::str(field)),
+        CsvType::Integer => field
+            .trim()
+            .parse()
+            .map(Value::int)
+            .map_err(|e| csv_error(line, format!("invalid integer {}: {}", field, e))),
+        CsvType::Boolean => match field.trim().to_lowercase().as_str() {
+            "true" => Ok(Value::true_()),
+            "false" => Ok(Value::false_()),
+            _ => Err(csv_error(line, format!("invalid boolean {}", field))),
+        },
+        CsvType::Date => Value::date(field.trim()),
+    }
+}
+
+fn split_labels(field: &str) -> impl Iterator<Item = LabelName> + '_ {
+    field
+        .split(ARRAY_DELIMITER)
+        .map(|label| label.trim())
+        .filter(|label| !label.is_empty())
+        .map(|label| label.to_string())
+}
+
+fn line(row: &StringRecord) -> u64 {
+    row.position().map_or(0, |position| position.line())
+}
+
+fn read_error(error: csv::Error) -> PgsError {
+    let line = error.position().map_or(0, |position| position.line());
+    csv_error(line, error)
+}
+
+fn csv_error(line: u64, error: impl ToString) -> PgsError {
+    PgsError::CsvError {
+        line,
+        error: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nodes_and_relationships() {
+        let nodes = "personId:ID,name,age:int,tags:string[],:LABEL\n\
+                     p1,Alice,23,a;b,Person;Student\n\
+                     p2,Bob,,,Person\n";
+        let relationships = ":START_ID,:END_ID,:TYPE,since:date\np1,p2,knows,2020-01-01\n";
+        let pg = CsvBuilder::new()
+            .parse_csv(&[relationships.to_string(), nodes.to_string()])
+            .unwrap();
+        let alice = pg.get_node_by_label("p1").unwrap();
+        assert_eq!(
+            alice.labels(),
+            &HashSet::from(["Person".to_string(), "Student".to_string()])
+        );
+        assert_eq!(
+            alice.content().get(&Key::new("tags")),
+            Some(&vec![Value::str("a"), Value::str("b")])
+        );
+        assert_eq!(
+            alice.content().get(&Key::new("age")),
+            Some(&vec![Value::int(23)])
+        );
+        let bob = pg.get_node_by_label("p2").unwrap();
+        assert_eq!(bob.content().get(&Key::new("age")), None);
+        let knows = pg.get_edge_by_label("e1").unwrap();
+        assert_eq!(knows.source, alice.id);
+        assert_eq!(
+            knows.content().get(&Key::new("since")),
+            Some(&vec![Value::date("2020-01-01").unwrap()])
+        );
+    }
+
+    #[test]
+    fn test_invalid_integer() {
+        let nodes = ":ID,age:int\np1,old\n";
+        let result = CsvBuilder::new().parse_csv(&[nodes.to_string()]);
+        assert!(matches!(result, Err(PgsError::CsvError { line: 2, .. })));
+    }
+
+    #[test]
+    fn test_duplicate_id() {
+        let nodes = ":ID,name\np1,Alice\np1,Bob\n";
+        let result = CsvBuilder::new().parse_csv(&[nodes.to_string()]);
+        assert!(matches!(result, Err(PgsError::CsvError { line: 3, .. })));
+    }
+}
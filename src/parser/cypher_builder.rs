@@ -0,0 +1,581 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    key::Key,
+    pg::{PropertyGraph, complete_names},
+    pgs_error::PgsError,
+    record::Record,
+    type_name::LabelName,
+    value::Value,
+};
+
+/// Builds property graphs from the `CREATE` and `MERGE` clauses of Cypher scripts, as in
+/// `CREATE (a:Person {name: 'Alice'})-[:KNOWS {since: 2020}]->(b:Person)`.
+///
+/// Variables are local to the statements separated by `;`. Nodes and relationships are
+/// named by the variable that first declares them, and the others get the names `n1`,
+/// `n2`, ... and `e1`, `e2`, ... which are not the names of other elements. Lists are
+/// multi-valued properties, maps are nested records, `date('2020-01-01')` gives dates and
+/// `null` values are missing properties, while empty lists are properties without values.
+/// `MERGE` reuses the nodes of its variables or the first node with its labels and
+/// properties, and doesn't repeat existing relationships.
+pub struct CypherBuilder {}
+
+impl Default for CypherBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CypherBuilder {
+    pub fn new() -> Self {
+        CypherBuilder {}
+    }
+
+    pub fn parse_cypher(&self, input: &str) -> Result<PropertyGraph, PgsError> {
+        let mut parser = CypherParser {
+            chars: input.chars().collect(),
+            pos: 0,
+            line: 1,
+        };
+        let mut elements = Elements::default();
+        parser.skip_layout();
+        while !parser.at_end() {
+            let result = parser.clause(&mut elements);
+            result.map_err(|e| cypher_error(parser.line, e))?;
+            parser.skip_layout();
+        }
+        let mut pg = PropertyGraph::new();
+        let node_names =
+            element_names(elements.nodes.iter().map(|node| node.variable.clone()), "n");
+        for (node, name) in elements.nodes.into_iter().zip(&node_names) {
+            pg.add_node(name.clone(), node.labels, node.record)?;
+        }
+        let edge_names =
+            element_names(elements.edges.iter().map(|edge| edge.variable.clone()), "e");
+        for (edge, name) in elements.edges.into_iter().zip(edge_names) {
+            pg.add_edge(
+                name,
+                node_names[edge.source].clone(),
+                edge.labels,
+                edge.record,
+                node_names[edge.target].clone(),
+            )?;
+        }
+        Ok(pg)
+    }
+}
+
+/// Names of the elements, where a variable names the first element it declares
+fn element_names(variables: impl Iterator<Item = Option<String>>, prefix: &str) -> Vec<String> {
+    let mut declared = HashSet::new();
+    let variables = variables
+        .map(|variable| variable.filter(|variable| declared.insert(variable.clone())))
+        .collect();
+    complete_names(variables, prefix)
+}
+
+/// Nodes and relationships in the order they are created
+#[derive(Default)]
+struct Elements {
+    nodes: Vec<CypherNode>,
+    edges: Vec<CypherEdge>,
+    /// Variables of the current statement
+    variables: HashMap<String, Variable>,
+}
+
+enum Variable {
+    Node(usize),
+    Edge,
+}
+
+struct CypherNode {
+    variable: Option<String>,
+    labels: HashSet<LabelName>,
+    record: Record,
+}
+
+struct CypherEdge {
+    variable: Option<String>,
+    source: usize,
+    labels: HashSet<LabelName>,
+    record: Record,
+    target: usize,
+}
+
+struct CypherParser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl CypherParser {
+    fn clause(&mut self, elements: &mut Elements) -> Result<(), String> {
+        if self.peek() == Some(';') {
+            self.pos += 1;
+            elements.variables.clear();
+            return Ok(());
+        }
+        let keyword = self.name()?;
+        let merge = match keyword.to_uppercase().as_str() {
+            "CREATE" => false,
+            "MERGE" => true,
+            _ => return Err(format!("unsupported clause {}", keyword)),
+        };
+        loop {
+            self.pattern(elements, merge)?;
+            self.skip_layout();
+            if self.peek() == Some(',') {
+                self.pos += 1;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    /// A path of nodes connected by relationships
+    fn pattern(&mut self, elements: &mut Elements, merge: bool) -> Result<(), String> {
+        let mut previous = self.node(elements, merge)?;
+        loop {
+            self.skip_layout();
+            if !matches!(self.peek(), Some('-' | '<')) {
+                return Ok(());
+            }
+            let incoming = self.peek() == Some('<');
+            if incoming {
+                self.pos += 1;
+            }
+            self.expect('-')?;
+            self.skip_layout();
+            let (variable, labels, record) = if self.peek() == Some('[') {
+                self.pos += 1;
+                let (variable, labels, record) = self.variable_labels_record()?;
+                self.expect(']')?;
+                (variable, labels, record)
+            } else {
+                (None, HashSet::new(), Record::new())
+            };
+            self.expect('-')?;
+            let outgoing = self.peek() == Some('>');
+            if outgoing {
+                self.pos += 1;
+            }
+            if incoming && outgoing {
+                return Err("relationship with both directions".to_string());
+            }
+            let next = self.node(elements, merge)?;
+            let (source, target) = if incoming {
+                (next, previous)
+            } else {
+                (previous, next)
+            };
+            if let Some(variable) = &variable
+                && elements
+                    .variables
+                    .insert(variable.clone(), Variable::Edge)
+                    .is_some()
+            {
+                return Err(format!("variable {} already declared", variable));
+            }
+            let edge = CypherEdge {
+                variable,
+                source,
+                labels,
+                record,
+                target,
+            };
+            let exists = elements.edges.iter().any(|other| {
+                other.source == edge.source
+                    && other.target == edge.target
+                    && other.labels == edge.labels
+                    && other.record == edge.record
+            });
+            if !(merge && exists) {
+                elements.edges.push(edge);
+            }
+            previous = next;
+        }
+    }
+
+    /// Parses a node pattern and returns the index of its node
+    fn node(&mut self, elements: &mut Elements, merge: bool) -> Result<usize, String> {
+        self.expect('(')?;
+        let (variable, labels, record) = self.variable_labels_record()?;
+        self.expect(')')?;
+        let declared = variable
+            .as_ref()
+            .and_then(|variable| elements.variables.get(variable));
+        match declared {
+            Some(Variable::Edge) => Err(format!(
+                "variable {} already declared",
+                variable.unwrap_or_default()
+            )),
+            Some(Variable::Node(index)) => {
+                let index = *index;
+                if !labels.is_empty() || record.iter().next().is_some() {
+                    if !merge {
+                        return Err(format!(
+                            "variable {} already declared",
+                            variable.unwrap_or_default()
+                        ));
+                    }
+                    let node = &mut elements.nodes[index];
+                    node.labels.extend(labels);
+                    for (key, values) in record.iter() {
+                        if node.record.get(key).is_none() {
+                            node.record.insert_values(key.clone(), values.clone());
+                        }
+                    }
+                }
+                Ok(index)
+            }
+            None => {
+                let existing = if merge {
+                    elements.nodes.iter().position(|node| {
+                        labels.is_subset(&node.labels)
+                            && record
+                                .iter()
+                                .all(|(key, values)| node.record.get(key) == Some(values))
+                    })
+                } else {
+                    None
+                };
+                let index = existing.unwrap_or_else(|| {
+                    elements.nodes.push(CypherNode {
+                        variable: variable.clone(),
+                        labels,
+                        record,
+                    });
+                    elements.nodes.len() - 1
+                });
+                if let Some(variable) = variable {
+                    elements.variables.insert(variable, Variable::Node(index));
+                }
+                Ok(index)
+            }
+        }
+    }
+
+    /// Contents of node and relationship patterns, as in `a:Person:Student {name: 'Alice'}`
+    fn variable_labels_record(
+        &mut self,
+    ) -> Result<(Option<String>, HashSet<LabelName>, Record), String> {
+        self.skip_layout();
+        let variable = if self.peek().is_some_and(|c| c == '`' || is_name_char(c)) {
+            Some(self.name()?)
+        } else {
+            None
+        };
+        let mut labels = HashSet::new();
+        self.skip_layout();
+        while self.peek() == Some(':') {
+            self.pos += 1;
+            labels.insert(self.name()?);
+            self.skip_layout();
+        }
+        let record = if self.peek() == Some('{') {
+            self.map()?
+        } else {
+            Record::new()
+        };
+        Ok((variable, labels, record))
+    }
+
+    fn map(&mut self) -> Result<Record, String> {
+        let mut record = Record::new();
+        self.expect('{')?;
+        let properties = self.separated('}', |parser| {
+            let key = parser.name()?;
+            parser.expect(':')?;
+            Ok((key, parser.values()?))
+        })?;
+        for (key, values) in properties {
+            if let Some(values) = values {
+                record.insert_values(Key::new(&key), values);
+            }
+        }
+        Ok(record)
+    }
+
+    /// Values of an expression, where lists give several values and `null` gives `None`
+    fn values(&mut self) -> Result<Option<Vec<Value>>, String> {
+        self.skip_layout();
+        match self.peek() {
+            Some('[') => {
+                self.pos += 1;
+                let values = self.separated(']', |parser| parser.values())?;
+                Ok(Some(values.into_iter().flatten().flatten().collect()))
+            }
+            Some('{') => Ok(Some(vec![Value::record(self.map()?)])),
+            Some('\'' | '"') => Ok(Some(vec![Value::str(&self.string()?)])),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let start = self.pos;
+                self.pos += 1;
+                while self
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || c == '.')
+                {
+                    self.pos += 1;
+                }
+                let number: String = self.chars[start..self.pos].iter().collect();
+                number
+                    .parse()
+                    .map(|n| Some(vec![Value::int(n)]))
+                    .map_err(|_| format!("unsupported number {}", number))
+            }
+            _ => {
+                let name = self.name()?;
+                match name.to_lowercase().as_str() {
+                    "true" => Ok(Some(vec![Value::true_()])),
+                    "false" => Ok(Some(vec![Value::false_()])),
+                    "null" => Ok(None),
+                    "date" => {
+                        self.expect('(')?;
+                        let date = self.string()?;
+                        self.expect(')')?;
+                        Value::date(&date)
+                            .map(|date| Some(vec![date]))
+                            .map_err(|e| e.to_string())
+                    }
+                    _ => Err(format!("unsupported expression {}", name)),
+                }
+            }
+        }
+    }
+
+    /// Items separated by commas until the closing character
+    fn separated<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let mut items = Vec::new();
+        self.skip_layout();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_layout();
+            match self.next() {
+                Some(',') => {}
+                Some(c) if c == close => return Ok(items),
+                other => return Err(format!("expected , or {} but found {:?}", close, other)),
+            }
+        }
+    }
+
+    /// Single or double quoted strings with backslash escapes
+    fn string(&mut self) -> Result<String, String> {
+        self.skip_layout();
+        let quote = match self.next() {
+            Some(c @ ('\'' | '"')) => c,
+            other => return Err(format!("expected string but found {:?}", other)),
+        };
+        let mut s = String::new();
+        while let Some(c) = self.next() {
+            match c {
+                '\\' => match self.next() {
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some(c) => s.push(c),
+                    None => break,
+                },
+                c if c == quote => return Ok(s),
+                c => s.push(c),
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+
+    /// Variables, labels, keys and keywords, which can be quoted with backticks where a
+    /// backtick is written twice
+    fn name(&mut self) -> Result<String, String> {
+        self.skip_layout();
+        if self.peek() == Some('`') {
+            self.pos += 1;
+            let mut name = String::new();
+            loop {
+                match self.next() {
+                    Some('`') if self.peek() == Some('`') => {
+                        self.pos += 1;
+                        name.push('`');
+                    }
+                    Some('`') => return Ok(name),
+                    Some(c) => name.push(c),
+                    None => return Err("unterminated name".to_string()),
+                }
+            }
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(format!("expected name but found {:?}", self.peek()));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_layout();
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            other => Err(format!("expected {} but found {:?}", expected, other)),
+        }
+    }
+
+    /// Skips whitespace and `//` comments
+    fn skip_layout(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '/' && self.peek_at(1) == Some('/') {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.pos += 1;
+                }
+            } else if c.is_whitespace() {
+                self.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn cypher_error(line: usize, error: String) -> PgsError {
+    PgsError::GraphFormatError {
+        format: "Cypher".to_string(),
+        error: format!("line {}: {}", line, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cypher() {
+        let input = r#"// People
+CREATE (a:Person:Student {name: 'Alice', tags: ['a', "b"], born: date('2000-01-31')})
+       -[:KNOWS {since: 2020}]->(b:Person {name: 'Bob', age: null}),
+       (a)<-[k:KNOWS]-(:Person {name: 'Carol'})
+MERGE (a)-[:KNOWS {since: 2020}]->(b);
+MERGE (:Person {name: 'Carol'})
+"#;
+        let pg = CypherBuilder::new().parse_cypher(input).unwrap();
+        let alice = pg.get_node_by_label("a").unwrap();
+        assert_eq!(
+            alice.labels(),
+            &HashSet::from(["Person".to_string(), "Student".to_string()])
+        );
+        assert_eq!(
+            alice.content().get(&Key::new("tags")),
+            Some(&vec![Value::str("a"), Value::str("b")])
+        );
+        let bob = pg.get_node_by_label("b").unwrap();
+        assert_eq!(bob.content().get(&Key::new("age")), None);
+        let knows = pg.get_edge_by_label("e1").unwrap();
+        assert_eq!(knows.source, alice.id);
+        assert_eq!(knows.target, bob.id);
+        let carol = pg.get_node_by_label("n1").unwrap();
+        assert_eq!(pg.get_edge_by_label("k").unwrap().source, carol.id);
+        assert_eq!(pg.edges().count(), 2);
+        assert_eq!(pg.nodes().count(), 3);
+    }
+
+    #[test]
+    fn test_generated_names_skip_variables() {
+        let input = "CREATE (:Person {name:'Anon'}); CREATE (n1:Robot {name:'R2'});";
+        let pg = CypherBuilder::new().parse_cypher(input).unwrap();
+        let robot = pg.get_node_by_label("n1").unwrap();
+        assert!(robot.labels().contains("Robot"));
+        let anon = pg.get_node_by_label("n2").unwrap();
+        assert!(anon.labels().contains("Person"));
+
+        let input = "CREATE (a:P)-[:R]->(b:P), (a)-[e1:S]->(b)";
+        let pg = CypherBuilder::new().parse_cypher(input).unwrap();
+        assert_eq!(pg.edges().count(), 2);
+        assert!(pg.get_edge_by_label("e1").unwrap().labels().contains("S"));
+        assert!(pg.get_edge_by_label("e2").unwrap().labels().contains("R"));
+    }
+
+    #[test]
+    fn test_statement_variables() {
+        let input = "CREATE (n:Person {name: 'Alice'});\nCREATE (n:Person {name: 'Bob'})";
+        let pg = CypherBuilder::new().parse_cypher(input).unwrap();
+        assert_eq!(pg.nodes_with_label("Person").count(), 2);
+        let alice = pg.get_node_by_label("n").unwrap();
+        assert_eq!(
+            alice.content().get(&Key::new("name")),
+            Some(&vec![Value::str("Alice")])
+        );
+        let bob = pg.get_node_by_label("n1").unwrap();
+        assert_eq!(
+            bob.content().get(&Key::new("name")),
+            Some(&vec![Value::str("Bob")])
+        );
+
+        let input = "CREATE (n:Person), (n:Person)";
+        let result = CypherBuilder::new().parse_cypher(input);
+        assert!(matches!(result, Err(PgsError::GraphFormatError { .. })));
+    }
+
+    #[test]
+    fn test_empty_list_and_null() {
+        let input = "CREATE (a:Series {tags: [], name: null, codes: ['x', null]})";
+        let pg = CypherBuilder::new().parse_cypher(input).unwrap();
+        let a = pg.get_node_by_label("a").unwrap();
+        assert_eq!(a.content().get(&Key::new("tags")), Some(&Vec::new()));
+        assert_eq!(a.content().get(&Key::new("name")), None);
+        assert_eq!(
+            a.content().get(&Key::new("codes")),
+            Some(&vec![Value::str("x")])
+        );
+    }
+
+    #[test]
+    fn test_quoted_names() {
+        let input = "CREATE (`bob ``the`` builder`:`Person Name` {`full name`: 'Bob'})";
+        let pg = CypherBuilder::new().parse_cypher(input).unwrap();
+        let bob = pg.get_node_by_label("bob `the` builder").unwrap();
+        assert!(bob.labels().contains("Person Name"));
+        assert_eq!(
+            bob.content().get(&Key::new("full name")),
+            Some(&vec![Value::str("Bob")])
+        );
+        let result = CypherBuilder::new().parse_cypher("CREATE (`bob)");
+        assert!(matches!(result, Err(PgsError::GraphFormatError { .. })));
+    }
+
+    #[test]
+    fn test_unsupported_clause() {
+        let input = "MATCH (a:Person) RETURN a";
+        let result = CypherBuilder::new().parse_cypher(input);
+        assert!(matches!(result, Err(PgsError::GraphFormatError { .. })));
+    }
+}
@@ -0,0 +1,687 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::{
+    card::Card,
+    key::Key,
+    label_property_spec::LabelPropertySpec,
+    pgs::PropertyGraphSchema,
+    pgs_error::PgsError,
+    property_value_spec::{PropertyValue, PropertyValueSpec, TypeSpec},
+    type_name::{LabelName, TypeName},
+};
+
+/// Builds property graph schemas from ISO GQL `CREATE GRAPH TYPE` statements, as in
+/// `CREATE GRAPH TYPE People { NODE TYPE PersonType (PersonType :Person {name :: STRING NOT NULL}),
+/// DIRECTED EDGE TYPE KnowsType (PersonType)-[:knows]->(PersonType) }`.
+///
+/// Node and edge types can be written as patterns or as phrases like
+/// `NODE TYPE PersonType LABEL Person {...}` and `EDGE TYPE KnowsType LABEL knows CONNECTING
+/// (PersonType -> PersonType)`. The properties of a type are closed, `NOT NULL` properties are
+/// required and lists are multi-valued properties. Endpoints which are node type names or
+/// aliases are references to those types. Undirected edge types are read as directed from
+/// the first endpoint to the second.
+pub struct GqlBuilder {}
+
+impl Default for GqlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GqlBuilder {
+    pub fn new() -> Self {
+        GqlBuilder {}
+    }
+
+    pub fn parse_gql(&self, input: &str) -> Result<PropertyGraphSchema, PgsError> {
+        let mut parser = GqlParser {
+            chars: input.chars().collect(),
+            pos: 0,
+            line: 1,
+        };
+        let mut types = GraphTypes::default();
+        parser.skip_layout();
+        while !parser.at_end() {
+            let result = parser.statement(&mut types);
+            result.map_err(|e| gql_error(parser.line, e))?;
+            parser.skip_layout();
+        }
+        types.schema()
+    }
+}
+
+/// Node and edge types in the order they are declared
+#[derive(Default)]
+struct GraphTypes {
+    nodes: Vec<(Option<TypeName>, Filler)>,
+    aliases: HashMap<String, TypeName>,
+    edges: Vec<(Option<TypeName>, Endpoint, Filler, Endpoint)>,
+}
+
+/// Labels and properties of a node or edge type, where `None` properties means that
+/// the type doesn't declare them
+#[derive(Default)]
+struct Filler {
+    labels: Vec<LabelName>,
+    properties: Option<PropertyValue>,
+}
+
+enum Endpoint {
+    Alias(String),
+    Filler(Filler),
+}
+
+enum GqlType {
+    String,
+    Integer,
+    Date,
+    Bool,
+    Any,
+    List(Box<GqlType>),
+    Record(PropertyValue),
+}
+
+impl GraphTypes {
+    fn schema(self) -> Result<PropertyGraphSchema, PgsError> {
+        let mut schema = PropertyGraphSchema::new();
+        for (type_name, filler) in self.nodes {
+            match type_name {
+                Some(type_name) => schema.add_node_spec(&type_name, filler.spec())?,
+                None => schema.add_blank_node_spec(filler.spec())?,
+            };
+        }
+        for (type_name, source, filler, target) in self.edges {
+            let source = endpoint_spec(source, &self.aliases)?;
+            let target = endpoint_spec(target, &self.aliases)?;
+            match type_name {
+                Some(type_name) => {
+                    schema.add_edge_spec(&type_name, source, filler.spec(), target)?
+                }
+                None => schema.add_blank_edge_spec(source, filler.spec(), target)?,
+            };
+        }
+        schema.resolve_references()?;
+        Ok(schema)
+    }
+}
+
+impl Filler {
+    fn labels_spec(&self) -> LabelPropertySpec {
+        self.labels
+            .iter()
+            .map(|label| LabelPropertySpec::label(label.clone()))
+            .reduce(LabelPropertySpec::and)
+            .unwrap_or(LabelPropertySpec::Empty)
+    }
+
+    fn spec(&self) -> LabelPropertySpec {
+        match &self.properties {
+            Some(properties) => LabelPropertySpec::content(
+                self.labels_spec(),
+                PropertyValueSpec::closed(properties.clone()),
+            ),
+            None => self.labels_spec(),
+        }
+    }
+}
+
+/// Inline endpoints without properties only constrain the labels of the node
+fn endpoint_spec(
+    endpoint: Endpoint,
+    aliases: &HashMap<String, TypeName>,
+) -> Result<LabelPropertySpec, PgsError> {
+    match endpoint {
+        Endpoint::Alias(alias) => match aliases.get(&alias) {
+            Some(type_name) => Ok(LabelPropertySpec::ref_(type_name.clone())),
+            None => Err(gql_error_msg(format!("unknown node type {}", alias))),
+        },
+        Endpoint::Filler(filler) if filler.properties.is_none() => Ok(LabelPropertySpec::content(
+            filler.labels_spec(),
+            PropertyValueSpec::open(PropertyValue::no_properties()),
+        )),
+        Endpoint::Filler(filler) => Ok(filler.spec()),
+    }
+}
+
+/// Property type where lists are properties with several values
+fn type_spec(gql_type: GqlType, card: Card) -> TypeSpec {
+    match gql_type {
+        GqlType::String => TypeSpec::string(card),
+        GqlType::Integer => TypeSpec::integer(card),
+        GqlType::Date => TypeSpec::date(card),
+        GqlType::Bool => TypeSpec::bool(card),
+        GqlType::Any => TypeSpec::any(),
+        GqlType::List(item) => match *item {
+            item @ GqlType::List(_) => {
+                TypeSpec::list(type_spec(item, Card::One).to_value_type(), Card::ZeroOrMore)
+            }
+            item => type_spec(item, Card::ZeroOrMore),
+        },
+        GqlType::Record(property_value) => {
+            let record_types = property_value
+                .semantics()
+                .into_iter()
+                .sorted_by_key(|record_type| record_type.to_string())
+                .collect();
+            TypeSpec::record(record_types, card)
+        }
+    }
+}
+
+struct GqlParser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl GqlParser {
+    fn statement(&mut self, types: &mut GraphTypes) -> Result<(), String> {
+        if self.peek() == Some(';') {
+            self.pos += 1;
+            return Ok(());
+        }
+        self.expect_keyword("CREATE")?;
+        if self.keyword("OR") {
+            self.expect_keyword("REPLACE")?;
+        }
+        self.keyword("PROPERTY");
+        self.expect_keyword("GRAPH")?;
+        self.expect_keyword("TYPE")?;
+        if self.keyword("IF") {
+            self.expect_keyword("NOT")?;
+            self.expect_keyword("EXISTS")?;
+        }
+        self.name()?;
+        self.keyword("AS");
+        if self.keyword("COPY") || self.keyword("LIKE") {
+            return Err("graph types copied from other graph types are not supported".to_string());
+        }
+        self.expect('{')?;
+        self.separated('}', |parser| parser.element(types))?;
+        Ok(())
+    }
+
+    /// Node or edge type, as a pattern or as a phrase
+    fn element(&mut self, types: &mut GraphTypes) -> Result<(), String> {
+        let mut edge = None;
+        if self.keyword("NODE") || self.keyword("VERTEX") {
+            edge = Some(false);
+        } else if self.keyword("DIRECTED") || self.keyword("UNDIRECTED") {
+            if !(self.keyword("EDGE") || self.keyword("RELATIONSHIP")) {
+                return Err("expected EDGE".to_string());
+            }
+            edge = Some(true);
+        } else if self.keyword("EDGE") || self.keyword("RELATIONSHIP") {
+            edge = Some(true);
+        }
+        let mut type_name = None;
+        if edge.is_some() {
+            self.keyword("TYPE");
+            self.skip_layout();
+            if !matches!(self.peek(), Some('(' | ':' | '{')) {
+                type_name = Some(self.name()?);
+            }
+        }
+        self.skip_layout();
+        if self.peek() == Some('(') {
+            return self.pattern(types, type_name, edge);
+        }
+        let filler = self.filler_phrase()?;
+        if edge == Some(true) {
+            self.expect_keyword("CONNECTING")?;
+            self.expect('(')?;
+            let first = self.name()?;
+            self.skip_layout();
+            let incoming = self.peek() == Some('<');
+            if incoming {
+                self.expect('<')?;
+                self.expect('-')?;
+            } else if self.peek() == Some('-') || self.peek() == Some('~') {
+                self.pos += 1;
+                if self.peek() == Some('>') {
+                    self.pos += 1;
+                }
+            } else if !self.keyword("TO") {
+                return Err(format!("expected TO or -> but found {:?}", self.peek()));
+            }
+            let second = self.name()?;
+            self.expect(')')?;
+            let (source, target) = if incoming {
+                (second, first)
+            } else {
+                (first, second)
+            };
+            types.edges.push((
+                type_name,
+                Endpoint::Alias(source),
+                filler,
+                Endpoint::Alias(target),
+            ));
+        } else {
+            types.add_node(type_name, None, filler);
+        }
+        Ok(())
+    }
+
+    /// Node type `(alias :Labels {...})` or edge type `(a)-[:Labels {...}]->(b)`
+    fn pattern(
+        &mut self,
+        types: &mut GraphTypes,
+        type_name: Option<TypeName>,
+        edge: Option<bool>,
+    ) -> Result<(), String> {
+        self.expect('(')?;
+        let (alias, filler) = self.alias_filler()?;
+        self.expect(')')?;
+        self.skip_layout();
+        if !matches!(self.peek(), Some('-' | '<' | '~')) {
+            if edge == Some(true) {
+                return Err("expected an edge pattern".to_string());
+            }
+            types.add_node(type_name, alias, filler);
+            return Ok(());
+        }
+        if edge == Some(false) {
+            return Err("expected a node pattern".to_string());
+        }
+        let source = endpoint(alias, filler);
+        let incoming = self.peek() == Some('<');
+        if incoming {
+            self.pos += 1;
+        }
+        let arc = match self.next() {
+            Some(c @ ('-' | '~')) => c,
+            other => return Err(format!("expected - or ~ but found {:?}", other)),
+        };
+        self.expect('[')?;
+        let (edge_alias, filler) = self.alias_filler()?;
+        self.expect(']')?;
+        self.expect(arc)?;
+        if !incoming && arc == '-' {
+            self.expect('>')?;
+        }
+        self.expect('(')?;
+        let (alias, target_filler) = self.alias_filler()?;
+        self.expect(')')?;
+        let target = endpoint(alias, target_filler);
+        let (source, target) = if incoming {
+            (target, source)
+        } else {
+            (source, target)
+        };
+        types
+            .edges
+            .push((type_name.or(edge_alias), source, filler, target));
+        Ok(())
+    }
+
+    /// Contents of node and edge patterns, as in `p :Person&Student {name :: STRING}`
+    fn alias_filler(&mut self) -> Result<(Option<String>, Filler), String> {
+        self.skip_layout();
+        let alias = if self
+            .peek()
+            .is_some_and(|c| c == '`' || c == '"' || is_name_char(c))
+            && !self.at_keyword("IS")
+        {
+            Some(self.name()?)
+        } else {
+            None
+        };
+        let filler = self.filler_phrase()?;
+        Ok((alias, filler))
+    }
+
+    /// Labels after `:`, `IS`, `LABEL` or `LABELS` and properties between braces
+    fn filler_phrase(&mut self) -> Result<Filler, String> {
+        let mut filler = Filler::default();
+        self.skip_layout();
+        let has_labels = if self.peek() == Some(':') {
+            self.pos += 1;
+            true
+        } else {
+            self.keyword("IS") || self.keyword("LABELS") || self.keyword("LABEL")
+        };
+        if has_labels {
+            filler.labels.push(self.name()?);
+            self.skip_layout();
+            while self.peek() == Some('&') {
+                self.pos += 1;
+                filler.labels.push(self.name()?);
+                self.skip_layout();
+            }
+        }
+        if self.peek() == Some('{') {
+            filler.properties = Some(self.properties()?);
+        }
+        Ok(filler)
+    }
+
+    fn properties(&mut self) -> Result<PropertyValue, String> {
+        self.expect('{')?;
+        let properties = self.separated('}', |parser| parser.property())?;
+        Ok(properties
+            .into_iter()
+            .reduce(PropertyValue::each_of)
+            .unwrap_or(PropertyValue::NoProperties))
+    }
+
+    /// Property types as in `name :: STRING NOT NULL`, where `::` can also be `TYPED`
+    fn property(&mut self) -> Result<PropertyValue, String> {
+        let key = Key::new(&self.name()?);
+        self.skip_layout();
+        if self.peek() == Some(':') && self.peek_at(1) == Some(':') {
+            self.pos += 2;
+        } else {
+            self.keyword("TYPED");
+        }
+        let gql_type = self.value_type()?;
+        if self.not_null()? {
+            Ok(PropertyValue::property(key, type_spec(gql_type, Card::One)))
+        } else {
+            Ok(PropertyValue::optional_property(
+                key,
+                type_spec(gql_type, Card::One),
+            ))
+        }
+    }
+
+    fn value_type(&mut self) -> Result<GqlType, String> {
+        let name = self.name()?.to_uppercase();
+        match name.as_str() {
+            "STRING" | "VARCHAR" | "CHAR" | "CHARACTER" => {
+                self.skip_layout();
+                if self.peek() == Some('(') {
+                    while self.next().is_some_and(|c| c != ')') {}
+                }
+                Ok(GqlType::String)
+            }
+            "BOOL" | "BOOLEAN" => Ok(GqlType::Bool),
+            "DATE" => Ok(GqlType::Date),
+            "ANY" => {
+                self.keyword("VALUE");
+                Ok(GqlType::Any)
+            }
+            "LIST" | "ARRAY" => {
+                self.expect('<')?;
+                let item = self.value_type()?;
+                self.not_null()?;
+                self.expect('>')?;
+                Ok(GqlType::List(Box::new(item)))
+            }
+            "RECORD" => Ok(GqlType::Record(self.properties()?)),
+            _ if name.starts_with("INT")
+                || name.starts_with("UINT")
+                || name == "BIGINT"
+                || name == "SMALLINT" =>
+            {
+                Ok(GqlType::Integer)
+            }
+            _ => Err(format!("unsupported type {}", name)),
+        }
+    }
+
+    fn not_null(&mut self) -> Result<bool, String> {
+        if self.keyword("NOT") {
+            self.expect_keyword("NULL")?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Items separated by commas until the closing character
+    fn separated<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let mut items = Vec::new();
+        self.skip_layout();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_layout();
+            match self.next() {
+                Some(',') => {}
+                Some(c) if c == close => return Ok(items),
+                other => return Err(format!("expected , or {} but found {:?}", close, other)),
+            }
+        }
+    }
+
+    /// Consumes the keyword, in any case, if it is the next word
+    fn keyword(&mut self, keyword: &str) -> bool {
+        if self.at_keyword(keyword) {
+            self.pos += keyword.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn at_keyword(&mut self, keyword: &str) -> bool {
+        self.skip_layout();
+        let end = self.pos + keyword.len();
+        end <= self.chars.len()
+            && self.chars[self.pos..end]
+                .iter()
+                .collect::<String>()
+                .eq_ignore_ascii_case(keyword)
+            && !self.chars.get(end).is_some_and(|c| is_name_char(*c))
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), String> {
+        if self.keyword(keyword) {
+            Ok(())
+        } else {
+            Err(format!("expected {} but found {:?}", keyword, self.peek()))
+        }
+    }
+
+    /// Names of types, labels and properties, which can be delimited by backticks or double quotes
+    fn name(&mut self) -> Result<String, String> {
+        self.skip_layout();
+        if let Some(quote @ ('`' | '"')) = self.peek() {
+            self.pos += 1;
+            let mut name = String::new();
+            loop {
+                match self.next() {
+                    Some(c) if c == quote && self.peek() == Some(quote) => {
+                        self.pos += 1;
+                        name.push(quote);
+                    }
+                    Some(c) if c == quote => return Ok(name),
+                    Some(c) => name.push(c),
+                    None => return Err("unterminated name".to_string()),
+                }
+            }
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(format!("expected name but found {:?}", self.peek()));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_layout();
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            other => Err(format!("expected {} but found {:?}", expected, other)),
+        }
+    }
+
+    /// Skips whitespace, `//` and `/* */` comments
+    fn skip_layout(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '/' && self.peek_at(1) == Some('/') {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.pos += 1;
+                }
+            } else if c == '/' && self.peek_at(1) == Some('*') {
+                self.pos += 2;
+                while !self.at_end() && !self.at_comment_end() {
+                    self.next();
+                }
+                self.pos += 2;
+            } else if c.is_whitespace() {
+                self.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn at_comment_end(&self) -> bool {
+        self.peek() == Some('*') && self.peek_at(1) == Some('/')
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+}
+
+impl GraphTypes {
+    /// Node types without name are named by their alias, so that edge types can refer to them
+    fn add_node(&mut self, type_name: Option<TypeName>, alias: Option<String>, filler: Filler) {
+        let type_name = type_name.or(alias.clone());
+        if let Some(type_name) = &type_name {
+            self.aliases.insert(type_name.clone(), type_name.clone());
+            if let Some(alias) = alias {
+                self.aliases.insert(alias, type_name.clone());
+            }
+        }
+        self.nodes.push((type_name, filler));
+    }
+}
+
+/// Endpoints with only a name refer to node types
+fn endpoint(alias: Option<String>, filler: Filler) -> Endpoint {
+    match alias {
+        Some(alias) if filler.labels.is_empty() && filler.properties.is_none() => {
+            Endpoint::Alias(alias)
+        }
+        _ => Endpoint::Filler(filler),
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn gql_error(line: usize, error: String) -> PgsError {
+    gql_error_msg(format!("line {}: {}", line, error))
+}
+
+fn gql_error_msg(error: String) -> PgsError {
+    PgsError::SchemaFormatError {
+        format: "GQL".to_string(),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        parser::pgs_builder::PgsBuilder, value_type::ValueType, writer::gql_writer::GqlWriter,
+    };
+
+    #[test]
+    fn test_parse_gql() {
+        let input = r#"/* People */
+CREATE GRAPH TYPE IF NOT EXISTS People {
+  NODE TYPE PersonType LABELS Person & Student {name :: STRING NOT NULL, tags LIST<STRING>},
+  (c :Company {name TYPED VARCHAR(20) NOT NULL}),
+  (p)-[WorksFor :worksFor {since :: INT32}]->(c), // Edge pattern
+  EDGE TYPE KnowsType LABEL knows CONNECTING (PersonType TO p)
+}"#;
+        let schema = GqlBuilder::new().parse_gql(input).unwrap_err();
+        assert_eq!(
+            schema.to_string(),
+            "Error reading GQL schema: unknown node type p"
+        );
+        let schema = GqlBuilder::new()
+            .parse_gql(&input.replace("(p)", "(PersonType)").replace("TO p", "TO c"))
+            .unwrap();
+        assert_eq!(
+            schema.get_node_semantics("PersonType").unwrap(),
+            &LabelPropertySpec::content(
+                LabelPropertySpec::and(
+                    LabelPropertySpec::label("Person".to_string()),
+                    LabelPropertySpec::label("Student".to_string())
+                ),
+                PropertyValueSpec::closed(PropertyValue::each_of(
+                    PropertyValue::property(Key::new("name"), TypeSpec::string(Card::One)),
+                    PropertyValue::optional_property(
+                        Key::new("tags"),
+                        TypeSpec::string(Card::ZeroOrMore)
+                    )
+                ))
+            )
+        );
+        let works_for = schema.get_edge_semantics("WorksFor").unwrap();
+        assert_eq!(works_for.target, LabelPropertySpec::ref_("c".to_string()));
+        let knows = schema.get_edge_semantics("KnowsType").unwrap();
+        assert_eq!(
+            knows.source,
+            LabelPropertySpec::ref_("PersonType".to_string())
+        );
+    }
+
+    #[test]
+    fn test_gql_round_trip() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( PersonType : Person {
+    name: STRING,
+    OPTIONAL emails: STRING*,
+    address: RECORD { city: STRING, OPTIONAL zip: STRING }
+}) ;
+CREATE EDGE TYPE (@PersonType) -[KnowsType : knows { since: DATE }]-> (: Person)"#,
+            )
+            .unwrap();
+        let gql = GqlWriter::new()
+            .write_graph_type("People", &schema)
+            .unwrap();
+        let parsed = GqlBuilder::new().parse_gql(&gql).unwrap();
+        // Properties are written in the order of their keys, so the specs are compared by their semantics
+        assert_eq!(
+            parsed.node_type_semantics("PersonType").unwrap(),
+            schema.node_type_semantics("PersonType").unwrap()
+        );
+        let knows = parsed.get_edge_semantics("KnowsType").unwrap();
+        let expected = schema.get_edge_semantics("KnowsType").unwrap();
+        assert_eq!(knows, expected);
+        assert!(matches!(
+            knows.edge.clone(),
+            LabelPropertySpec::Content(_, PropertyValueSpec::Closed(PropertyValue::Property(_, type_spec)))
+                if type_spec.to_value_type() == ValueType::date(Card::One)
+        ));
+    }
+}
@@ -0,0 +1,375 @@
+use std::collections::{HashMap, HashSet};
+
+use quick_xml::{
+    Reader,
+    escape::unescape,
+    events::{BytesStart, Event},
+};
+
+use crate::{
+    key::Key,
+    pg::{PropertyGraph, complete_names},
+    pgs_error::PgsError,
+    record::Record,
+    type_name::LabelName,
+    value::Value,
+};
+
+/// Builds property graphs from GraphML documents.
+///
+/// Properties are declared with `<key>` elements whose `attr.type` can be `string`, `int`,
+/// `long` or `boolean`, and multi-valued properties have an `attr.list` type with a JSON array
+/// as value. Labels are taken from the `labels` attribute of nodes and the `label` attribute of
+/// edges, or from data with those names, as in `:Person:Student`.
+pub struct GraphmlBuilder {}
+
+impl Default for GraphmlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphmlBuilder {
+    pub fn new() -> Self {
+        GraphmlBuilder {}
+    }
+
+    pub fn parse_graphml(&self, input: &str) -> Result<PropertyGraph, PgsError> {
+        let mut reader = Reader::from_str(input);
+        let mut keys: HashMap<String, GraphmlKey> = HashMap::new();
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut current_key: Option<GraphmlKey> = None;
+        let mut current: Option<Element> = None;
+        loop {
+            let event = reader.read_event().map_err(graphml_error)?;
+            let (start, empty) = match &event {
+                Event::Start(start) => (start, false),
+                Event::Empty(start) => (start, true),
+                Event::End(end) => {
+                    match end.local_name().as_ref() {
+                        b"key" => {
+                            if let Some(key) = current_key.take() {
+                                keys.insert(key.id.clone(), key);
+                            }
+                        }
+                        b"node" => nodes.extend(current.take()),
+                        b"edge" => edges.extend(current.take()),
+                        _ => {}
+                    }
+                    continue;
+                }
+                Event::Eof => break,
+                _ => continue,
+            };
+            match start.local_name().as_ref() {
+                b"key" => {
+                    let key = GraphmlKey::parse(start)?;
+                    if empty {
+                        keys.insert(key.id.clone(), key);
+                    } else {
+                        current_key = Some(key);
+                    }
+                }
+                b"default" if !empty => {
+                    let text = read_text(&mut reader, start)?;
+                    if let Some(key) = current_key.as_mut() {
+                        key.default = Some(text);
+                    }
+                }
+                b"node" | b"edge" => {
+                    let element = Element::parse(start)?;
+                    if empty {
+                        match element.kind {
+                            ElementKind::Node => nodes.push(element),
+                            ElementKind::Edge { .. } => edges.push(element),
+                        }
+                    } else {
+                        current = Some(element);
+                    }
+                }
+                b"data" if !empty => {
+                    let key_id = attribute(start, "key")?.unwrap_or_default();
+                    let text = read_text(&mut reader, start)?;
+                    if let Some(element) = current.as_mut() {
+                        element.data.push((key_id, text));
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut pg = PropertyGraph::new();
+        for node in nodes {
+            let (labels, record) = node.content(&keys, "node")?;
+            pg.add_node(node.id, labels, record)
+                .map_err(graphml_error)?;
+        }
+        let names = complete_names(
+            edges
+                .iter()
+                .map(|edge| Some(edge.id.clone()).filter(|id| !id.is_empty()))
+                .collect(),
+            "e",
+        );
+        for (edge, name) in edges.into_iter().zip(names) {
+            let (labels, record) = edge.content(&keys, "edge")?;
+            let ElementKind::Edge { source, target } = edge.kind else {
+                continue;
+            };
+            pg.add_edge(name, source, labels, record, target)
+                .map_err(graphml_error)?;
+        }
+        Ok(pg)
+    }
+}
+
+/// Declaration of a property in a `<key>` element
+#[derive(Debug, Clone)]
+struct GraphmlKey {
+    id: String,
+    domain: String,
+    name: String,
+    attr_type: String,
+    list: Option<String>,
+    default: Option<String>,
+}
+
+impl GraphmlKey {
+    fn parse(start: &BytesStart) -> Result<GraphmlKey, PgsError> {
+        let id = attribute(start, "id")?.ok_or(graphml_error("key without id"))?;
+        Ok(GraphmlKey {
+            name: attribute(start, "attr.name")?.unwrap_or(id.clone()),
+            id,
+            domain: attribute(start, "for")?.unwrap_or("all".to_string()),
+            attr_type: attribute(start, "attr.type")?.unwrap_or("string".to_string()),
+            list: attribute(start, "attr.list")?,
+            default: None,
+        })
+    }
+
+    fn applies_to(&self, domain: &str) -> bool {
+        self.domain == domain || self.domain == "all"
+    }
+
+    fn values(&self, text: &str) -> Result<Vec<Value>, PgsError> {
+        match &self.list {
+            None => Ok(vec![graphml_value(&self.attr_type, text)?]),
+            Some(item_type) => {
+                let items: Vec<serde_json::Value> = serde_json::from_str(text).map_err(|e| {
+                    graphml_error(format!("invalid list {} for {}: {}", text, self.name, e))
+                })?;
+                items
+                    .iter()
+                    .map(|item| match item {
+                        serde_json::Value::String(s) => graphml_value(item_type, s),
+                        other => graphml_value(item_type, &other.to_string()),
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+enum ElementKind {
+    Node,
+    Edge { source: String, target: String },
+}
+
+/// Node or edge with the raw contents of its `<data>` elements
+#[derive(Debug)]
+struct Element {
+    id: String,
+    kind: ElementKind,
+    labels: Option<String>,
+    data: Vec<(String, String)>,
+}
+
+impl Element {
+    fn parse(start: &BytesStart) -> Result<Element, PgsError> {
+        let id = attribute(start, "id")?.unwrap_or_default();
+        if start.local_name().as_ref() == b"node" {
+            if id.is_empty() {
+                return Err(graphml_error("node without id"));
+            }
+            Ok(Element {
+                id,
+                kind: ElementKind::Node,
+                labels: attribute(start, "labels")?,
+                data: Vec::new(),
+            })
+        } else {
+            let source = attribute(start, "source")?.ok_or(graphml_error("edge without source"))?;
+            let target = attribute(start, "target")?.ok_or(graphml_error("edge without target"))?;
+            Ok(Element {
+                id,
+                kind: ElementKind::Edge { source, target },
+                labels: attribute(start, "label")?,
+                data: Vec::new(),
+            })
+        }
+    }
+
+    fn content(
+        &self,
+        keys: &HashMap<String, GraphmlKey>,
+        domain: &str,
+    ) -> Result<(HashSet<LabelName>, Record), PgsError> {
+        let labels_name = if domain == "node" { "labels" } else { "label" };
+        let mut labels: HashSet<LabelName> = self
+            .labels
+            .iter()
+            .flat_map(|labels| split_labels(labels))
+            .collect();
+        let mut record = Record::new();
+        for (key_id, text) in &self.data {
+            let key = match keys.get(key_id) {
+                Some(key) => key.clone(),
+                None => GraphmlKey {
+                    id: key_id.clone(),
+                    domain: domain.to_string(),
+                    name: key_id.clone(),
+                    attr_type: "string".to_string(),
+                    list: None,
+                    default: None,
+                },
+            };
+            if key.name == labels_name {
+                labels.extend(split_labels(text));
+            } else {
+                record.insert_values(Key::new(&key.name), key.values(text)?);
+            }
+        }
+        for key in keys.values().filter(|key| key.applies_to(domain)) {
+            if let Some(default) = &key.default
+                && key.name != labels_name
+                && record.get(&Key::new(&key.name)).is_none()
+            {
+                record.insert_values(Key::new(&key.name), key.values(default)?);
+            }
+        }
+        Ok((labels, record))
+    }
+}
+
+fn graphml_value(attr_type: &str, text: &str) -> Result<Value, PgsError> {
+    match attr_type {
+        "string" => Ok(Value::str(text)),
+        "int" | "long" => text
+            .trim()
+            .parse()
+            .map(Value::int)
+            .map_err(|e| graphml_error(format!("invalid {} {}: {}", attr_type, text, e))),
+        "boolean" => match text.trim() {
+            "true" => Ok(Value::true_()),
+            "false" => Ok(Value::false_()),
+            _ => Err(graphml_error(format!("invalid boolean {}", text))),
+        },
+        _ => Err(graphml_error(format!(
+            "unsupported attr.type {}",
+            attr_type
+        ))),
+    }
+}
+
+/// Labels written as `:Person:Student` or `Person`
+fn split_labels(labels: &str) -> impl Iterator<Item = LabelName> + '_ {
+    labels
+        .split(':')
+        .map(|label| label.trim())
+        .filter(|label| !label.is_empty())
+        .map(|label| label.to_string())
+}
+
+/// Unescaped text of an element
+fn read_text(reader: &mut Reader<&[u8]>, start: &BytesStart) -> Result<String, PgsError> {
+    let text = reader.read_text(start.name()).map_err(graphml_error)?;
+    Ok(unescape(&text).map_err(graphml_error)?.to_string())
+}
+
+fn attribute(start: &BytesStart, name: &str) -> Result<Option<String>, PgsError> {
+    match start.try_get_attribute(name).map_err(graphml_error)? {
+        Some(attribute) => Ok(Some(
+            attribute
+                .unescape_value()
+                .map_err(graphml_error)?
+                .to_string(),
+        )),
+        None => Ok(None),
+    }
+}
+
+fn graphml_error(error: impl ToString) -> PgsError {
+    PgsError::GraphmlError {
+        error: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_graphml() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="name" attr.type="string"/>
+  <key id="d1" for="node" attr.name="age" attr.type="int"/>
+  <key id="d2" for="node" attr.name="tags" attr.type="string" attr.list="string"/>
+  <key id="d3" for="edge" attr.name="weight" attr.type="int">
+    <default>1</default>
+  </key>
+  <graph id="G" edgedefault="directed">
+    <node id="alice" labels=":Person:Student">
+      <data key="d0">Alice</data>
+      <data key="d1">23</data>
+      <data key="d2">["a", "b"]</data>
+    </node>
+    <node id="bob" labels=":Person"/>
+    <edge source="alice" target="bob" label="knows"/>
+  </graph>
+</graphml>"#;
+        let pg = GraphmlBuilder::new().parse_graphml(input).unwrap();
+        let alice = pg.get_node_by_label("alice").unwrap();
+        assert_eq!(
+            alice.labels(),
+            &HashSet::from(["Person".to_string(), "Student".to_string()])
+        );
+        assert_eq!(
+            alice.content().get(&Key::new("tags")),
+            Some(&vec![Value::str("a"), Value::str("b")])
+        );
+        assert_eq!(
+            alice.content().get(&Key::new("age")),
+            Some(&vec![Value::int(23)])
+        );
+        let knows = pg.get_edge_by_label("e1").unwrap();
+        assert_eq!(knows.labels(), &HashSet::from(["knows".to_string()]));
+        assert_eq!(
+            knows.content().get(&Key::new("weight")),
+            Some(&vec![Value::int(1)])
+        );
+    }
+
+    #[test]
+    fn test_duplicate_ids() {
+        let input = r#"<graphml>
+  <graph edgedefault="directed">
+    <node id="alice"/>
+    <node id="alice"/>
+  </graph>
+</graphml>"#;
+        let result = GraphmlBuilder::new().parse_graphml(input);
+        assert!(matches!(result, Err(PgsError::GraphmlError { .. })));
+        let input = r#"<graphml>
+  <graph edgedefault="directed">
+    <node id="alice"/>
+    <edge id="e2" source="alice" target="alice"/>
+    <edge source="alice" target="alice"/>
+    <edge id="e2" source="alice" target="alice"/>
+  </graph>
+</graphml>"#;
+        let result = GraphmlBuilder::new().parse_graphml(input);
+        assert!(matches!(result, Err(PgsError::GraphmlError { .. })));
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{
+    key::Key,
+    pg::{PropertyGraph, complete_names},
+    pgs_error::PgsError,
+    record::Record,
+    type_name::LabelName,
+    value::Value,
+};
+
+/// Builds property graphs from JSON Lines where each line is a node or an edge, as in
+/// `{"type":"node","id":"alice","labels":["Person"],"properties":{"name":"Alice"}}` or
+/// `{"type":"edge","id":"k1","source":"alice","target":"bob","labels":["knows"]}`.
+///
+/// Arrays are multi-valued properties and objects are nested records. Edges without
+/// `id` get the names `e1`, `e2`, ... in the order they are read, skipping the ids of
+/// other edges.
+pub struct JsonlBuilder {}
+
+impl Default for JsonlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonlBuilder {
+    pub fn new() -> Self {
+        JsonlBuilder {}
+    }
+
+    /// Parses the lines, all nodes are added before the edges so edges can come first
+    pub fn parse_jsonl(&self, input: &str) -> Result<PropertyGraph, PgsError> {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let object: Map<String, JsonValue> =
+                serde_json::from_str(line).map_err(|e| jsonl_error(line_number, e.to_string()))?;
+            match object.get("type").and_then(JsonValue::as_str) {
+                Some("node") => nodes.push((line_number, object)),
+                Some("edge") | Some("relationship") => edges.push((line_number, object)),
+                _ => {
+                    return Err(jsonl_error(
+                        line_number,
+                        "type must be node or edge".to_string(),
+                    ));
+                }
+            }
+        }
+        let mut pg = PropertyGraph::new();
+        for (line, object) in nodes {
+            let id = string_field(&object, "id")
+                .ok_or(jsonl_error(line, "node without id".to_string()))?;
+            let (labels, record) =
+                labels_record(&object).map_err(|e| jsonl_error(line, e.to_string()))?;
+            pg.add_node(id, labels, record)
+                .map_err(|e| jsonl_error(line, e.to_string()))?;
+        }
+        let names = complete_names(
+            edges
+                .iter()
+                .map(|(_, object)| string_field(object, "id"))
+                .collect(),
+            "e",
+        );
+        for ((line, object), name) in edges.into_iter().zip(names) {
+            let source = string_field(&object, "source")
+                .ok_or(jsonl_error(line, "edge without source".to_string()))?;
+            let target = string_field(&object, "target")
+                .ok_or(jsonl_error(line, "edge without target".to_string()))?;
+            let (labels, record) =
+                labels_record(&object).map_err(|e| jsonl_error(line, e.to_string()))?;
+            pg.add_edge(name, source, labels, record, target)
+                .map_err(|e| jsonl_error(line, e.to_string()))?;
+        }
+        Ok(pg)
+    }
+}
+
+/// Identifiers can be strings or numbers
+pub(crate) fn string_field(object: &Map<String, JsonValue>, field: &str) -> Option<String> {
+    match object.get(field)? {
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Labels can be an array of strings or a single string
+pub(crate) fn labels_record(
+    object: &Map<String, JsonValue>,
+) -> Result<(HashSet<LabelName>, Record), PgsError> {
+    let labels = match object.get("labels").or(object.get("label")) {
+        None | Some(JsonValue::Null) => HashSet::new(),
+        Some(JsonValue::String(label)) => HashSet::from([label.clone()]),
+        Some(JsonValue::Array(labels)) => labels
+            .iter()
+            .map(|label| {
+                label
+                    .as_str()
+                    .map(|label| label.to_string())
+                    .ok_or(PgsError::JsonValueError {
+                        value: label.to_string(),
+                    })
+            })
+            .collect::<Result<_, _>>()?,
+        Some(other) => {
+            return Err(PgsError::JsonValueError {
+                value: other.to_string(),
+            });
+        }
+    };
+    let record = match object.get("properties") {
+        None | Some(JsonValue::Null) => Record::new(),
+        Some(JsonValue::Object(properties)) => json_record(properties)?,
+        Some(other) => {
+            return Err(PgsError::JsonValueError {
+                value: other.to_string(),
+            });
+        }
+    };
+    Ok((labels, record))
+}
+
+/// Converts a JSON object into a record where arrays are multi-valued properties
+/// and null values are missing properties
+pub fn json_record(properties: &Map<String, JsonValue>) -> Result<Record, PgsError> {
+    let mut record = Record::new();
+    for (key, value) in properties {
+        let values = match value {
+            JsonValue::Null => continue,
+            JsonValue::Array(items) => items.iter().map(json_value).collect::<Result<_, _>>()?,
+            single => vec![json_value(single)?],
+        };
+        record.insert_values(Key::new(key), values);
+    }
+    Ok(record)
+}
+
+/// Converts a JSON value into a single value, only integers that fit in the
+/// integer values of the schemas are supported as numbers
+pub fn json_value(value: &JsonValue) -> Result<Value, PgsError> {
+    match value {
+        JsonValue::String(s) => Ok(Value::str(s)),
+        JsonValue::Bool(b) => Ok(Value::Bool(*b)),
+        JsonValue::Number(n) => n
+            .as_i64()
+            .and_then(|n| i32::try_from(n).ok())
+            .map(Value::int)
+            .ok_or(PgsError::JsonValueError {
+                value: n.to_string(),
+            }),
+        JsonValue::Object(object) => Ok(Value::record(json_record(object)?)),
+        JsonValue::Null | JsonValue::Array(_) => Err(PgsError::JsonValueError {
+            value: value.to_string(),
+        }),
+    }
+}
+
+fn jsonl_error(line: usize, error: String) -> PgsError {
+    PgsError::JsonlError { line, error }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jsonl() {
+        let input = r#"
+{"type":"edge","source":"alice","target":"bob","labels":["knows"],"properties":{"since":2020}}
+{"type":"node","id":"alice","labels":["Person"],"properties":{"name":"Alice","tags":["a","b"],"address":{"city":"Oviedo"}}}
+{"type":"node","id":"bob","labels":"Person","properties":{"active":true,"age":null}}
+"#;
+        let pg = JsonlBuilder::new().parse_jsonl(input).unwrap();
+        let alice = pg.get_node_by_label("alice").unwrap();
+        assert_eq!(
+            alice.content().get(&Key::new("tags")),
+            Some(&vec![Value::str("a"), Value::str("b")])
+        );
+        assert_eq!(
+            alice.content().get(&Key::new("address")),
+            Some(&vec![Value::record(
+                Record::new().with_key_value("city", Value::str("Oviedo"))
+            )])
+        );
+        let bob = pg.get_node_by_label("bob").unwrap();
+        assert_eq!(bob.labels(), &HashSet::from(["Person".to_string()]));
+        assert_eq!(bob.content().get(&Key::new("age")), None);
+        let knows = pg.get_edge_by_label("e1").unwrap();
+        assert_eq!(knows.source, alice.id);
+    }
+
+    #[test]
+    fn test_decimal_number() {
+        let input = r#"{"type":"node","id":"alice","properties":{"height":1.7}}"#;
+        let result = JsonlBuilder::new().parse_jsonl(input);
+        assert!(matches!(result, Err(PgsError::JsonlError { line: 1, .. })));
+    }
+
+    #[test]
+    fn test_generated_edge_names() {
+        let input = r#"{"type":"node","id":"alice"}
+{"type":"edge","id":"e1","source":"alice","target":"alice","labels":["knows"]}
+{"type":"edge","source":"alice","target":"alice","labels":["likes"]}
+"#;
+        let pg = JsonlBuilder::new().parse_jsonl(input).unwrap();
+        assert_eq!(pg.edges().count(), 2);
+        let likes = pg.get_edge_by_label("e2").unwrap();
+        assert!(likes.labels().contains("likes"));
+    }
+
+    #[test]
+    fn test_duplicate_edge_id() {
+        let input = r#"{"type":"node","id":"alice"}
+{"type":"edge","id":"k1","source":"alice","target":"alice"}
+{"type":"edge","id":"k1","source":"alice","target":"alice"}
+"#;
+        let result = JsonlBuilder::new().parse_jsonl(input);
+        assert!(matches!(result, Err(PgsError::JsonlError { line: 3, .. })));
+    }
+}
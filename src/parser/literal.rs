@@ -0,0 +1,84 @@
+use crate::value::Value;
+
+/// Reads a literal written without quotes in the PG format and YARS-PG, which can be
+/// a boolean, an integer or a date as `2020-01-31`. Other tokens give `None`.
+pub(crate) fn unquoted_value(token: &str) -> Result<Option<Value>, String> {
+    match token {
+        "true" => Ok(Some(Value::true_())),
+        "false" => Ok(Some(Value::false_())),
+        _ if is_number(token) => integer(token).map(|n| Some(Value::int(n))),
+        _ if is_date(token) => Value::date(token).map(Some).map_err(|e| e.to_string()),
+        _ => Ok(None),
+    }
+}
+
+/// Reads a double quoted string with the escapes of JSON, other tokens are kept as they are
+pub(crate) fn unquote(token: &str) -> Result<String, String> {
+    if token.starts_with('"') {
+        serde_json::from_str(token).map_err(|e| format!("invalid string {}: {}", token, e))
+    } else {
+        Ok(token.to_string())
+    }
+}
+
+/// Property values only have 32-bit integers, so decimals and larger integers are rejected
+fn integer(token: &str) -> Result<i32, String> {
+    match token.parse::<i64>() {
+        Ok(n) => i32::try_from(n).map_err(|_| {
+            format!(
+                "integer {} is out of range, integers must be between {} and {}",
+                token,
+                i32::MIN,
+                i32::MAX
+            )
+        }),
+        Err(_) => Err(format!(
+            "number {} is not supported, only integers can be property values",
+            token
+        )),
+    }
+}
+
+fn is_number(token: &str) -> bool {
+    token
+        .trim_start_matches('-')
+        .starts_with(|c: char| c.is_ascii_digit())
+        && token.parse::<f64>().is_ok()
+}
+
+fn is_date(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(index, b)| match index {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unquoted_value() {
+        assert_eq!(unquoted_value("true"), Ok(Some(Value::true_())));
+        assert_eq!(unquoted_value("-23"), Ok(Some(Value::int(-23))));
+        assert_eq!(
+            unquoted_value("2020-01-31"),
+            Ok(Some(Value::date("2020-01-31").unwrap()))
+        );
+        assert_eq!(unquoted_value("Alice"), Ok(None));
+        assert!(unquoted_value("2020-13-31").is_err());
+        assert_eq!(
+            unquoted_value("1.5"),
+            Err("number 1.5 is not supported, only integers can be property values".to_string())
+        );
+        assert_eq!(
+            unquoted_value("3000000000"),
+            Err(
+                "integer 3000000000 is out of range, integers must be between -2147483648 and 2147483647"
+                    .to_string()
+            )
+        );
+    }
+}
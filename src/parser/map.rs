@@ -1,27 +1,27 @@
-use super::map_actions;
-use rustemo::Action::{self, Accept, Reduce, Shift};
-use rustemo::LRBuilder;
+/// Generated by rustemo. Do not edit manually!
+use std::fmt::Debug;
+use std::hash::Hash;
+use rustemo::{
+    Result, Input as InputT, Lexer, Token, TokenRecognizer as TokenRecognizerT, Parser,
+    ParserDefinition, State as StateT, Builder,
+};
+use rustemo::regex::Regex;
+use rustemo::once_cell::sync::Lazy;
 use rustemo::StringLexer;
+use rustemo::LRBuilder;
+use super::map_actions;
+use rustemo::{LRParser, LRContext};
+use rustemo::Action::{self, Shift, Reduce, Accept};
+#[allow(unused_imports)]
+use rustemo::debug::{log, logn};
 #[allow(unused_imports)]
 #[cfg(debug_assertions)]
 use rustemo::colored::*;
-#[allow(unused_imports)]
-use rustemo::debug::{log, logn};
-use rustemo::once_cell::sync::Lazy;
-use rustemo::regex::Regex;
-use rustemo::{
-    Builder, Input as InputT, Lexer, Parser, ParserDefinition, Result, State as StateT, Token,
-    TokenRecognizer as TokenRecognizerT,
-};
-use rustemo::{LRContext, LRParser};
-/// Generated by rustemo. Do not edit manually!
-use std::fmt::Debug;
-use std::hash::Hash;
 pub type Input = str;
 const STATE_COUNT: usize = 32usize;
 const MAX_RECOGNIZERS: usize = 6usize;
 #[allow(dead_code)]
-const TERMINAL_COUNT: usize = 13usize;
+const TERMINAL_COUNT: usize = 14usize;
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
@@ -33,6 +33,7 @@ pub enum TokenKind {
     START_COMMENT,
     END_COMMENT,
     IDENTIFIER,
+    TYPE_NAME,
     AT,
     COMMA,
     SEMICOLON,
@@ -86,7 +87,7 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::NOTOptP1 => "NOTOpt: NOT",
             ProdKind::NOTOptP2 => "NOTOpt: ",
             ProdKind::NodeIdP1 => "NodeId: IDENTIFIER",
-            ProdKind::TypeNameP1 => "TypeName: IDENTIFIER",
+            ProdKind::TypeNameP1 => "TypeName: TYPE_NAME",
             ProdKind::LayoutP1 => "Layout: LayoutItem0",
             ProdKind::LayoutItem1P1 => "LayoutItem1: LayoutItem1 LayoutItem",
             ProdKind::LayoutItem1P2 => "LayoutItem1: LayoutItem",
@@ -177,7 +178,7 @@ pub enum State {
     AssociationS8,
     NOTS9,
     NOTOptS10,
-    IDENTIFIERS11,
+    TYPE_NAMES11,
     TypeNameS12,
     AUGLS13,
     WSS14,
@@ -223,7 +224,7 @@ impl std::fmt::Debug for State {
             State::AssociationS8 => "8:Association",
             State::NOTS9 => "9:NOT",
             State::NOTOptS10 => "10:NOTOpt",
-            State::IDENTIFIERS11 => "11:IDENTIFIER",
+            State::TYPE_NAMES11 => "11:TYPE_NAME",
             State::TypeNameS12 => "12:TypeName",
             State::AUGLS13 => "13:AUGL",
             State::WSS14 => "14:WS",
@@ -257,6 +258,7 @@ pub enum Symbol {
 #[derive(Debug)]
 pub enum Terminal {
     IDENTIFIER(map_actions::IDENTIFIER),
+    TYPE_NAME(map_actions::TYPE_NAME),
     COMMA,
     SEMICOLON,
     NOT,
@@ -322,7 +324,7 @@ fn action_comma_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
 }
 fn action_semicolon_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Reduce(PK::NOTOptP2, 0usize)]),
+        TK::TYPE_NAME => Vec::from(&[Reduce(PK::NOTOptP2, 0usize)]),
         TK::NOT => Vec::from(&[Shift(State::NOTS9)]),
         _ => vec![],
     }
@@ -336,17 +338,17 @@ fn action_association_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
 }
 fn action_not_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Reduce(PK::NOTOptP1, 1usize)]),
+        TK::TYPE_NAME => Vec::from(&[Reduce(PK::NOTOptP1, 1usize)]),
         _ => vec![],
     }
 }
 fn action_notopt_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS11)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES11)]),
         _ => vec![],
     }
 }
-fn action_identifier_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_type_name_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
@@ -648,7 +650,7 @@ pub(crate) static PARSER_DEFINITION: MapParserDefinition = MapParserDefinition {
         action_association_s8,
         action_not_s9,
         action_notopt_s10,
-        action_identifier_s11,
+        action_type_name_s11,
         action_typename_s12,
         action_augl_s13,
         action_ws_s14,
@@ -708,58 +710,16 @@ pub(crate) static PARSER_DEFINITION: MapParserDefinition = MapParserDefinition {
         [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
         [Some((TK::SEMICOLON, true)), None, None, None, None, None],
         [Some((TK::STOP, false)), None, None, None, None, None],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::COMMA, true)),
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::COMMA, true)),
-            None,
-            None,
-            None,
-            None,
-        ],
+        [Some((TK::STOP, true)), Some((TK::COMMA, true)), None, None, None, None],
+        [Some((TK::STOP, true)), Some((TK::COMMA, true)), None, None, None, None],
         [Some((TK::SEMICOLON, true)), None, None, None, None, None],
         [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [
-            Some((TK::NOT, true)),
-            Some((TK::IDENTIFIER, false)),
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::COMMA, true)),
-            None,
-            None,
-            None,
-            None,
-        ],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::COMMA, true)),
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::COMMA, true)),
-            None,
-            None,
-            None,
-            None,
-        ],
+        [Some((TK::NOT, true)), Some((TK::TYPE_NAME, false)), None, None, None, None],
+        [Some((TK::STOP, true)), Some((TK::COMMA, true)), None, None, None, None],
+        [Some((TK::TYPE_NAME, false)), None, None, None, None, None],
+        [Some((TK::TYPE_NAME, false)), None, None, None, None, None],
+        [Some((TK::STOP, true)), Some((TK::COMMA, true)), None, None, None, None],
+        [Some((TK::STOP, true)), Some((TK::COMMA, true)), None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::START_COMMENT, true)),
@@ -894,10 +854,7 @@ impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for MapParserDefi
         PARSER_DEFINITION.gotos[state as usize](nonterm)
     }
     fn expected_token_kinds(&self, state: State) -> Vec<(TokenKind, bool)> {
-        PARSER_DEFINITION.token_kinds[state as usize]
-            .iter()
-            .map_while(|t| *t)
-            .collect()
+        PARSER_DEFINITION.token_kinds[state as usize].iter().map_while(|t| *t).collect()
     }
     fn longest_match() -> bool {
         true
@@ -927,27 +884,30 @@ pub struct MapParser<
     >,
 );
 #[allow(dead_code)]
-impl<'i>
-    MapParser<
-        'i,
-        Input,
-        StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
-        DefaultBuilder,
-    >
-{
+impl<
+    'i,
+> MapParser<
+    'i,
+    Input,
+    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
+    DefaultBuilder,
+> {
     pub fn new() -> Self {
-        Self(LRParser::new(
-            &PARSER_DEFINITION,
-            State::default(),
-            false,
-            true,
-            StringLexer::new(false, &RECOGNIZERS),
-            DefaultBuilder::new(),
-        ))
+        Self(
+            LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                true,
+                StringLexer::new(false, &RECOGNIZERS),
+                DefaultBuilder::new(),
+            ),
+        )
     }
 }
 #[allow(dead_code)]
-impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind> for MapParser<'i, I, L, B>
+impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
+for MapParser<'i, I, L, B>
 where
     I: InputT + ?Sized + Debug,
     L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
@@ -964,7 +924,10 @@ where
     ) -> Result<Self::Output> {
         self.0.parse_with_context(context, input)
     }
-    fn parse_file<'a, F: AsRef<std::path::Path>>(&'a mut self, file: F) -> Result<Self::Output>
+    fn parse_file<'a, F: AsRef<std::path::Path>>(
+        &'a mut self,
+        file: F,
+    ) -> Result<Self::Output>
     where
         'a: 'i,
     {
@@ -1028,23 +991,37 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
     TokenRecognizer(
         TokenKind::WS,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "\\s+")).unwrap())),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\s+")).unwrap() }),
+        ),
     ),
     TokenRecognizer(
         TokenKind::CommentLine,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "//.*")).unwrap())),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "//.*")).unwrap() }),
+        ),
     ),
     TokenRecognizer(
         TokenKind::NotComment,
-        Recognizer::RegexMatch(Lazy::new(|| {
-            Regex::new(concat!("^", "((\\*[^/])|[^\\s*/]|/[^\\*])+")).unwrap()
-        })),
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "((\\*[^/])|[^\\s*/]|/[^\\*])+")).unwrap()
+            }),
+        ),
     ),
     TokenRecognizer(TokenKind::START_COMMENT, Recognizer::StrMatch("/*")),
     TokenRecognizer(TokenKind::END_COMMENT, Recognizer::StrMatch("*/")),
     TokenRecognizer(
         TokenKind::IDENTIFIER,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "\\w+")).unwrap())),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\w+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::TYPE_NAME,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\w+(::\\w+)*")).unwrap() }),
+        ),
     ),
     TokenRecognizer(TokenKind::AT, Recognizer::StrMatch("@")),
     TokenRecognizer(TokenKind::COMMA, Recognizer::StrMatch(",")),
@@ -1071,12 +1048,22 @@ impl Builder for DefaultBuilder {
         }
     }
 }
-impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> for DefaultBuilder {
+impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
+for DefaultBuilder {
     #![allow(unused_variables)]
-    fn shift_action(&mut self, context: &Context<'i, Input>, token: Token<'i, Input, TokenKind>) {
+    fn shift_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        token: Token<'i, Input, TokenKind>,
+    ) {
         let val = match token.kind {
             TokenKind::STOP => panic!("Cannot shift STOP token!"),
-            TokenKind::IDENTIFIER => Terminal::IDENTIFIER(map_actions::identifier(context, token)),
+            TokenKind::IDENTIFIER => {
+                Terminal::IDENTIFIER(map_actions::identifier(context, token))
+            }
+            TokenKind::TYPE_NAME => {
+                Terminal::TYPE_NAME(map_actions::type_name(context, token))
+            }
             TokenKind::COMMA => Terminal::COMMA,
             TokenKind::SEMICOLON => Terminal::SEMICOLON,
             TokenKind::NOT => Terminal::NOT,
@@ -1084,7 +1071,12 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
         };
         self.res_stack.push(Symbol::Terminal(val));
     }
-    fn reduce_action(&mut self, context: &Context<'i, Input>, prod: ProdKind, prod_len: usize) {
+    fn reduce_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        prod: ProdKind,
+        prod_len: usize,
+    ) {
         let prod = match prod {
             ProdKind::MapP1 => {
                 let mut i = self
@@ -1108,7 +1100,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::Association1(p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::Association(p1)),
-                    ) => NonTerminal::Association1(map_actions::association1_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::Association1(
+                            map_actions::association1_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -1118,9 +1114,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Association(p0)) => NonTerminal::Association1(
-                        map_actions::association1_association(context, p0),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::Association(p0)) => {
+                        NonTerminal::Association1(
+                            map_actions::association1_association(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -1140,7 +1138,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         _,
                         Symbol::NonTerminal(NonTerminal::NOTOpt(p1)),
                         Symbol::NonTerminal(NonTerminal::TypeName(p2)),
-                    ) => NonTerminal::Association(map_actions::association_c1(context, p0, p1, p2)),
+                    ) => {
+                        NonTerminal::Association(
+                            map_actions::association_c1(context, p0, p1, p2),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -1170,8 +1172,10 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::IDENTIFIER(p0)) => {
-                        NonTerminal::TypeName(map_actions::type_name_identifier(context, p0))
+                    Symbol::Terminal(Terminal::TYPE_NAME(p0)) => {
+                        NonTerminal::TypeName(
+                            map_actions::type_name_type_name(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -10,6 +10,10 @@ pub type IDENTIFIER = String;
 pub fn identifier(_ctx: &Ctx, token: Token) -> IDENTIFIER {
     token.value.into()
 }
+pub type TYPE_NAME = String;
+pub fn type_name(_ctx: &Ctx, token: Token) -> TYPE_NAME {
+    token.value.into()
+}
 pub type Map = Association1;
 pub fn map_association1(_ctx: &Ctx, association1: Association1) -> Map {
     association1
@@ -59,7 +63,7 @@ pub type NodeId = IDENTIFIER;
 pub fn node_id_identifier(_ctx: &Ctx, identifier: IDENTIFIER) -> NodeId {
     identifier
 }
-pub type TypeName = IDENTIFIER;
-pub fn type_name_identifier(_ctx: &Ctx, identifier: IDENTIFIER) -> TypeName {
-    identifier
+pub type TypeName = TYPE_NAME;
+pub fn type_name_type_name(_ctx: &Ctx, type_name: TYPE_NAME) -> TypeName {
+    type_name
 }
@@ -1,5 +1,6 @@
 // Parser for simple Property Graphs
 #[allow(clippy::all)]
+#[rustfmt::skip]
 pub mod pg;
 
 #[allow(clippy::all)]
@@ -7,8 +8,33 @@ pub mod pg_actions;
 
 pub mod pg_builder;
 
+// Builder of property graphs from Neo4j import CSV files
+pub mod csv_builder;
+
+// Builder of property graphs from GraphML documents
+pub mod graphml_builder;
+
+// Builder of property graphs from JSON Lines with a node or edge per line
+pub mod jsonl_builder;
+
+// Builder of property graphs from the CREATE and MERGE clauses of Cypher scripts
+pub mod cypher_builder;
+
+// Literals shared by the builders of the PG format and YARS-PG
+pub(crate) mod literal;
+
+// Builder of property graphs from the PG format of the pg-format tools
+pub mod pg_format_builder;
+
+// Builder of property graphs from PG-JSON documents
+pub mod pg_json_builder;
+
+// Builder of property graphs from YARS-PG node and edge statements
+pub mod yarspg_builder;
+
 // Parser for property graph schemas
 #[allow(clippy::all)]
+#[rustfmt::skip]
 pub mod pgs;
 #[allow(clippy::all)]
 pub mod pgs_actions;
@@ -16,8 +42,15 @@ pub mod pgs_actions;
 // Builder created for property graph schemas
 pub mod pgs_builder;
 
+// Resolver of the schemas imported by other schemas
+pub mod pgs_resolver;
+
+// Builder of property graph schemas from ISO GQL graph type statements
+pub mod gql_builder;
+
 // Parser for type maps associations
 #[allow(clippy::all)]
+#[rustfmt::skip]
 pub mod map;
 #[allow(clippy::all)]
 pub mod map_actions;
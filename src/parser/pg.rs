@@ -1,25 +1,25 @@
-use super::pg_actions;
-use rustemo::Action::{self, Accept, Reduce, Shift};
-use rustemo::LRBuilder;
+/// Generated by rustemo. Do not edit manually!
+use std::fmt::Debug;
+use std::hash::Hash;
+use rustemo::{
+    Result, Input as InputT, Lexer, Token, TokenRecognizer as TokenRecognizerT, Parser,
+    ParserDefinition, State as StateT, Builder,
+};
+use rustemo::regex::Regex;
+use rustemo::once_cell::sync::Lazy;
 use rustemo::StringLexer;
+use rustemo::LRBuilder;
+use super::pg_actions;
+use rustemo::{LRParser, LRContext};
+use rustemo::Action::{self, Shift, Reduce, Accept};
+#[allow(unused_imports)]
+use rustemo::debug::{log, logn};
 #[allow(unused_imports)]
 #[cfg(debug_assertions)]
 use rustemo::colored::*;
-#[allow(unused_imports)]
-use rustemo::debug::{log, logn};
-use rustemo::once_cell::sync::Lazy;
-use rustemo::regex::Regex;
-use rustemo::{
-    Builder, Input as InputT, Lexer, Parser, ParserDefinition, Result, State as StateT, Token,
-    TokenRecognizer as TokenRecognizerT,
-};
-use rustemo::{LRContext, LRParser};
-/// Generated by rustemo. Do not edit manually!
-use std::fmt::Debug;
-use std::hash::Hash;
 pub type Input = str;
-const STATE_COUNT: usize = 82usize;
-const MAX_RECOGNIZERS: usize = 6usize;
+const STATE_COUNT: usize = 83usize;
+const MAX_RECOGNIZERS: usize = 7usize;
 #[allow(dead_code)]
 const TERMINAL_COUNT: usize = 40usize;
 #[allow(clippy::upper_case_acronyms)]
@@ -110,6 +110,7 @@ pub enum ProdKind {
     SingleValueNumberValue,
     SingleValueDateValue,
     SingleValueBooleanValue,
+    SingleValueRecordValue,
     BOOLP1,
     BOOLP2,
     LayoutP1,
@@ -136,7 +137,9 @@ impl std::fmt::Debug for ProdKind {
         let name = match self {
             ProdKind::PgP1 => "Pg: Declarations",
             ProdKind::DeclarationsP1 => "Declarations: Declaration1",
-            ProdKind::Declaration1P1 => "Declaration1: Declaration1 SEMICOLON Declaration",
+            ProdKind::Declaration1P1 => {
+                "Declaration1: Declaration1 SEMICOLON Declaration"
+            }
             ProdKind::Declaration1P2 => "Declaration1: Declaration",
             ProdKind::DeclarationP1 => "Declaration: Node",
             ProdKind::DeclarationP2 => "Declaration: Edge",
@@ -160,7 +163,9 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::PropertyP1 => "Property: key COLON Values",
             ProdKind::keyP1 => "key: IDENTIFIER",
             ProdKind::ValuesP1 => "Values: SingleValue",
-            ProdKind::ValuesListValue => "Values: OPENSQUAREBRACKET ListValues CLOSESQUAREBRACKET",
+            ProdKind::ValuesListValue => {
+                "Values: OPENSQUAREBRACKET ListValues CLOSESQUAREBRACKET"
+            }
             ProdKind::ListValuesP1 => "ListValues: SingleValue0",
             ProdKind::SingleValue1P1 => "SingleValue1: SingleValue1 COMMA SingleValue",
             ProdKind::SingleValue1P2 => "SingleValue1: SingleValue",
@@ -170,6 +175,7 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::SingleValueNumberValue => "SingleValue: NUMBER",
             ProdKind::SingleValueDateValue => "SingleValue: DATE QUOTED_STRING",
             ProdKind::SingleValueBooleanValue => "SingleValue: BOOL",
+            ProdKind::SingleValueRecordValue => "SingleValue: Record",
             ProdKind::BOOLP1 => "BOOL: TRUE",
             ProdKind::BOOLP2 => "BOOL: FALSE",
             ProdKind::LayoutP1 => "Layout: LayoutItem0",
@@ -270,6 +276,7 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::SingleValueNumberValue => NonTermKind::SingleValue,
             ProdKind::SingleValueDateValue => NonTermKind::SingleValue,
             ProdKind::SingleValueBooleanValue => NonTermKind::SingleValue,
+            ProdKind::SingleValueRecordValue => NonTermKind::SingleValue,
             ProdKind::BOOLP1 => NonTermKind::BOOL,
             ProdKind::BOOLP2 => NonTermKind::BOOL,
             ProdKind::LayoutP1 => NonTermKind::Layout,
@@ -341,47 +348,48 @@ pub enum State {
     TRUES42,
     FALSES43,
     DATES44,
-    ValuesS45,
-    SingleValueS46,
-    BOOLS47,
-    START_ARROWS48,
-    ListValuesS49,
-    SingleValue1S50,
-    SingleValue0S51,
-    SingleValueS52,
-    QUOTED_STRINGS53,
-    LabelsRecordS54,
-    CLOSESQUAREBRACKETS55,
-    COMMAS56,
-    END_ARROWS57,
-    SingleValueS58,
-    OPEN_PARENS59,
-    IDENTIFIERS60,
-    CLOSE_PARENS61,
-    CLOSESQUAREBRACKETS62,
-    AUGLS63,
-    WSS64,
-    CommentLineS65,
-    START_COMMENTS66,
-    LayoutS67,
-    LayoutItem1S68,
-    LayoutItem0S69,
-    LayoutItemS70,
-    CommentS71,
-    WSS72,
-    NotCommentS73,
-    CommentS74,
-    CorncsS75,
-    Cornc1S76,
-    Cornc0S77,
-    CorncS78,
-    LayoutItemS79,
-    END_COMMENTS80,
-    CorncS81,
+    RecordS45,
+    ValuesS46,
+    SingleValueS47,
+    BOOLS48,
+    START_ARROWS49,
+    ListValuesS50,
+    SingleValue1S51,
+    SingleValue0S52,
+    SingleValueS53,
+    QUOTED_STRINGS54,
+    LabelsRecordS55,
+    CLOSESQUAREBRACKETS56,
+    COMMAS57,
+    END_ARROWS58,
+    SingleValueS59,
+    OPEN_PARENS60,
+    IDENTIFIERS61,
+    CLOSE_PARENS62,
+    CLOSESQUAREBRACKETS63,
+    AUGLS64,
+    WSS65,
+    CommentLineS66,
+    START_COMMENTS67,
+    LayoutS68,
+    LayoutItem1S69,
+    LayoutItem0S70,
+    LayoutItemS71,
+    CommentS72,
+    WSS73,
+    NotCommentS74,
+    CommentS75,
+    CorncsS76,
+    Cornc1S77,
+    Cornc0S78,
+    CorncS79,
+    LayoutItemS80,
+    END_COMMENTS81,
+    CorncS82,
 }
 impl StateT for State {
     fn default_layout() -> Option<Self> {
-        Some(State::AUGLS63)
+        Some(State::AUGLS64)
     }
 }
 impl From<State> for usize {
@@ -437,43 +445,44 @@ impl std::fmt::Debug for State {
             State::TRUES42 => "42:TRUE",
             State::FALSES43 => "43:FALSE",
             State::DATES44 => "44:DATE",
-            State::ValuesS45 => "45:Values",
-            State::SingleValueS46 => "46:SingleValue",
-            State::BOOLS47 => "47:BOOL",
-            State::START_ARROWS48 => "48:START_ARROW",
-            State::ListValuesS49 => "49:ListValues",
-            State::SingleValue1S50 => "50:SingleValue1",
-            State::SingleValue0S51 => "51:SingleValue0",
-            State::SingleValueS52 => "52:SingleValue",
-            State::QUOTED_STRINGS53 => "53:QUOTED_STRING",
-            State::LabelsRecordS54 => "54:LabelsRecord",
-            State::CLOSESQUAREBRACKETS55 => "55:CLOSESQUAREBRACKET",
-            State::COMMAS56 => "56:COMMA",
-            State::END_ARROWS57 => "57:END_ARROW",
-            State::SingleValueS58 => "58:SingleValue",
-            State::OPEN_PARENS59 => "59:OPEN_PAREN",
-            State::IDENTIFIERS60 => "60:IDENTIFIER",
-            State::CLOSE_PARENS61 => "61:CLOSE_PAREN",
-            State::CLOSESQUAREBRACKETS62 => "62:CLOSESQUAREBRACKET",
-            State::AUGLS63 => "63:AUGL",
-            State::WSS64 => "64:WS",
-            State::CommentLineS65 => "65:CommentLine",
-            State::START_COMMENTS66 => "66:START_COMMENT",
-            State::LayoutS67 => "67:Layout",
-            State::LayoutItem1S68 => "68:LayoutItem1",
-            State::LayoutItem0S69 => "69:LayoutItem0",
-            State::LayoutItemS70 => "70:LayoutItem",
-            State::CommentS71 => "71:Comment",
-            State::WSS72 => "72:WS",
-            State::NotCommentS73 => "73:NotComment",
-            State::CommentS74 => "74:Comment",
-            State::CorncsS75 => "75:Corncs",
-            State::Cornc1S76 => "76:Cornc1",
-            State::Cornc0S77 => "77:Cornc0",
-            State::CorncS78 => "78:Cornc",
-            State::LayoutItemS79 => "79:LayoutItem",
-            State::END_COMMENTS80 => "80:END_COMMENT",
-            State::CorncS81 => "81:Cornc",
+            State::RecordS45 => "45:Record",
+            State::ValuesS46 => "46:Values",
+            State::SingleValueS47 => "47:SingleValue",
+            State::BOOLS48 => "48:BOOL",
+            State::START_ARROWS49 => "49:START_ARROW",
+            State::ListValuesS50 => "50:ListValues",
+            State::SingleValue1S51 => "51:SingleValue1",
+            State::SingleValue0S52 => "52:SingleValue0",
+            State::SingleValueS53 => "53:SingleValue",
+            State::QUOTED_STRINGS54 => "54:QUOTED_STRING",
+            State::LabelsRecordS55 => "55:LabelsRecord",
+            State::CLOSESQUAREBRACKETS56 => "56:CLOSESQUAREBRACKET",
+            State::COMMAS57 => "57:COMMA",
+            State::END_ARROWS58 => "58:END_ARROW",
+            State::SingleValueS59 => "59:SingleValue",
+            State::OPEN_PARENS60 => "60:OPEN_PAREN",
+            State::IDENTIFIERS61 => "61:IDENTIFIER",
+            State::CLOSE_PARENS62 => "62:CLOSE_PAREN",
+            State::CLOSESQUAREBRACKETS63 => "63:CLOSESQUAREBRACKET",
+            State::AUGLS64 => "64:AUGL",
+            State::WSS65 => "65:WS",
+            State::CommentLineS66 => "66:CommentLine",
+            State::START_COMMENTS67 => "67:START_COMMENT",
+            State::LayoutS68 => "68:Layout",
+            State::LayoutItem1S69 => "69:LayoutItem1",
+            State::LayoutItem0S70 => "70:LayoutItem0",
+            State::LayoutItemS71 => "71:LayoutItem",
+            State::CommentS72 => "72:Comment",
+            State::WSS73 => "73:WS",
+            State::NotCommentS74 => "74:NotComment",
+            State::CommentS75 => "75:Comment",
+            State::CorncsS76 => "76:Corncs",
+            State::Cornc1S77 => "77:Cornc1",
+            State::Cornc0S78 => "78:Cornc0",
+            State::CorncS79 => "79:Cornc",
+            State::LayoutItemS80 => "80:LayoutItem",
+            State::END_COMMENTS81 => "81:END_COMMENT",
+            State::CorncS82 => "82:Cornc",
         };
         write!(f, "{name}")
     }
@@ -773,6 +782,9 @@ fn action_identifier_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
 fn action_close_curly_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordP1, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordP1, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordP1, 3usize)]),
+        TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::RecordP1, 3usize)]),
         TK::END_ARROW => Vec::from(&[Reduce(PK::RecordP1, 3usize)]),
         _ => vec![],
     }
@@ -786,6 +798,7 @@ fn action_comma_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
 fn action_colon_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS39)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS22)]),
         TK::NUMBER => Vec::from(&[Shift(State::NUMBERS40)]),
         TK::OPENSQUAREBRACKET => Vec::from(&[Shift(State::OPENSQUAREBRACKETS41)]),
         TK::TRUE => Vec::from(&[Shift(State::TRUES42)]),
@@ -796,7 +809,7 @@ fn action_colon_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
 }
 fn action_close_paren_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::START_ARROW => Vec::from(&[Shift(State::START_ARROWS48)]),
+        TK::START_ARROW => Vec::from(&[Shift(State::START_ARROWS49)]),
         _ => vec![],
     }
 }
@@ -811,7 +824,9 @@ fn action_quoted_string_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind
     match token_kind {
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::CLOSESQUAREBRACKET => {
+            Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)])
+        }
         _ => vec![],
     }
 }
@@ -819,13 +834,16 @@ fn action_number_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::CLOSESQUAREBRACKET => {
+            Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)])
+        }
         _ => vec![],
     }
 }
 fn action_opensquarebracket_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS39)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS22)]),
         TK::NUMBER => Vec::from(&[Shift(State::NUMBERS40)]),
         TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::SingleValue0P2, 0usize)]),
         TK::TRUE => Vec::from(&[Shift(State::TRUES42)]),
@@ -852,33 +870,45 @@ fn action_false_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
 }
 fn action_date_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS53)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS54)]),
         _ => vec![],
     }
 }
-fn action_values_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_record_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueRecordValue, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueRecordValue, 1usize)]),
+        TK::CLOSESQUAREBRACKET => {
+            Vec::from(&[Reduce(PK::SingleValueRecordValue, 1usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_values_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlevalue_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::ValuesP1, 1usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::ValuesP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_bool_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_bool_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::CLOSESQUAREBRACKET => {
+            Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)])
+        }
         _ => vec![],
     }
 }
-fn action_start_arrow_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_start_arrow_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelsOptP2, 0usize)]),
         TK::COLON => Vec::from(&[Shift(State::COLONS13)]),
@@ -886,33 +916,33 @@ fn action_start_arrow_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_listvalues_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_listvalues_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSESQUAREBRACKET => Vec::from(&[Shift(State::CLOSESQUAREBRACKETS55)]),
+        TK::CLOSESQUAREBRACKET => Vec::from(&[Shift(State::CLOSESQUAREBRACKETS56)]),
         _ => vec![],
     }
 }
-fn action_singlevalue1_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlevalue1_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::COMMA => Vec::from(&[Shift(State::COMMAS56)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS57)]),
         TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::SingleValue0P1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_singlevalue0_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlevalue0_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::ListValuesP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlevalue_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::COMMA => Vec::from(&[Reduce(PK::SingleValue1P2, 1usize)]),
         TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::SingleValue1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_quoted_string_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_quoted_string_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
@@ -920,22 +950,23 @@ fn action_quoted_string_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind
         _ => vec![],
     }
 }
-fn action_labelsrecord_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_labelsrecord_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::END_ARROW => Vec::from(&[Shift(State::END_ARROWS57)]),
+        TK::END_ARROW => Vec::from(&[Shift(State::END_ARROWS58)]),
         _ => vec![],
     }
 }
-fn action_closesquarebracket_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_closesquarebracket_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::ValuesListValue, 3usize)]),
         TK::COMMA => Vec::from(&[Reduce(PK::ValuesListValue, 3usize)]),
         _ => vec![],
     }
 }
-fn action_comma_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS39)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS22)]),
         TK::NUMBER => Vec::from(&[Shift(State::NUMBERS40)]),
         TK::TRUE => Vec::from(&[Shift(State::TRUES42)]),
         TK::FALSE => Vec::from(&[Shift(State::FALSES43)]),
@@ -943,54 +974,54 @@ fn action_comma_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_end_arrow_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_end_arrow_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS59)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS60)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlevalue_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::COMMA => Vec::from(&[Reduce(PK::SingleValue1P1, 3usize)]),
         TK::CLOSESQUAREBRACKET => Vec::from(&[Reduce(PK::SingleValue1P1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_open_paren_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS60)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS61)]),
         _ => vec![],
     }
 }
-fn action_identifier_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS61)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS62)]),
         _ => vec![],
     }
 }
-fn action_close_paren_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSESQUAREBRACKET => Vec::from(&[Shift(State::CLOSESQUAREBRACKETS62)]),
+        TK::CLOSESQUAREBRACKET => Vec::from(&[Shift(State::CLOSESQUAREBRACKETS63)]),
         _ => vec![],
     }
 }
-fn action_closesquarebracket_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_closesquarebracket_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::EdgeP1, 13usize)]),
         TK::SEMICOLON => Vec::from(&[Reduce(PK::EdgeP1, 13usize)]),
         _ => vec![],
     }
 }
-fn action_augl_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_augl_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P2, 0usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS64)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS65)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS66)]),
+        TK::WS => Vec::from(&[Shift(State::WSS65)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS66)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS67)]),
         _ => vec![],
     }
 }
-fn action_ws_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ws_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
         TK::WS => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
@@ -999,7 +1030,7 @@ fn action_ws_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_commentline_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_commentline_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
         TK::WS => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
@@ -1010,38 +1041,38 @@ fn action_commentline_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_start_comment_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_start_comment_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Shift(State::WSS72)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS65)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS73)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS66)]),
+        TK::WS => Vec::from(&[Shift(State::WSS73)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS66)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS74)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS67)]),
         TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc0P2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_layout_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layout_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Accept]),
         _ => vec![],
     }
 }
-fn action_layoutitem1_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem1_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P1, 1usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS64)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS65)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS66)]),
+        TK::WS => Vec::from(&[Shift(State::WSS65)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS66)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS67)]),
         _ => vec![],
     }
 }
-fn action_layoutitem0_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem0_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_layoutitem_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
         TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
@@ -1050,7 +1081,7 @@ fn action_layoutitem_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_comment_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comment_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
         TK::WS => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
@@ -1059,7 +1090,7 @@ fn action_comment_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_ws_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ws_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::WS => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
         TK::CommentLine => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
@@ -1069,7 +1100,7 @@ fn action_ws_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_notcomment_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_notcomment_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::WS => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
         TK::CommentLine => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
@@ -1079,7 +1110,7 @@ fn action_notcomment_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_comment_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comment_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::WS => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
         TK::CommentLine => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
@@ -1089,29 +1120,29 @@ fn action_comment_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_corncs_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_corncs_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::END_COMMENT => Vec::from(&[Shift(State::END_COMMENTS80)]),
+        TK::END_COMMENT => Vec::from(&[Shift(State::END_COMMENTS81)]),
         _ => vec![],
     }
 }
-fn action_cornc1_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc1_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Shift(State::WSS72)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS65)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS73)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS66)]),
+        TK::WS => Vec::from(&[Shift(State::WSS73)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS66)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS74)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS67)]),
         TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc0P1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cornc0_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc0_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncsP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cornc_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::WS => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
         TK::CommentLine => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
@@ -1121,7 +1152,7 @@ fn action_cornc_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_layoutitem_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
         TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
@@ -1130,7 +1161,7 @@ fn action_layoutitem_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_end_comment_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_end_comment_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
         TK::WS => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
@@ -1141,7 +1172,7 @@ fn action_end_comment_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_cornc_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::WS => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
         TK::CommentLine => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
@@ -1266,9 +1297,10 @@ fn goto_comma_s35(nonterm_kind: NonTermKind) -> State {
 }
 fn goto_colon_s36(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Values => State::ValuesS45,
-        NonTermKind::SingleValue => State::SingleValueS46,
-        NonTermKind::BOOL => State::BOOLS47,
+        NonTermKind::Record => State::RecordS45,
+        NonTermKind::Values => State::ValuesS46,
+        NonTermKind::SingleValue => State::SingleValueS47,
+        NonTermKind::BOOL => State::BOOLS48,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -1279,11 +1311,12 @@ fn goto_colon_s36(nonterm_kind: NonTermKind) -> State {
 }
 fn goto_opensquarebracket_s41(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ListValues => State::ListValuesS49,
-        NonTermKind::SingleValue1 => State::SingleValue1S50,
-        NonTermKind::SingleValue0 => State::SingleValue0S51,
-        NonTermKind::SingleValue => State::SingleValueS52,
-        NonTermKind::BOOL => State::BOOLS47,
+        NonTermKind::Record => State::RecordS45,
+        NonTermKind::ListValues => State::ListValuesS50,
+        NonTermKind::SingleValue1 => State::SingleValue1S51,
+        NonTermKind::SingleValue0 => State::SingleValue0S52,
+        NonTermKind::SingleValue => State::SingleValueS53,
+        NonTermKind::BOOL => State::BOOLS48,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -1292,81 +1325,82 @@ fn goto_opensquarebracket_s41(nonterm_kind: NonTermKind) -> State {
         }
     }
 }
-fn goto_start_arrow_s48(nonterm_kind: NonTermKind) -> State {
+fn goto_start_arrow_s49(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::LabelsRecord => State::LabelsRecordS54,
+        NonTermKind::LabelsRecord => State::LabelsRecordS55,
         NonTermKind::LabelsOpt => State::LabelsOptS15,
         NonTermKind::Labels => State::LabelsS16,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::START_ARROWS48
+                State::START_ARROWS49
             )
         }
     }
 }
-fn goto_comma_s56(nonterm_kind: NonTermKind) -> State {
+fn goto_comma_s57(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::SingleValue => State::SingleValueS58,
-        NonTermKind::BOOL => State::BOOLS47,
+        NonTermKind::Record => State::RecordS45,
+        NonTermKind::SingleValue => State::SingleValueS59,
+        NonTermKind::BOOL => State::BOOLS48,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::COMMAS56
+                State::COMMAS57
             )
         }
     }
 }
-fn goto_augl_s63(nonterm_kind: NonTermKind) -> State {
+fn goto_augl_s64(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Layout => State::LayoutS67,
-        NonTermKind::LayoutItem1 => State::LayoutItem1S68,
-        NonTermKind::LayoutItem0 => State::LayoutItem0S69,
-        NonTermKind::LayoutItem => State::LayoutItemS70,
-        NonTermKind::Comment => State::CommentS71,
+        NonTermKind::Layout => State::LayoutS68,
+        NonTermKind::LayoutItem1 => State::LayoutItem1S69,
+        NonTermKind::LayoutItem0 => State::LayoutItem0S70,
+        NonTermKind::LayoutItem => State::LayoutItemS71,
+        NonTermKind::Comment => State::CommentS72,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGLS63
+                State::AUGLS64
             )
         }
     }
 }
-fn goto_start_comment_s66(nonterm_kind: NonTermKind) -> State {
+fn goto_start_comment_s67(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Comment => State::CommentS74,
-        NonTermKind::Corncs => State::CorncsS75,
-        NonTermKind::Cornc1 => State::Cornc1S76,
-        NonTermKind::Cornc0 => State::Cornc0S77,
-        NonTermKind::Cornc => State::CorncS78,
+        NonTermKind::Comment => State::CommentS75,
+        NonTermKind::Corncs => State::CorncsS76,
+        NonTermKind::Cornc1 => State::Cornc1S77,
+        NonTermKind::Cornc0 => State::Cornc0S78,
+        NonTermKind::Cornc => State::CorncS79,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::START_COMMENTS66
+                State::START_COMMENTS67
             )
         }
     }
 }
-fn goto_layoutitem1_s68(nonterm_kind: NonTermKind) -> State {
+fn goto_layoutitem1_s69(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::LayoutItem => State::LayoutItemS79,
-        NonTermKind::Comment => State::CommentS71,
+        NonTermKind::LayoutItem => State::LayoutItemS80,
+        NonTermKind::Comment => State::CommentS72,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LayoutItem1S68
+                State::LayoutItem1S69
             )
         }
     }
 }
-fn goto_cornc1_s76(nonterm_kind: NonTermKind) -> State {
+fn goto_cornc1_s77(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Comment => State::CommentS74,
-        NonTermKind::Cornc => State::CorncS81,
+        NonTermKind::Comment => State::CommentS75,
+        NonTermKind::Cornc => State::CorncS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Cornc1S76
+                State::Cornc1S77
             )
         }
     }
@@ -1421,43 +1455,44 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
         action_true_s42,
         action_false_s43,
         action_date_s44,
-        action_values_s45,
-        action_singlevalue_s46,
-        action_bool_s47,
-        action_start_arrow_s48,
-        action_listvalues_s49,
-        action_singlevalue1_s50,
-        action_singlevalue0_s51,
-        action_singlevalue_s52,
-        action_quoted_string_s53,
-        action_labelsrecord_s54,
-        action_closesquarebracket_s55,
-        action_comma_s56,
-        action_end_arrow_s57,
-        action_singlevalue_s58,
-        action_open_paren_s59,
-        action_identifier_s60,
-        action_close_paren_s61,
-        action_closesquarebracket_s62,
-        action_augl_s63,
-        action_ws_s64,
-        action_commentline_s65,
-        action_start_comment_s66,
-        action_layout_s67,
-        action_layoutitem1_s68,
-        action_layoutitem0_s69,
-        action_layoutitem_s70,
-        action_comment_s71,
-        action_ws_s72,
-        action_notcomment_s73,
-        action_comment_s74,
-        action_corncs_s75,
-        action_cornc1_s76,
-        action_cornc0_s77,
-        action_cornc_s78,
-        action_layoutitem_s79,
-        action_end_comment_s80,
-        action_cornc_s81,
+        action_record_s45,
+        action_values_s46,
+        action_singlevalue_s47,
+        action_bool_s48,
+        action_start_arrow_s49,
+        action_listvalues_s50,
+        action_singlevalue1_s51,
+        action_singlevalue0_s52,
+        action_singlevalue_s53,
+        action_quoted_string_s54,
+        action_labelsrecord_s55,
+        action_closesquarebracket_s56,
+        action_comma_s57,
+        action_end_arrow_s58,
+        action_singlevalue_s59,
+        action_open_paren_s60,
+        action_identifier_s61,
+        action_close_paren_s62,
+        action_closesquarebracket_s63,
+        action_augl_s64,
+        action_ws_s65,
+        action_commentline_s66,
+        action_start_comment_s67,
+        action_layout_s68,
+        action_layoutitem1_s69,
+        action_layoutitem0_s70,
+        action_layoutitem_s71,
+        action_comment_s72,
+        action_ws_s73,
+        action_notcomment_s74,
+        action_comment_s75,
+        action_corncs_s76,
+        action_cornc1_s77,
+        action_cornc0_s78,
+        action_cornc_s79,
+        action_layoutitem_s80,
+        action_end_comment_s81,
+        action_cornc_s82,
     ],
     gotos: [
         goto_aug_s0,
@@ -1508,7 +1543,8 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_start_arrow_s48,
+        goto_invalid,
+        goto_start_arrow_s49,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -1516,19 +1552,19 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_comma_s56,
+        goto_comma_s57,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_augl_s63,
+        goto_augl_s64,
         goto_invalid,
         goto_invalid,
-        goto_start_comment_s66,
+        goto_start_comment_s67,
         goto_invalid,
-        goto_layoutitem1_s68,
+        goto_layoutitem1_s69,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -1536,7 +1572,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_cornc1_s76,
+        goto_cornc1_s77,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -1551,11 +1587,12 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [Some((TK::STOP, false)), None, None, None, None, None],
-        [Some((TK::STOP, false)), None, None, None, None, None],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
+        [Some((TK::STOP, false)), None, None, None, None, None, None],
+        [Some((TK::STOP, false)), None, None, None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::SEMICOLON, true)),
@@ -1563,6 +1600,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1571,6 +1609,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1579,6 +1618,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1587,6 +1627,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_PAREN, true)),
@@ -1595,6 +1636,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_PAREN, true)),
@@ -1603,8 +1645,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::COLON, true)), None, None, None, None, None],
+        [Some((TK::COLON, true)), None, None, None, None, None, None],
         [
             Some((TK::OPEN_PAREN, true)),
             Some((TK::OPENSQUAREBRACKET, true)),
@@ -1612,9 +1655,10 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [Some((TK::CLOSE_PAREN, true)), None, None, None, None, None],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
+        [Some((TK::CLOSE_PAREN, true)), None, None, None, None, None, None],
         [
             Some((TK::END_ARROW, true)),
             Some((TK::CLOSE_PAREN, true)),
@@ -1622,6 +1666,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::END_ARROW, true)),
@@ -1630,8 +1675,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::OPEN_PAREN, true)), None, None, None, None, None],
+        [Some((TK::OPEN_PAREN, true)), None, None, None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::SEMICOLON, true)),
@@ -1639,6 +1685,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::END_ARROW, true)),
@@ -1647,6 +1694,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::AMPERSAND, true)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::END_ARROW, true)),
@@ -1655,6 +1703,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::AMPERSAND, true)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1663,8 +1712,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
         [
             Some((TK::END_ARROW, true)),
             Some((TK::CLOSE_PAREN, true)),
@@ -1672,6 +1722,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::END_ARROW, true)),
@@ -1680,11 +1731,12 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [Some((TK::COLON, true)), None, None, None, None, None],
-        [Some((TK::CLOSE_CURLY, true)), None, None, None, None, None],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
+        [Some((TK::COLON, true)), None, None, None, None, None, None],
+        [Some((TK::CLOSE_CURLY, true)), None, None, None, None, None, None],
         [
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
@@ -1692,6 +1744,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_CURLY, true)),
@@ -1700,9 +1753,10 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::COLON, true)), None, None, None, None, None],
-        [Some((TK::CLOSE_PAREN, true)), None, None, None, None, None],
+        [Some((TK::COLON, true)), None, None, None, None, None, None],
+        [Some((TK::CLOSE_PAREN, true)), None, None, None, None, None, None],
         [
             Some((TK::END_ARROW, true)),
             Some((TK::CLOSE_PAREN, true)),
@@ -1710,25 +1764,28 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::AMPERSAND, true)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::END_ARROW, true)),
             Some((TK::CLOSE_PAREN, true)),
-            None,
-            None,
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::CLOSESQUAREBRACKET, true)),
             None,
             None,
         ],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
         [
             Some((TK::FALSE, true)),
             Some((TK::TRUE, true)),
             Some((TK::DATE, true)),
+            Some((TK::OPEN_CURLY, true)),
             Some((TK::OPENSQUAREBRACKET, true)),
             Some((TK::QUOTED_STRING, false)),
             Some((TK::NUMBER, false)),
         ],
-        [Some((TK::START_ARROW, true)), None, None, None, None, None],
+        [Some((TK::START_ARROW, true)), None, None, None, None, None, None],
         [
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
@@ -1736,6 +1793,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_CURLY, true)),
@@ -1744,6 +1802,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_CURLY, true)),
@@ -1752,11 +1811,13 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::FALSE, true)),
             Some((TK::TRUE, true)),
             Some((TK::DATE, true)),
+            Some((TK::OPEN_CURLY, true)),
             Some((TK::CLOSESQUAREBRACKET, true)),
             Some((TK::QUOTED_STRING, false)),
             Some((TK::NUMBER, false)),
@@ -1768,6 +1829,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_CURLY, true)),
@@ -1776,10 +1838,13 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
+        [Some((TK::QUOTED_STRING, false)), None, None, None, None, None, None],
         [
-            Some((TK::QUOTED_STRING, false)),
-            None,
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::CLOSESQUAREBRACKET, true)),
             None,
             None,
             None,
@@ -1792,6 +1857,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_CURLY, true)),
@@ -1800,6 +1866,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_CURLY, true)),
@@ -1808,6 +1875,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::END_ARROW, true)),
@@ -1816,15 +1884,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSESQUAREBRACKET, true)),
-            None,
-            None,
-            None,
-            None,
             None,
         ],
+        [Some((TK::CLOSESQUAREBRACKET, true)), None, None, None, None, None, None],
         [
             Some((TK::COMMA, true)),
             Some((TK::CLOSESQUAREBRACKET, true)),
@@ -1832,15 +1894,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSESQUAREBRACKET, true)),
-            None,
-            None,
-            None,
-            None,
             None,
         ],
+        [Some((TK::CLOSESQUAREBRACKET, true)), None, None, None, None, None, None],
         [
             Some((TK::COMMA, true)),
             Some((TK::CLOSESQUAREBRACKET, true)),
@@ -1848,6 +1904,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CLOSE_CURLY, true)),
@@ -1856,8 +1913,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
-        [Some((TK::END_ARROW, true)), None, None, None, None, None],
+        [Some((TK::END_ARROW, true)), None, None, None, None, None, None],
         [
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
@@ -1865,16 +1923,18 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::FALSE, true)),
             Some((TK::TRUE, true)),
             Some((TK::DATE, true)),
+            Some((TK::OPEN_CURLY, true)),
             Some((TK::QUOTED_STRING, false)),
             Some((TK::NUMBER, false)),
             None,
         ],
-        [Some((TK::OPEN_PAREN, true)), None, None, None, None, None],
+        [Some((TK::OPEN_PAREN, true)), None, None, None, None, None, None],
         [
             Some((TK::COMMA, true)),
             Some((TK::CLOSESQUAREBRACKET, true)),
@@ -1882,17 +1942,11 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
-        ],
-        [Some((TK::IDENTIFIER, false)), None, None, None, None, None],
-        [Some((TK::CLOSE_PAREN, true)), None, None, None, None, None],
-        [
-            Some((TK::CLOSESQUAREBRACKET, true)),
-            None,
-            None,
-            None,
-            None,
             None,
         ],
+        [Some((TK::IDENTIFIER, false)), None, None, None, None, None, None],
+        [Some((TK::CLOSE_PAREN, true)), None, None, None, None, None, None],
+        [Some((TK::CLOSESQUAREBRACKET, true)), None, None, None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::SEMICOLON, true)),
@@ -1900,6 +1954,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1908,6 +1963,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1916,6 +1972,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1924,6 +1981,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::WS, false)),
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
+            None,
         ],
         [
             Some((TK::START_COMMENT, true)),
@@ -1932,8 +1990,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
             None,
+            None,
         ],
-        [Some((TK::STOP, false)), None, None, None, None, None],
+        [Some((TK::STOP, false)), None, None, None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::START_COMMENT, true)),
@@ -1941,8 +2000,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             None,
             None,
+            None,
         ],
-        [Some((TK::STOP, false)), None, None, None, None, None],
+        [Some((TK::STOP, false)), None, None, None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::START_COMMENT, true)),
@@ -1950,6 +2010,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -1958,6 +2019,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::START_COMMENT, true)),
@@ -1966,6 +2028,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
             None,
+            None,
         ],
         [
             Some((TK::START_COMMENT, true)),
@@ -1974,6 +2037,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
             None,
+            None,
         ],
         [
             Some((TK::START_COMMENT, true)),
@@ -1982,8 +2046,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
             None,
+            None,
         ],
-        [Some((TK::END_COMMENT, true)), None, None, None, None, None],
+        [Some((TK::END_COMMENT, true)), None, None, None, None, None, None],
         [
             Some((TK::START_COMMENT, true)),
             Some((TK::END_COMMENT, true)),
@@ -1991,8 +2056,9 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
             None,
+            None,
         ],
-        [Some((TK::END_COMMENT, true)), None, None, None, None, None],
+        [Some((TK::END_COMMENT, true)), None, None, None, None, None, None],
         [
             Some((TK::START_COMMENT, true)),
             Some((TK::END_COMMENT, true)),
@@ -2000,6 +2066,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -2008,6 +2075,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -2016,6 +2084,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::WS, false)),
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
+            None,
         ],
         [
             Some((TK::START_COMMENT, true)),
@@ -2024,6 +2093,7 @@ pub(crate) static PARSER_DEFINITION: PgParserDefinition = PgParserDefinition {
             Some((TK::CommentLine, false)),
             Some((TK::NotComment, false)),
             None,
+            None,
         ],
     ],
 };
@@ -2035,10 +2105,7 @@ impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for PgParserDefin
         PARSER_DEFINITION.gotos[state as usize](nonterm)
     }
     fn expected_token_kinds(&self, state: State) -> Vec<(TokenKind, bool)> {
-        PARSER_DEFINITION.token_kinds[state as usize]
-            .iter()
-            .map_while(|t| *t)
-            .collect()
+        PARSER_DEFINITION.token_kinds[state as usize].iter().map_while(|t| *t).collect()
     }
     fn longest_match() -> bool {
         true
@@ -2068,27 +2135,30 @@ pub struct PgParser<
     >,
 );
 #[allow(dead_code)]
-impl<'i>
-    PgParser<
-        'i,
-        Input,
-        StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
-        DefaultBuilder,
-    >
-{
+impl<
+    'i,
+> PgParser<
+    'i,
+    Input,
+    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
+    DefaultBuilder,
+> {
     pub fn new() -> Self {
-        Self(LRParser::new(
-            &PARSER_DEFINITION,
-            State::default(),
-            false,
-            true,
-            StringLexer::new(false, &RECOGNIZERS),
-            DefaultBuilder::new(),
-        ))
+        Self(
+            LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                true,
+                StringLexer::new(false, &RECOGNIZERS),
+                DefaultBuilder::new(),
+            ),
+        )
     }
 }
 #[allow(dead_code)]
-impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind> for PgParser<'i, I, L, B>
+impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
+for PgParser<'i, I, L, B>
 where
     I: InputT + ?Sized + Debug,
     L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
@@ -2105,7 +2175,10 @@ where
     ) -> Result<Self::Output> {
         self.0.parse_with_context(context, input)
     }
-    fn parse_file<'a, F: AsRef<std::path::Path>>(&'a mut self, file: F) -> Result<Self::Output>
+    fn parse_file<'a, F: AsRef<std::path::Path>>(
+        &'a mut self,
+        file: F,
+    ) -> Result<Self::Output>
     where
         'a: 'i,
     {
@@ -2169,29 +2242,37 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
     TokenRecognizer(
         TokenKind::WS,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "\\s+")).unwrap())),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\s+")).unwrap() }),
+        ),
     ),
     TokenRecognizer(
         TokenKind::CommentLine,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "//.*")).unwrap())),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "//.*")).unwrap() }),
+        ),
     ),
     TokenRecognizer(
         TokenKind::NotComment,
-        Recognizer::RegexMatch(Lazy::new(|| {
-            Regex::new(concat!("^", "((\\*[^/])|[^\\s*/]|/[^\\*])+")).unwrap()
-        })),
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "((\\*[^/])|[^\\s*/]|/[^\\*])+")).unwrap()
+            }),
+        ),
     ),
     TokenRecognizer(TokenKind::START_COMMENT, Recognizer::StrMatch("/*")),
     TokenRecognizer(TokenKind::END_COMMENT, Recognizer::StrMatch("*/")),
     TokenRecognizer(
         TokenKind::QUOTED_STRING,
-        Recognizer::RegexMatch(Lazy::new(|| {
-            Regex::new(concat!("^", "\"((\\\\\")|[^\"])*\"")).unwrap()
-        })),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\"((\\\\\")|[^\"])*\"")).unwrap() }),
+        ),
     ),
     TokenRecognizer(
         TokenKind::IDENTIFIER,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "\\w+")).unwrap())),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\w+")).unwrap() }),
+        ),
     ),
     TokenRecognizer(TokenKind::SEMICOLON, Recognizer::StrMatch(";")),
     TokenRecognizer(TokenKind::CREATE, Recognizer::StrMatch("CREATE")),
@@ -2216,7 +2297,9 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(TokenKind::DATE_NAME, Recognizer::StrMatch("DATE")),
     TokenRecognizer(
         TokenKind::NUMBER,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "\\d+")).unwrap())),
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\d+")).unwrap() }),
+        ),
     ),
     TokenRecognizer(TokenKind::OPTIONAL, Recognizer::StrMatch("OPTIONAL")),
     TokenRecognizer(TokenKind::QUOTE, Recognizer::StrMatch("\\\"")),
@@ -2247,15 +2330,22 @@ impl Builder for DefaultBuilder {
         }
     }
 }
-impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> for DefaultBuilder {
+impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
+for DefaultBuilder {
     #![allow(unused_variables)]
-    fn shift_action(&mut self, context: &Context<'i, Input>, token: Token<'i, Input, TokenKind>) {
+    fn shift_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        token: Token<'i, Input, TokenKind>,
+    ) {
         let val = match token.kind {
             TokenKind::STOP => panic!("Cannot shift STOP token!"),
             TokenKind::QUOTED_STRING => {
                 Terminal::QUOTED_STRING(pg_actions::quoted_string(context, token))
             }
-            TokenKind::IDENTIFIER => Terminal::IDENTIFIER(pg_actions::identifier(context, token)),
+            TokenKind::IDENTIFIER => {
+                Terminal::IDENTIFIER(pg_actions::identifier(context, token))
+            }
             TokenKind::SEMICOLON => Terminal::SEMICOLON,
             TokenKind::OPEN_PAREN => Terminal::OPEN_PAREN,
             TokenKind::CLOSE_PAREN => Terminal::CLOSE_PAREN,
@@ -2276,7 +2366,12 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
         };
         self.res_stack.push(Symbol::Terminal(val));
     }
-    fn reduce_action(&mut self, context: &Context<'i, Input>, prod: ProdKind, prod_len: usize) {
+    fn reduce_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        prod: ProdKind,
+        prod_len: usize,
+    ) {
         let prod = match prod {
             ProdKind::PgP1 => {
                 let mut i = self
@@ -2297,9 +2392,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Declaration1(p0)) => {
-                        NonTerminal::Declarations(pg_actions::declarations_declaration1(
-                            context, p0,
-                        ))
+                        NonTerminal::Declarations(
+                            pg_actions::declarations_declaration1(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2314,7 +2409,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::Declaration1(p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::Declaration(p1)),
-                    ) => NonTerminal::Declaration1(pg_actions::declaration1_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::Declaration1(
+                            pg_actions::declaration1_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -2325,7 +2424,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Declaration(p0)) => {
-                        NonTerminal::Declaration1(pg_actions::declaration1_declaration(context, p0))
+                        NonTerminal::Declaration1(
+                            pg_actions::declaration1_declaration(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2337,7 +2438,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Node(p0)) => {
-                        NonTerminal::Declaration(pg_actions::declaration_node(context, p0))
+                        NonTerminal::Declaration(
+                            pg_actions::declaration_node(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2349,7 +2452,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Edge(p0)) => {
-                        NonTerminal::Declaration(pg_actions::declaration_edge(context, p0))
+                        NonTerminal::Declaration(
+                            pg_actions::declaration_edge(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2433,7 +2538,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     (
                         Symbol::NonTerminal(NonTerminal::LabelsOpt(p0)),
                         Symbol::NonTerminal(NonTerminal::RecordOpt(p1)),
-                    ) => NonTerminal::LabelsRecord(pg_actions::labels_record_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::LabelsRecord(
+                            pg_actions::labels_record_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -2444,12 +2553,16 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Labels(p0)) => {
-                        NonTerminal::LabelsOpt(pg_actions::labels_opt_labels(context, p0))
+                        NonTerminal::LabelsOpt(
+                            pg_actions::labels_opt_labels(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::LabelsOptP2 => NonTerminal::LabelsOpt(pg_actions::labels_opt_empty(context)),
+            ProdKind::LabelsOptP2 => {
+                NonTerminal::LabelsOpt(pg_actions::labels_opt_empty(context))
+            }
             ProdKind::RecordOptP1 => {
                 let mut i = self
                     .res_stack
@@ -2457,12 +2570,16 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Record(p0)) => {
-                        NonTerminal::RecordOpt(pg_actions::record_opt_record(context, p0))
+                        NonTerminal::RecordOpt(
+                            pg_actions::record_opt_record(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::RecordOptP2 => NonTerminal::RecordOpt(pg_actions::record_opt_empty(context)),
+            ProdKind::RecordOptP2 => {
+                NonTerminal::RecordOpt(pg_actions::record_opt_empty(context))
+            }
             ProdKind::LabelsP1 => {
                 let mut i = self
                     .res_stack
@@ -2485,7 +2602,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::IDENTIFIER1(p0)),
                         _,
                         Symbol::Terminal(Terminal::IDENTIFIER(p1)),
-                    ) => NonTerminal::IDENTIFIER1(pg_actions::identifier1_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::IDENTIFIER1(
+                            pg_actions::identifier1_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -2496,7 +2617,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::Terminal(Terminal::IDENTIFIER(p0)) => {
-                        NonTerminal::IDENTIFIER1(pg_actions::identifier1_identifier(context, p0))
+                        NonTerminal::IDENTIFIER1(
+                            pg_actions::identifier1_identifier(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2520,7 +2643,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Property1(p0)) => {
-                        NonTerminal::Properties(pg_actions::properties_property1(context, p0))
+                        NonTerminal::Properties(
+                            pg_actions::properties_property1(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2535,7 +2660,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::Property1(p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::Property(p1)),
-                    ) => NonTerminal::Property1(pg_actions::property1_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::Property1(pg_actions::property1_c1(context, p0, p1))
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -2546,7 +2673,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Property(p0)) => {
-                        NonTerminal::Property1(pg_actions::property1_property(context, p0))
+                        NonTerminal::Property1(
+                            pg_actions::property1_property(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2608,7 +2737,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::SingleValue0(p0)) => {
-                        NonTerminal::ListValues(pg_actions::list_values_single_value0(context, p0))
+                        NonTerminal::ListValues(
+                            pg_actions::list_values_single_value0(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2623,7 +2754,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::SingleValue1(p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::SingleValue(p1)),
-                    ) => NonTerminal::SingleValue1(pg_actions::single_value1_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::SingleValue1(
+                            pg_actions::single_value1_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -2633,9 +2768,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::SingleValue(p0)) => NonTerminal::SingleValue1(
-                        pg_actions::single_value1_single_value(context, p0),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::SingleValue(p0)) => {
+                        NonTerminal::SingleValue1(
+                            pg_actions::single_value1_single_value(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -2646,9 +2783,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::SingleValue1(p0)) => {
-                        NonTerminal::SingleValue0(pg_actions::single_value0_single_value1(
-                            context, p0,
-                        ))
+                        NonTerminal::SingleValue0(
+                            pg_actions::single_value0_single_value1(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2663,7 +2800,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::Terminal(Terminal::QUOTED_STRING(p0)) => {
-                        NonTerminal::SingleValue(pg_actions::single_value_string_value(context, p0))
+                        NonTerminal::SingleValue(
+                            pg_actions::single_value_string_value(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2675,7 +2814,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::Terminal(Terminal::NUMBER(p0)) => {
-                        NonTerminal::SingleValue(pg_actions::single_value_number_value(context, p0))
+                        NonTerminal::SingleValue(
+                            pg_actions::single_value_number_value(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2687,7 +2828,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::Terminal(Terminal::QUOTED_STRING(p0))) => {
-                        NonTerminal::SingleValue(pg_actions::single_value_date_value(context, p0))
+                        NonTerminal::SingleValue(
+                            pg_actions::single_value_date_value(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -2698,9 +2841,25 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::BOOL(p0)) => NonTerminal::SingleValue(
-                        pg_actions::single_value_boolean_value(context, p0),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::BOOL(p0)) => {
+                        NonTerminal::SingleValue(
+                            pg_actions::single_value_boolean_value(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::SingleValueRecordValue => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Record(p0)) => {
+                        NonTerminal::SingleValue(
+                            pg_actions::single_value_record_value(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
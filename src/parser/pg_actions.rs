@@ -191,6 +191,7 @@ pub enum SingleValue {
     NumberValue(NUMBER),
     DateValue(QUOTED_STRING),
     BooleanValue(BOOL),
+    RecordValue(Box<Record>),
 }
 pub fn single_value_string_value(_ctx: &Ctx, quoted_string: QUOTED_STRING) -> SingleValue {
     SingleValue::StringValue(quoted_string)
@@ -204,6 +205,9 @@ pub fn single_value_date_value(_ctx: &Ctx, quoted_string: QUOTED_STRING) -> Sing
 pub fn single_value_boolean_value(_ctx: &Ctx, bool: BOOL) -> SingleValue {
     SingleValue::BooleanValue(bool)
 }
+pub fn single_value_record_value(_ctx: &Ctx, record: Record) -> SingleValue {
+    SingleValue::RecordValue(Box::new(record))
+}
 #[derive(Debug, Clone)]
 pub enum BOOL {
     TRUE,
@@ -49,7 +49,7 @@ fn get_declaration(decl: Declaration, pg: &mut PropertyGraph) -> Result<(), PgsE
     let either = get_node_or_edge(decl.node_edge)?;
     match either {
         Either::Left((labels, record)) => {
-            pg.add_node(id, labels, record);
+            pg.add_node(id, labels, record)?;
         }
         Either::Right((source, labels, record, target)) => {
             pg.add_edge(id, source, labels, record, target)?;
@@ -69,8 +69,7 @@ fn get_edge(edge: Edge, pg: &mut PropertyGraph) -> Result<(), PgsError> {
 fn get_node(node: Node, pg: &mut PropertyGraph) -> Result<(), PgsError> {
     let id = get_id(node.id)?;
     let (labels, record) = get_labels_record(node.labels_record)?;
-    pg.add_node(id, labels, record);
-    Ok(())
+    pg.add_node(id, labels, record)
 }
 
 fn get_labels_record(
@@ -110,24 +109,24 @@ fn get_properties(property_spec: Vec<Property>) -> Result<Record, PgsError> {
     Ok(record)
 }
 
-fn get_property(property: Property) -> Result<(String, HashSet<Value>), PgsError> {
+fn get_property(property: Property) -> Result<(String, Vec<Value>), PgsError> {
     let key = property.key;
     let values = get_values(property.values)?;
     Ok((key, values))
 }
 
-fn get_values(values: Values) -> Result<HashSet<Value>, PgsError> {
-    let mut result = HashSet::new();
+fn get_values(values: Values) -> Result<Vec<Value>, PgsError> {
+    let mut result = Vec::new();
     match values {
         Values::SingleValue(value) => {
             let value = get_value(value)?;
-            result.insert(value);
+            result.push(value);
         }
         Values::ListValue(values_opt) => match values_opt {
             Some(values) => {
                 for value in values {
                     let value = get_value(value)?;
-                    result.insert(value);
+                    result.push(value);
                 }
             }
             None => {}
@@ -156,6 +155,10 @@ fn get_value(value: SingleValue) -> Result<Value, PgsError> {
             super::pg_actions::BOOL::TRUE => Ok(Value::true_()),
             super::pg_actions::BOOL::FALSE => Ok(Value::false_()),
         },
+        SingleValue::RecordValue(record) => {
+            let record = get_properties(*record)?;
+            Ok(Value::record(record))
+        }
     }
 }
 
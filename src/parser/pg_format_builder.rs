@@ -0,0 +1,215 @@
+use std::collections::HashSet;
+
+use crate::{
+    key::Key,
+    parser::literal::{unquote, unquoted_value},
+    pg::PropertyGraph,
+    pgs_error::PgsError,
+    record::Record,
+    type_name::LabelName,
+    value::Value,
+};
+
+/// Builds property graphs from the PG format of the pg-format tools, where each line
+/// is a node as `alice :Person name:Alice age:23` or an edge as `alice -> bob :knows`.
+///
+/// Lines that start with whitespace continue the previous line, lines that start with `#`
+/// are comments and repeated keys give several values. Undirected edges (`--`) are read
+/// as edges from the first node to the second and edges get the names `e1`, `e2`, ...
+pub struct PgFormatBuilder {}
+
+impl Default for PgFormatBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PgFormatBuilder {
+    pub fn new() -> Self {
+        PgFormatBuilder {}
+    }
+
+    pub fn parse_pg_format(&self, input: &str) -> Result<PropertyGraph, PgsError> {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for (line, content) in logical_lines(input) {
+            let tokens = tokenize(&content).map_err(|e| pg_format_error(line, e))?;
+            match tokens.as_slice() {
+                [] => {}
+                [source, arrow, target, rest @ ..] if arrow == "->" || arrow == "--" => {
+                    let source = unquote(source).map_err(|e| pg_format_error(line, e))?;
+                    let target = unquote(target).map_err(|e| pg_format_error(line, e))?;
+                    let (labels, record) =
+                        labels_record(rest).map_err(|e| pg_format_error(line, e))?;
+                    edges.push((line, source, labels, record, target));
+                }
+                [id, rest @ ..] => {
+                    let id = unquote(id).map_err(|e| pg_format_error(line, e))?;
+                    let (labels, record) =
+                        labels_record(rest).map_err(|e| pg_format_error(line, e))?;
+                    nodes.push((id, labels, record));
+                }
+            }
+        }
+        let mut pg = PropertyGraph::new();
+        for (id, labels, record) in nodes {
+            pg.add_node(id, labels, record)?;
+        }
+        for (index, (line, source, labels, record, target)) in edges.into_iter().enumerate() {
+            pg.add_edge(format!("e{}", index + 1), source, labels, record, target)
+                .map_err(|e| pg_format_error(line, e.to_string()))?;
+        }
+        Ok(pg)
+    }
+}
+
+/// Joins the lines that continue a previous line and removes comments
+fn logical_lines(input: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        match lines.last_mut() {
+            Some((_, previous)) if line.starts_with(char::is_whitespace) => {
+                previous.push(' ');
+                previous.push_str(line.trim());
+            }
+            _ => lines.push((index + 1, line.trim().to_string())),
+        }
+    }
+    lines
+}
+
+/// Splits a line by whitespace outside double quoted strings
+fn tokenize(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                current.push(c);
+                let mut closed = false;
+                while let Some(c) = chars.next() {
+                    current.push(c);
+                    if c == '\\' {
+                        current.extend(chars.next());
+                    } else if c == '"' {
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    return Err(format!("unterminated string in {}", line));
+                }
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+fn labels_record(tokens: &[String]) -> Result<(HashSet<LabelName>, Record), String> {
+    let mut labels = HashSet::new();
+    let mut record = Record::new();
+    for token in tokens {
+        if let Some(label) = token.strip_prefix(':') {
+            labels.insert(unquote(label)?);
+        } else {
+            let (key, value) = split_property(token)?;
+            let key = unquote(key)?;
+            let value = parse_value(value).map_err(|e| format!("property {}: {}", key, e))?;
+            record.insert(Key::new(&key), value);
+        }
+    }
+    Ok((labels, record))
+}
+
+/// Splits `key:value` at the first colon outside quotes
+fn split_property(token: &str) -> Result<(&str, &str), String> {
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (index, c) in token.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => return Ok((&token[..index], &token[index + 1..])),
+            _ => {}
+        }
+    }
+    Err(format!("expected label or property but found {}", token))
+}
+
+/// Quoted values are strings, unquoted values can be integers, booleans, dates or strings
+fn parse_value(value: &str) -> Result<Value, String> {
+    if value.starts_with('"') {
+        return Ok(Value::str(&unquote(value)?));
+    }
+    Ok(unquoted_value(value)?.unwrap_or(Value::str(value)))
+}
+
+fn pg_format_error(line: usize, error: String) -> PgsError {
+    PgsError::GraphFormatError {
+        format: "PG format".to_string(),
+        error: format!("line {}: {}", line, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pg_format() {
+        let input = r#"# People
+alice :Person :Student name:"Alice Smith" age:23
+  tags:a tags:b
+bob :Person active:true
+alice -> bob :knows since:2020
+"#;
+        let pg = PgFormatBuilder::new().parse_pg_format(input).unwrap();
+        let alice = pg.get_node_by_label("alice").unwrap();
+        assert_eq!(
+            alice.labels(),
+            &HashSet::from(["Person".to_string(), "Student".to_string()])
+        );
+        assert_eq!(
+            alice.content(),
+            &Record::new()
+                .with_key_value("name", Value::str("Alice Smith"))
+                .with_key_value("age", Value::int(23))
+                .with_key_value("tags", Value::str("a"))
+                .with_key_value("tags", Value::str("b"))
+        );
+        let knows = pg.get_edge_by_label("e1").unwrap();
+        assert_eq!(knows.labels(), &HashSet::from(["knows".to_string()]));
+    }
+
+    #[test]
+    fn test_dates_and_numbers() {
+        let input = "alice born:2000-01-31 day:\"2000-01-31\"\n";
+        let pg = PgFormatBuilder::new().parse_pg_format(input).unwrap();
+        assert_eq!(
+            pg.get_node_by_label("alice").unwrap().content(),
+            &Record::new()
+                .with_key_value("born", Value::date("2000-01-31").unwrap())
+                .with_key_value("day", Value::str("2000-01-31"))
+        );
+        let input = "alice\nbob followers:3000000000\n";
+        let result = PgFormatBuilder::new().parse_pg_format(input);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error reading PG format property graph: line 2: property followers: integer 3000000000 is out of range, integers must be between -2147483648 and 2147483647"
+        );
+    }
+}
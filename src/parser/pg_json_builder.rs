@@ -0,0 +1,108 @@
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{
+    parser::jsonl_builder::{labels_record, string_field},
+    pg::{PropertyGraph, complete_names},
+    pgs_error::PgsError,
+};
+
+/// Builds property graphs from PG-JSON documents, as in
+/// `{"nodes":[{"id":"alice","labels":["Person"],"properties":{"name":["Alice"]}}],
+///   "edges":[{"from":"alice","to":"bob","undirected":false,"labels":["knows"],"properties":{}}]}`.
+///
+/// Labels and properties are read as in `JsonlBuilder`, so property values can be arrays,
+/// single values or nested objects. Undirected edges are read as edges from `from` to `to`.
+/// Edges can have an `id`, otherwise they get the names `e1`, `e2`, ... in the order they
+/// are read, skipping the ids of other edges.
+pub struct PgJsonBuilder {}
+
+impl Default for PgJsonBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PgJsonBuilder {
+    pub fn new() -> Self {
+        PgJsonBuilder {}
+    }
+
+    pub fn parse_pg_json(&self, input: &str) -> Result<PropertyGraph, PgsError> {
+        let document: Map<String, JsonValue> =
+            serde_json::from_str(input).map_err(|e| pg_json_error(e.to_string()))?;
+        let mut pg = PropertyGraph::new();
+        for node in elements(&document, "nodes")? {
+            let id = string_field(node, "id").ok_or(pg_json_error("node without id"))?;
+            let (labels, record) = labels_record(node)?;
+            pg.add_node(id, labels, record)?;
+        }
+        let edges = elements(&document, "edges")?;
+        let names = complete_names(
+            edges.iter().map(|edge| string_field(edge, "id")).collect(),
+            "e",
+        );
+        for (edge, name) in edges.into_iter().zip(names) {
+            let source = string_field(edge, "from").ok_or(pg_json_error("edge without from"))?;
+            let target = string_field(edge, "to").ok_or(pg_json_error("edge without to"))?;
+            let (labels, record) = labels_record(edge)?;
+            pg.add_edge(name, source, labels, record, target)?;
+        }
+        Ok(pg)
+    }
+}
+
+fn elements<'a>(
+    document: &'a Map<String, JsonValue>,
+    field: &str,
+) -> Result<Vec<&'a Map<String, JsonValue>>, PgsError> {
+    match document.get(field) {
+        None => Ok(Vec::new()),
+        Some(JsonValue::Array(elements)) => elements
+            .iter()
+            .map(|element| {
+                element
+                    .as_object()
+                    .ok_or(pg_json_error(format!("{} must contain objects", field)))
+            })
+            .collect(),
+        Some(_) => Err(pg_json_error(format!("{} must be an array", field))),
+    }
+}
+
+fn pg_json_error(error: impl ToString) -> PgsError {
+    PgsError::GraphFormatError {
+        format: "PG-JSON".to_string(),
+        error: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{key::Key, value::Value};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_pg_json() {
+        let input = r#"{
+  "nodes": [
+    {"id": 1, "labels": ["Person"], "properties": {"name": ["Alice"], "tags": ["a", "b"]}},
+    {"id": 2, "labels": ["Person"], "properties": {"name": ["Bob"]}}
+  ],
+  "edges": [
+    {"from": 1, "to": 2, "undirected": false, "labels": ["knows"], "properties": {"since": [2020]}}
+  ]
+}"#;
+        let pg = PgJsonBuilder::new().parse_pg_json(input).unwrap();
+        let alice = pg.get_node_by_label("1").unwrap();
+        assert_eq!(
+            alice.content().get(&Key::new("tags")),
+            Some(&vec![Value::str("a"), Value::str("b")])
+        );
+        let knows = pg.get_edge_by_label("e1").unwrap();
+        assert_eq!(
+            knows.content().get(&Key::new("since")),
+            Some(&vec![Value::int(2020)])
+        );
+    }
+}
@@ -1,27 +1,27 @@
-use super::pgs_actions;
-use rustemo::Action::{self, Accept, Reduce, Shift};
-use rustemo::LRBuilder;
+/// Generated by rustemo. Do not edit manually!
+use std::fmt::Debug;
+use std::hash::Hash;
+use rustemo::{
+    Result, Input as InputT, Lexer, Token, TokenRecognizer as TokenRecognizerT, Parser,
+    ParserDefinition, State as StateT, Builder,
+};
+use rustemo::regex::Regex;
+use rustemo::once_cell::sync::Lazy;
 use rustemo::StringLexer;
+use rustemo::LRBuilder;
+use super::pgs_actions;
+use rustemo::{LRParser, LRContext};
+use rustemo::Action::{self, Shift, Reduce, Accept};
+#[allow(unused_imports)]
+use rustemo::debug::{log, logn};
 #[allow(unused_imports)]
 #[cfg(debug_assertions)]
 use rustemo::colored::*;
-#[allow(unused_imports)]
-use rustemo::debug::{log, logn};
-use rustemo::once_cell::sync::Lazy;
-use rustemo::regex::Regex;
-use rustemo::{
-    Builder, Input as InputT, Lexer, Parser, ParserDefinition, Result, State as StateT, Token,
-    TokenRecognizer as TokenRecognizerT,
-};
-use rustemo::{LRContext, LRParser};
-/// Generated by rustemo. Do not edit manually!
-use std::fmt::Debug;
-use std::hash::Hash;
 pub type Input = str;
-const STATE_COUNT: usize = 170usize;
-const MAX_RECOGNIZERS: usize = 11usize;
+const STATE_COUNT: usize = 332usize;
+const MAX_RECOGNIZERS: usize = 18usize;
 #[allow(dead_code)]
-const TERMINAL_COUNT: usize = 53usize;
+const TERMINAL_COUNT: usize = 84usize;
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
@@ -33,12 +33,23 @@ pub enum TokenKind {
     START_COMMENT,
     END_COMMENT,
     IDENTIFIER,
+    TYPE_NAME,
     SEMICOLON,
     CREATE,
     NODE,
     EDGE,
     GRAPH,
     TYPE,
+    CONSTRAINT,
+    FOR,
+    OUTGOING,
+    INCOMING,
+    KEY,
+    REFERENCES,
+    REACHES,
+    VIA,
+    SLASH,
+    CARET,
     OPEN_PAREN,
     CLOSE_PAREN,
     OPEN_ARROW,
@@ -56,6 +67,10 @@ pub enum TokenKind {
     BOOL_NAME,
     STRING_NAME,
     DATE_NAME,
+    LIST,
+    ARRAY,
+    SET,
+    RECORD,
     NUMBER,
     OPTIONAL,
     AMPERSAND,
@@ -68,17 +83,33 @@ pub enum TokenKind {
     GE,
     LE,
     EQUALS,
+    NOT_EQUALS,
     QUOTED_STRING,
     REGEX,
     AND,
     OR,
     NOT,
+    IS,
+    NULL,
+    IF,
+    THEN,
+    ELSE,
+    EXCLUSIVE,
     ANY,
+    UNIQUE,
+    SUM,
+    MIN,
+    MAX,
+    ALL,
+    NONE,
+    CONTAINS,
     DATE,
     STRICT,
     LOOSE,
     ABSTRACT,
+    EXTENDS,
     IMPORTS,
+    AS,
 }
 use TokenKind as TK;
 impl From<TokenKind> for usize {
@@ -90,17 +121,47 @@ impl From<TokenKind> for usize {
 #[derive(Clone, Copy, PartialEq)]
 pub enum ProdKind {
     PgsP1,
+    GraphTypeImportsOptP1,
+    GraphTypeImportsOptP2,
     CreateType1P1,
     CreateType1P2,
+    CreateType0P1,
+    CreateType0P2,
     CreateTypeP1,
     CreateTypeP2,
     CreateTypeP3,
+    CreateTypeP4,
     CreateNodeTypeP1,
+    ABSTRACTOptP1,
+    ABSTRACTOptP2,
     CreateEdgeTypeP1,
     CreateGraphTypeP1,
+    CreateConstraintP1,
+    ConstraintDegree,
+    ConstraintUnique,
+    key1P1,
+    key1P2,
+    ConstraintNodeKey,
+    ConstraintReference,
+    ConstraintReaches,
+    PathExprPathSeq,
+    PathExprPathAlt,
+    PathExprPathStar,
+    PathExprPathPlus,
+    PathExprPathOptional,
+    PathExprPathInverse,
+    PathExprPathLabel,
+    PathExprPathParen,
+    DirectionP1,
+    DirectionP2,
     NodeTypeP1,
     TypeNameOptP1,
     TypeNameOptP2,
+    ExtendsOptP1,
+    ExtendsOptP2,
+    ExtendsP1,
+    TypeName1P1,
+    TypeName1P2,
     EdgeTypeP1,
     GraphTypeP1,
     GraphTypeModeOptP1,
@@ -108,9 +169,13 @@ pub enum ProdKind {
     GraphTypeElementsOptP1,
     GraphTypeElementsOptP2,
     GraphTypeImportsP1,
-    ImportsP1,
-    TypeName1P1,
-    TypeName1P2,
+    Import1P1,
+    Import1P2,
+    ImportImportPath,
+    ImportAliasOptP1,
+    ImportAliasOptP2,
+    ImportImportName,
+    ImportAliasP1,
     GraphTypeModeP1,
     GraphTypeModeP2,
     GraphTypeElementsP1,
@@ -118,6 +183,7 @@ pub enum ProdKind {
     GraphTypeElementsP3,
     TypeNameP1,
     EndpointTypeP1,
+    EndpointTypeEndpointRef,
     LabelPropertySpecP1,
     LabelSpecOptP1,
     LabelSpecOptP2,
@@ -138,6 +204,14 @@ pub enum ProdKind {
     PropertiesBaseProperty,
     OPTIONALOptP1,
     OPTIONALOptP2,
+    PropertiesRecordCheck,
+    PropertiesIfThen,
+    ElsePropertiesOptP1,
+    ElsePropertiesOptP2,
+    PropertiesExclusive,
+    Property1P1,
+    Property1P2,
+    ElsePropertiesP1,
     PropertyP1,
     keyP1,
     TypeSpecP1,
@@ -153,9 +227,14 @@ pub enum ProdKind {
     SimpleTypeInteger,
     SimpleTypeDate,
     SimpleTypeBool,
+    SimpleTypeListSpec,
+    SimpleTypeRecordSpec,
     SimpleTypeAny,
     SimpleTypeCond,
     CheckP1,
+    ListNameP1,
+    ListNameP2,
+    ListNameP3,
     CondP1,
     CondP2,
     CondGT,
@@ -168,6 +247,29 @@ pub enum ProdKind {
     CondOR,
     CondNot,
     CondParenCond,
+    CondUnique,
+    CondSum,
+    CondMin,
+    CondMax,
+    CondAll,
+    CondAnyValue,
+    CondNoneValue,
+    CondContains,
+    RecordCondRecordAnd,
+    RecordCondRecordOr,
+    RecordCondRecordNot,
+    RecordCondRecordParen,
+    RecordCondIsNull,
+    RecordCondIsNotNull,
+    RecordCondComparison,
+    OperandPropertyRef,
+    OperandLiteral,
+    ComparatorP1,
+    ComparatorP2,
+    ComparatorP3,
+    ComparatorP4,
+    ComparatorP5,
+    ComparatorP6,
     CardOptional,
     CardOneOrMore,
     CardZeroOrMore,
@@ -202,20 +304,58 @@ use ProdKind as PK;
 impl std::fmt::Debug for ProdKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = match self {
-            ProdKind::PgsP1 => "Pgs: CreateType1",
+            ProdKind::PgsP1 => "Pgs: GraphTypeImportsOpt CreateType0",
+            ProdKind::GraphTypeImportsOptP1 => "GraphTypeImportsOpt: GraphTypeImports",
+            ProdKind::GraphTypeImportsOptP2 => "GraphTypeImportsOpt: ",
             ProdKind::CreateType1P1 => "CreateType1: CreateType1 SEMICOLON CreateType",
             ProdKind::CreateType1P2 => "CreateType1: CreateType",
+            ProdKind::CreateType0P1 => "CreateType0: CreateType1",
+            ProdKind::CreateType0P2 => "CreateType0: ",
             ProdKind::CreateTypeP1 => "CreateType: CreateNodeType",
             ProdKind::CreateTypeP2 => "CreateType: CreateEdgeType",
             ProdKind::CreateTypeP3 => "CreateType: CreateGraphType",
-            ProdKind::CreateNodeTypeP1 => "CreateNodeType: CREATE NODE TYPE NodeType",
+            ProdKind::CreateTypeP4 => "CreateType: CreateConstraint",
+            ProdKind::CreateNodeTypeP1 => {
+                "CreateNodeType: CREATE ABSTRACTOpt NODE TYPE NodeType"
+            }
+            ProdKind::ABSTRACTOptP1 => "ABSTRACTOpt: ABSTRACT",
+            ProdKind::ABSTRACTOptP2 => "ABSTRACTOpt: ",
             ProdKind::CreateEdgeTypeP1 => "CreateEdgeType: CREATE EDGE TYPE EdgeType",
             ProdKind::CreateGraphTypeP1 => "CreateGraphType: CREATE GRAPH TYPE GraphType",
+            ProdKind::CreateConstraintP1 => {
+                "CreateConstraint: CREATE CONSTRAINT TypeName FOR OPEN_PAREN COLON IDENTIFIER CLOSE_PAREN Constraint"
+            }
+            ProdKind::ConstraintDegree => "Constraint: Direction COLON IDENTIFIER Card",
+            ProdKind::ConstraintUnique => {
+                "Constraint: UNIQUE OPEN_PAREN key1 CLOSE_PAREN"
+            }
+            ProdKind::key1P1 => "key1: key1 COMMA key",
+            ProdKind::key1P2 => "key1: key",
+            ProdKind::ConstraintNodeKey => "Constraint: KEY OPEN_PAREN key1 CLOSE_PAREN",
+            ProdKind::ConstraintReference => "Constraint: key REFERENCES AT TypeName",
+            ProdKind::ConstraintReaches => {
+                "Constraint: REACHES OPEN_PAREN COLON IDENTIFIER CLOSE_PAREN VIA PathExpr"
+            }
+            ProdKind::PathExprPathSeq => "PathExpr: PathExpr SLASH PathExpr",
+            ProdKind::PathExprPathAlt => "PathExpr: PathExpr BAR PathExpr",
+            ProdKind::PathExprPathStar => "PathExpr: PathExpr STAR",
+            ProdKind::PathExprPathPlus => "PathExpr: PathExpr PLUS",
+            ProdKind::PathExprPathOptional => "PathExpr: PathExpr QUESTION",
+            ProdKind::PathExprPathInverse => "PathExpr: CARET IDENTIFIER",
+            ProdKind::PathExprPathLabel => "PathExpr: IDENTIFIER",
+            ProdKind::PathExprPathParen => "PathExpr: OPEN_PAREN PathExpr CLOSE_PAREN",
+            ProdKind::DirectionP1 => "Direction: OUTGOING",
+            ProdKind::DirectionP2 => "Direction: INCOMING",
             ProdKind::NodeTypeP1 => {
-                "NodeType: OPEN_PAREN TypeNameOpt LabelPropertySpec CLOSE_PAREN"
+                "NodeType: OPEN_PAREN TypeNameOpt ExtendsOpt LabelPropertySpec CLOSE_PAREN"
             }
             ProdKind::TypeNameOptP1 => "TypeNameOpt: TypeName",
             ProdKind::TypeNameOptP2 => "TypeNameOpt: ",
+            ProdKind::ExtendsOptP1 => "ExtendsOpt: Extends",
+            ProdKind::ExtendsOptP2 => "ExtendsOpt: ",
+            ProdKind::ExtendsP1 => "Extends: EXTENDS TypeName1",
+            ProdKind::TypeName1P1 => "TypeName1: TypeName1 COMMA TypeName",
+            ProdKind::TypeName1P2 => "TypeName1: TypeName",
             ProdKind::EdgeTypeP1 => {
                 "EdgeType: EndpointType OPEN_ARROW TypeNameOpt LabelPropertySpec CLOSE_ARROW EndpointType"
             }
@@ -226,18 +366,29 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::GraphTypeModeOptP2 => "GraphTypeModeOpt: ",
             ProdKind::GraphTypeElementsOptP1 => "GraphTypeElementsOpt: GraphTypeElements",
             ProdKind::GraphTypeElementsOptP2 => "GraphTypeElementsOpt: ",
-            ProdKind::GraphTypeImportsP1 => "GraphTypeImports: IMPORTS Imports",
-            ProdKind::ImportsP1 => "Imports: TypeName1",
-            ProdKind::TypeName1P1 => "TypeName1: TypeName1 COMMA TypeName",
-            ProdKind::TypeName1P2 => "TypeName1: TypeName",
+            ProdKind::GraphTypeImportsP1 => "GraphTypeImports: IMPORTS Import1 SEMICOLON",
+            ProdKind::Import1P1 => "Import1: Import1 COMMA Import",
+            ProdKind::Import1P2 => "Import1: Import",
+            ProdKind::ImportImportPath => "Import: QUOTED_STRING ImportAliasOpt",
+            ProdKind::ImportAliasOptP1 => "ImportAliasOpt: ImportAlias",
+            ProdKind::ImportAliasOptP2 => "ImportAliasOpt: ",
+            ProdKind::ImportImportName => "Import: IDENTIFIER ImportAliasOpt",
+            ProdKind::ImportAliasP1 => "ImportAlias: AS IDENTIFIER",
             ProdKind::GraphTypeModeP1 => "GraphTypeMode: STRICT",
             ProdKind::GraphTypeModeP2 => "GraphTypeMode: LOOSE",
             ProdKind::GraphTypeElementsP1 => "GraphTypeElements: TypeName",
             ProdKind::GraphTypeElementsP2 => "GraphTypeElements: NodeType",
             ProdKind::GraphTypeElementsP3 => "GraphTypeElements: EdgeType",
-            ProdKind::TypeNameP1 => "TypeName: IDENTIFIER",
-            ProdKind::EndpointTypeP1 => "EndpointType: OPEN_PAREN LabelPropertySpec CLOSE_PAREN",
-            ProdKind::LabelPropertySpecP1 => "LabelPropertySpec: LabelSpecOpt PropertySpecOpt",
+            ProdKind::TypeNameP1 => "TypeName: TYPE_NAME",
+            ProdKind::EndpointTypeP1 => {
+                "EndpointType: OPEN_PAREN LabelPropertySpec CLOSE_PAREN"
+            }
+            ProdKind::EndpointTypeEndpointRef => {
+                "EndpointType: OPEN_PAREN AT TypeName CLOSE_PAREN"
+            }
+            ProdKind::LabelPropertySpecP1 => {
+                "LabelPropertySpec: LabelSpecOpt PropertySpecOpt"
+            }
             ProdKind::LabelSpecOptP1 => "LabelSpecOpt: LabelSpec",
             ProdKind::LabelSpecOptP2 => "LabelSpecOpt: ",
             ProdKind::PropertySpecOptP1 => "PropertySpecOpt: PropertySpec",
@@ -246,10 +397,12 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::LabelsP1 => "Labels: SingleLabel MoreLabelsOpt",
             ProdKind::MoreLabelsOptP1 => "MoreLabelsOpt: MoreLabels",
             ProdKind::MoreLabelsOptP2 => "MoreLabelsOpt: ",
-            ProdKind::MoreLabelsAndLabels => "MoreLabels: AMPERSAND SingleLabel MoreLabelsOpt",
+            ProdKind::MoreLabelsAndLabels => {
+                "MoreLabels: AMPERSAND SingleLabel MoreLabelsOpt"
+            }
             ProdKind::MoreLabelsOrLabels => "MoreLabels: BAR SingleLabel MoreLabelsOpt",
             ProdKind::SingleLabelSingleLabel => "SingleLabel: IDENTIFIER",
-            ProdKind::SingleLabelTypeName => "SingleLabel: AT IDENTIFIER",
+            ProdKind::SingleLabelTypeName => "SingleLabel: AT TypeName",
             ProdKind::PropertySpecP1 => "PropertySpec: OPEN_CURLY Properties CLOSE_CURLY",
             ProdKind::PropertiesEachOf => "Properties: Properties COMMA Properties",
             ProdKind::PropertiesOneOf => "Properties: Properties DOUBLE_BAR Properties",
@@ -257,12 +410,28 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::PropertiesBaseProperty => "Properties: OPTIONALOpt Property",
             ProdKind::OPTIONALOptP1 => "OPTIONALOpt: OPTIONAL",
             ProdKind::OPTIONALOptP2 => "OPTIONALOpt: ",
+            ProdKind::PropertiesRecordCheck => "Properties: CHECK RecordCond",
+            ProdKind::PropertiesIfThen => {
+                "Properties: IF RecordCond THEN OPEN_PAREN Properties CLOSE_PAREN ElsePropertiesOpt"
+            }
+            ProdKind::ElsePropertiesOptP1 => "ElsePropertiesOpt: ElseProperties",
+            ProdKind::ElsePropertiesOptP2 => "ElsePropertiesOpt: ",
+            ProdKind::PropertiesExclusive => {
+                "Properties: EXCLUSIVE OPEN_PAREN Property1 CLOSE_PAREN"
+            }
+            ProdKind::Property1P1 => "Property1: Property1 COMMA Property",
+            ProdKind::Property1P2 => "Property1: Property",
+            ProdKind::ElsePropertiesP1 => {
+                "ElseProperties: ELSE OPEN_PAREN Properties CLOSE_PAREN"
+            }
             ProdKind::PropertyP1 => "Property: key COLON TypeSpec",
             ProdKind::keyP1 => "key: IDENTIFIER",
             ProdKind::TypeSpecP1 => "TypeSpec: SimpleType MoreTypesOpt",
             ProdKind::MoreTypesOptP1 => "MoreTypesOpt: MoreTypes",
             ProdKind::MoreTypesOptP2 => "MoreTypesOpt: ",
-            ProdKind::MoreTypesIntersectionType => "MoreTypes: AMPERSAND SimpleType MoreTypesOpt",
+            ProdKind::MoreTypesIntersectionType => {
+                "MoreTypes: AMPERSAND SimpleType MoreTypesOpt"
+            }
             ProdKind::MoreTypesUnionType => "MoreTypes: BAR SimpleType MoreTypesOpt",
             ProdKind::SimpleTypeStringSpec => "SimpleType: STRING_NAME CardOpt CheckOpt",
             ProdKind::CardOptP1 => "CardOpt: Card",
@@ -272,9 +441,16 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::SimpleTypeInteger => "SimpleType: INTEGER_NAME CardOpt CheckOpt",
             ProdKind::SimpleTypeDate => "SimpleType: DATE_NAME CardOpt CheckOpt",
             ProdKind::SimpleTypeBool => "SimpleType: BOOL_NAME CardOpt CheckOpt",
+            ProdKind::SimpleTypeListSpec => {
+                "SimpleType: ListName LT TypeSpec GT CardOpt CheckOpt"
+            }
+            ProdKind::SimpleTypeRecordSpec => "SimpleType: RECORD PropertySpec CardOpt",
             ProdKind::SimpleTypeAny => "SimpleType: ANY CheckOpt",
             ProdKind::SimpleTypeCond => "SimpleType: CHECK Cond",
             ProdKind::CheckP1 => "Check: CHECK Cond",
+            ProdKind::ListNameP1 => "ListName: LIST",
+            ProdKind::ListNameP2 => "ListName: ARRAY",
+            ProdKind::ListNameP3 => "ListName: SET",
             ProdKind::CondP1 => "Cond: TRUE",
             ProdKind::CondP2 => "Cond: FALSE",
             ProdKind::CondGT => "Cond: GT SingleValue",
@@ -287,6 +463,31 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::CondOR => "Cond: Cond OR Cond",
             ProdKind::CondNot => "Cond: NOT Cond",
             ProdKind::CondParenCond => "Cond: OPEN_PAREN Cond CLOSE_PAREN",
+            ProdKind::CondUnique => "Cond: UNIQUE",
+            ProdKind::CondSum => "Cond: SUM OPEN_PAREN Cond CLOSE_PAREN",
+            ProdKind::CondMin => "Cond: MIN OPEN_PAREN Cond CLOSE_PAREN",
+            ProdKind::CondMax => "Cond: MAX OPEN_PAREN Cond CLOSE_PAREN",
+            ProdKind::CondAll => "Cond: ALL OPEN_PAREN Cond CLOSE_PAREN",
+            ProdKind::CondAnyValue => "Cond: ANY OPEN_PAREN Cond CLOSE_PAREN",
+            ProdKind::CondNoneValue => "Cond: NONE OPEN_PAREN Cond CLOSE_PAREN",
+            ProdKind::CondContains => "Cond: CONTAINS SingleValue",
+            ProdKind::RecordCondRecordAnd => "RecordCond: RecordCond AND RecordCond",
+            ProdKind::RecordCondRecordOr => "RecordCond: RecordCond OR RecordCond",
+            ProdKind::RecordCondRecordNot => "RecordCond: NOT RecordCond",
+            ProdKind::RecordCondRecordParen => {
+                "RecordCond: OPEN_PAREN RecordCond CLOSE_PAREN"
+            }
+            ProdKind::RecordCondIsNull => "RecordCond: Operand IS NULL",
+            ProdKind::RecordCondIsNotNull => "RecordCond: Operand IS NOT NULL",
+            ProdKind::RecordCondComparison => "RecordCond: Operand Comparator Operand",
+            ProdKind::OperandPropertyRef => "Operand: key",
+            ProdKind::OperandLiteral => "Operand: SingleValue",
+            ProdKind::ComparatorP1 => "Comparator: GT",
+            ProdKind::ComparatorP2 => "Comparator: GE",
+            ProdKind::ComparatorP3 => "Comparator: LT",
+            ProdKind::ComparatorP4 => "Comparator: LE",
+            ProdKind::ComparatorP5 => "Comparator: EQUALS",
+            ProdKind::ComparatorP6 => "Comparator: NOT_EQUALS",
             ProdKind::CardOptional => "Card: QUESTION",
             ProdKind::CardOneOrMore => "Card: PLUS",
             ProdKind::CardZeroOrMore => "Card: STAR",
@@ -328,20 +529,33 @@ pub enum NonTermKind {
     AUG,
     AUGL,
     Pgs,
+    GraphTypeImportsOpt,
     CreateType1,
+    CreateType0,
     CreateType,
     CreateNodeType,
+    ABSTRACTOpt,
     CreateEdgeType,
     CreateGraphType,
+    CreateConstraint,
+    Constraint,
+    key1,
+    PathExpr,
+    Direction,
     NodeType,
     TypeNameOpt,
+    ExtendsOpt,
+    Extends,
+    TypeName1,
     EdgeType,
     GraphType,
     GraphTypeModeOpt,
     GraphTypeElementsOpt,
     GraphTypeImports,
-    Imports,
-    TypeName1,
+    Import1,
+    Import,
+    ImportAliasOpt,
+    ImportAlias,
     GraphTypeMode,
     GraphTypeElements,
     TypeName,
@@ -357,6 +571,9 @@ pub enum NonTermKind {
     PropertySpec,
     Properties,
     OPTIONALOpt,
+    ElsePropertiesOpt,
+    Property1,
+    ElseProperties,
     Property,
     key,
     TypeSpec,
@@ -366,7 +583,11 @@ pub enum NonTermKind {
     CardOpt,
     CheckOpt,
     Check,
+    ListName,
     Cond,
+    RecordCond,
+    Operand,
+    Comparator,
     Card,
     Max,
     SingleValue,
@@ -385,17 +606,47 @@ impl From<ProdKind> for NonTermKind {
     fn from(prod: ProdKind) -> Self {
         match prod {
             ProdKind::PgsP1 => NonTermKind::Pgs,
+            ProdKind::GraphTypeImportsOptP1 => NonTermKind::GraphTypeImportsOpt,
+            ProdKind::GraphTypeImportsOptP2 => NonTermKind::GraphTypeImportsOpt,
             ProdKind::CreateType1P1 => NonTermKind::CreateType1,
             ProdKind::CreateType1P2 => NonTermKind::CreateType1,
+            ProdKind::CreateType0P1 => NonTermKind::CreateType0,
+            ProdKind::CreateType0P2 => NonTermKind::CreateType0,
             ProdKind::CreateTypeP1 => NonTermKind::CreateType,
             ProdKind::CreateTypeP2 => NonTermKind::CreateType,
             ProdKind::CreateTypeP3 => NonTermKind::CreateType,
+            ProdKind::CreateTypeP4 => NonTermKind::CreateType,
             ProdKind::CreateNodeTypeP1 => NonTermKind::CreateNodeType,
+            ProdKind::ABSTRACTOptP1 => NonTermKind::ABSTRACTOpt,
+            ProdKind::ABSTRACTOptP2 => NonTermKind::ABSTRACTOpt,
             ProdKind::CreateEdgeTypeP1 => NonTermKind::CreateEdgeType,
             ProdKind::CreateGraphTypeP1 => NonTermKind::CreateGraphType,
+            ProdKind::CreateConstraintP1 => NonTermKind::CreateConstraint,
+            ProdKind::ConstraintDegree => NonTermKind::Constraint,
+            ProdKind::ConstraintUnique => NonTermKind::Constraint,
+            ProdKind::key1P1 => NonTermKind::key1,
+            ProdKind::key1P2 => NonTermKind::key1,
+            ProdKind::ConstraintNodeKey => NonTermKind::Constraint,
+            ProdKind::ConstraintReference => NonTermKind::Constraint,
+            ProdKind::ConstraintReaches => NonTermKind::Constraint,
+            ProdKind::PathExprPathSeq => NonTermKind::PathExpr,
+            ProdKind::PathExprPathAlt => NonTermKind::PathExpr,
+            ProdKind::PathExprPathStar => NonTermKind::PathExpr,
+            ProdKind::PathExprPathPlus => NonTermKind::PathExpr,
+            ProdKind::PathExprPathOptional => NonTermKind::PathExpr,
+            ProdKind::PathExprPathInverse => NonTermKind::PathExpr,
+            ProdKind::PathExprPathLabel => NonTermKind::PathExpr,
+            ProdKind::PathExprPathParen => NonTermKind::PathExpr,
+            ProdKind::DirectionP1 => NonTermKind::Direction,
+            ProdKind::DirectionP2 => NonTermKind::Direction,
             ProdKind::NodeTypeP1 => NonTermKind::NodeType,
             ProdKind::TypeNameOptP1 => NonTermKind::TypeNameOpt,
             ProdKind::TypeNameOptP2 => NonTermKind::TypeNameOpt,
+            ProdKind::ExtendsOptP1 => NonTermKind::ExtendsOpt,
+            ProdKind::ExtendsOptP2 => NonTermKind::ExtendsOpt,
+            ProdKind::ExtendsP1 => NonTermKind::Extends,
+            ProdKind::TypeName1P1 => NonTermKind::TypeName1,
+            ProdKind::TypeName1P2 => NonTermKind::TypeName1,
             ProdKind::EdgeTypeP1 => NonTermKind::EdgeType,
             ProdKind::GraphTypeP1 => NonTermKind::GraphType,
             ProdKind::GraphTypeModeOptP1 => NonTermKind::GraphTypeModeOpt,
@@ -403,9 +654,13 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::GraphTypeElementsOptP1 => NonTermKind::GraphTypeElementsOpt,
             ProdKind::GraphTypeElementsOptP2 => NonTermKind::GraphTypeElementsOpt,
             ProdKind::GraphTypeImportsP1 => NonTermKind::GraphTypeImports,
-            ProdKind::ImportsP1 => NonTermKind::Imports,
-            ProdKind::TypeName1P1 => NonTermKind::TypeName1,
-            ProdKind::TypeName1P2 => NonTermKind::TypeName1,
+            ProdKind::Import1P1 => NonTermKind::Import1,
+            ProdKind::Import1P2 => NonTermKind::Import1,
+            ProdKind::ImportImportPath => NonTermKind::Import,
+            ProdKind::ImportAliasOptP1 => NonTermKind::ImportAliasOpt,
+            ProdKind::ImportAliasOptP2 => NonTermKind::ImportAliasOpt,
+            ProdKind::ImportImportName => NonTermKind::Import,
+            ProdKind::ImportAliasP1 => NonTermKind::ImportAlias,
             ProdKind::GraphTypeModeP1 => NonTermKind::GraphTypeMode,
             ProdKind::GraphTypeModeP2 => NonTermKind::GraphTypeMode,
             ProdKind::GraphTypeElementsP1 => NonTermKind::GraphTypeElements,
@@ -413,6 +668,7 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::GraphTypeElementsP3 => NonTermKind::GraphTypeElements,
             ProdKind::TypeNameP1 => NonTermKind::TypeName,
             ProdKind::EndpointTypeP1 => NonTermKind::EndpointType,
+            ProdKind::EndpointTypeEndpointRef => NonTermKind::EndpointType,
             ProdKind::LabelPropertySpecP1 => NonTermKind::LabelPropertySpec,
             ProdKind::LabelSpecOptP1 => NonTermKind::LabelSpecOpt,
             ProdKind::LabelSpecOptP2 => NonTermKind::LabelSpecOpt,
@@ -433,6 +689,14 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::PropertiesBaseProperty => NonTermKind::Properties,
             ProdKind::OPTIONALOptP1 => NonTermKind::OPTIONALOpt,
             ProdKind::OPTIONALOptP2 => NonTermKind::OPTIONALOpt,
+            ProdKind::PropertiesRecordCheck => NonTermKind::Properties,
+            ProdKind::PropertiesIfThen => NonTermKind::Properties,
+            ProdKind::ElsePropertiesOptP1 => NonTermKind::ElsePropertiesOpt,
+            ProdKind::ElsePropertiesOptP2 => NonTermKind::ElsePropertiesOpt,
+            ProdKind::PropertiesExclusive => NonTermKind::Properties,
+            ProdKind::Property1P1 => NonTermKind::Property1,
+            ProdKind::Property1P2 => NonTermKind::Property1,
+            ProdKind::ElsePropertiesP1 => NonTermKind::ElseProperties,
             ProdKind::PropertyP1 => NonTermKind::Property,
             ProdKind::keyP1 => NonTermKind::key,
             ProdKind::TypeSpecP1 => NonTermKind::TypeSpec,
@@ -448,9 +712,14 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::SimpleTypeInteger => NonTermKind::SimpleType,
             ProdKind::SimpleTypeDate => NonTermKind::SimpleType,
             ProdKind::SimpleTypeBool => NonTermKind::SimpleType,
+            ProdKind::SimpleTypeListSpec => NonTermKind::SimpleType,
+            ProdKind::SimpleTypeRecordSpec => NonTermKind::SimpleType,
             ProdKind::SimpleTypeAny => NonTermKind::SimpleType,
             ProdKind::SimpleTypeCond => NonTermKind::SimpleType,
             ProdKind::CheckP1 => NonTermKind::Check,
+            ProdKind::ListNameP1 => NonTermKind::ListName,
+            ProdKind::ListNameP2 => NonTermKind::ListName,
+            ProdKind::ListNameP3 => NonTermKind::ListName,
             ProdKind::CondP1 => NonTermKind::Cond,
             ProdKind::CondP2 => NonTermKind::Cond,
             ProdKind::CondGT => NonTermKind::Cond,
@@ -463,6 +732,29 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::CondOR => NonTermKind::Cond,
             ProdKind::CondNot => NonTermKind::Cond,
             ProdKind::CondParenCond => NonTermKind::Cond,
+            ProdKind::CondUnique => NonTermKind::Cond,
+            ProdKind::CondSum => NonTermKind::Cond,
+            ProdKind::CondMin => NonTermKind::Cond,
+            ProdKind::CondMax => NonTermKind::Cond,
+            ProdKind::CondAll => NonTermKind::Cond,
+            ProdKind::CondAnyValue => NonTermKind::Cond,
+            ProdKind::CondNoneValue => NonTermKind::Cond,
+            ProdKind::CondContains => NonTermKind::Cond,
+            ProdKind::RecordCondRecordAnd => NonTermKind::RecordCond,
+            ProdKind::RecordCondRecordOr => NonTermKind::RecordCond,
+            ProdKind::RecordCondRecordNot => NonTermKind::RecordCond,
+            ProdKind::RecordCondRecordParen => NonTermKind::RecordCond,
+            ProdKind::RecordCondIsNull => NonTermKind::RecordCond,
+            ProdKind::RecordCondIsNotNull => NonTermKind::RecordCond,
+            ProdKind::RecordCondComparison => NonTermKind::RecordCond,
+            ProdKind::OperandPropertyRef => NonTermKind::Operand,
+            ProdKind::OperandLiteral => NonTermKind::Operand,
+            ProdKind::ComparatorP1 => NonTermKind::Comparator,
+            ProdKind::ComparatorP2 => NonTermKind::Comparator,
+            ProdKind::ComparatorP3 => NonTermKind::Comparator,
+            ProdKind::ComparatorP4 => NonTermKind::Comparator,
+            ProdKind::ComparatorP5 => NonTermKind::Comparator,
+            ProdKind::ComparatorP6 => NonTermKind::Comparator,
             ProdKind::CardOptional => NonTermKind::Card,
             ProdKind::CardOneOrMore => NonTermKind::Card,
             ProdKind::CardZeroOrMore => NonTermKind::Card,
@@ -500,179 +792,341 @@ impl From<ProdKind> for NonTermKind {
 pub enum State {
     #[default]
     AUGS0,
-    CREATES1,
+    IMPORTSS1,
     PgsS2,
-    CreateType1S3,
-    CreateTypeS4,
-    CreateNodeTypeS5,
-    CreateEdgeTypeS6,
-    CreateGraphTypeS7,
-    NODES8,
-    EDGES9,
-    GRAPHS10,
-    SEMICOLONS11,
-    TYPES12,
-    TYPES13,
-    TYPES14,
-    CreateTypeS15,
-    OPEN_PARENS16,
-    NodeTypeS17,
-    OPEN_PARENS18,
-    EdgeTypeS19,
-    EndpointTypeS20,
-    IDENTIFIERS21,
-    GraphTypeS22,
-    TypeNameS23,
-    TypeNameOptS24,
-    TypeNameS25,
-    COLONS26,
-    LabelPropertySpecS27,
-    LabelSpecOptS28,
-    LabelSpecS29,
-    OPEN_ARROWS30,
-    STRICTS31,
-    LOOSES32,
-    GraphTypeModeOptS33,
-    GraphTypeModeS34,
-    LabelPropertySpecS35,
-    IDENTIFIERS36,
-    ATS37,
-    LabelsS38,
-    SingleLabelS39,
-    CLOSE_PARENS40,
-    OPEN_CURLYS41,
-    PropertySpecOptS42,
-    PropertySpecS43,
-    TypeNameOptS44,
-    OPEN_CURLYS45,
-    CLOSE_PARENS46,
-    IDENTIFIERS47,
-    BARS48,
-    AMPERSANDS49,
-    MoreLabelsOptS50,
-    MoreLabelsS51,
-    OPEN_PARENS52,
-    OPTIONALS53,
-    PropertiesS54,
-    OPTIONALOptS55,
-    LabelPropertySpecS56,
-    OPEN_PARENS57,
-    NodeTypeS58,
-    EdgeTypeS59,
-    GraphTypeElementsOptS60,
-    GraphTypeElementsS61,
-    TypeNameS62,
-    SingleLabelS63,
-    SingleLabelS64,
-    PropertiesS65,
-    CLOSE_CURLYS66,
-    COMMAS67,
-    DOUBLE_BARS68,
-    IDENTIFIERS69,
-    PropertyS70,
-    keyS71,
-    CLOSE_ARROWS72,
-    CLOSE_CURLYS73,
+    GraphTypeImportsOptS3,
+    GraphTypeImportsS4,
+    IDENTIFIERS5,
+    QUOTED_STRINGS6,
+    Import1S7,
+    ImportS8,
+    CREATES9,
+    CreateType1S10,
+    CreateType0S11,
+    CreateTypeS12,
+    CreateNodeTypeS13,
+    CreateEdgeTypeS14,
+    CreateGraphTypeS15,
+    CreateConstraintS16,
+    ASS17,
+    ImportAliasOptS18,
+    ImportAliasS19,
+    ImportAliasOptS20,
+    SEMICOLONS21,
+    COMMAS22,
+    EDGES23,
+    GRAPHS24,
+    CONSTRAINTS25,
+    ABSTRACTS26,
+    ABSTRACTOptS27,
+    SEMICOLONS28,
+    IDENTIFIERS29,
+    ImportS30,
+    TYPES31,
+    TYPES32,
+    TYPE_NAMES33,
+    TypeNameS34,
+    NODES35,
+    CreateTypeS36,
+    OPEN_PARENS37,
+    EdgeTypeS38,
+    EndpointTypeS39,
+    GraphTypeS40,
+    TypeNameS41,
+    FORS42,
+    TYPES43,
+    COLONS44,
+    ATS45,
+    LabelPropertySpecS46,
+    LabelSpecOptS47,
+    LabelSpecS48,
+    OPEN_ARROWS49,
+    STRICTS50,
+    LOOSES51,
+    GraphTypeModeOptS52,
+    GraphTypeModeS53,
+    OPEN_PARENS54,
+    OPEN_PARENS55,
+    NodeTypeS56,
+    IDENTIFIERS57,
+    ATS58,
+    LabelsS59,
+    SingleLabelS60,
+    TypeNameS61,
+    CLOSE_PARENS62,
+    OPEN_CURLYS63,
+    PropertySpecOptS64,
+    PropertySpecS65,
+    TypeNameOptS66,
+    TypeNameS67,
+    OPEN_CURLYS68,
+    COLONS69,
+    TypeNameOptS70,
+    TypeNameS71,
+    BARS72,
+    AMPERSANDS73,
     MoreLabelsOptS74,
-    MoreLabelsOptS75,
+    MoreLabelsS75,
     CLOSE_PARENS76,
-    PropertiesS77,
-    PropertiesS78,
-    COLONS79,
-    EndpointTypeS80,
-    INTEGER_NAMES81,
-    BOOL_NAMES82,
-    STRING_NAMES83,
-    DATE_NAMES84,
-    CHECKS85,
-    ANYS86,
-    TypeSpecS87,
-    SimpleTypeS88,
-    OPEN_CURLYS89,
-    PLUSS90,
-    STARS91,
-    QUESTIONS92,
-    CardOptS93,
-    CardS94,
-    CardOptS95,
-    CardOptS96,
-    CardOptS97,
-    OPEN_PARENS98,
-    TRUES99,
-    FALSES100,
-    GTS101,
-    LTS102,
-    GES103,
-    LES104,
-    EQUALSS105,
-    REGEXS106,
-    NOTS107,
-    CondS108,
-    CHECKS109,
-    CheckOptS110,
-    CheckS111,
-    BARS112,
-    AMPERSANDS113,
-    MoreTypesOptS114,
-    MoreTypesS115,
-    NUMBERS116,
-    CheckOptS117,
-    CheckOptS118,
-    CheckOptS119,
-    CheckOptS120,
-    CondS121,
-    NUMBERS122,
-    TRUES123,
-    FALSES124,
-    QUOTED_STRINGS125,
-    DATES126,
-    SingleValueS127,
-    BOOLS128,
-    SingleValueS129,
-    SingleValueS130,
-    SingleValueS131,
-    SingleValueS132,
-    QUOTED_STRINGS133,
-    CondS134,
-    ANDS135,
-    ORS136,
-    CondS137,
-    SimpleTypeS138,
-    SimpleTypeS139,
-    COMMAS140,
-    CLOSE_PARENS141,
-    QUOTED_STRINGS142,
-    CondS143,
-    CondS144,
-    MoreTypesOptS145,
-    MoreTypesOptS146,
-    STARS147,
-    NUMBERS148,
-    MaxS149,
-    CLOSE_CURLYS150,
-    AUGLS151,
-    WSS152,
-    CommentLineS153,
-    START_COMMENTS154,
-    LayoutS155,
-    LayoutItem1S156,
-    LayoutItem0S157,
-    LayoutItemS158,
-    CommentS159,
-    WSS160,
-    NotCommentS161,
-    CommentS162,
-    CorncsS163,
-    Cornc1S164,
-    Cornc0S165,
-    CorncS166,
-    LayoutItemS167,
-    END_COMMENTS168,
-    CorncS169,
+    OPEN_PARENS77,
+    OPTIONALS78,
+    CHECKS79,
+    IFS80,
+    EXCLUSIVES81,
+    PropertiesS82,
+    OPTIONALOptS83,
+    LabelPropertySpecS84,
+    OPEN_PARENS85,
+    NodeTypeS86,
+    EdgeTypeS87,
+    GraphTypeElementsOptS88,
+    GraphTypeElementsS89,
+    TypeNameS90,
+    IDENTIFIERS91,
+    EXTENDSS92,
+    ExtendsOptS93,
+    ExtendsS94,
+    SingleLabelS95,
+    SingleLabelS96,
+    PropertiesS97,
+    IDENTIFIERS98,
+    OPEN_PARENS99,
+    NUMBERS100,
+    TRUES101,
+    FALSES102,
+    QUOTED_STRINGS103,
+    NOTS104,
+    DATES105,
+    keyS106,
+    RecordCondS107,
+    OperandS108,
+    SingleValueS109,
+    BOOLS110,
+    RecordCondS111,
+    OPEN_PARENS112,
+    CLOSE_CURLYS113,
+    COMMAS114,
+    DOUBLE_BARS115,
+    PropertyS116,
+    keyS117,
+    CLOSE_ARROWS118,
+    CLOSE_CURLYS119,
+    CLOSE_PARENS120,
+    TypeName1S121,
+    TypeNameS122,
+    LabelPropertySpecS123,
+    MoreLabelsOptS124,
+    MoreLabelsOptS125,
+    CLOSE_PARENS126,
+    RecordCondS127,
+    RecordCondS128,
+    QUOTED_STRINGS129,
+    ANDS130,
+    ORS131,
+    GTS132,
+    LTS133,
+    GES134,
+    LES135,
+    EQUALSS136,
+    NOT_EQUALSS137,
+    ISS138,
+    ComparatorS139,
+    THENS140,
+    Property1S141,
+    PropertyS142,
+    PropertiesS143,
+    PropertiesS144,
+    COLONS145,
+    EndpointTypeS146,
+    OUTGOINGS147,
+    INCOMINGS148,
+    KEYS149,
+    REACHESS150,
+    UNIQUES151,
+    ConstraintS152,
+    DirectionS153,
+    keyS154,
+    COMMAS155,
+    CLOSE_PARENS156,
+    CLOSE_PARENS157,
+    RecordCondS158,
+    RecordCondS159,
+    NOTS160,
+    NULLS161,
+    OperandS162,
+    OPEN_PARENS163,
+    CLOSE_PARENS164,
+    COMMAS165,
+    INTEGER_NAMES166,
+    BOOL_NAMES167,
+    STRING_NAMES168,
+    DATE_NAMES169,
+    LISTS170,
+    ARRAYS171,
+    SETS172,
+    RECORDS173,
+    CHECKS174,
+    ANYS175,
+    TypeSpecS176,
+    SimpleTypeS177,
+    ListNameS178,
+    OPEN_PARENS179,
+    OPEN_PARENS180,
+    OPEN_PARENS181,
+    COLONS182,
+    REFERENCESS183,
+    TypeNameS184,
+    NULLS185,
+    PropertiesS186,
+    PropertyS187,
+    OPEN_CURLYS188,
+    PLUSS189,
+    STARS190,
+    QUESTIONS191,
+    CardOptS192,
+    CardS193,
+    CardOptS194,
+    CardOptS195,
+    CardOptS196,
+    PropertySpecS197,
+    OPEN_PARENS198,
+    TRUES199,
+    FALSES200,
+    GTS201,
+    LTS202,
+    GES203,
+    LES204,
+    EQUALSS205,
+    REGEXS206,
+    NOTS207,
+    ANYS208,
+    UNIQUES209,
+    SUMS210,
+    MINS211,
+    MAXS212,
+    ALLS213,
+    NONES214,
+    CONTAINSS215,
+    CondS216,
+    CHECKS217,
+    CheckOptS218,
+    CheckS219,
+    BARS220,
+    AMPERSANDS221,
+    MoreTypesOptS222,
+    MoreTypesS223,
+    LTS224,
+    key1S225,
+    keyS226,
+    COLONS227,
+    key1S228,
+    IDENTIFIERS229,
+    ATS230,
+    CLOSE_PARENS231,
+    NUMBERS232,
+    CheckOptS233,
+    CheckOptS234,
+    CheckOptS235,
+    CheckOptS236,
+    CardOptS237,
+    CondS238,
+    SingleValueS239,
+    SingleValueS240,
+    SingleValueS241,
+    SingleValueS242,
+    SingleValueS243,
+    QUOTED_STRINGS244,
+    CondS245,
+    OPEN_PARENS246,
+    OPEN_PARENS247,
+    OPEN_PARENS248,
+    OPEN_PARENS249,
+    OPEN_PARENS250,
+    OPEN_PARENS251,
+    SingleValueS252,
+    ANDS253,
+    ORS254,
+    CondS255,
+    SimpleTypeS256,
+    SimpleTypeS257,
+    TypeSpecS258,
+    CLOSE_PARENS259,
+    COMMAS260,
+    IDENTIFIERS261,
+    CLOSE_PARENS262,
+    CardS263,
+    TypeNameS264,
+    ELSES265,
+    ElsePropertiesOptS266,
+    ElsePropertiesS267,
+    COMMAS268,
+    CLOSE_PARENS269,
+    CondS270,
+    CondS271,
+    CondS272,
+    CondS273,
+    CondS274,
+    CondS275,
+    CondS276,
+    CondS277,
+    MoreTypesOptS278,
+    MoreTypesOptS279,
+    GTS280,
+    keyS281,
+    CLOSE_PARENS282,
+    OPEN_PARENS283,
+    STARS284,
+    NUMBERS285,
+    MaxS286,
+    CLOSE_PARENS287,
+    CLOSE_PARENS288,
+    CLOSE_PARENS289,
+    CLOSE_PARENS290,
+    CLOSE_PARENS291,
+    CLOSE_PARENS292,
+    CardOptS293,
+    VIAS294,
+    PropertiesS295,
+    CLOSE_CURLYS296,
+    CheckOptS297,
+    IDENTIFIERS298,
+    CARETS299,
+    OPEN_PARENS300,
+    PathExprS301,
+    CLOSE_PARENS302,
+    IDENTIFIERS303,
+    PathExprS304,
+    SLASHS305,
+    BARS306,
+    PLUSS307,
+    STARS308,
+    QUESTIONS309,
+    CLOSE_PARENS310,
+    PathExprS311,
+    PathExprS312,
+    AUGLS313,
+    WSS314,
+    CommentLineS315,
+    START_COMMENTS316,
+    LayoutS317,
+    LayoutItem1S318,
+    LayoutItem0S319,
+    LayoutItemS320,
+    CommentS321,
+    WSS322,
+    NotCommentS323,
+    CommentS324,
+    CorncsS325,
+    Cornc1S326,
+    Cornc0S327,
+    CorncS328,
+    LayoutItemS329,
+    END_COMMENTS330,
+    CorncS331,
 }
 impl StateT for State {
     fn default_layout() -> Option<Self> {
-        Some(State::AUGLS151)
+        Some(State::AUGLS313)
     }
 }
 impl From<State> for usize {
@@ -684,175 +1138,337 @@ impl std::fmt::Debug for State {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = match self {
             State::AUGS0 => "0:AUG",
-            State::CREATES1 => "1:CREATE",
+            State::IMPORTSS1 => "1:IMPORTS",
             State::PgsS2 => "2:Pgs",
-            State::CreateType1S3 => "3:CreateType1",
-            State::CreateTypeS4 => "4:CreateType",
-            State::CreateNodeTypeS5 => "5:CreateNodeType",
-            State::CreateEdgeTypeS6 => "6:CreateEdgeType",
-            State::CreateGraphTypeS7 => "7:CreateGraphType",
-            State::NODES8 => "8:NODE",
-            State::EDGES9 => "9:EDGE",
-            State::GRAPHS10 => "10:GRAPH",
-            State::SEMICOLONS11 => "11:SEMICOLON",
-            State::TYPES12 => "12:TYPE",
-            State::TYPES13 => "13:TYPE",
-            State::TYPES14 => "14:TYPE",
-            State::CreateTypeS15 => "15:CreateType",
-            State::OPEN_PARENS16 => "16:OPEN_PAREN",
-            State::NodeTypeS17 => "17:NodeType",
-            State::OPEN_PARENS18 => "18:OPEN_PAREN",
-            State::EdgeTypeS19 => "19:EdgeType",
-            State::EndpointTypeS20 => "20:EndpointType",
-            State::IDENTIFIERS21 => "21:IDENTIFIER",
-            State::GraphTypeS22 => "22:GraphType",
-            State::TypeNameS23 => "23:TypeName",
-            State::TypeNameOptS24 => "24:TypeNameOpt",
-            State::TypeNameS25 => "25:TypeName",
-            State::COLONS26 => "26:COLON",
-            State::LabelPropertySpecS27 => "27:LabelPropertySpec",
-            State::LabelSpecOptS28 => "28:LabelSpecOpt",
-            State::LabelSpecS29 => "29:LabelSpec",
-            State::OPEN_ARROWS30 => "30:OPEN_ARROW",
-            State::STRICTS31 => "31:STRICT",
-            State::LOOSES32 => "32:LOOSE",
-            State::GraphTypeModeOptS33 => "33:GraphTypeModeOpt",
-            State::GraphTypeModeS34 => "34:GraphTypeMode",
-            State::LabelPropertySpecS35 => "35:LabelPropertySpec",
-            State::IDENTIFIERS36 => "36:IDENTIFIER",
-            State::ATS37 => "37:AT",
-            State::LabelsS38 => "38:Labels",
-            State::SingleLabelS39 => "39:SingleLabel",
-            State::CLOSE_PARENS40 => "40:CLOSE_PAREN",
-            State::OPEN_CURLYS41 => "41:OPEN_CURLY",
-            State::PropertySpecOptS42 => "42:PropertySpecOpt",
-            State::PropertySpecS43 => "43:PropertySpec",
-            State::TypeNameOptS44 => "44:TypeNameOpt",
-            State::OPEN_CURLYS45 => "45:OPEN_CURLY",
-            State::CLOSE_PARENS46 => "46:CLOSE_PAREN",
-            State::IDENTIFIERS47 => "47:IDENTIFIER",
-            State::BARS48 => "48:BAR",
-            State::AMPERSANDS49 => "49:AMPERSAND",
-            State::MoreLabelsOptS50 => "50:MoreLabelsOpt",
-            State::MoreLabelsS51 => "51:MoreLabels",
-            State::OPEN_PARENS52 => "52:OPEN_PAREN",
-            State::OPTIONALS53 => "53:OPTIONAL",
-            State::PropertiesS54 => "54:Properties",
-            State::OPTIONALOptS55 => "55:OPTIONALOpt",
-            State::LabelPropertySpecS56 => "56:LabelPropertySpec",
-            State::OPEN_PARENS57 => "57:OPEN_PAREN",
-            State::NodeTypeS58 => "58:NodeType",
-            State::EdgeTypeS59 => "59:EdgeType",
-            State::GraphTypeElementsOptS60 => "60:GraphTypeElementsOpt",
-            State::GraphTypeElementsS61 => "61:GraphTypeElements",
-            State::TypeNameS62 => "62:TypeName",
-            State::SingleLabelS63 => "63:SingleLabel",
-            State::SingleLabelS64 => "64:SingleLabel",
-            State::PropertiesS65 => "65:Properties",
-            State::CLOSE_CURLYS66 => "66:CLOSE_CURLY",
-            State::COMMAS67 => "67:COMMA",
-            State::DOUBLE_BARS68 => "68:DOUBLE_BAR",
-            State::IDENTIFIERS69 => "69:IDENTIFIER",
-            State::PropertyS70 => "70:Property",
-            State::keyS71 => "71:key",
-            State::CLOSE_ARROWS72 => "72:CLOSE_ARROW",
-            State::CLOSE_CURLYS73 => "73:CLOSE_CURLY",
+            State::GraphTypeImportsOptS3 => "3:GraphTypeImportsOpt",
+            State::GraphTypeImportsS4 => "4:GraphTypeImports",
+            State::IDENTIFIERS5 => "5:IDENTIFIER",
+            State::QUOTED_STRINGS6 => "6:QUOTED_STRING",
+            State::Import1S7 => "7:Import1",
+            State::ImportS8 => "8:Import",
+            State::CREATES9 => "9:CREATE",
+            State::CreateType1S10 => "10:CreateType1",
+            State::CreateType0S11 => "11:CreateType0",
+            State::CreateTypeS12 => "12:CreateType",
+            State::CreateNodeTypeS13 => "13:CreateNodeType",
+            State::CreateEdgeTypeS14 => "14:CreateEdgeType",
+            State::CreateGraphTypeS15 => "15:CreateGraphType",
+            State::CreateConstraintS16 => "16:CreateConstraint",
+            State::ASS17 => "17:AS",
+            State::ImportAliasOptS18 => "18:ImportAliasOpt",
+            State::ImportAliasS19 => "19:ImportAlias",
+            State::ImportAliasOptS20 => "20:ImportAliasOpt",
+            State::SEMICOLONS21 => "21:SEMICOLON",
+            State::COMMAS22 => "22:COMMA",
+            State::EDGES23 => "23:EDGE",
+            State::GRAPHS24 => "24:GRAPH",
+            State::CONSTRAINTS25 => "25:CONSTRAINT",
+            State::ABSTRACTS26 => "26:ABSTRACT",
+            State::ABSTRACTOptS27 => "27:ABSTRACTOpt",
+            State::SEMICOLONS28 => "28:SEMICOLON",
+            State::IDENTIFIERS29 => "29:IDENTIFIER",
+            State::ImportS30 => "30:Import",
+            State::TYPES31 => "31:TYPE",
+            State::TYPES32 => "32:TYPE",
+            State::TYPE_NAMES33 => "33:TYPE_NAME",
+            State::TypeNameS34 => "34:TypeName",
+            State::NODES35 => "35:NODE",
+            State::CreateTypeS36 => "36:CreateType",
+            State::OPEN_PARENS37 => "37:OPEN_PAREN",
+            State::EdgeTypeS38 => "38:EdgeType",
+            State::EndpointTypeS39 => "39:EndpointType",
+            State::GraphTypeS40 => "40:GraphType",
+            State::TypeNameS41 => "41:TypeName",
+            State::FORS42 => "42:FOR",
+            State::TYPES43 => "43:TYPE",
+            State::COLONS44 => "44:COLON",
+            State::ATS45 => "45:AT",
+            State::LabelPropertySpecS46 => "46:LabelPropertySpec",
+            State::LabelSpecOptS47 => "47:LabelSpecOpt",
+            State::LabelSpecS48 => "48:LabelSpec",
+            State::OPEN_ARROWS49 => "49:OPEN_ARROW",
+            State::STRICTS50 => "50:STRICT",
+            State::LOOSES51 => "51:LOOSE",
+            State::GraphTypeModeOptS52 => "52:GraphTypeModeOpt",
+            State::GraphTypeModeS53 => "53:GraphTypeMode",
+            State::OPEN_PARENS54 => "54:OPEN_PAREN",
+            State::OPEN_PARENS55 => "55:OPEN_PAREN",
+            State::NodeTypeS56 => "56:NodeType",
+            State::IDENTIFIERS57 => "57:IDENTIFIER",
+            State::ATS58 => "58:AT",
+            State::LabelsS59 => "59:Labels",
+            State::SingleLabelS60 => "60:SingleLabel",
+            State::TypeNameS61 => "61:TypeName",
+            State::CLOSE_PARENS62 => "62:CLOSE_PAREN",
+            State::OPEN_CURLYS63 => "63:OPEN_CURLY",
+            State::PropertySpecOptS64 => "64:PropertySpecOpt",
+            State::PropertySpecS65 => "65:PropertySpec",
+            State::TypeNameOptS66 => "66:TypeNameOpt",
+            State::TypeNameS67 => "67:TypeName",
+            State::OPEN_CURLYS68 => "68:OPEN_CURLY",
+            State::COLONS69 => "69:COLON",
+            State::TypeNameOptS70 => "70:TypeNameOpt",
+            State::TypeNameS71 => "71:TypeName",
+            State::BARS72 => "72:BAR",
+            State::AMPERSANDS73 => "73:AMPERSAND",
             State::MoreLabelsOptS74 => "74:MoreLabelsOpt",
-            State::MoreLabelsOptS75 => "75:MoreLabelsOpt",
+            State::MoreLabelsS75 => "75:MoreLabels",
             State::CLOSE_PARENS76 => "76:CLOSE_PAREN",
-            State::PropertiesS77 => "77:Properties",
-            State::PropertiesS78 => "78:Properties",
-            State::COLONS79 => "79:COLON",
-            State::EndpointTypeS80 => "80:EndpointType",
-            State::INTEGER_NAMES81 => "81:INTEGER_NAME",
-            State::BOOL_NAMES82 => "82:BOOL_NAME",
-            State::STRING_NAMES83 => "83:STRING_NAME",
-            State::DATE_NAMES84 => "84:DATE_NAME",
-            State::CHECKS85 => "85:CHECK",
-            State::ANYS86 => "86:ANY",
-            State::TypeSpecS87 => "87:TypeSpec",
-            State::SimpleTypeS88 => "88:SimpleType",
-            State::OPEN_CURLYS89 => "89:OPEN_CURLY",
-            State::PLUSS90 => "90:PLUS",
-            State::STARS91 => "91:STAR",
-            State::QUESTIONS92 => "92:QUESTION",
-            State::CardOptS93 => "93:CardOpt",
-            State::CardS94 => "94:Card",
-            State::CardOptS95 => "95:CardOpt",
-            State::CardOptS96 => "96:CardOpt",
-            State::CardOptS97 => "97:CardOpt",
-            State::OPEN_PARENS98 => "98:OPEN_PAREN",
-            State::TRUES99 => "99:TRUE",
-            State::FALSES100 => "100:FALSE",
-            State::GTS101 => "101:GT",
-            State::LTS102 => "102:LT",
-            State::GES103 => "103:GE",
-            State::LES104 => "104:LE",
-            State::EQUALSS105 => "105:EQUALS",
-            State::REGEXS106 => "106:REGEX",
-            State::NOTS107 => "107:NOT",
-            State::CondS108 => "108:Cond",
-            State::CHECKS109 => "109:CHECK",
-            State::CheckOptS110 => "110:CheckOpt",
-            State::CheckS111 => "111:Check",
-            State::BARS112 => "112:BAR",
-            State::AMPERSANDS113 => "113:AMPERSAND",
-            State::MoreTypesOptS114 => "114:MoreTypesOpt",
-            State::MoreTypesS115 => "115:MoreTypes",
-            State::NUMBERS116 => "116:NUMBER",
-            State::CheckOptS117 => "117:CheckOpt",
-            State::CheckOptS118 => "118:CheckOpt",
-            State::CheckOptS119 => "119:CheckOpt",
-            State::CheckOptS120 => "120:CheckOpt",
-            State::CondS121 => "121:Cond",
-            State::NUMBERS122 => "122:NUMBER",
-            State::TRUES123 => "123:TRUE",
-            State::FALSES124 => "124:FALSE",
-            State::QUOTED_STRINGS125 => "125:QUOTED_STRING",
-            State::DATES126 => "126:DATE",
-            State::SingleValueS127 => "127:SingleValue",
-            State::BOOLS128 => "128:BOOL",
-            State::SingleValueS129 => "129:SingleValue",
-            State::SingleValueS130 => "130:SingleValue",
-            State::SingleValueS131 => "131:SingleValue",
-            State::SingleValueS132 => "132:SingleValue",
-            State::QUOTED_STRINGS133 => "133:QUOTED_STRING",
-            State::CondS134 => "134:Cond",
-            State::ANDS135 => "135:AND",
-            State::ORS136 => "136:OR",
-            State::CondS137 => "137:Cond",
-            State::SimpleTypeS138 => "138:SimpleType",
-            State::SimpleTypeS139 => "139:SimpleType",
-            State::COMMAS140 => "140:COMMA",
-            State::CLOSE_PARENS141 => "141:CLOSE_PAREN",
-            State::QUOTED_STRINGS142 => "142:QUOTED_STRING",
-            State::CondS143 => "143:Cond",
-            State::CondS144 => "144:Cond",
-            State::MoreTypesOptS145 => "145:MoreTypesOpt",
-            State::MoreTypesOptS146 => "146:MoreTypesOpt",
-            State::STARS147 => "147:STAR",
-            State::NUMBERS148 => "148:NUMBER",
-            State::MaxS149 => "149:Max",
-            State::CLOSE_CURLYS150 => "150:CLOSE_CURLY",
-            State::AUGLS151 => "151:AUGL",
-            State::WSS152 => "152:WS",
-            State::CommentLineS153 => "153:CommentLine",
-            State::START_COMMENTS154 => "154:START_COMMENT",
-            State::LayoutS155 => "155:Layout",
-            State::LayoutItem1S156 => "156:LayoutItem1",
-            State::LayoutItem0S157 => "157:LayoutItem0",
-            State::LayoutItemS158 => "158:LayoutItem",
-            State::CommentS159 => "159:Comment",
-            State::WSS160 => "160:WS",
-            State::NotCommentS161 => "161:NotComment",
-            State::CommentS162 => "162:Comment",
-            State::CorncsS163 => "163:Corncs",
-            State::Cornc1S164 => "164:Cornc1",
-            State::Cornc0S165 => "165:Cornc0",
-            State::CorncS166 => "166:Cornc",
-            State::LayoutItemS167 => "167:LayoutItem",
-            State::END_COMMENTS168 => "168:END_COMMENT",
-            State::CorncS169 => "169:Cornc",
+            State::OPEN_PARENS77 => "77:OPEN_PAREN",
+            State::OPTIONALS78 => "78:OPTIONAL",
+            State::CHECKS79 => "79:CHECK",
+            State::IFS80 => "80:IF",
+            State::EXCLUSIVES81 => "81:EXCLUSIVE",
+            State::PropertiesS82 => "82:Properties",
+            State::OPTIONALOptS83 => "83:OPTIONALOpt",
+            State::LabelPropertySpecS84 => "84:LabelPropertySpec",
+            State::OPEN_PARENS85 => "85:OPEN_PAREN",
+            State::NodeTypeS86 => "86:NodeType",
+            State::EdgeTypeS87 => "87:EdgeType",
+            State::GraphTypeElementsOptS88 => "88:GraphTypeElementsOpt",
+            State::GraphTypeElementsS89 => "89:GraphTypeElements",
+            State::TypeNameS90 => "90:TypeName",
+            State::IDENTIFIERS91 => "91:IDENTIFIER",
+            State::EXTENDSS92 => "92:EXTENDS",
+            State::ExtendsOptS93 => "93:ExtendsOpt",
+            State::ExtendsS94 => "94:Extends",
+            State::SingleLabelS95 => "95:SingleLabel",
+            State::SingleLabelS96 => "96:SingleLabel",
+            State::PropertiesS97 => "97:Properties",
+            State::IDENTIFIERS98 => "98:IDENTIFIER",
+            State::OPEN_PARENS99 => "99:OPEN_PAREN",
+            State::NUMBERS100 => "100:NUMBER",
+            State::TRUES101 => "101:TRUE",
+            State::FALSES102 => "102:FALSE",
+            State::QUOTED_STRINGS103 => "103:QUOTED_STRING",
+            State::NOTS104 => "104:NOT",
+            State::DATES105 => "105:DATE",
+            State::keyS106 => "106:key",
+            State::RecordCondS107 => "107:RecordCond",
+            State::OperandS108 => "108:Operand",
+            State::SingleValueS109 => "109:SingleValue",
+            State::BOOLS110 => "110:BOOL",
+            State::RecordCondS111 => "111:RecordCond",
+            State::OPEN_PARENS112 => "112:OPEN_PAREN",
+            State::CLOSE_CURLYS113 => "113:CLOSE_CURLY",
+            State::COMMAS114 => "114:COMMA",
+            State::DOUBLE_BARS115 => "115:DOUBLE_BAR",
+            State::PropertyS116 => "116:Property",
+            State::keyS117 => "117:key",
+            State::CLOSE_ARROWS118 => "118:CLOSE_ARROW",
+            State::CLOSE_CURLYS119 => "119:CLOSE_CURLY",
+            State::CLOSE_PARENS120 => "120:CLOSE_PAREN",
+            State::TypeName1S121 => "121:TypeName1",
+            State::TypeNameS122 => "122:TypeName",
+            State::LabelPropertySpecS123 => "123:LabelPropertySpec",
+            State::MoreLabelsOptS124 => "124:MoreLabelsOpt",
+            State::MoreLabelsOptS125 => "125:MoreLabelsOpt",
+            State::CLOSE_PARENS126 => "126:CLOSE_PAREN",
+            State::RecordCondS127 => "127:RecordCond",
+            State::RecordCondS128 => "128:RecordCond",
+            State::QUOTED_STRINGS129 => "129:QUOTED_STRING",
+            State::ANDS130 => "130:AND",
+            State::ORS131 => "131:OR",
+            State::GTS132 => "132:GT",
+            State::LTS133 => "133:LT",
+            State::GES134 => "134:GE",
+            State::LES135 => "135:LE",
+            State::EQUALSS136 => "136:EQUALS",
+            State::NOT_EQUALSS137 => "137:NOT_EQUALS",
+            State::ISS138 => "138:IS",
+            State::ComparatorS139 => "139:Comparator",
+            State::THENS140 => "140:THEN",
+            State::Property1S141 => "141:Property1",
+            State::PropertyS142 => "142:Property",
+            State::PropertiesS143 => "143:Properties",
+            State::PropertiesS144 => "144:Properties",
+            State::COLONS145 => "145:COLON",
+            State::EndpointTypeS146 => "146:EndpointType",
+            State::OUTGOINGS147 => "147:OUTGOING",
+            State::INCOMINGS148 => "148:INCOMING",
+            State::KEYS149 => "149:KEY",
+            State::REACHESS150 => "150:REACHES",
+            State::UNIQUES151 => "151:UNIQUE",
+            State::ConstraintS152 => "152:Constraint",
+            State::DirectionS153 => "153:Direction",
+            State::keyS154 => "154:key",
+            State::COMMAS155 => "155:COMMA",
+            State::CLOSE_PARENS156 => "156:CLOSE_PAREN",
+            State::CLOSE_PARENS157 => "157:CLOSE_PAREN",
+            State::RecordCondS158 => "158:RecordCond",
+            State::RecordCondS159 => "159:RecordCond",
+            State::NOTS160 => "160:NOT",
+            State::NULLS161 => "161:NULL",
+            State::OperandS162 => "162:Operand",
+            State::OPEN_PARENS163 => "163:OPEN_PAREN",
+            State::CLOSE_PARENS164 => "164:CLOSE_PAREN",
+            State::COMMAS165 => "165:COMMA",
+            State::INTEGER_NAMES166 => "166:INTEGER_NAME",
+            State::BOOL_NAMES167 => "167:BOOL_NAME",
+            State::STRING_NAMES168 => "168:STRING_NAME",
+            State::DATE_NAMES169 => "169:DATE_NAME",
+            State::LISTS170 => "170:LIST",
+            State::ARRAYS171 => "171:ARRAY",
+            State::SETS172 => "172:SET",
+            State::RECORDS173 => "173:RECORD",
+            State::CHECKS174 => "174:CHECK",
+            State::ANYS175 => "175:ANY",
+            State::TypeSpecS176 => "176:TypeSpec",
+            State::SimpleTypeS177 => "177:SimpleType",
+            State::ListNameS178 => "178:ListName",
+            State::OPEN_PARENS179 => "179:OPEN_PAREN",
+            State::OPEN_PARENS180 => "180:OPEN_PAREN",
+            State::OPEN_PARENS181 => "181:OPEN_PAREN",
+            State::COLONS182 => "182:COLON",
+            State::REFERENCESS183 => "183:REFERENCES",
+            State::TypeNameS184 => "184:TypeName",
+            State::NULLS185 => "185:NULL",
+            State::PropertiesS186 => "186:Properties",
+            State::PropertyS187 => "187:Property",
+            State::OPEN_CURLYS188 => "188:OPEN_CURLY",
+            State::PLUSS189 => "189:PLUS",
+            State::STARS190 => "190:STAR",
+            State::QUESTIONS191 => "191:QUESTION",
+            State::CardOptS192 => "192:CardOpt",
+            State::CardS193 => "193:Card",
+            State::CardOptS194 => "194:CardOpt",
+            State::CardOptS195 => "195:CardOpt",
+            State::CardOptS196 => "196:CardOpt",
+            State::PropertySpecS197 => "197:PropertySpec",
+            State::OPEN_PARENS198 => "198:OPEN_PAREN",
+            State::TRUES199 => "199:TRUE",
+            State::FALSES200 => "200:FALSE",
+            State::GTS201 => "201:GT",
+            State::LTS202 => "202:LT",
+            State::GES203 => "203:GE",
+            State::LES204 => "204:LE",
+            State::EQUALSS205 => "205:EQUALS",
+            State::REGEXS206 => "206:REGEX",
+            State::NOTS207 => "207:NOT",
+            State::ANYS208 => "208:ANY",
+            State::UNIQUES209 => "209:UNIQUE",
+            State::SUMS210 => "210:SUM",
+            State::MINS211 => "211:MIN",
+            State::MAXS212 => "212:MAX",
+            State::ALLS213 => "213:ALL",
+            State::NONES214 => "214:NONE",
+            State::CONTAINSS215 => "215:CONTAINS",
+            State::CondS216 => "216:Cond",
+            State::CHECKS217 => "217:CHECK",
+            State::CheckOptS218 => "218:CheckOpt",
+            State::CheckS219 => "219:Check",
+            State::BARS220 => "220:BAR",
+            State::AMPERSANDS221 => "221:AMPERSAND",
+            State::MoreTypesOptS222 => "222:MoreTypesOpt",
+            State::MoreTypesS223 => "223:MoreTypes",
+            State::LTS224 => "224:LT",
+            State::key1S225 => "225:key1",
+            State::keyS226 => "226:key",
+            State::COLONS227 => "227:COLON",
+            State::key1S228 => "228:key1",
+            State::IDENTIFIERS229 => "229:IDENTIFIER",
+            State::ATS230 => "230:AT",
+            State::CLOSE_PARENS231 => "231:CLOSE_PAREN",
+            State::NUMBERS232 => "232:NUMBER",
+            State::CheckOptS233 => "233:CheckOpt",
+            State::CheckOptS234 => "234:CheckOpt",
+            State::CheckOptS235 => "235:CheckOpt",
+            State::CheckOptS236 => "236:CheckOpt",
+            State::CardOptS237 => "237:CardOpt",
+            State::CondS238 => "238:Cond",
+            State::SingleValueS239 => "239:SingleValue",
+            State::SingleValueS240 => "240:SingleValue",
+            State::SingleValueS241 => "241:SingleValue",
+            State::SingleValueS242 => "242:SingleValue",
+            State::SingleValueS243 => "243:SingleValue",
+            State::QUOTED_STRINGS244 => "244:QUOTED_STRING",
+            State::CondS245 => "245:Cond",
+            State::OPEN_PARENS246 => "246:OPEN_PAREN",
+            State::OPEN_PARENS247 => "247:OPEN_PAREN",
+            State::OPEN_PARENS248 => "248:OPEN_PAREN",
+            State::OPEN_PARENS249 => "249:OPEN_PAREN",
+            State::OPEN_PARENS250 => "250:OPEN_PAREN",
+            State::OPEN_PARENS251 => "251:OPEN_PAREN",
+            State::SingleValueS252 => "252:SingleValue",
+            State::ANDS253 => "253:AND",
+            State::ORS254 => "254:OR",
+            State::CondS255 => "255:Cond",
+            State::SimpleTypeS256 => "256:SimpleType",
+            State::SimpleTypeS257 => "257:SimpleType",
+            State::TypeSpecS258 => "258:TypeSpec",
+            State::CLOSE_PARENS259 => "259:CLOSE_PAREN",
+            State::COMMAS260 => "260:COMMA",
+            State::IDENTIFIERS261 => "261:IDENTIFIER",
+            State::CLOSE_PARENS262 => "262:CLOSE_PAREN",
+            State::CardS263 => "263:Card",
+            State::TypeNameS264 => "264:TypeName",
+            State::ELSES265 => "265:ELSE",
+            State::ElsePropertiesOptS266 => "266:ElsePropertiesOpt",
+            State::ElsePropertiesS267 => "267:ElseProperties",
+            State::COMMAS268 => "268:COMMA",
+            State::CLOSE_PARENS269 => "269:CLOSE_PAREN",
+            State::CondS270 => "270:Cond",
+            State::CondS271 => "271:Cond",
+            State::CondS272 => "272:Cond",
+            State::CondS273 => "273:Cond",
+            State::CondS274 => "274:Cond",
+            State::CondS275 => "275:Cond",
+            State::CondS276 => "276:Cond",
+            State::CondS277 => "277:Cond",
+            State::MoreTypesOptS278 => "278:MoreTypesOpt",
+            State::MoreTypesOptS279 => "279:MoreTypesOpt",
+            State::GTS280 => "280:GT",
+            State::keyS281 => "281:key",
+            State::CLOSE_PARENS282 => "282:CLOSE_PAREN",
+            State::OPEN_PARENS283 => "283:OPEN_PAREN",
+            State::STARS284 => "284:STAR",
+            State::NUMBERS285 => "285:NUMBER",
+            State::MaxS286 => "286:Max",
+            State::CLOSE_PARENS287 => "287:CLOSE_PAREN",
+            State::CLOSE_PARENS288 => "288:CLOSE_PAREN",
+            State::CLOSE_PARENS289 => "289:CLOSE_PAREN",
+            State::CLOSE_PARENS290 => "290:CLOSE_PAREN",
+            State::CLOSE_PARENS291 => "291:CLOSE_PAREN",
+            State::CLOSE_PARENS292 => "292:CLOSE_PAREN",
+            State::CardOptS293 => "293:CardOpt",
+            State::VIAS294 => "294:VIA",
+            State::PropertiesS295 => "295:Properties",
+            State::CLOSE_CURLYS296 => "296:CLOSE_CURLY",
+            State::CheckOptS297 => "297:CheckOpt",
+            State::IDENTIFIERS298 => "298:IDENTIFIER",
+            State::CARETS299 => "299:CARET",
+            State::OPEN_PARENS300 => "300:OPEN_PAREN",
+            State::PathExprS301 => "301:PathExpr",
+            State::CLOSE_PARENS302 => "302:CLOSE_PAREN",
+            State::IDENTIFIERS303 => "303:IDENTIFIER",
+            State::PathExprS304 => "304:PathExpr",
+            State::SLASHS305 => "305:SLASH",
+            State::BARS306 => "306:BAR",
+            State::PLUSS307 => "307:PLUS",
+            State::STARS308 => "308:STAR",
+            State::QUESTIONS309 => "309:QUESTION",
+            State::CLOSE_PARENS310 => "310:CLOSE_PAREN",
+            State::PathExprS311 => "311:PathExpr",
+            State::PathExprS312 => "312:PathExpr",
+            State::AUGLS313 => "313:AUGL",
+            State::WSS314 => "314:WS",
+            State::CommentLineS315 => "315:CommentLine",
+            State::START_COMMENTS316 => "316:START_COMMENT",
+            State::LayoutS317 => "317:Layout",
+            State::LayoutItem1S318 => "318:LayoutItem1",
+            State::LayoutItem0S319 => "319:LayoutItem0",
+            State::LayoutItemS320 => "320:LayoutItem",
+            State::CommentS321 => "321:Comment",
+            State::WSS322 => "322:WS",
+            State::NotCommentS323 => "323:NotComment",
+            State::CommentS324 => "324:Comment",
+            State::CorncsS325 => "325:Corncs",
+            State::Cornc1S326 => "326:Cornc1",
+            State::Cornc0S327 => "327:Cornc0",
+            State::CorncS328 => "328:Cornc",
+            State::LayoutItemS329 => "329:LayoutItem",
+            State::END_COMMENTS330 => "330:END_COMMENT",
+            State::CorncS331 => "331:Cornc",
         };
         write!(f, "{name}")
     }
@@ -866,12 +1482,23 @@ pub enum Symbol {
 #[derive(Debug)]
 pub enum Terminal {
     IDENTIFIER(pgs_actions::IDENTIFIER),
+    TYPE_NAME(pgs_actions::TYPE_NAME),
     SEMICOLON,
     CREATE,
     NODE,
     EDGE,
     GRAPH,
     TYPE,
+    CONSTRAINT,
+    FOR,
+    OUTGOING,
+    INCOMING,
+    KEY,
+    REFERENCES,
+    REACHES,
+    VIA,
+    SLASH,
+    CARET,
     OPEN_PAREN,
     CLOSE_PAREN,
     OPEN_ARROW,
@@ -889,6 +1516,10 @@ pub enum Terminal {
     BOOL_NAME,
     STRING_NAME,
     DATE_NAME,
+    LIST,
+    ARRAY,
+    SET,
+    RECORD,
     NUMBER(pgs_actions::NUMBER),
     OPTIONAL,
     AMPERSAND,
@@ -901,30 +1532,64 @@ pub enum Terminal {
     GE,
     LE,
     EQUALS,
+    NOT_EQUALS,
     QUOTED_STRING(pgs_actions::QUOTED_STRING),
     REGEX,
     AND,
     OR,
     NOT,
+    IS,
+    NULL,
+    IF,
+    THEN,
+    ELSE,
+    EXCLUSIVE,
     ANY,
+    UNIQUE,
+    SUM,
+    MIN,
+    MAX,
+    ALL,
+    NONE,
+    CONTAINS,
     DATE,
     STRICT,
     LOOSE,
+    ABSTRACT,
+    EXTENDS,
+    IMPORTS,
+    AS,
 }
 #[derive(Debug)]
 pub enum NonTerminal {
     Pgs(pgs_actions::Pgs),
+    GraphTypeImportsOpt(pgs_actions::GraphTypeImportsOpt),
     CreateType1(pgs_actions::CreateType1),
+    CreateType0(pgs_actions::CreateType0),
     CreateType(pgs_actions::CreateType),
     CreateNodeType(pgs_actions::CreateNodeType),
+    ABSTRACTOpt(pgs_actions::ABSTRACTOpt),
     CreateEdgeType(pgs_actions::CreateEdgeType),
     CreateGraphType(pgs_actions::CreateGraphType),
+    CreateConstraint(pgs_actions::CreateConstraint),
+    Constraint(pgs_actions::Constraint),
+    key1(pgs_actions::key1),
+    PathExpr(pgs_actions::PathExpr),
+    Direction(pgs_actions::Direction),
     NodeType(pgs_actions::NodeType),
     TypeNameOpt(pgs_actions::TypeNameOpt),
+    ExtendsOpt(pgs_actions::ExtendsOpt),
+    Extends(pgs_actions::Extends),
+    TypeName1(pgs_actions::TypeName1),
     EdgeType(pgs_actions::EdgeType),
     GraphType(pgs_actions::GraphType),
     GraphTypeModeOpt(pgs_actions::GraphTypeModeOpt),
     GraphTypeElementsOpt(pgs_actions::GraphTypeElementsOpt),
+    GraphTypeImports(pgs_actions::GraphTypeImports),
+    Import1(pgs_actions::Import1),
+    Import(pgs_actions::Import),
+    ImportAliasOpt(pgs_actions::ImportAliasOpt),
+    ImportAlias(pgs_actions::ImportAlias),
     GraphTypeMode(pgs_actions::GraphTypeMode),
     GraphTypeElements(pgs_actions::GraphTypeElements),
     TypeName(pgs_actions::TypeName),
@@ -940,6 +1605,9 @@ pub enum NonTerminal {
     PropertySpec(pgs_actions::PropertySpec),
     Properties(pgs_actions::Properties),
     OPTIONALOpt(pgs_actions::OPTIONALOpt),
+    ElsePropertiesOpt(pgs_actions::ElsePropertiesOpt),
+    Property1(pgs_actions::Property1),
+    ElseProperties(pgs_actions::ElseProperties),
     Property(pgs_actions::Property),
     key(pgs_actions::key),
     TypeSpec(pgs_actions::TypeSpec),
@@ -949,7 +1617,11 @@ pub enum NonTerminal {
     CardOpt(pgs_actions::CardOpt),
     CheckOpt(pgs_actions::CheckOpt),
     Check(pgs_actions::Check),
+    ListName(pgs_actions::ListName),
     Cond(pgs_actions::Cond),
+    RecordCond(pgs_actions::RecordCond),
+    Operand(pgs_actions::Operand),
+    Comparator(pgs_actions::Comparator),
     Card(pgs_actions::Card),
     Max(pgs_actions::Max),
     SingleValue(pgs_actions::SingleValue),
@@ -963,15 +1635,16 @@ pub struct PgsParserDefinition {
 }
 fn action_aug_s0(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CREATE => Vec::from(&[Shift(State::CREATES1)]),
+        TK::STOP => Vec::from(&[Reduce(PK::GraphTypeImportsOptP2, 0usize)]),
+        TK::CREATE => Vec::from(&[Reduce(PK::GraphTypeImportsOptP2, 0usize)]),
+        TK::IMPORTS => Vec::from(&[Shift(State::IMPORTSS1)]),
         _ => vec![],
     }
 }
-fn action_create_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_imports_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::NODE => Vec::from(&[Shift(State::NODES8)]),
-        TK::EDGE => Vec::from(&[Shift(State::EDGES9)]),
-        TK::GRAPH => Vec::from(&[Shift(State::GRAPHS10)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS5)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS6)]),
         _ => vec![],
     }
 }
@@ -981,193 +1654,326 @@ fn action_pgs_s2(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_createtype1_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_graphtypeimportsopt_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CreateType0P2, 0usize)]),
+        TK::CREATE => Vec::from(&[Shift(State::CREATES9)]),
+        _ => vec![],
+    }
+}
+fn action_graphtypeimports_s4(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::GraphTypeImportsOptP1, 1usize)]),
+        TK::CREATE => Vec::from(&[Reduce(PK::GraphTypeImportsOptP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ImportAliasOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ImportAliasOptP2, 0usize)]),
+        TK::AS => Vec::from(&[Shift(State::ASS17)]),
+        _ => vec![],
+    }
+}
+fn action_quoted_string_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ImportAliasOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ImportAliasOptP2, 0usize)]),
+        TK::AS => Vec::from(&[Shift(State::ASS17)]),
+        _ => vec![],
+    }
+}
+fn action_import1_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SEMICOLON => Vec::from(&[Shift(State::SEMICOLONS21)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS22)]),
+        _ => vec![],
+    }
+}
+fn action_import_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::Import1P2, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::Import1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_create_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::NODE => Vec::from(&[Reduce(PK::ABSTRACTOptP2, 0usize)]),
+        TK::EDGE => Vec::from(&[Shift(State::EDGES23)]),
+        TK::GRAPH => Vec::from(&[Shift(State::GRAPHS24)]),
+        TK::CONSTRAINT => Vec::from(&[Shift(State::CONSTRAINTS25)]),
+        TK::ABSTRACT => Vec::from(&[Shift(State::ABSTRACTS26)]),
+        _ => vec![],
+    }
+}
+fn action_createtype1_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CreateType0P1, 1usize)]),
+        TK::SEMICOLON => Vec::from(&[Shift(State::SEMICOLONS28)]),
+        _ => vec![],
+    }
+}
+fn action_createtype0_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::PgsP1, 1usize)]),
-        TK::SEMICOLON => Vec::from(&[Shift(State::SEMICOLONS11)]),
+        TK::STOP => Vec::from(&[Reduce(PK::PgsP1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_createtype_s4(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_createtype_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CreateType1P2, 1usize)]),
         TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateType1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_createnodetype_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_createnodetype_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CreateTypeP1, 1usize)]),
         TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateTypeP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_createedgetype_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_createedgetype_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CreateTypeP2, 1usize)]),
         TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateTypeP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_creategraphtype_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_creategraphtype_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CreateTypeP3, 1usize)]),
         TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateTypeP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_node_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_createconstraint_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TYPE => Vec::from(&[Shift(State::TYPES12)]),
+        TK::STOP => Vec::from(&[Reduce(PK::CreateTypeP4, 1usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateTypeP4, 1usize)]),
         _ => vec![],
     }
 }
-fn action_edge_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_as_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TYPE => Vec::from(&[Shift(State::TYPES13)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS29)]),
         _ => vec![],
     }
 }
-fn action_graph_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_importaliasopt_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TYPE => Vec::from(&[Shift(State::TYPES14)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ImportImportName, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ImportImportName, 2usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_importalias_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CREATE => Vec::from(&[Shift(State::CREATES1)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ImportAliasOptP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ImportAliasOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_type_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_importaliasopt_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS16)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ImportImportPath, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ImportImportPath, 2usize)]),
         _ => vec![],
     }
 }
-fn action_type_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS18)]),
+        TK::STOP => Vec::from(&[Reduce(PK::GraphTypeImportsP1, 3usize)]),
+        TK::CREATE => Vec::from(&[Reduce(PK::GraphTypeImportsP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_type_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS21)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS5)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS6)]),
         _ => vec![],
     }
 }
-fn action_createtype_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_edge_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::CreateType1P1, 3usize)]),
-        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateType1P1, 3usize)]),
+        TK::TYPE => Vec::from(&[Shift(State::TYPES31)]),
         _ => vec![],
     }
 }
-fn action_open_paren_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_graph_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS21)]),
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
-        TK::COLON => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
+        TK::TYPE => Vec::from(&[Shift(State::TYPES32)]),
         _ => vec![],
     }
 }
-fn action_nodetype_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_constraint_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::CreateNodeTypeP1, 4usize)]),
-        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateNodeTypeP1, 4usize)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
         _ => vec![],
     }
 }
-fn action_open_paren_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_abstract_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
-        TK::COLON => Vec::from(&[Shift(State::COLONS26)]),
+        TK::NODE => Vec::from(&[Reduce(PK::ABSTRACTOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_edgetype_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_abstractopt_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::CreateEdgeTypeP1, 4usize)]),
-        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateEdgeTypeP1, 4usize)]),
+        TK::NODE => Vec::from(&[Shift(State::NODES35)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CREATE => Vec::from(&[Shift(State::CREATES9)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ImportAliasP1, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ImportAliasP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_import_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::Import1P1, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::Import1P1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_type_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS37)]),
         _ => vec![],
     }
 }
-fn action_endpointtype_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_type_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_ARROW => Vec::from(&[Shift(State::OPEN_ARROWS30)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
         _ => vec![],
     }
 }
-fn action_identifier_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_type_name_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::FOR => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::COLON => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::STRICT => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         TK::LOOSE => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::EXTENDS => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::FOR => Vec::from(&[Shift(State::FORS42)]),
+        _ => vec![],
+    }
+}
+fn action_node_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TYPE => Vec::from(&[Shift(State::TYPES43)]),
+        _ => vec![],
+    }
+}
+fn action_createtype_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CreateType1P1, 3usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateType1P1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_graphtype_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
+        TK::COLON => Vec::from(&[Shift(State::COLONS44)]),
+        TK::AT => Vec::from(&[Shift(State::ATS45)]),
+        _ => vec![],
+    }
+}
+fn action_edgetype_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CreateEdgeTypeP1, 4usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateEdgeTypeP1, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_endpointtype_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_ARROW => Vec::from(&[Shift(State::OPEN_ARROWS49)]),
+        _ => vec![],
+    }
+}
+fn action_graphtype_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CreateGraphTypeP1, 4usize)]),
         TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateGraphTypeP1, 4usize)]),
         _ => vec![],
     }
 }
-fn action_typename_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typename_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::GraphTypeModeOptP2, 0usize)]),
-        TK::STRICT => Vec::from(&[Shift(State::STRICTS31)]),
-        TK::LOOSE => Vec::from(&[Shift(State::LOOSES32)]),
+        TK::STRICT => Vec::from(&[Shift(State::STRICTS50)]),
+        TK::LOOSE => Vec::from(&[Shift(State::LOOSES51)]),
         _ => vec![],
     }
 }
-fn action_typenameopt_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_for_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
-        TK::COLON => Vec::from(&[Shift(State::COLONS26)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS54)]),
         _ => vec![],
     }
 }
-fn action_typename_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_type_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
-        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
-        TK::COLON => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS55)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS57)]),
+        TK::AT => Vec::from(&[Shift(State::ATS58)]),
         _ => vec![],
     }
 }
-fn action_colon_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_at_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS36)]),
-        TK::AT => Vec::from(&[Shift(State::ATS37)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
         _ => vec![],
     }
 }
-fn action_labelpropertyspec_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_labelpropertyspec_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS40)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS62)]),
         _ => vec![],
     }
 }
-fn action_labelspecopt_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_labelspecopt_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertySpecOptP2, 0usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::PropertySpecOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS41)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS63)]),
         _ => vec![],
     }
 }
-fn action_labelspec_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_labelspec_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecOptP1, 1usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::LabelSpecOptP1, 1usize)]),
@@ -1175,46 +1981,63 @@ fn action_labelspec_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_open_arrow_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_arrow_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS21)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
         TK::COLON => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_strict_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strict_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::GraphTypeModeP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_loose_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_loose_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::GraphTypeModeP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_graphtypemodeopt_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_graphtypemodeopt_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS45)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS68)]),
         _ => vec![],
     }
 }
-fn action_graphtypemode_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_graphtypemode_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::GraphTypeModeOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_labelpropertyspec_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::COLON => Vec::from(&[Shift(State::COLONS69)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
+        TK::EXTENDS => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_nodetype_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS46)]),
+        TK::STOP => Vec::from(&[Reduce(PK::CreateNodeTypeP1, 5usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateNodeTypeP1, 5usize)]),
         _ => vec![],
     }
 }
-fn action_identifier_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleLabelSingleLabel, 1usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::SingleLabelSingleLabel, 1usize)]),
@@ -1224,13 +2047,13 @@ fn action_identifier_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_at_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_at_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS47)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
         _ => vec![],
     }
 }
-fn action_labels_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_labels_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecP1, 2usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::LabelSpecP1, 2usize)]),
@@ -1238,17 +2061,23 @@ fn action_labels_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_singlelabel_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlelabel_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Shift(State::BARS48)]),
-        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS49)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS72)]),
+        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS73)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS76)]),
         _ => vec![],
     }
 }
-fn action_close_paren_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::EndpointTypeP1, 3usize)]),
         TK::SEMICOLON => Vec::from(&[Reduce(PK::EndpointTypeP1, 3usize)]),
@@ -1257,53 +2086,73 @@ fn action_close_paren_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_open_curly_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_curly_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS52)]),
-        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS53)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS77)]),
+        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS78)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS79)]),
+        TK::IF => Vec::from(&[Shift(State::IFS80)]),
+        TK::EXCLUSIVE => Vec::from(&[Shift(State::EXCLUSIVES81)]),
         _ => vec![],
     }
 }
-fn action_propertyspecopt_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_propertyspecopt_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelPropertySpecP1, 2usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::LabelPropertySpecP1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_propertyspec_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_propertyspec_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertySpecOptP1, 1usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::PropertySpecOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_typenameopt_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typenameopt_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
         TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
-        TK::COLON => Vec::from(&[Shift(State::COLONS26)]),
+        TK::COLON => Vec::from(&[Shift(State::COLONS44)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
+        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
+        TK::EXTENDS => Vec::from(&[Reduce(PK::TypeNameOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_open_curly_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_curly_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS21)]),
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS57)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS85)]),
         TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_close_paren_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS91)]),
+        _ => vec![],
+    }
+}
+fn action_typenameopt_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::NodeTypeP1, 4usize)]),
-        TK::SEMICOLON => Vec::from(&[Reduce(PK::NodeTypeP1, 4usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::NodeTypeP1, 4usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::ExtendsOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::ExtendsOptP2, 0usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::ExtendsOptP2, 0usize)]),
+        TK::EXTENDS => Vec::from(&[Shift(State::EXTENDSS92)]),
         _ => vec![],
     }
 }
-fn action_identifier_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typename_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleLabelTypeName, 2usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::SingleLabelTypeName, 2usize)]),
@@ -1313,21 +2162,21 @@ fn action_identifier_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_bar_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_bar_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS36)]),
-        TK::AT => Vec::from(&[Shift(State::ATS37)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS57)]),
+        TK::AT => Vec::from(&[Shift(State::ATS58)]),
         _ => vec![],
     }
 }
-fn action_ampersand_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ampersand_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS36)]),
-        TK::AT => Vec::from(&[Shift(State::ATS37)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS57)]),
+        TK::AT => Vec::from(&[Shift(State::ATS58)]),
         _ => vec![],
     }
 }
-fn action_morelabelsopt_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_morelabelsopt_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelsP1, 2usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::LabelsP1, 2usize)]),
@@ -1335,7 +2184,7 @@ fn action_morelabelsopt_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind
         _ => vec![],
     }
 }
-fn action_morelabels_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_morelabels_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOptP1, 1usize)]),
         TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOptP1, 1usize)]),
@@ -1343,2247 +2192,8968 @@ fn action_morelabels_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_open_paren_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::EndpointTypeEndpointRef, 4usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::EndpointTypeEndpointRef, 4usize)]),
+        TK::OPEN_ARROW => Vec::from(&[Reduce(PK::EndpointTypeEndpointRef, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::EndpointTypeEndpointRef, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS52)]),
-        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS53)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS77)]),
+        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS78)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS79)]),
+        TK::IF => Vec::from(&[Shift(State::IFS80)]),
+        TK::EXCLUSIVE => Vec::from(&[Shift(State::EXCLUSIVES81)]),
         _ => vec![],
     }
 }
-fn action_optional_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_optional_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_properties_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_check_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Shift(State::CLOSE_CURLYS66)]),
-        TK::COMMA => Vec::from(&[Shift(State::COMMAS67)]),
-        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS68)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS99)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS104)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
         _ => vec![],
     }
 }
-fn action_optionalopt_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_if_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS69)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS99)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS104)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
         _ => vec![],
     }
 }
-fn action_labelpropertyspec_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_exclusive_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_ARROW => Vec::from(&[Shift(State::CLOSE_ARROWS72)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS112)]),
         _ => vec![],
     }
 }
-fn action_open_paren_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_properties_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS21)]),
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
-        TK::COLON => Vec::from(&[Shift(State::COLONS26)]),
+        TK::CLOSE_CURLY => Vec::from(&[Shift(State::CLOSE_CURLYS113)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS114)]),
+        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS115)]),
         _ => vec![],
     }
 }
-fn action_nodetype_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_optionalopt_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsP2, 1usize)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
         _ => vec![],
     }
 }
-fn action_edgetype_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_labelpropertyspec_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsP3, 1usize)]),
+        TK::CLOSE_ARROW => Vec::from(&[Shift(State::CLOSE_ARROWS118)]),
         _ => vec![],
     }
 }
-fn action_graphtypeelementsopt_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Shift(State::CLOSE_CURLYS73)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
+        TK::COLON => Vec::from(&[Shift(State::COLONS44)]),
+        TK::AT => Vec::from(&[Shift(State::ATS45)]),
+        TK::EXTENDS => Vec::from(&[Reduce(PK::TypeNameOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_graphtypeelements_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nodetype_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsOptP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_typename_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_edgetype_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_singlelabel_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_graphtypeelementsopt_s88(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
-        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Shift(State::BARS48)]),
-        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS49)]),
+        TK::CLOSE_CURLY => Vec::from(&[Shift(State::CLOSE_CURLYS119)]),
         _ => vec![],
     }
 }
-fn action_singlelabel_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_graphtypeelements_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
-        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Shift(State::BARS48)]),
-        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS49)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_properties_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typename_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS76)]),
-        TK::COMMA => Vec::from(&[Shift(State::COMMAS67)]),
-        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS68)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::GraphTypeElementsP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_close_curly_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
-        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS120)]),
         _ => vec![],
     }
 }
-fn action_comma_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_extends_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS52)]),
-        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS53)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
         _ => vec![],
     }
 }
-fn action_double_bar_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_extendsopt_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS52)]),
-        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS53)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::LabelSpecOptP2, 0usize)]),
+        TK::COLON => Vec::from(&[Shift(State::COLONS44)]),
         _ => vec![],
     }
 }
-fn action_identifier_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_extends_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::COLON => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::ExtendsOptP1, 1usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::ExtendsOptP1, 1usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::ExtendsOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_property_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlelabel_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
+        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS72)]),
+        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS73)]),
         _ => vec![],
     }
 }
-fn action_key_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlelabel_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::COLON => Vec::from(&[Shift(State::COLONS79)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
+        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS72)]),
+        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS73)]),
         _ => vec![],
     }
 }
-fn action_close_arrow_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_properties_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS18)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS126)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS114)]),
+        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS115)]),
         _ => vec![],
     }
 }
-fn action_close_curly_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::GraphTypeP1, 5usize)]),
-        TK::SEMICOLON => Vec::from(&[Reduce(PK::GraphTypeP1, 5usize)]),
+        TK::REFERENCES => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::keyP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_morelabelsopt_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOrLabels, 3usize)]),
-        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOrLabels, 3usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsOrLabels, 3usize)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS99)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS104)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
         _ => vec![],
     }
 }
-fn action_morelabelsopt_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_number_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsAndLabels, 3usize)]),
-        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsAndLabels, 3usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsAndLabels, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
         _ => vec![],
     }
 }
-fn action_close_paren_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_true_s101(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_properties_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_false_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesEachOf, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesEachOf, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesEachOf, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS68)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_properties_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_quoted_string_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
         _ => vec![],
     }
 }
-fn action_colon_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_not_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::INTEGER_NAME => Vec::from(&[Shift(State::INTEGER_NAMES81)]),
-        TK::BOOL_NAME => Vec::from(&[Shift(State::BOOL_NAMES82)]),
-        TK::STRING_NAME => Vec::from(&[Shift(State::STRING_NAMES83)]),
-        TK::DATE_NAME => Vec::from(&[Shift(State::DATE_NAMES84)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS85)]),
-        TK::ANY => Vec::from(&[Shift(State::ANYS86)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS99)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS104)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
         _ => vec![],
     }
 }
-fn action_endpointtype_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_date_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::EdgeTypeP1, 6usize)]),
-        TK::SEMICOLON => Vec::from(&[Reduce(PK::EdgeTypeP1, 6usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::EdgeTypeP1, 6usize)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS129)]),
         _ => vec![],
     }
 }
-fn action_integer_name_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_key_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS89)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::PLUS => Vec::from(&[Shift(State::PLUSS90)]),
-        TK::STAR => Vec::from(&[Shift(State::STARS91)]),
-        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS92)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::OperandPropertyRef, 1usize)]),
         _ => vec![],
     }
 }
-fn action_bool_name_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_recordcond_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS89)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::PLUS => Vec::from(&[Shift(State::PLUSS90)]),
-        TK::STAR => Vec::from(&[Shift(State::STARS91)]),
-        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS92)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesRecordCheck, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesRecordCheck, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesRecordCheck, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesRecordCheck, 2usize)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS130)]),
+        TK::OR => Vec::from(&[Shift(State::ORS131)]),
         _ => vec![],
     }
 }
-fn action_string_name_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_operand_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS89)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::PLUS => Vec::from(&[Shift(State::PLUSS90)]),
-        TK::STAR => Vec::from(&[Shift(State::STARS91)]),
-        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS92)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::GT => Vec::from(&[Shift(State::GTS132)]),
+        TK::LT => Vec::from(&[Shift(State::LTS133)]),
+        TK::GE => Vec::from(&[Shift(State::GES134)]),
+        TK::LE => Vec::from(&[Shift(State::LES135)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS136)]),
+        TK::NOT_EQUALS => Vec::from(&[Shift(State::NOT_EQUALSS137)]),
+        TK::IS => Vec::from(&[Shift(State::ISS138)]),
         _ => vec![],
     }
 }
-fn action_date_name_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singlevalue_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS89)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::PLUS => Vec::from(&[Shift(State::PLUSS90)]),
-        TK::STAR => Vec::from(&[Shift(State::STARS91)]),
-        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS92)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::OperandLiteral, 1usize)]),
         _ => vec![],
     }
 }
-fn action_check_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_bool_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS98)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES99)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES100)]),
-        TK::GT => Vec::from(&[Shift(State::GTS101)]),
-        TK::LT => Vec::from(&[Shift(State::LTS102)]),
-        TK::GE => Vec::from(&[Shift(State::GES103)]),
-        TK::LE => Vec::from(&[Shift(State::LES104)]),
-        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS105)]),
-        TK::REGEX => Vec::from(&[Shift(State::REGEXS106)]),
-        TK::NOT => Vec::from(&[Shift(State::NOTS107)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
         _ => vec![],
     }
 }
-fn action_any_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_recordcond_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS109)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS130)]),
+        TK::OR => Vec::from(&[Shift(State::ORS131)]),
+        TK::THEN => Vec::from(&[Shift(State::THENS140)]),
         _ => vec![],
     }
 }
-fn action_typespec_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
         _ => vec![],
     }
 }
-fn action_simpletype_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_curly_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Shift(State::BARS112)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS113)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::PLUS => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::STAR => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::QUESTION => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::PropertySpecP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_open_curly_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS116)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS77)]),
+        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS78)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS79)]),
+        TK::IF => Vec::from(&[Shift(State::IFS80)]),
+        TK::EXCLUSIVE => Vec::from(&[Shift(State::EXCLUSIVES81)]),
         _ => vec![],
     }
 }
-fn action_plus_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_double_bar_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS77)]),
+        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS78)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS79)]),
+        TK::IF => Vec::from(&[Shift(State::IFS80)]),
+        TK::EXCLUSIVE => Vec::from(&[Shift(State::EXCLUSIVES81)]),
         _ => vec![],
     }
 }
-fn action_star_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_property_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesBaseProperty, 2usize)]),
         _ => vec![],
     }
 }
-fn action_question_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_key_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::COLON => Vec::from(&[Shift(State::COLONS145)]),
         _ => vec![],
     }
 }
-fn action_cardopt_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_arrow_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS109)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS37)]),
         _ => vec![],
     }
 }
-fn action_card_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_curly_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::GraphTypeP1, 5usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::GraphTypeP1, 5usize)]),
         _ => vec![],
     }
 }
-fn action_cardopt_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS109)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::OUTGOING => Vec::from(&[Shift(State::OUTGOINGS147)]),
+        TK::INCOMING => Vec::from(&[Shift(State::INCOMINGS148)]),
+        TK::KEY => Vec::from(&[Shift(State::KEYS149)]),
+        TK::REACHES => Vec::from(&[Shift(State::REACHESS150)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES151)]),
         _ => vec![],
     }
 }
-fn action_cardopt_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typename1_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS109)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::ExtendsP1, 2usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::ExtendsP1, 2usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::ExtendsP1, 2usize)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS155)]),
         _ => vec![],
     }
 }
-fn action_cardopt_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typename_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS109)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeName1P2, 1usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeName1P2, 1usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::TypeName1P2, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::TypeName1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_open_paren_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_labelpropertyspec_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS98)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES99)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES100)]),
-        TK::GT => Vec::from(&[Shift(State::GTS101)]),
-        TK::LT => Vec::from(&[Shift(State::LTS102)]),
-        TK::GE => Vec::from(&[Shift(State::GES103)]),
-        TK::LE => Vec::from(&[Shift(State::LES104)]),
-        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS105)]),
-        TK::REGEX => Vec::from(&[Shift(State::REGEXS106)]),
-        TK::NOT => Vec::from(&[Shift(State::NOTS107)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS156)]),
         _ => vec![],
     }
 }
-fn action_true_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_morelabelsopt_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsOrLabels, 3usize)]),
+        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsOrLabels, 3usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsOrLabels, 3usize)]),
         _ => vec![],
     }
 }
-fn action_false_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_morelabelsopt_s125(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreLabelsAndLabels, 3usize)]),
+        TK::CLOSE_ARROW => Vec::from(&[Reduce(PK::MoreLabelsAndLabels, 3usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::MoreLabelsAndLabels, 3usize)]),
         _ => vec![],
     }
 }
-fn action_gt_s101(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS122)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES123)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES124)]),
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS125)]),
-        TK::DATE => Vec::from(&[Shift(State::DATES126)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesParen, 3usize)]),
         _ => vec![],
     }
 }
-fn action_lt_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_recordcond_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS122)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES123)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES124)]),
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS125)]),
-        TK::DATE => Vec::from(&[Shift(State::DATES126)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS157)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS130)]),
+        TK::OR => Vec::from(&[Shift(State::ORS131)]),
         _ => vec![],
     }
 }
-fn action_ge_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_recordcond_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS122)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES123)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES124)]),
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS125)]),
-        TK::DATE => Vec::from(&[Shift(State::DATES126)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordCondRecordNot, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordCondRecordNot, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordCondRecordNot, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::RecordCondRecordNot, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::RecordCondRecordNot, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::RecordCondRecordNot, 2usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::RecordCondRecordNot, 2usize)]),
         _ => vec![],
     }
 }
-fn action_le_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_quoted_string_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS122)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES123)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES124)]),
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS125)]),
-        TK::DATE => Vec::from(&[Shift(State::DATES126)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::GE => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::LE => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::EQUALS => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::NOT_EQUALS => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::IS => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
         _ => vec![],
     }
 }
-fn action_equals_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_and_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS122)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES123)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES124)]),
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS125)]),
-        TK::DATE => Vec::from(&[Shift(State::DATES126)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS99)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS104)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
         _ => vec![],
     }
 }
-fn action_regex_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_or_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS133)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS99)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS104)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
         _ => vec![],
     }
 }
-fn action_not_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_gt_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS98)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES99)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES100)]),
-        TK::GT => Vec::from(&[Shift(State::GTS101)]),
-        TK::LT => Vec::from(&[Shift(State::LTS102)]),
-        TK::GE => Vec::from(&[Shift(State::GES103)]),
-        TK::LE => Vec::from(&[Shift(State::LES104)]),
-        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS105)]),
-        TK::REGEX => Vec::from(&[Shift(State::REGEXS106)]),
-        TK::NOT => Vec::from(&[Shift(State::NOTS107)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::ComparatorP1, 1usize)]),
+        TK::NUMBER => Vec::from(&[Reduce(PK::ComparatorP1, 1usize)]),
+        TK::TRUE => Vec::from(&[Reduce(PK::ComparatorP1, 1usize)]),
+        TK::FALSE => Vec::from(&[Reduce(PK::ComparatorP1, 1usize)]),
+        TK::QUOTED_STRING => Vec::from(&[Reduce(PK::ComparatorP1, 1usize)]),
+        TK::DATE => Vec::from(&[Reduce(PK::ComparatorP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cond_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_lt_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
-        TK::AND => Vec::from(&[Shift(State::ANDS135)]),
-        TK::OR => Vec::from(&[Shift(State::ORS136)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::ComparatorP3, 1usize)]),
+        TK::NUMBER => Vec::from(&[Reduce(PK::ComparatorP3, 1usize)]),
+        TK::TRUE => Vec::from(&[Reduce(PK::ComparatorP3, 1usize)]),
+        TK::FALSE => Vec::from(&[Reduce(PK::ComparatorP3, 1usize)]),
+        TK::QUOTED_STRING => Vec::from(&[Reduce(PK::ComparatorP3, 1usize)]),
+        TK::DATE => Vec::from(&[Reduce(PK::ComparatorP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_check_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ge_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS98)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES99)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES100)]),
-        TK::GT => Vec::from(&[Shift(State::GTS101)]),
-        TK::LT => Vec::from(&[Shift(State::LTS102)]),
-        TK::GE => Vec::from(&[Shift(State::GES103)]),
-        TK::LE => Vec::from(&[Shift(State::LES104)]),
-        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS105)]),
-        TK::REGEX => Vec::from(&[Shift(State::REGEXS106)]),
-        TK::NOT => Vec::from(&[Shift(State::NOTS107)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::ComparatorP2, 1usize)]),
+        TK::NUMBER => Vec::from(&[Reduce(PK::ComparatorP2, 1usize)]),
+        TK::TRUE => Vec::from(&[Reduce(PK::ComparatorP2, 1usize)]),
+        TK::FALSE => Vec::from(&[Reduce(PK::ComparatorP2, 1usize)]),
+        TK::QUOTED_STRING => Vec::from(&[Reduce(PK::ComparatorP2, 1usize)]),
+        TK::DATE => Vec::from(&[Reduce(PK::ComparatorP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_checkopt_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_le_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::ComparatorP4, 1usize)]),
+        TK::NUMBER => Vec::from(&[Reduce(PK::ComparatorP4, 1usize)]),
+        TK::TRUE => Vec::from(&[Reduce(PK::ComparatorP4, 1usize)]),
+        TK::FALSE => Vec::from(&[Reduce(PK::ComparatorP4, 1usize)]),
+        TK::QUOTED_STRING => Vec::from(&[Reduce(PK::ComparatorP4, 1usize)]),
+        TK::DATE => Vec::from(&[Reduce(PK::ComparatorP4, 1usize)]),
         _ => vec![],
     }
 }
-fn action_check_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_equals_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::ComparatorP5, 1usize)]),
+        TK::NUMBER => Vec::from(&[Reduce(PK::ComparatorP5, 1usize)]),
+        TK::TRUE => Vec::from(&[Reduce(PK::ComparatorP5, 1usize)]),
+        TK::FALSE => Vec::from(&[Reduce(PK::ComparatorP5, 1usize)]),
+        TK::QUOTED_STRING => Vec::from(&[Reduce(PK::ComparatorP5, 1usize)]),
+        TK::DATE => Vec::from(&[Reduce(PK::ComparatorP5, 1usize)]),
         _ => vec![],
     }
 }
-fn action_bar_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_not_equals_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::INTEGER_NAME => Vec::from(&[Shift(State::INTEGER_NAMES81)]),
-        TK::BOOL_NAME => Vec::from(&[Shift(State::BOOL_NAMES82)]),
-        TK::STRING_NAME => Vec::from(&[Shift(State::STRING_NAMES83)]),
-        TK::DATE_NAME => Vec::from(&[Shift(State::DATE_NAMES84)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS85)]),
-        TK::ANY => Vec::from(&[Shift(State::ANYS86)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::ComparatorP6, 1usize)]),
+        TK::NUMBER => Vec::from(&[Reduce(PK::ComparatorP6, 1usize)]),
+        TK::TRUE => Vec::from(&[Reduce(PK::ComparatorP6, 1usize)]),
+        TK::FALSE => Vec::from(&[Reduce(PK::ComparatorP6, 1usize)]),
+        TK::QUOTED_STRING => Vec::from(&[Reduce(PK::ComparatorP6, 1usize)]),
+        TK::DATE => Vec::from(&[Reduce(PK::ComparatorP6, 1usize)]),
         _ => vec![],
     }
 }
-fn action_ampersand_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_is_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::INTEGER_NAME => Vec::from(&[Shift(State::INTEGER_NAMES81)]),
-        TK::BOOL_NAME => Vec::from(&[Shift(State::BOOL_NAMES82)]),
-        TK::STRING_NAME => Vec::from(&[Shift(State::STRING_NAMES83)]),
-        TK::DATE_NAME => Vec::from(&[Shift(State::DATE_NAMES84)]),
-        TK::CHECK => Vec::from(&[Shift(State::CHECKS85)]),
-        TK::ANY => Vec::from(&[Shift(State::ANYS86)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS160)]),
+        TK::NULL => Vec::from(&[Shift(State::NULLS161)]),
         _ => vec![],
     }
 }
-fn action_moretypesopt_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comparator_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
         _ => vec![],
     }
 }
-fn action_moretypes_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_then_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS163)]),
         _ => vec![],
     }
 }
-fn action_number_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_property1_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::COMMA => Vec::from(&[Shift(State::COMMAS140)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS164)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS165)]),
         _ => vec![],
     }
 }
-fn action_checkopt_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_property_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::Property1P2, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::Property1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_checkopt_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_properties_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesEachOf, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesEachOf, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesEachOf, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS115)]),
         _ => vec![],
     }
 }
-fn action_checkopt_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_properties_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesOneOf, 3usize)]),
         _ => vec![],
     }
 }
-fn action_checkopt_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s145(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        TK::INTEGER_NAME => Vec::from(&[Shift(State::INTEGER_NAMES166)]),
+        TK::BOOL_NAME => Vec::from(&[Shift(State::BOOL_NAMES167)]),
+        TK::STRING_NAME => Vec::from(&[Shift(State::STRING_NAMES168)]),
+        TK::DATE_NAME => Vec::from(&[Shift(State::DATE_NAMES169)]),
+        TK::LIST => Vec::from(&[Shift(State::LISTS170)]),
+        TK::ARRAY => Vec::from(&[Shift(State::ARRAYS171)]),
+        TK::SET => Vec::from(&[Shift(State::SETS172)]),
+        TK::RECORD => Vec::from(&[Shift(State::RECORDS173)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS174)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS175)]),
         _ => vec![],
     }
 }
-fn action_cond_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_endpointtype_s146(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS141)]),
-        TK::AND => Vec::from(&[Shift(State::ANDS135)]),
-        TK::OR => Vec::from(&[Shift(State::ORS136)]),
+        TK::STOP => Vec::from(&[Reduce(PK::EdgeTypeP1, 6usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::EdgeTypeP1, 6usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::EdgeTypeP1, 6usize)]),
         _ => vec![],
     }
 }
-fn action_number_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_outgoing_s147(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::SingleValueNumberValue, 1usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::DirectionP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_true_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_incoming_s148(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::BOOLP1, 1usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::DirectionP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_false_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_key_s149(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::BOOLP2, 1usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS179)]),
         _ => vec![],
     }
 }
-fn action_quoted_string_s125(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_reaches_s150(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::SingleValueStringValue, 1usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS180)]),
         _ => vec![],
     }
 }
-fn action_date_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_unique_s151(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS142)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS181)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_constraint_s152(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::CreateConstraintP1, 9usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CreateConstraintP1, 9usize)]),
         _ => vec![],
     }
 }
-fn action_bool_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_direction_s153(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::SingleValueBooleanValue, 1usize)]),
+        TK::COLON => Vec::from(&[Shift(State::COLONS182)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_key_s154(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::REFERENCES => Vec::from(&[Shift(State::REFERENCESS183)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s155(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s156(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::NodeTypeP1, 5usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::NodeTypeP1, 5usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::NodeTypeP1, 5usize)]),
         _ => vec![],
     }
 }
-fn action_singlevalue_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s157(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordCondRecordParen, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordCondRecordParen, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordCondRecordParen, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::RecordCondRecordParen, 3usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::RecordCondRecordParen, 3usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::RecordCondRecordParen, 3usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::RecordCondRecordParen, 3usize)]),
         _ => vec![],
     }
 }
-fn action_quoted_string_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_recordcond_s158(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordCondRecordAnd, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordCondRecordAnd, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordCondRecordAnd, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::RecordCondRecordAnd, 3usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::RecordCondRecordAnd, 3usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::RecordCondRecordAnd, 3usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::RecordCondRecordAnd, 3usize)]),
         _ => vec![],
     }
 }
-fn action_cond_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_recordcond_s159(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordCondRecordOr, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordCondRecordOr, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordCondRecordOr, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::RecordCondRecordOr, 3usize)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS130)]),
+        TK::OR => Vec::from(&[Reduce(PK::RecordCondRecordOr, 3usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::RecordCondRecordOr, 3usize)]),
         _ => vec![],
     }
 }
-fn action_and_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_not_s160(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS98)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES99)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES100)]),
-        TK::GT => Vec::from(&[Shift(State::GTS101)]),
-        TK::LT => Vec::from(&[Shift(State::LTS102)]),
-        TK::GE => Vec::from(&[Shift(State::GES103)]),
-        TK::LE => Vec::from(&[Shift(State::LES104)]),
-        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS105)]),
-        TK::REGEX => Vec::from(&[Shift(State::REGEXS106)]),
-        TK::NOT => Vec::from(&[Shift(State::NOTS107)]),
+        TK::NULL => Vec::from(&[Shift(State::NULLS185)]),
         _ => vec![],
     }
 }
-fn action_or_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_null_s161(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS98)]),
-        TK::TRUE => Vec::from(&[Shift(State::TRUES99)]),
-        TK::FALSE => Vec::from(&[Shift(State::FALSES100)]),
-        TK::GT => Vec::from(&[Shift(State::GTS101)]),
-        TK::LT => Vec::from(&[Shift(State::LTS102)]),
-        TK::GE => Vec::from(&[Shift(State::GES103)]),
-        TK::LE => Vec::from(&[Shift(State::LES104)]),
-        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS105)]),
-        TK::REGEX => Vec::from(&[Shift(State::REGEXS106)]),
-        TK::NOT => Vec::from(&[Shift(State::NOTS107)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordCondIsNull, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordCondIsNull, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordCondIsNull, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::RecordCondIsNull, 3usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::RecordCondIsNull, 3usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::RecordCondIsNull, 3usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::RecordCondIsNull, 3usize)]),
         _ => vec![],
     }
 }
-fn action_cond_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_operand_s162(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
-        TK::AND => Vec::from(&[Shift(State::ANDS135)]),
-        TK::OR => Vec::from(&[Shift(State::ORS136)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordCondComparison, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordCondComparison, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordCondComparison, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::RecordCondComparison, 3usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::RecordCondComparison, 3usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::RecordCondComparison, 3usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::RecordCondComparison, 3usize)]),
         _ => vec![],
     }
 }
-fn action_simpletype_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s163(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Shift(State::BARS112)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS113)]),
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS77)]),
+        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS78)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS79)]),
+        TK::IF => Vec::from(&[Shift(State::IFS80)]),
+        TK::EXCLUSIVE => Vec::from(&[Shift(State::EXCLUSIVES81)]),
         _ => vec![],
     }
 }
-fn action_simpletype_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_close_paren_s164(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::BAR => Vec::from(&[Shift(State::BARS112)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
-        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS113)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesExclusive, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesExclusive, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesExclusive, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesExclusive, 4usize)]),
         _ => vec![],
     }
 }
-fn action_comma_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s165(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STAR => Vec::from(&[Shift(State::STARS147)]),
-        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS148)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
         _ => vec![],
     }
 }
-fn action_close_paren_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_integer_name_s166(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS188)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS189)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS190)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS191)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_quoted_string_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_bool_name_s167(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::SingleValueDateValue, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS188)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS189)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS190)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS191)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_cond_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_string_name_s168(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS188)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS189)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS190)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS191)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_cond_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_date_name_s169(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
-        TK::AND => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
-        TK::OR => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS188)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS189)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS190)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS191)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_moretypesopt_s145(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_list_s170(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::ListNameP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_moretypesopt_s146(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_array_s171(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::ListNameP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_star_s147(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_set_s172(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MaxStar, 1usize)]),
+        TK::LT => Vec::from(&[Reduce(PK::ListNameP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_number_s148(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_record_s173(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MaxP1, 1usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS63)]),
         _ => vec![],
     }
 }
-fn action_max_s149(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_check_s174(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_CURLY => Vec::from(&[Shift(State::CLOSE_CURLYS150)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
         _ => vec![],
     }
 }
-fn action_close_curly_s150(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_any_s175(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
-        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
-        TK::COMMA => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
-        TK::BAR => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
-        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
-        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
-        TK::CHECK => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS217)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_augl_s151(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typespec_s176(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P2, 0usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS152)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS153)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS154)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertyP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_ws_s152(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_simpletype_s177(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS220)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS221)]),
+        TK::GT => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_commentline_s153(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_listname_s178(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
-        TK::NotComment => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::LT => Vec::from(&[Shift(State::LTS224)]),
         _ => vec![],
     }
 }
-fn action_start_comment_s154(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s179(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Shift(State::WSS160)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS153)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS161)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS154)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc0P2, 0usize)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
         _ => vec![],
     }
 }
-fn action_layout_s155(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s180(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Accept]),
+        TK::COLON => Vec::from(&[Shift(State::COLONS227)]),
         _ => vec![],
     }
 }
-fn action_layoutitem1_s156(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_paren_s181(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P1, 1usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS152)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS153)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS154)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
         _ => vec![],
     }
 }
-fn action_layoutitem0_s157(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s182(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS229)]),
         _ => vec![],
     }
 }
-fn action_layoutitem_s158(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_references_s183(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::AT => Vec::from(&[Shift(State::ATS230)]),
         _ => vec![],
     }
 }
-fn action_comment_s159(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typename_s184(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeName1P1, 3usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Reduce(PK::TypeName1P1, 3usize)]),
+        TK::COLON => Vec::from(&[Reduce(PK::TypeName1P1, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::TypeName1P1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_ws_s160(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_null_s185(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
-        TK::NotComment => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::RecordCondIsNotNull, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::RecordCondIsNotNull, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::RecordCondIsNotNull, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::RecordCondIsNotNull, 4usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::RecordCondIsNotNull, 4usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::RecordCondIsNotNull, 4usize)]),
+        TK::THEN => Vec::from(&[Reduce(PK::RecordCondIsNotNull, 4usize)]),
         _ => vec![],
     }
 }
-fn action_notcomment_s161(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_properties_s186(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
-        TK::NotComment => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS231)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS114)]),
+        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS115)]),
         _ => vec![],
     }
 }
-fn action_comment_s162(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_property_s187(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
-        TK::NotComment => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::Property1P1, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::Property1P1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_corncs_s163(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_open_curly_s188(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::END_COMMENT => Vec::from(&[Shift(State::END_COMMENTS168)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS232)]),
         _ => vec![],
     }
 }
-fn action_cornc1_s164(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_plus_s189(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Shift(State::WSS160)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS153)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS161)]),
-        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS154)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc0P1, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOneOrMore, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cornc0_s165(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_star_s190(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncsP1, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardZeroOrMore, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cornc_s166(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_question_s191(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
-        TK::NotComment => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptional, 1usize)]),
         _ => vec![],
     }
 }
-fn action_layoutitem_s167(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cardopt_s192(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS217)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_end_comment_s168(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_card_s193(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
-        TK::NotComment => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cornc_s169(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cardopt_s194(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::WS => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
-        TK::CommentLine => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
-        TK::NotComment => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
-        TK::START_COMMENT => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
-        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS217)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Pgs => State::PgsS2,
-        NonTermKind::CreateType1 => State::CreateType1S3,
-        NonTermKind::CreateType => State::CreateTypeS4,
-        NonTermKind::CreateNodeType => State::CreateNodeTypeS5,
-        NonTermKind::CreateEdgeType => State::CreateEdgeTypeS6,
-        NonTermKind::CreateGraphType => State::CreateGraphTypeS7,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGS0
-            )
-        }
+fn action_cardopt_s195(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS217)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        _ => vec![],
     }
 }
-fn goto_semicolon_s11(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CreateType => State::CreateTypeS15,
-        NonTermKind::CreateNodeType => State::CreateNodeTypeS5,
-        NonTermKind::CreateEdgeType => State::CreateEdgeTypeS6,
-        NonTermKind::CreateGraphType => State::CreateGraphTypeS7,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SEMICOLONS11
-            )
-        }
+fn action_cardopt_s196(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS217)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        _ => vec![],
     }
 }
-fn goto_type_s12(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::NodeType => State::NodeTypeS17,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TYPES12
-            )
-        }
+fn action_propertyspec_s197(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS188)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS189)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS190)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS191)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        _ => vec![],
     }
 }
-fn goto_type_s13(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::EdgeType => State::EdgeTypeS19,
-        NonTermKind::EndpointType => State::EndpointTypeS20,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TYPES13
-            )
-        }
+fn action_open_paren_s198(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
     }
 }
-fn goto_type_s14(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::GraphType => State::GraphTypeS22,
-        NonTermKind::TypeName => State::TypeNameS23,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TYPES14
-            )
-        }
+fn action_true_s199(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondP1, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_open_paren_s16(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::TypeNameOpt => State::TypeNameOptS24,
-        NonTermKind::TypeName => State::TypeNameS25,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_PARENS16
-            )
-        }
+fn action_false_s200(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondP2, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_open_paren_s18(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS27,
-        NonTermKind::LabelSpecOpt => State::LabelSpecOptS28,
-        NonTermKind::LabelSpec => State::LabelSpecS29,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_PARENS18
-            )
-        }
+fn action_gt_s201(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
+        _ => vec![],
     }
 }
-fn goto_typename_s23(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::GraphTypeModeOpt => State::GraphTypeModeOptS33,
-        NonTermKind::GraphTypeMode => State::GraphTypeModeS34,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TypeNameS23
-            )
-        }
+fn action_lt_s202(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
+        _ => vec![],
     }
 }
-fn goto_typenameopt_s24(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS35,
-        NonTermKind::LabelSpecOpt => State::LabelSpecOptS28,
-        NonTermKind::LabelSpec => State::LabelSpecS29,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TypeNameOptS24
-            )
-        }
+fn action_ge_s203(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
+        _ => vec![],
     }
 }
-fn goto_colon_s26(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Labels => State::LabelsS38,
-        NonTermKind::SingleLabel => State::SingleLabelS39,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::COLONS26
-            )
-        }
+fn action_le_s204(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
+        _ => vec![],
     }
 }
-fn goto_labelspecopt_s28(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::PropertySpecOpt => State::PropertySpecOptS42,
-        NonTermKind::PropertySpec => State::PropertySpecS43,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LabelSpecOptS28
-            )
-        }
+fn action_equals_s205(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
+        _ => vec![],
     }
 }
-fn goto_open_arrow_s30(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::TypeNameOpt => State::TypeNameOptS44,
-        NonTermKind::TypeName => State::TypeNameS25,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_ARROWS30
-            )
-        }
+fn action_regex_s206(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS244)]),
+        _ => vec![],
     }
 }
-fn goto_singlelabel_s39(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::MoreLabelsOpt => State::MoreLabelsOptS50,
-        NonTermKind::MoreLabels => State::MoreLabelsS51,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SingleLabelS39
-            )
-        }
+fn action_not_s207(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
     }
 }
-fn goto_open_curly_s41(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Properties => State::PropertiesS54,
-        NonTermKind::OPTIONALOpt => State::OPTIONALOptS55,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_CURLYS41
-            )
-        }
+fn action_any_s208(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS246)]),
+        _ => vec![],
     }
 }
-fn goto_typenameopt_s44(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS56,
-        NonTermKind::LabelSpecOpt => State::LabelSpecOptS28,
-        NonTermKind::LabelSpec => State::LabelSpecS29,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TypeNameOptS44
-            )
-        }
+fn action_unique_s209(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondUnique, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_open_curly_s45(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::NodeType => State::NodeTypeS58,
-        NonTermKind::EdgeType => State::EdgeTypeS59,
-        NonTermKind::GraphTypeElementsOpt => State::GraphTypeElementsOptS60,
-        NonTermKind::GraphTypeElements => State::GraphTypeElementsS61,
-        NonTermKind::TypeName => State::TypeNameS62,
-        NonTermKind::EndpointType => State::EndpointTypeS20,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_CURLYS45
-            )
-        }
+fn action_sum_s210(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS247)]),
+        _ => vec![],
     }
 }
-fn goto_bar_s48(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SingleLabel => State::SingleLabelS63,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::BARS48
-            )
-        }
+fn action_min_s211(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS248)]),
+        _ => vec![],
     }
 }
-fn goto_ampersand_s49(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SingleLabel => State::SingleLabelS64,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AMPERSANDS49
-            )
-        }
+fn action_max_s212(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS249)]),
+        _ => vec![],
     }
 }
-fn goto_open_paren_s52(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Properties => State::PropertiesS65,
-        NonTermKind::OPTIONALOpt => State::OPTIONALOptS55,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_PARENS52
-            )
-        }
+fn action_all_s213(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS250)]),
+        _ => vec![],
     }
 }
-fn goto_optionalopt_s55(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Property => State::PropertyS70,
-        NonTermKind::key => State::keyS71,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPTIONALOptS55
-            )
-        }
+fn action_none_s214(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS251)]),
+        _ => vec![],
     }
 }
-fn goto_open_paren_s57(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::TypeNameOpt => State::TypeNameOptS24,
-        NonTermKind::TypeName => State::TypeNameS25,
-        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS27,
-        NonTermKind::LabelSpecOpt => State::LabelSpecOptS28,
-        NonTermKind::LabelSpec => State::LabelSpecS29,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_PARENS57
-            )
-        }
+fn action_contains_s215(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS100)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES101)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES102)]),
+        TK::QUOTED_STRING => Vec::from(&[Shift(State::QUOTED_STRINGS103)]),
+        TK::DATE => Vec::from(&[Shift(State::DATES105)]),
+        _ => vec![],
     }
 }
-fn goto_singlelabel_s63(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::MoreLabelsOpt => State::MoreLabelsOptS74,
-        NonTermKind::MoreLabels => State::MoreLabelsS51,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SingleLabelS63
-            )
-        }
+fn action_cond_s216(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeCond, 2usize)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
     }
 }
-fn goto_singlelabel_s64(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::MoreLabelsOpt => State::MoreLabelsOptS75,
-        NonTermKind::MoreLabels => State::MoreLabelsS51,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SingleLabelS64
-            )
-        }
+fn action_check_s217(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
     }
 }
-fn goto_comma_s67(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Properties => State::PropertiesS77,
-        NonTermKind::OPTIONALOpt => State::OPTIONALOptS55,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::COMMAS67
-            )
-        }
-    }
+fn action_checkopt_s218(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeAny, 2usize)]),
+        _ => vec![],
+    }
 }
-fn goto_double_bar_s68(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Properties => State::PropertiesS78,
-        NonTermKind::OPTIONALOpt => State::OPTIONALOptS55,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::DOUBLE_BARS68
-            )
-        }
+fn action_check_s219(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckOptP1, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_close_arrow_s72(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::EndpointType => State::EndpointTypeS80,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CLOSE_ARROWS72
-            )
-        }
+fn action_bar_s220(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::INTEGER_NAME => Vec::from(&[Shift(State::INTEGER_NAMES166)]),
+        TK::BOOL_NAME => Vec::from(&[Shift(State::BOOL_NAMES167)]),
+        TK::STRING_NAME => Vec::from(&[Shift(State::STRING_NAMES168)]),
+        TK::DATE_NAME => Vec::from(&[Shift(State::DATE_NAMES169)]),
+        TK::LIST => Vec::from(&[Shift(State::LISTS170)]),
+        TK::ARRAY => Vec::from(&[Shift(State::ARRAYS171)]),
+        TK::SET => Vec::from(&[Shift(State::SETS172)]),
+        TK::RECORD => Vec::from(&[Shift(State::RECORDS173)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS174)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS175)]),
+        _ => vec![],
     }
 }
-fn goto_colon_s79(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::TypeSpec => State::TypeSpecS87,
-        NonTermKind::SimpleType => State::SimpleTypeS88,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::COLONS79
-            )
-        }
+fn action_ampersand_s221(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::INTEGER_NAME => Vec::from(&[Shift(State::INTEGER_NAMES166)]),
+        TK::BOOL_NAME => Vec::from(&[Shift(State::BOOL_NAMES167)]),
+        TK::STRING_NAME => Vec::from(&[Shift(State::STRING_NAMES168)]),
+        TK::DATE_NAME => Vec::from(&[Shift(State::DATE_NAMES169)]),
+        TK::LIST => Vec::from(&[Shift(State::LISTS170)]),
+        TK::ARRAY => Vec::from(&[Shift(State::ARRAYS171)]),
+        TK::SET => Vec::from(&[Shift(State::SETS172)]),
+        TK::RECORD => Vec::from(&[Shift(State::RECORDS173)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS174)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS175)]),
+        _ => vec![],
     }
 }
-fn goto_integer_name_s81(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CardOpt => State::CardOptS93,
-        NonTermKind::Card => State::CardS94,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::INTEGER_NAMES81
-            )
-        }
+fn action_moretypesopt_s222(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::TypeSpecP1, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_bool_name_s82(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CardOpt => State::CardOptS95,
-        NonTermKind::Card => State::CardS94,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::BOOL_NAMES82
-            )
-        }
+fn action_moretypes_s223(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::MoreTypesOptP1, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_string_name_s83(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CardOpt => State::CardOptS96,
-        NonTermKind::Card => State::CardS94,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::STRING_NAMES83
-            )
-        }
+fn action_lt_s224(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::INTEGER_NAME => Vec::from(&[Shift(State::INTEGER_NAMES166)]),
+        TK::BOOL_NAME => Vec::from(&[Shift(State::BOOL_NAMES167)]),
+        TK::STRING_NAME => Vec::from(&[Shift(State::STRING_NAMES168)]),
+        TK::DATE_NAME => Vec::from(&[Shift(State::DATE_NAMES169)]),
+        TK::LIST => Vec::from(&[Shift(State::LISTS170)]),
+        TK::ARRAY => Vec::from(&[Shift(State::ARRAYS171)]),
+        TK::SET => Vec::from(&[Shift(State::SETS172)]),
+        TK::RECORD => Vec::from(&[Shift(State::RECORDS173)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS174)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS175)]),
+        _ => vec![],
     }
 }
-fn goto_date_name_s84(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CardOpt => State::CardOptS97,
-        NonTermKind::Card => State::CardS94,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::DATE_NAMES84
-            )
-        }
+fn action_key1_s225(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS259)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS260)]),
+        _ => vec![],
     }
 }
-fn goto_check_s85(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Cond => State::CondS108,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CHECKS85
-            )
-        }
+fn action_key_s226(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::key1P2, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::key1P2, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_any_s86(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CheckOpt => State::CheckOptS110,
-        NonTermKind::Check => State::CheckS111,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ANYS86
-            )
-        }
+fn action_colon_s227(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS261)]),
+        _ => vec![],
     }
 }
-fn goto_simpletype_s88(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::MoreTypesOpt => State::MoreTypesOptS114,
-        NonTermKind::MoreTypes => State::MoreTypesS115,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SimpleTypeS88
-            )
-        }
+fn action_key1_s228(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS262)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS260)]),
+        _ => vec![],
     }
 }
-fn goto_cardopt_s93(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CheckOpt => State::CheckOptS117,
-        NonTermKind::Check => State::CheckS111,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CardOptS93
-            )
-        }
+fn action_identifier_s229(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS188)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS189)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS190)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS191)]),
+        _ => vec![],
     }
 }
-fn goto_cardopt_s95(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CheckOpt => State::CheckOptS118,
-        NonTermKind::Check => State::CheckS111,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CardOptS95
-            )
-        }
+fn action_at_s230(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TYPE_NAME => Vec::from(&[Shift(State::TYPE_NAMES33)]),
+        _ => vec![],
     }
 }
-fn goto_cardopt_s96(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CheckOpt => State::CheckOptS119,
-        NonTermKind::Check => State::CheckS111,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CardOptS96
-            )
-        }
+fn action_close_paren_s231(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::ElsePropertiesOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::ElsePropertiesOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ElsePropertiesOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::ElsePropertiesOptP2, 0usize)]),
+        TK::ELSE => Vec::from(&[Shift(State::ELSES265)]),
+        _ => vec![],
     }
 }
-fn goto_cardopt_s97(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::CheckOpt => State::CheckOptS120,
-        NonTermKind::Check => State::CheckS111,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CardOptS97
-            )
-        }
+fn action_number_s232(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS268)]),
+        _ => vec![],
     }
 }
-fn goto_open_paren_s98(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Cond => State::CondS121,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OPEN_PARENS98
-            )
-        }
+fn action_checkopt_s233(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeInteger, 3usize)]),
+        _ => vec![],
     }
 }
-fn goto_gt_s101(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SingleValue => State::SingleValueS127,
-        NonTermKind::BOOL => State::BOOLS128,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GTS101
-            )
-        }
+fn action_checkopt_s234(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeBool, 3usize)]),
+        _ => vec![],
     }
 }
-fn goto_lt_s102(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SingleValue => State::SingleValueS129,
-        NonTermKind::BOOL => State::BOOLS128,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LTS102
-            )
-        }
+fn action_checkopt_s235(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeStringSpec, 3usize)]),
+        _ => vec![],
     }
 }
-fn goto_ge_s103(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SingleValue => State::SingleValueS130,
-        NonTermKind::BOOL => State::BOOLS128,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GES103
-            )
-        }
+fn action_checkopt_s236(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeDate, 3usize)]),
+        _ => vec![],
     }
 }
-fn goto_le_s104(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SingleValue => State::SingleValueS131,
-        NonTermKind::BOOL => State::BOOLS128,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LES104
-            )
-        }
+fn action_cardopt_s237(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeRecordSpec, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeRecordSpec, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeRecordSpec, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeRecordSpec, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeRecordSpec, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeRecordSpec, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeRecordSpec, 3usize)]),
+        _ => vec![],
     }
 }
-fn goto_equals_s105(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SingleValue => State::SingleValueS132,
-        NonTermKind::BOOL => State::BOOLS128,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EQUALSS105
-            )
-        }
+fn action_cond_s238(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS269)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
     }
 }
-fn goto_not_s107(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Cond => State::CondS134,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::NOTS107
-            )
-        }
+fn action_singlevalue_s239(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondGT, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_check_s109(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Cond => State::CondS137,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CHECKS109
-            )
-        }
+fn action_singlevalue_s240(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondLT, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_bar_s112(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SimpleType => State::SimpleTypeS138,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::BARS112
-            )
-        }
+fn action_singlevalue_s241(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondGE, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_ampersand_s113(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SimpleType => State::SimpleTypeS139,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AMPERSANDS113
-            )
-        }
+fn action_singlevalue_s242(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondLE, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_and_s135(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Cond => State::CondS143,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ANDS135
-            )
-        }
+fn action_singlevalue_s243(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondEQ, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_or_s136(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Cond => State::CondS144,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ORS136
-            )
-        }
+fn action_quoted_string_s244(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondRegex, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_simpletype_s138(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::MoreTypesOpt => State::MoreTypesOptS145,
-        NonTermKind::MoreTypes => State::MoreTypesS115,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SimpleTypeS138
-            )
-        }
+fn action_cond_s245(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondNot, 2usize)]),
+        _ => vec![],
     }
 }
-fn goto_simpletype_s139(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::MoreTypesOpt => State::MoreTypesOptS146,
-        NonTermKind::MoreTypes => State::MoreTypesS115,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SimpleTypeS139
-            )
-        }
+fn action_open_paren_s246(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s247(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s248(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s249(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s250(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s251(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_singlevalue_s252(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondContains, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_and_s253(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_or_s254(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS198)]),
+        TK::TRUE => Vec::from(&[Shift(State::TRUES199)]),
+        TK::FALSE => Vec::from(&[Shift(State::FALSES200)]),
+        TK::GT => Vec::from(&[Shift(State::GTS201)]),
+        TK::LT => Vec::from(&[Shift(State::LTS202)]),
+        TK::GE => Vec::from(&[Shift(State::GES203)]),
+        TK::LE => Vec::from(&[Shift(State::LES204)]),
+        TK::EQUALS => Vec::from(&[Shift(State::EQUALSS205)]),
+        TK::REGEX => Vec::from(&[Shift(State::REGEXS206)]),
+        TK::NOT => Vec::from(&[Shift(State::NOTS207)]),
+        TK::ANY => Vec::from(&[Shift(State::ANYS208)]),
+        TK::UNIQUE => Vec::from(&[Shift(State::UNIQUES209)]),
+        TK::SUM => Vec::from(&[Shift(State::SUMS210)]),
+        TK::MIN => Vec::from(&[Shift(State::MINS211)]),
+        TK::MAX => Vec::from(&[Shift(State::MAXS212)]),
+        TK::ALL => Vec::from(&[Shift(State::ALLS213)]),
+        TK::NONE => Vec::from(&[Shift(State::NONES214)]),
+        TK::CONTAINS => Vec::from(&[Shift(State::CONTAINSS215)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s255(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckP1, 2usize)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
     }
 }
-fn goto_comma_s140(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Max => State::MaxS149,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::COMMAS140
-            )
-        }
+fn action_simpletype_s256(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS220)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS221)]),
+        TK::GT => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        _ => vec![],
     }
 }
-fn goto_augl_s151(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Layout => State::LayoutS155,
-        NonTermKind::LayoutItem1 => State::LayoutItem1S156,
-        NonTermKind::LayoutItem0 => State::LayoutItem0S157,
-        NonTermKind::LayoutItem => State::LayoutItemS158,
-        NonTermKind::Comment => State::CommentS159,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGLS151
-            )
-        }
+fn action_simpletype_s257(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS220)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Shift(State::AMPERSANDS221)]),
+        TK::GT => Vec::from(&[Reduce(PK::MoreTypesOptP2, 0usize)]),
+        _ => vec![],
     }
 }
-fn goto_start_comment_s154(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Comment => State::CommentS162,
-        NonTermKind::Corncs => State::CorncsS163,
-        NonTermKind::Cornc1 => State::Cornc1S164,
-        NonTermKind::Cornc0 => State::Cornc0S165,
-        NonTermKind::Cornc => State::CorncS166,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::START_COMMENTS154
-            )
-        }
+fn action_typespec_s258(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::GT => Vec::from(&[Shift(State::GTS280)]),
+        _ => vec![],
     }
 }
-fn goto_layoutitem1_s156(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::LayoutItem => State::LayoutItemS167,
-        NonTermKind::Comment => State::CommentS159,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LayoutItem1S156
-            )
-        }
+fn action_close_paren_s259(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConstraintNodeKey, 4usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ConstraintNodeKey, 4usize)]),
+        _ => vec![],
     }
 }
-fn goto_cornc1_s164(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Comment => State::CommentS162,
-        NonTermKind::Cornc => State::CorncS169,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Cornc1S164
-            )
-        }
+fn action_comma_s260(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS98)]),
+        _ => vec![],
     }
 }
-fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
-    panic!("Invalid GOTO entry!");
+fn action_identifier_s261(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS282)]),
+        _ => vec![],
+    }
 }
-pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
-    actions: [
-        action_aug_s0,
-        action_create_s1,
-        action_pgs_s2,
-        action_createtype1_s3,
-        action_createtype_s4,
-        action_createnodetype_s5,
-        action_createedgetype_s6,
-        action_creategraphtype_s7,
-        action_node_s8,
-        action_edge_s9,
-        action_graph_s10,
-        action_semicolon_s11,
-        action_type_s12,
-        action_type_s13,
-        action_type_s14,
-        action_createtype_s15,
-        action_open_paren_s16,
-        action_nodetype_s17,
-        action_open_paren_s18,
-        action_edgetype_s19,
-        action_endpointtype_s20,
-        action_identifier_s21,
-        action_graphtype_s22,
-        action_typename_s23,
-        action_typenameopt_s24,
-        action_typename_s25,
-        action_colon_s26,
-        action_labelpropertyspec_s27,
-        action_labelspecopt_s28,
-        action_labelspec_s29,
-        action_open_arrow_s30,
-        action_strict_s31,
-        action_loose_s32,
-        action_graphtypemodeopt_s33,
-        action_graphtypemode_s34,
-        action_labelpropertyspec_s35,
-        action_identifier_s36,
-        action_at_s37,
-        action_labels_s38,
-        action_singlelabel_s39,
-        action_close_paren_s40,
-        action_open_curly_s41,
-        action_propertyspecopt_s42,
-        action_propertyspec_s43,
-        action_typenameopt_s44,
-        action_open_curly_s45,
-        action_close_paren_s46,
-        action_identifier_s47,
-        action_bar_s48,
-        action_ampersand_s49,
-        action_morelabelsopt_s50,
-        action_morelabels_s51,
-        action_open_paren_s52,
-        action_optional_s53,
-        action_properties_s54,
-        action_optionalopt_s55,
-        action_labelpropertyspec_s56,
-        action_open_paren_s57,
-        action_nodetype_s58,
-        action_edgetype_s59,
-        action_graphtypeelementsopt_s60,
-        action_graphtypeelements_s61,
-        action_typename_s62,
-        action_singlelabel_s63,
-        action_singlelabel_s64,
-        action_properties_s65,
-        action_close_curly_s66,
-        action_comma_s67,
-        action_double_bar_s68,
-        action_identifier_s69,
-        action_property_s70,
-        action_key_s71,
-        action_close_arrow_s72,
-        action_close_curly_s73,
-        action_morelabelsopt_s74,
-        action_morelabelsopt_s75,
-        action_close_paren_s76,
-        action_properties_s77,
-        action_properties_s78,
-        action_colon_s79,
-        action_endpointtype_s80,
-        action_integer_name_s81,
-        action_bool_name_s82,
-        action_string_name_s83,
-        action_date_name_s84,
-        action_check_s85,
-        action_any_s86,
-        action_typespec_s87,
-        action_simpletype_s88,
-        action_open_curly_s89,
-        action_plus_s90,
-        action_star_s91,
-        action_question_s92,
-        action_cardopt_s93,
-        action_card_s94,
-        action_cardopt_s95,
-        action_cardopt_s96,
-        action_cardopt_s97,
-        action_open_paren_s98,
-        action_true_s99,
-        action_false_s100,
-        action_gt_s101,
-        action_lt_s102,
-        action_ge_s103,
-        action_le_s104,
-        action_equals_s105,
-        action_regex_s106,
-        action_not_s107,
-        action_cond_s108,
-        action_check_s109,
-        action_checkopt_s110,
-        action_check_s111,
-        action_bar_s112,
-        action_ampersand_s113,
-        action_moretypesopt_s114,
-        action_moretypes_s115,
-        action_number_s116,
-        action_checkopt_s117,
-        action_checkopt_s118,
-        action_checkopt_s119,
-        action_checkopt_s120,
-        action_cond_s121,
-        action_number_s122,
-        action_true_s123,
-        action_false_s124,
-        action_quoted_string_s125,
-        action_date_s126,
-        action_singlevalue_s127,
-        action_bool_s128,
-        action_singlevalue_s129,
-        action_singlevalue_s130,
-        action_singlevalue_s131,
-        action_singlevalue_s132,
-        action_quoted_string_s133,
-        action_cond_s134,
-        action_and_s135,
-        action_or_s136,
-        action_cond_s137,
-        action_simpletype_s138,
-        action_simpletype_s139,
-        action_comma_s140,
-        action_close_paren_s141,
-        action_quoted_string_s142,
-        action_cond_s143,
-        action_cond_s144,
-        action_moretypesopt_s145,
-        action_moretypesopt_s146,
-        action_star_s147,
-        action_number_s148,
-        action_max_s149,
-        action_close_curly_s150,
-        action_augl_s151,
-        action_ws_s152,
-        action_commentline_s153,
-        action_start_comment_s154,
-        action_layout_s155,
-        action_layoutitem1_s156,
-        action_layoutitem0_s157,
-        action_layoutitem_s158,
-        action_comment_s159,
-        action_ws_s160,
-        action_notcomment_s161,
-        action_comment_s162,
-        action_corncs_s163,
-        action_cornc1_s164,
-        action_cornc0_s165,
-        action_cornc_s166,
-        action_layoutitem_s167,
-        action_end_comment_s168,
-        action_cornc_s169,
-    ],
-    gotos: [
-        goto_aug_s0,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_semicolon_s11,
-        goto_type_s12,
-        goto_type_s13,
-        goto_type_s14,
-        goto_invalid,
-        goto_open_paren_s16,
-        goto_invalid,
-        goto_open_paren_s18,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_typename_s23,
-        goto_typenameopt_s24,
-        goto_invalid,
-        goto_colon_s26,
-        goto_invalid,
-        goto_labelspecopt_s28,
-        goto_invalid,
-        goto_open_arrow_s30,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_singlelabel_s39,
-        goto_invalid,
-        goto_open_curly_s41,
-        goto_invalid,
-        goto_invalid,
-        goto_typenameopt_s44,
-        goto_open_curly_s45,
-        goto_invalid,
-        goto_invalid,
-        goto_bar_s48,
-        goto_ampersand_s49,
-        goto_invalid,
-        goto_invalid,
-        goto_open_paren_s52,
-        goto_invalid,
-        goto_invalid,
-        goto_optionalopt_s55,
-        goto_invalid,
-        goto_open_paren_s57,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_singlelabel_s63,
-        goto_singlelabel_s64,
-        goto_invalid,
-        goto_invalid,
-        goto_comma_s67,
-        goto_double_bar_s68,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_close_arrow_s72,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_colon_s79,
-        goto_invalid,
-        goto_integer_name_s81,
-        goto_bool_name_s82,
-        goto_string_name_s83,
-        goto_date_name_s84,
-        goto_check_s85,
-        goto_any_s86,
-        goto_invalid,
-        goto_simpletype_s88,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_cardopt_s93,
-        goto_invalid,
-        goto_cardopt_s95,
-        goto_cardopt_s96,
-        goto_cardopt_s97,
-        goto_open_paren_s98,
-        goto_invalid,
-        goto_invalid,
-        goto_gt_s101,
-        goto_lt_s102,
-        goto_ge_s103,
-        goto_le_s104,
-        goto_equals_s105,
-        goto_invalid,
-        goto_not_s107,
-        goto_invalid,
-        goto_check_s109,
-        goto_invalid,
-        goto_invalid,
-        goto_bar_s112,
-        goto_ampersand_s113,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_and_s135,
-        goto_or_s136,
-        goto_invalid,
-        goto_simpletype_s138,
-        goto_simpletype_s139,
-        goto_comma_s140,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_augl_s151,
-        goto_invalid,
-        goto_invalid,
-        goto_start_comment_s154,
-        goto_invalid,
-        goto_layoutitem1_s156,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_cornc1_s164,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-    ],
-    token_kinds: [
-        [
-            Some((TK::CREATE, true)),
+fn action_close_paren_s262(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConstraintUnique, 4usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ConstraintUnique, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_card_s263(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConstraintDegree, 4usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ConstraintDegree, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s264(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConstraintReference, 4usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ConstraintReference, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_else_s265(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS283)]),
+        _ => vec![],
+    }
+}
+fn action_elsepropertiesopt_s266(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PropertiesIfThen, 7usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::PropertiesIfThen, 7usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::PropertiesIfThen, 7usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::PropertiesIfThen, 7usize)]),
+        _ => vec![],
+    }
+}
+fn action_elseproperties_s267(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::ElsePropertiesOptP1, 1usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::ElsePropertiesOptP1, 1usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ElsePropertiesOptP1, 1usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::ElsePropertiesOptP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s268(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STAR => Vec::from(&[Shift(State::STARS284)]),
+        TK::NUMBER => Vec::from(&[Shift(State::NUMBERS285)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s269(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondParenCond, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s270(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS287)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s271(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS288)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s272(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS289)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s273(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS290)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s274(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS291)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s275(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS292)]),
+        TK::AND => Vec::from(&[Shift(State::ANDS253)]),
+        TK::OR => Vec::from(&[Shift(State::ORS254)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s276(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondAnd, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_cond_s277(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondOR, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_moretypesopt_s278(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::MoreTypesUnionType, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_moretypesopt_s279(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::MoreTypesIntersectionType, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_gt_s280(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::OPEN_CURLY => Vec::from(&[Shift(State::OPEN_CURLYS188)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS189)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS190)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS191)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_key_s281(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::key1P1, 3usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::key1P1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s282(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::VIA => Vec::from(&[Shift(State::VIAS294)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s283(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Reduce(PK::OPTIONALOptP2, 0usize)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS77)]),
+        TK::OPTIONAL => Vec::from(&[Shift(State::OPTIONALS78)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS79)]),
+        TK::IF => Vec::from(&[Shift(State::IFS80)]),
+        TK::EXCLUSIVE => Vec::from(&[Shift(State::EXCLUSIVES81)]),
+        _ => vec![],
+    }
+}
+fn action_star_s284(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MaxStar, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_number_s285(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::MaxP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_max_s286(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_CURLY => Vec::from(&[Shift(State::CLOSE_CURLYS296)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s287(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondAnyValue, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s288(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondSum, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s289(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondMin, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s290(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondMax, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s291(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondAll, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s292(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::AND => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        TK::OR => Vec::from(&[Reduce(PK::CondNoneValue, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_cardopt_s293(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        TK::CHECK => Vec::from(&[Shift(State::CHECKS217)]),
+        TK::GT => Vec::from(&[Reduce(PK::CheckOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_via_s294(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS298)]),
+        TK::CARET => Vec::from(&[Shift(State::CARETS299)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS300)]),
+        _ => vec![],
+    }
+}
+fn action_properties_s295(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS302)]),
+        TK::COMMA => Vec::from(&[Shift(State::COMMAS114)]),
+        TK::DOUBLE_BAR => Vec::from(&[Shift(State::DOUBLE_BARS115)]),
+        _ => vec![],
+    }
+}
+fn action_close_curly_s296(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::CHECK => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::CardRange, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_checkopt_s297(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::SimpleTypeListSpec, 6usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::SimpleTypeListSpec, 6usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::SimpleTypeListSpec, 6usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::SimpleTypeListSpec, 6usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::SimpleTypeListSpec, 6usize)]),
+        TK::AMPERSAND => Vec::from(&[Reduce(PK::SimpleTypeListSpec, 6usize)]),
+        TK::GT => Vec::from(&[Reduce(PK::SimpleTypeListSpec, 6usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s298(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        TK::SLASH => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        TK::PLUS => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        TK::STAR => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        TK::QUESTION => Vec::from(&[Reduce(PK::PathExprPathLabel, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_caret_s299(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS303)]),
+        _ => vec![],
+    }
+}
+fn action_open_paren_s300(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS298)]),
+        TK::CARET => Vec::from(&[Shift(State::CARETS299)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS300)]),
+        _ => vec![],
+    }
+}
+fn action_pathexpr_s301(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConstraintReaches, 7usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::ConstraintReaches, 7usize)]),
+        TK::SLASH => Vec::from(&[Shift(State::SLASHS305)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS306)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS307)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS308)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS309)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s302(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::ElsePropertiesP1, 4usize)]),
+        TK::CLOSE_CURLY => Vec::from(&[Reduce(PK::ElsePropertiesP1, 4usize)]),
+        TK::COMMA => Vec::from(&[Reduce(PK::ElsePropertiesP1, 4usize)]),
+        TK::DOUBLE_BAR => Vec::from(&[Reduce(PK::ElsePropertiesP1, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s303(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        TK::SLASH => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        TK::PLUS => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        TK::STAR => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        TK::QUESTION => Vec::from(&[Reduce(PK::PathExprPathInverse, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_pathexpr_s304(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SLASH => Vec::from(&[Shift(State::SLASHS305)]),
+        TK::CLOSE_PAREN => Vec::from(&[Shift(State::CLOSE_PARENS310)]),
+        TK::BAR => Vec::from(&[Shift(State::BARS306)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS307)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS308)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS309)]),
+        _ => vec![],
+    }
+}
+fn action_slash_s305(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS298)]),
+        TK::CARET => Vec::from(&[Shift(State::CARETS299)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS300)]),
+        _ => vec![],
+    }
+}
+fn action_bar_s306(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IDENTIFIER => Vec::from(&[Shift(State::IDENTIFIERS298)]),
+        TK::CARET => Vec::from(&[Shift(State::CARETS299)]),
+        TK::OPEN_PAREN => Vec::from(&[Shift(State::OPEN_PARENS300)]),
+        _ => vec![],
+    }
+}
+fn action_plus_s307(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        TK::SLASH => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        TK::PLUS => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        TK::STAR => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        TK::QUESTION => Vec::from(&[Reduce(PK::PathExprPathPlus, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_star_s308(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        TK::SLASH => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        TK::PLUS => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        TK::STAR => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        TK::QUESTION => Vec::from(&[Reduce(PK::PathExprPathStar, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_question_s309(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        TK::SLASH => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        TK::PLUS => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        TK::STAR => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        TK::QUESTION => Vec::from(&[Reduce(PK::PathExprPathOptional, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_close_paren_s310(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        TK::SLASH => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        TK::PLUS => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        TK::STAR => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        TK::QUESTION => Vec::from(&[Reduce(PK::PathExprPathParen, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_pathexpr_s311(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathSeq, 3usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathSeq, 3usize)]),
+        TK::SLASH => Vec::from(&[Reduce(PK::PathExprPathSeq, 3usize)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathSeq, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathSeq, 3usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS307)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS308)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS309)]),
+        _ => vec![],
+    }
+}
+fn action_pathexpr_s312(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::PathExprPathAlt, 3usize)]),
+        TK::SEMICOLON => Vec::from(&[Reduce(PK::PathExprPathAlt, 3usize)]),
+        TK::SLASH => Vec::from(&[Shift(State::SLASHS305)]),
+        TK::CLOSE_PAREN => Vec::from(&[Reduce(PK::PathExprPathAlt, 3usize)]),
+        TK::BAR => Vec::from(&[Reduce(PK::PathExprPathAlt, 3usize)]),
+        TK::PLUS => Vec::from(&[Shift(State::PLUSS307)]),
+        TK::STAR => Vec::from(&[Shift(State::STARS308)]),
+        TK::QUESTION => Vec::from(&[Shift(State::QUESTIONS309)]),
+        _ => vec![],
+    }
+}
+fn action_augl_s313(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P2, 0usize)]),
+        TK::WS => Vec::from(&[Shift(State::WSS314)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS315)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS316)]),
+        _ => vec![],
+    }
+}
+fn action_ws_s314(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_commentline_s315(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_start_comment_s316(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WS => Vec::from(&[Shift(State::WSS322)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS315)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS323)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS316)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc0P2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_layout_s317(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Accept]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem1_s318(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P1, 1usize)]),
+        TK::WS => Vec::from(&[Shift(State::WSS314)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS315)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS316)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem0_s319(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem_s320(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_comment_s321(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_ws_s322(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WS => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_notcomment_s323(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WS => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_comment_s324(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WS => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_corncs_s325(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::END_COMMENT => Vec::from(&[Shift(State::END_COMMENTS330)]),
+        _ => vec![],
+    }
+}
+fn action_cornc1_s326(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WS => Vec::from(&[Shift(State::WSS322)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS315)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS323)]),
+        TK::START_COMMENT => Vec::from(&[Shift(State::START_COMMENTS316)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc0P1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_cornc0_s327(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::CorncsP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_cornc_s328(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WS => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem_s329(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_end_comment_s330(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_cornc_s331(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WS => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
+        TK::CommentLine => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
+        TK::START_COMMENT => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
+        TK::END_COMMENT => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Pgs => State::PgsS2,
+        NonTermKind::GraphTypeImportsOpt => State::GraphTypeImportsOptS3,
+        NonTermKind::GraphTypeImports => State::GraphTypeImportsS4,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AUGS0
+            )
+        }
+    }
+}
+fn goto_imports_s1(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Import1 => State::Import1S7,
+        NonTermKind::Import => State::ImportS8,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IMPORTSS1
+            )
+        }
+    }
+}
+fn goto_graphtypeimportsopt_s3(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CreateType1 => State::CreateType1S10,
+        NonTermKind::CreateType0 => State::CreateType0S11,
+        NonTermKind::CreateType => State::CreateTypeS12,
+        NonTermKind::CreateNodeType => State::CreateNodeTypeS13,
+        NonTermKind::CreateEdgeType => State::CreateEdgeTypeS14,
+        NonTermKind::CreateGraphType => State::CreateGraphTypeS15,
+        NonTermKind::CreateConstraint => State::CreateConstraintS16,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GraphTypeImportsOptS3
+            )
+        }
+    }
+}
+fn goto_identifier_s5(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ImportAliasOpt => State::ImportAliasOptS18,
+        NonTermKind::ImportAlias => State::ImportAliasS19,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IDENTIFIERS5
+            )
+        }
+    }
+}
+fn goto_quoted_string_s6(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ImportAliasOpt => State::ImportAliasOptS20,
+        NonTermKind::ImportAlias => State::ImportAliasS19,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::QUOTED_STRINGS6
+            )
+        }
+    }
+}
+fn goto_create_s9(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ABSTRACTOpt => State::ABSTRACTOptS27,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CREATES9
+            )
+        }
+    }
+}
+fn goto_comma_s22(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Import => State::ImportS30,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COMMAS22
+            )
+        }
+    }
+}
+fn goto_constraint_s25(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName => State::TypeNameS34,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CONSTRAINTS25
+            )
+        }
+    }
+}
+fn goto_semicolon_s28(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CreateType => State::CreateTypeS36,
+        NonTermKind::CreateNodeType => State::CreateNodeTypeS13,
+        NonTermKind::CreateEdgeType => State::CreateEdgeTypeS14,
+        NonTermKind::CreateGraphType => State::CreateGraphTypeS15,
+        NonTermKind::CreateConstraint => State::CreateConstraintS16,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SEMICOLONS28
+            )
+        }
+    }
+}
+fn goto_type_s31(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::EdgeType => State::EdgeTypeS38,
+        NonTermKind::EndpointType => State::EndpointTypeS39,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TYPES31
+            )
+        }
+    }
+}
+fn goto_type_s32(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::GraphType => State::GraphTypeS40,
+        NonTermKind::TypeName => State::TypeNameS41,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TYPES32
+            )
+        }
+    }
+}
+fn goto_open_paren_s37(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS46,
+        NonTermKind::LabelSpecOpt => State::LabelSpecOptS47,
+        NonTermKind::LabelSpec => State::LabelSpecS48,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS37
+            )
+        }
+    }
+}
+fn goto_typename_s41(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::GraphTypeModeOpt => State::GraphTypeModeOptS52,
+        NonTermKind::GraphTypeMode => State::GraphTypeModeS53,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TypeNameS41
+            )
+        }
+    }
+}
+fn goto_type_s43(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::NodeType => State::NodeTypeS56,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TYPES43
+            )
+        }
+    }
+}
+fn goto_colon_s44(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Labels => State::LabelsS59,
+        NonTermKind::SingleLabel => State::SingleLabelS60,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COLONS44
+            )
+        }
+    }
+}
+fn goto_at_s45(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName => State::TypeNameS61,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ATS45
+            )
+        }
+    }
+}
+fn goto_labelspecopt_s47(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PropertySpecOpt => State::PropertySpecOptS64,
+        NonTermKind::PropertySpec => State::PropertySpecS65,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LabelSpecOptS47
+            )
+        }
+    }
+}
+fn goto_open_arrow_s49(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeNameOpt => State::TypeNameOptS66,
+        NonTermKind::TypeName => State::TypeNameS67,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_ARROWS49
+            )
+        }
+    }
+}
+fn goto_open_paren_s55(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeNameOpt => State::TypeNameOptS70,
+        NonTermKind::TypeName => State::TypeNameS67,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS55
+            )
+        }
+    }
+}
+fn goto_at_s58(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName => State::TypeNameS71,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ATS58
+            )
+        }
+    }
+}
+fn goto_singlelabel_s60(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::MoreLabelsOpt => State::MoreLabelsOptS74,
+        NonTermKind::MoreLabels => State::MoreLabelsS75,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SingleLabelS60
+            )
+        }
+    }
+}
+fn goto_open_curly_s63(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Properties => State::PropertiesS82,
+        NonTermKind::OPTIONALOpt => State::OPTIONALOptS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_CURLYS63
+            )
+        }
+    }
+}
+fn goto_typenameopt_s66(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS84,
+        NonTermKind::LabelSpecOpt => State::LabelSpecOptS47,
+        NonTermKind::LabelSpec => State::LabelSpecS48,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TypeNameOptS66
+            )
+        }
+    }
+}
+fn goto_open_curly_s68(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::NodeType => State::NodeTypeS86,
+        NonTermKind::EdgeType => State::EdgeTypeS87,
+        NonTermKind::GraphTypeElementsOpt => State::GraphTypeElementsOptS88,
+        NonTermKind::GraphTypeElements => State::GraphTypeElementsS89,
+        NonTermKind::TypeName => State::TypeNameS90,
+        NonTermKind::EndpointType => State::EndpointTypeS39,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_CURLYS68
+            )
+        }
+    }
+}
+fn goto_typenameopt_s70(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ExtendsOpt => State::ExtendsOptS93,
+        NonTermKind::Extends => State::ExtendsS94,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TypeNameOptS70
+            )
+        }
+    }
+}
+fn goto_bar_s72(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleLabel => State::SingleLabelS95,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BARS72
+            )
+        }
+    }
+}
+fn goto_ampersand_s73(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleLabel => State::SingleLabelS96,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AMPERSANDS73
+            )
+        }
+    }
+}
+fn goto_open_paren_s77(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Properties => State::PropertiesS97,
+        NonTermKind::OPTIONALOpt => State::OPTIONALOptS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS77
+            )
+        }
+    }
+}
+fn goto_check_s79(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS106,
+        NonTermKind::RecordCond => State::RecordCondS107,
+        NonTermKind::Operand => State::OperandS108,
+        NonTermKind::SingleValue => State::SingleValueS109,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CHECKS79
+            )
+        }
+    }
+}
+fn goto_if_s80(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS106,
+        NonTermKind::RecordCond => State::RecordCondS111,
+        NonTermKind::Operand => State::OperandS108,
+        NonTermKind::SingleValue => State::SingleValueS109,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IFS80
+            )
+        }
+    }
+}
+fn goto_optionalopt_s83(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Property => State::PropertyS116,
+        NonTermKind::key => State::keyS117,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPTIONALOptS83
+            )
+        }
+    }
+}
+fn goto_open_paren_s85(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeNameOpt => State::TypeNameOptS70,
+        NonTermKind::TypeName => State::TypeNameS67,
+        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS46,
+        NonTermKind::LabelSpecOpt => State::LabelSpecOptS47,
+        NonTermKind::LabelSpec => State::LabelSpecS48,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS85
+            )
+        }
+    }
+}
+fn goto_extends_s92(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName1 => State::TypeName1S121,
+        NonTermKind::TypeName => State::TypeNameS122,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EXTENDSS92
+            )
+        }
+    }
+}
+fn goto_extendsopt_s93(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LabelPropertySpec => State::LabelPropertySpecS123,
+        NonTermKind::LabelSpecOpt => State::LabelSpecOptS47,
+        NonTermKind::LabelSpec => State::LabelSpecS48,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ExtendsOptS93
+            )
+        }
+    }
+}
+fn goto_singlelabel_s95(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::MoreLabelsOpt => State::MoreLabelsOptS124,
+        NonTermKind::MoreLabels => State::MoreLabelsS75,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SingleLabelS95
+            )
+        }
+    }
+}
+fn goto_singlelabel_s96(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::MoreLabelsOpt => State::MoreLabelsOptS125,
+        NonTermKind::MoreLabels => State::MoreLabelsS75,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SingleLabelS96
+            )
+        }
+    }
+}
+fn goto_open_paren_s99(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS106,
+        NonTermKind::RecordCond => State::RecordCondS127,
+        NonTermKind::Operand => State::OperandS108,
+        NonTermKind::SingleValue => State::SingleValueS109,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS99
+            )
+        }
+    }
+}
+fn goto_not_s104(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS106,
+        NonTermKind::RecordCond => State::RecordCondS128,
+        NonTermKind::Operand => State::OperandS108,
+        NonTermKind::SingleValue => State::SingleValueS109,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::NOTS104
+            )
+        }
+    }
+}
+fn goto_operand_s108(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Comparator => State::ComparatorS139,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OperandS108
+            )
+        }
+    }
+}
+fn goto_open_paren_s112(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Property1 => State::Property1S141,
+        NonTermKind::Property => State::PropertyS142,
+        NonTermKind::key => State::keyS117,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS112
+            )
+        }
+    }
+}
+fn goto_comma_s114(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Properties => State::PropertiesS143,
+        NonTermKind::OPTIONALOpt => State::OPTIONALOptS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COMMAS114
+            )
+        }
+    }
+}
+fn goto_double_bar_s115(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Properties => State::PropertiesS144,
+        NonTermKind::OPTIONALOpt => State::OPTIONALOptS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::DOUBLE_BARS115
+            )
+        }
+    }
+}
+fn goto_close_arrow_s118(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::EndpointType => State::EndpointTypeS146,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CLOSE_ARROWS118
+            )
+        }
+    }
+}
+fn goto_close_paren_s120(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Constraint => State::ConstraintS152,
+        NonTermKind::Direction => State::DirectionS153,
+        NonTermKind::key => State::keyS154,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CLOSE_PARENS120
+            )
+        }
+    }
+}
+fn goto_and_s130(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS106,
+        NonTermKind::RecordCond => State::RecordCondS158,
+        NonTermKind::Operand => State::OperandS108,
+        NonTermKind::SingleValue => State::SingleValueS109,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ANDS130
+            )
+        }
+    }
+}
+fn goto_or_s131(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS106,
+        NonTermKind::RecordCond => State::RecordCondS159,
+        NonTermKind::Operand => State::OperandS108,
+        NonTermKind::SingleValue => State::SingleValueS109,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ORS131
+            )
+        }
+    }
+}
+fn goto_comparator_s139(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS106,
+        NonTermKind::Operand => State::OperandS162,
+        NonTermKind::SingleValue => State::SingleValueS109,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ComparatorS139
+            )
+        }
+    }
+}
+fn goto_colon_s145(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeSpec => State::TypeSpecS176,
+        NonTermKind::SimpleType => State::SimpleTypeS177,
+        NonTermKind::ListName => State::ListNameS178,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COLONS145
+            )
+        }
+    }
+}
+fn goto_comma_s155(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName => State::TypeNameS184,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COMMAS155
+            )
+        }
+    }
+}
+fn goto_open_paren_s163(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Properties => State::PropertiesS186,
+        NonTermKind::OPTIONALOpt => State::OPTIONALOptS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS163
+            )
+        }
+    }
+}
+fn goto_comma_s165(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Property => State::PropertyS187,
+        NonTermKind::key => State::keyS117,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COMMAS165
+            )
+        }
+    }
+}
+fn goto_integer_name_s166(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CardOpt => State::CardOptS192,
+        NonTermKind::Card => State::CardS193,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::INTEGER_NAMES166
+            )
+        }
+    }
+}
+fn goto_bool_name_s167(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CardOpt => State::CardOptS194,
+        NonTermKind::Card => State::CardS193,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BOOL_NAMES167
+            )
+        }
+    }
+}
+fn goto_string_name_s168(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CardOpt => State::CardOptS195,
+        NonTermKind::Card => State::CardS193,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::STRING_NAMES168
+            )
+        }
+    }
+}
+fn goto_date_name_s169(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CardOpt => State::CardOptS196,
+        NonTermKind::Card => State::CardS193,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::DATE_NAMES169
+            )
+        }
+    }
+}
+fn goto_record_s173(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PropertySpec => State::PropertySpecS197,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::RECORDS173
+            )
+        }
+    }
+}
+fn goto_check_s174(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS216,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CHECKS174
+            )
+        }
+    }
+}
+fn goto_any_s175(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CheckOpt => State::CheckOptS218,
+        NonTermKind::Check => State::CheckS219,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ANYS175
+            )
+        }
+    }
+}
+fn goto_simpletype_s177(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::MoreTypesOpt => State::MoreTypesOptS222,
+        NonTermKind::MoreTypes => State::MoreTypesS223,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SimpleTypeS177
+            )
+        }
+    }
+}
+fn goto_open_paren_s179(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key1 => State::key1S225,
+        NonTermKind::key => State::keyS226,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS179
+            )
+        }
+    }
+}
+fn goto_open_paren_s181(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key1 => State::key1S228,
+        NonTermKind::key => State::keyS226,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS181
+            )
+        }
+    }
+}
+fn goto_cardopt_s192(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CheckOpt => State::CheckOptS233,
+        NonTermKind::Check => State::CheckS219,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CardOptS192
+            )
+        }
+    }
+}
+fn goto_cardopt_s194(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CheckOpt => State::CheckOptS234,
+        NonTermKind::Check => State::CheckS219,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CardOptS194
+            )
+        }
+    }
+}
+fn goto_cardopt_s195(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CheckOpt => State::CheckOptS235,
+        NonTermKind::Check => State::CheckS219,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CardOptS195
+            )
+        }
+    }
+}
+fn goto_cardopt_s196(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CheckOpt => State::CheckOptS236,
+        NonTermKind::Check => State::CheckS219,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CardOptS196
+            )
+        }
+    }
+}
+fn goto_propertyspec_s197(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CardOpt => State::CardOptS237,
+        NonTermKind::Card => State::CardS193,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PropertySpecS197
+            )
+        }
+    }
+}
+fn goto_open_paren_s198(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS238,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS198
+            )
+        }
+    }
+}
+fn goto_gt_s201(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleValue => State::SingleValueS239,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GTS201
+            )
+        }
+    }
+}
+fn goto_lt_s202(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleValue => State::SingleValueS240,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LTS202
+            )
+        }
+    }
+}
+fn goto_ge_s203(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleValue => State::SingleValueS241,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GES203
+            )
+        }
+    }
+}
+fn goto_le_s204(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleValue => State::SingleValueS242,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LES204
+            )
+        }
+    }
+}
+fn goto_equals_s205(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleValue => State::SingleValueS243,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EQUALSS205
+            )
+        }
+    }
+}
+fn goto_not_s207(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS245,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::NOTS207
+            )
+        }
+    }
+}
+fn goto_contains_s215(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SingleValue => State::SingleValueS252,
+        NonTermKind::BOOL => State::BOOLS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CONTAINSS215
+            )
+        }
+    }
+}
+fn goto_check_s217(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS255,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CHECKS217
+            )
+        }
+    }
+}
+fn goto_bar_s220(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SimpleType => State::SimpleTypeS256,
+        NonTermKind::ListName => State::ListNameS178,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BARS220
+            )
+        }
+    }
+}
+fn goto_ampersand_s221(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::SimpleType => State::SimpleTypeS257,
+        NonTermKind::ListName => State::ListNameS178,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AMPERSANDS221
+            )
+        }
+    }
+}
+fn goto_lt_s224(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeSpec => State::TypeSpecS258,
+        NonTermKind::SimpleType => State::SimpleTypeS177,
+        NonTermKind::ListName => State::ListNameS178,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LTS224
+            )
+        }
+    }
+}
+fn goto_identifier_s229(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Card => State::CardS263,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IDENTIFIERS229
+            )
+        }
+    }
+}
+fn goto_at_s230(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName => State::TypeNameS264,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ATS230
+            )
+        }
+    }
+}
+fn goto_close_paren_s231(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ElsePropertiesOpt => State::ElsePropertiesOptS266,
+        NonTermKind::ElseProperties => State::ElsePropertiesS267,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CLOSE_PARENS231
+            )
+        }
+    }
+}
+fn goto_open_paren_s246(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS270,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS246
+            )
+        }
+    }
+}
+fn goto_open_paren_s247(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS271,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS247
+            )
+        }
+    }
+}
+fn goto_open_paren_s248(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS272,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS248
+            )
+        }
+    }
+}
+fn goto_open_paren_s249(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS273,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS249
+            )
+        }
+    }
+}
+fn goto_open_paren_s250(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS274,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS250
+            )
+        }
+    }
+}
+fn goto_open_paren_s251(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS275,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS251
+            )
+        }
+    }
+}
+fn goto_and_s253(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS276,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ANDS253
+            )
+        }
+    }
+}
+fn goto_or_s254(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Cond => State::CondS277,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ORS254
+            )
+        }
+    }
+}
+fn goto_simpletype_s256(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::MoreTypesOpt => State::MoreTypesOptS278,
+        NonTermKind::MoreTypes => State::MoreTypesS223,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SimpleTypeS256
+            )
+        }
+    }
+}
+fn goto_simpletype_s257(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::MoreTypesOpt => State::MoreTypesOptS279,
+        NonTermKind::MoreTypes => State::MoreTypesS223,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SimpleTypeS257
+            )
+        }
+    }
+}
+fn goto_comma_s260(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::key => State::keyS281,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COMMAS260
+            )
+        }
+    }
+}
+fn goto_comma_s268(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Max => State::MaxS286,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::COMMAS268
+            )
+        }
+    }
+}
+fn goto_gt_s280(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CardOpt => State::CardOptS293,
+        NonTermKind::Card => State::CardS193,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GTS280
+            )
+        }
+    }
+}
+fn goto_open_paren_s283(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Properties => State::PropertiesS295,
+        NonTermKind::OPTIONALOpt => State::OPTIONALOptS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS283
+            )
+        }
+    }
+}
+fn goto_cardopt_s293(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CheckOpt => State::CheckOptS297,
+        NonTermKind::Check => State::CheckS219,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CardOptS293
+            )
+        }
+    }
+}
+fn goto_via_s294(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PathExpr => State::PathExprS301,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::VIAS294
+            )
+        }
+    }
+}
+fn goto_open_paren_s300(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PathExpr => State::PathExprS304,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OPEN_PARENS300
+            )
+        }
+    }
+}
+fn goto_slash_s305(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PathExpr => State::PathExprS311,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SLASHS305
+            )
+        }
+    }
+}
+fn goto_bar_s306(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PathExpr => State::PathExprS312,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BARS306
+            )
+        }
+    }
+}
+fn goto_augl_s313(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Layout => State::LayoutS317,
+        NonTermKind::LayoutItem1 => State::LayoutItem1S318,
+        NonTermKind::LayoutItem0 => State::LayoutItem0S319,
+        NonTermKind::LayoutItem => State::LayoutItemS320,
+        NonTermKind::Comment => State::CommentS321,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AUGLS313
+            )
+        }
+    }
+}
+fn goto_start_comment_s316(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Comment => State::CommentS324,
+        NonTermKind::Corncs => State::CorncsS325,
+        NonTermKind::Cornc1 => State::Cornc1S326,
+        NonTermKind::Cornc0 => State::Cornc0S327,
+        NonTermKind::Cornc => State::CorncS328,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::START_COMMENTS316
+            )
+        }
+    }
+}
+fn goto_layoutitem1_s318(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LayoutItem => State::LayoutItemS329,
+        NonTermKind::Comment => State::CommentS321,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LayoutItem1S318
+            )
+        }
+    }
+}
+fn goto_cornc1_s326(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Comment => State::CommentS324,
+        NonTermKind::Cornc => State::CorncS331,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::Cornc1S326
+            )
+        }
+    }
+}
+fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
+    panic!("Invalid GOTO entry!");
+}
+pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
+    actions: [
+        action_aug_s0,
+        action_imports_s1,
+        action_pgs_s2,
+        action_graphtypeimportsopt_s3,
+        action_graphtypeimports_s4,
+        action_identifier_s5,
+        action_quoted_string_s6,
+        action_import1_s7,
+        action_import_s8,
+        action_create_s9,
+        action_createtype1_s10,
+        action_createtype0_s11,
+        action_createtype_s12,
+        action_createnodetype_s13,
+        action_createedgetype_s14,
+        action_creategraphtype_s15,
+        action_createconstraint_s16,
+        action_as_s17,
+        action_importaliasopt_s18,
+        action_importalias_s19,
+        action_importaliasopt_s20,
+        action_semicolon_s21,
+        action_comma_s22,
+        action_edge_s23,
+        action_graph_s24,
+        action_constraint_s25,
+        action_abstract_s26,
+        action_abstractopt_s27,
+        action_semicolon_s28,
+        action_identifier_s29,
+        action_import_s30,
+        action_type_s31,
+        action_type_s32,
+        action_type_name_s33,
+        action_typename_s34,
+        action_node_s35,
+        action_createtype_s36,
+        action_open_paren_s37,
+        action_edgetype_s38,
+        action_endpointtype_s39,
+        action_graphtype_s40,
+        action_typename_s41,
+        action_for_s42,
+        action_type_s43,
+        action_colon_s44,
+        action_at_s45,
+        action_labelpropertyspec_s46,
+        action_labelspecopt_s47,
+        action_labelspec_s48,
+        action_open_arrow_s49,
+        action_strict_s50,
+        action_loose_s51,
+        action_graphtypemodeopt_s52,
+        action_graphtypemode_s53,
+        action_open_paren_s54,
+        action_open_paren_s55,
+        action_nodetype_s56,
+        action_identifier_s57,
+        action_at_s58,
+        action_labels_s59,
+        action_singlelabel_s60,
+        action_typename_s61,
+        action_close_paren_s62,
+        action_open_curly_s63,
+        action_propertyspecopt_s64,
+        action_propertyspec_s65,
+        action_typenameopt_s66,
+        action_typename_s67,
+        action_open_curly_s68,
+        action_colon_s69,
+        action_typenameopt_s70,
+        action_typename_s71,
+        action_bar_s72,
+        action_ampersand_s73,
+        action_morelabelsopt_s74,
+        action_morelabels_s75,
+        action_close_paren_s76,
+        action_open_paren_s77,
+        action_optional_s78,
+        action_check_s79,
+        action_if_s80,
+        action_exclusive_s81,
+        action_properties_s82,
+        action_optionalopt_s83,
+        action_labelpropertyspec_s84,
+        action_open_paren_s85,
+        action_nodetype_s86,
+        action_edgetype_s87,
+        action_graphtypeelementsopt_s88,
+        action_graphtypeelements_s89,
+        action_typename_s90,
+        action_identifier_s91,
+        action_extends_s92,
+        action_extendsopt_s93,
+        action_extends_s94,
+        action_singlelabel_s95,
+        action_singlelabel_s96,
+        action_properties_s97,
+        action_identifier_s98,
+        action_open_paren_s99,
+        action_number_s100,
+        action_true_s101,
+        action_false_s102,
+        action_quoted_string_s103,
+        action_not_s104,
+        action_date_s105,
+        action_key_s106,
+        action_recordcond_s107,
+        action_operand_s108,
+        action_singlevalue_s109,
+        action_bool_s110,
+        action_recordcond_s111,
+        action_open_paren_s112,
+        action_close_curly_s113,
+        action_comma_s114,
+        action_double_bar_s115,
+        action_property_s116,
+        action_key_s117,
+        action_close_arrow_s118,
+        action_close_curly_s119,
+        action_close_paren_s120,
+        action_typename1_s121,
+        action_typename_s122,
+        action_labelpropertyspec_s123,
+        action_morelabelsopt_s124,
+        action_morelabelsopt_s125,
+        action_close_paren_s126,
+        action_recordcond_s127,
+        action_recordcond_s128,
+        action_quoted_string_s129,
+        action_and_s130,
+        action_or_s131,
+        action_gt_s132,
+        action_lt_s133,
+        action_ge_s134,
+        action_le_s135,
+        action_equals_s136,
+        action_not_equals_s137,
+        action_is_s138,
+        action_comparator_s139,
+        action_then_s140,
+        action_property1_s141,
+        action_property_s142,
+        action_properties_s143,
+        action_properties_s144,
+        action_colon_s145,
+        action_endpointtype_s146,
+        action_outgoing_s147,
+        action_incoming_s148,
+        action_key_s149,
+        action_reaches_s150,
+        action_unique_s151,
+        action_constraint_s152,
+        action_direction_s153,
+        action_key_s154,
+        action_comma_s155,
+        action_close_paren_s156,
+        action_close_paren_s157,
+        action_recordcond_s158,
+        action_recordcond_s159,
+        action_not_s160,
+        action_null_s161,
+        action_operand_s162,
+        action_open_paren_s163,
+        action_close_paren_s164,
+        action_comma_s165,
+        action_integer_name_s166,
+        action_bool_name_s167,
+        action_string_name_s168,
+        action_date_name_s169,
+        action_list_s170,
+        action_array_s171,
+        action_set_s172,
+        action_record_s173,
+        action_check_s174,
+        action_any_s175,
+        action_typespec_s176,
+        action_simpletype_s177,
+        action_listname_s178,
+        action_open_paren_s179,
+        action_open_paren_s180,
+        action_open_paren_s181,
+        action_colon_s182,
+        action_references_s183,
+        action_typename_s184,
+        action_null_s185,
+        action_properties_s186,
+        action_property_s187,
+        action_open_curly_s188,
+        action_plus_s189,
+        action_star_s190,
+        action_question_s191,
+        action_cardopt_s192,
+        action_card_s193,
+        action_cardopt_s194,
+        action_cardopt_s195,
+        action_cardopt_s196,
+        action_propertyspec_s197,
+        action_open_paren_s198,
+        action_true_s199,
+        action_false_s200,
+        action_gt_s201,
+        action_lt_s202,
+        action_ge_s203,
+        action_le_s204,
+        action_equals_s205,
+        action_regex_s206,
+        action_not_s207,
+        action_any_s208,
+        action_unique_s209,
+        action_sum_s210,
+        action_min_s211,
+        action_max_s212,
+        action_all_s213,
+        action_none_s214,
+        action_contains_s215,
+        action_cond_s216,
+        action_check_s217,
+        action_checkopt_s218,
+        action_check_s219,
+        action_bar_s220,
+        action_ampersand_s221,
+        action_moretypesopt_s222,
+        action_moretypes_s223,
+        action_lt_s224,
+        action_key1_s225,
+        action_key_s226,
+        action_colon_s227,
+        action_key1_s228,
+        action_identifier_s229,
+        action_at_s230,
+        action_close_paren_s231,
+        action_number_s232,
+        action_checkopt_s233,
+        action_checkopt_s234,
+        action_checkopt_s235,
+        action_checkopt_s236,
+        action_cardopt_s237,
+        action_cond_s238,
+        action_singlevalue_s239,
+        action_singlevalue_s240,
+        action_singlevalue_s241,
+        action_singlevalue_s242,
+        action_singlevalue_s243,
+        action_quoted_string_s244,
+        action_cond_s245,
+        action_open_paren_s246,
+        action_open_paren_s247,
+        action_open_paren_s248,
+        action_open_paren_s249,
+        action_open_paren_s250,
+        action_open_paren_s251,
+        action_singlevalue_s252,
+        action_and_s253,
+        action_or_s254,
+        action_cond_s255,
+        action_simpletype_s256,
+        action_simpletype_s257,
+        action_typespec_s258,
+        action_close_paren_s259,
+        action_comma_s260,
+        action_identifier_s261,
+        action_close_paren_s262,
+        action_card_s263,
+        action_typename_s264,
+        action_else_s265,
+        action_elsepropertiesopt_s266,
+        action_elseproperties_s267,
+        action_comma_s268,
+        action_close_paren_s269,
+        action_cond_s270,
+        action_cond_s271,
+        action_cond_s272,
+        action_cond_s273,
+        action_cond_s274,
+        action_cond_s275,
+        action_cond_s276,
+        action_cond_s277,
+        action_moretypesopt_s278,
+        action_moretypesopt_s279,
+        action_gt_s280,
+        action_key_s281,
+        action_close_paren_s282,
+        action_open_paren_s283,
+        action_star_s284,
+        action_number_s285,
+        action_max_s286,
+        action_close_paren_s287,
+        action_close_paren_s288,
+        action_close_paren_s289,
+        action_close_paren_s290,
+        action_close_paren_s291,
+        action_close_paren_s292,
+        action_cardopt_s293,
+        action_via_s294,
+        action_properties_s295,
+        action_close_curly_s296,
+        action_checkopt_s297,
+        action_identifier_s298,
+        action_caret_s299,
+        action_open_paren_s300,
+        action_pathexpr_s301,
+        action_close_paren_s302,
+        action_identifier_s303,
+        action_pathexpr_s304,
+        action_slash_s305,
+        action_bar_s306,
+        action_plus_s307,
+        action_star_s308,
+        action_question_s309,
+        action_close_paren_s310,
+        action_pathexpr_s311,
+        action_pathexpr_s312,
+        action_augl_s313,
+        action_ws_s314,
+        action_commentline_s315,
+        action_start_comment_s316,
+        action_layout_s317,
+        action_layoutitem1_s318,
+        action_layoutitem0_s319,
+        action_layoutitem_s320,
+        action_comment_s321,
+        action_ws_s322,
+        action_notcomment_s323,
+        action_comment_s324,
+        action_corncs_s325,
+        action_cornc1_s326,
+        action_cornc0_s327,
+        action_cornc_s328,
+        action_layoutitem_s329,
+        action_end_comment_s330,
+        action_cornc_s331,
+    ],
+    gotos: [
+        goto_aug_s0,
+        goto_imports_s1,
+        goto_invalid,
+        goto_graphtypeimportsopt_s3,
+        goto_invalid,
+        goto_identifier_s5,
+        goto_quoted_string_s6,
+        goto_invalid,
+        goto_invalid,
+        goto_create_s9,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_comma_s22,
+        goto_invalid,
+        goto_invalid,
+        goto_constraint_s25,
+        goto_invalid,
+        goto_invalid,
+        goto_semicolon_s28,
+        goto_invalid,
+        goto_invalid,
+        goto_type_s31,
+        goto_type_s32,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s37,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_typename_s41,
+        goto_invalid,
+        goto_type_s43,
+        goto_colon_s44,
+        goto_at_s45,
+        goto_invalid,
+        goto_labelspecopt_s47,
+        goto_invalid,
+        goto_open_arrow_s49,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s55,
+        goto_invalid,
+        goto_invalid,
+        goto_at_s58,
+        goto_invalid,
+        goto_singlelabel_s60,
+        goto_invalid,
+        goto_invalid,
+        goto_open_curly_s63,
+        goto_invalid,
+        goto_invalid,
+        goto_typenameopt_s66,
+        goto_invalid,
+        goto_open_curly_s68,
+        goto_invalid,
+        goto_typenameopt_s70,
+        goto_invalid,
+        goto_bar_s72,
+        goto_ampersand_s73,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s77,
+        goto_invalid,
+        goto_check_s79,
+        goto_if_s80,
+        goto_invalid,
+        goto_invalid,
+        goto_optionalopt_s83,
+        goto_invalid,
+        goto_open_paren_s85,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_extends_s92,
+        goto_extendsopt_s93,
+        goto_invalid,
+        goto_singlelabel_s95,
+        goto_singlelabel_s96,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s99,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_not_s104,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_operand_s108,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s112,
+        goto_invalid,
+        goto_comma_s114,
+        goto_double_bar_s115,
+        goto_invalid,
+        goto_invalid,
+        goto_close_arrow_s118,
+        goto_invalid,
+        goto_close_paren_s120,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_and_s130,
+        goto_or_s131,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_comparator_s139,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_colon_s145,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_comma_s155,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s163,
+        goto_invalid,
+        goto_comma_s165,
+        goto_integer_name_s166,
+        goto_bool_name_s167,
+        goto_string_name_s168,
+        goto_date_name_s169,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_record_s173,
+        goto_check_s174,
+        goto_any_s175,
+        goto_invalid,
+        goto_simpletype_s177,
+        goto_invalid,
+        goto_open_paren_s179,
+        goto_invalid,
+        goto_open_paren_s181,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_cardopt_s192,
+        goto_invalid,
+        goto_cardopt_s194,
+        goto_cardopt_s195,
+        goto_cardopt_s196,
+        goto_propertyspec_s197,
+        goto_open_paren_s198,
+        goto_invalid,
+        goto_invalid,
+        goto_gt_s201,
+        goto_lt_s202,
+        goto_ge_s203,
+        goto_le_s204,
+        goto_equals_s205,
+        goto_invalid,
+        goto_not_s207,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_contains_s215,
+        goto_invalid,
+        goto_check_s217,
+        goto_invalid,
+        goto_invalid,
+        goto_bar_s220,
+        goto_ampersand_s221,
+        goto_invalid,
+        goto_invalid,
+        goto_lt_s224,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_identifier_s229,
+        goto_at_s230,
+        goto_close_paren_s231,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s246,
+        goto_open_paren_s247,
+        goto_open_paren_s248,
+        goto_open_paren_s249,
+        goto_open_paren_s250,
+        goto_open_paren_s251,
+        goto_invalid,
+        goto_and_s253,
+        goto_or_s254,
+        goto_invalid,
+        goto_simpletype_s256,
+        goto_simpletype_s257,
+        goto_invalid,
+        goto_invalid,
+        goto_comma_s260,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_comma_s268,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_gt_s280,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s283,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_cardopt_s293,
+        goto_via_s294,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_open_paren_s300,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_slash_s305,
+        goto_bar_s306,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_augl_s313,
+        goto_invalid,
+        goto_invalid,
+        goto_start_comment_s316,
+        goto_invalid,
+        goto_layoutitem1_s318,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_cornc1_s326,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+    ],
+    token_kinds: [
+        [
+            Some((TK::STOP, true)),
+            Some((TK::IMPORTS, true)),
+            Some((TK::CREATE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CREATE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CREATE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AS, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AS, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CONSTRAINT, true)),
+            Some((TK::ABSTRACT, true)),
+            Some((TK::GRAPH, true)),
+            Some((TK::NODE, true)),
+            Some((TK::EDGE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CREATE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NODE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NODE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CREATE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SEMICOLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::EXTENDS, true)),
+            Some((TK::STRICT, true)),
+            Some((TK::LOOSE, true)),
+            Some((TK::FOR, true)),
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FOR, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::AT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_ARROW, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STRICT, true)),
+            Some((TK::LOOSE, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AT, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXTENDS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::OPEN_ARROW, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXCLUSIVE, true)),
+            Some((TK::OPTIONAL, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::IF, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXTENDS, true)),
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXTENDS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AT, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AT, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::OPEN_ARROW, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXCLUSIVE, true)),
+            Some((TK::OPTIONAL, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::IF, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXTENDS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::AT, true)),
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::REFERENCES, true)),
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::IS, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXCLUSIVE, true)),
+            Some((TK::OPTIONAL, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::IF, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXCLUSIVE, true)),
+            Some((TK::OPTIONAL, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::IF, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OUTGOING, true)),
+            Some((TK::INCOMING, true)),
+            Some((TK::REACHES, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::KEY, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::NOT_EQUALS, true)),
+            Some((TK::OR, true)),
+            Some((TK::IS, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NULL, true)),
+            Some((TK::NOT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::IDENTIFIER, false)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::INTEGER_NAME, true)),
+            Some((TK::STRING_NAME, true)),
+            Some((TK::RECORD, true)),
+            Some((TK::ARRAY, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::BOOL_NAME, true)),
+            Some((TK::DATE_NAME, true)),
+            Some((TK::LIST, true)),
+            Some((TK::SET, true)),
+            Some((TK::ANY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::REFERENCES, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TYPE_NAME, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NULL, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EXCLUSIVE, true)),
+            Some((TK::OPTIONAL, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::IF, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::LT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::LT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::LT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_CURLY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::LT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::COLON, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::COLON, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::THEN, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NUMBER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CHECK, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3596,9 +11166,16 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::GRAPH, true)),
-            Some((TK::NODE, true)),
-            Some((TK::EDGE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3609,7 +11186,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, false)),
+            Some((TK::FALSE, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::DATE, true)),
+            Some((TK::NUMBER, false)),
+            Some((TK::QUOTED_STRING, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3622,8 +11206,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -3635,8 +11226,111 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::INTEGER_NAME, true)),
+            Some((TK::STRING_NAME, true)),
+            Some((TK::RECORD, true)),
+            Some((TK::ARRAY, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::BOOL_NAME, true)),
+            Some((TK::DATE_NAME, true)),
+            Some((TK::LIST, true)),
+            Some((TK::SET, true)),
+            Some((TK::ANY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::INTEGER_NAME, true)),
+            Some((TK::STRING_NAME, true)),
+            Some((TK::RECORD, true)),
+            Some((TK::ARRAY, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::BOOL_NAME, true)),
+            Some((TK::DATE_NAME, true)),
+            Some((TK::LIST, true)),
+            Some((TK::SET, true)),
+            Some((TK::ANY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -3646,10 +11340,141 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::INTEGER_NAME, true)),
+            Some((TK::STRING_NAME, true)),
+            Some((TK::RECORD, true)),
+            Some((TK::ARRAY, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::BOOL_NAME, true)),
+            Some((TK::DATE_NAME, true)),
+            Some((TK::LIST, true)),
+            Some((TK::SET, true)),
+            Some((TK::ANY, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OPEN_CURLY, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3661,9 +11486,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
-            None,
+            Some((TK::TYPE_NAME, false)),
             None,
             None,
             None,
@@ -3672,10 +11495,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
             None,
             None,
             None,
@@ -3687,7 +11506,11 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::TYPE, true)),
+            Some((TK::ELSE, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
             None,
             None,
             None,
@@ -3698,12 +11521,12 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TYPE, true)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::COMMA, true)),
             None,
             None,
             None,
@@ -3711,9 +11534,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TYPE, true)),
             None,
             None,
             None,
@@ -3726,7 +11546,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CREATE, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3739,7 +11566,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::OPEN_PAREN, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3752,7 +11586,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::OPEN_PAREN, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3765,7 +11606,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::IDENTIFIER, false)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3778,8 +11626,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3791,10 +11646,9 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::COLON, true)),
-            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
@@ -3802,11 +11656,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
-            None,
             None,
             None,
             None,
@@ -3817,9 +11666,16 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::COLON, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3830,8 +11686,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -3843,8 +11706,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::OPEN_ARROW, true)),
-            None,
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -3856,21 +11726,35 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STRICT, true)),
-            Some((TK::LOOSE, true)),
-            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
             Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COLON, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -3882,9 +11766,16 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STRICT, true)),
-            Some((TK::LOOSE, true)),
-            Some((TK::OPEN_CURLY, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3895,9 +11786,16 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::COLON, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3908,10 +11806,137 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::COLON, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3921,8 +11946,55 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::AT, true)),
-            Some((TK::IDENTIFIER, false)),
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::CONTAINS, true)),
+            Some((TK::UNIQUE, true)),
+            Some((TK::FALSE, true)),
+            Some((TK::REGEX, true)),
+            Some((TK::TRUE, true)),
+            Some((TK::NONE, true)),
+            Some((TK::NOT, true)),
+            Some((TK::ANY, true)),
+            Some((TK::SUM, true)),
+            Some((TK::MIN, true)),
+            Some((TK::MAX, true)),
+            Some((TK::ALL, true)),
+            Some((TK::GE, true)),
+            Some((TK::LE, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::GT, true)),
+            Some((TK::LT, true)),
+            Some((TK::EQUALS, true)),
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -3934,7 +12006,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
+            Some((TK::DOUBLE_BAR, true)),
             Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -3947,22 +12026,16 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::DOUBLE_BAR, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
             None,
             None,
             None,
@@ -3973,10 +12046,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::COLON, true)),
-            Some((TK::IDENTIFIER, false)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -3984,9 +12054,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::OPEN_CURLY, true)),
             None,
             None,
             None,
@@ -3999,20 +12066,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::OPEN_CURLY, true)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::OPEN_CURLY, true)),
             None,
             None,
             None,
@@ -4025,10 +12086,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::OPEN_CURLY, true)),
-            None,
-            None,
-            None,
+            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
@@ -4036,9 +12094,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_PAREN, true)),
             None,
             None,
             None,
@@ -4051,20 +12106,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::IDENTIFIER, false)),
+            None,
             None,
             None,
             None,
@@ -4077,36 +12126,17 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::OPEN_ARROW, true)),
-            Some((TK::SEMICOLON, true)),
-            Some((TK::CLOSE_CURLY, true)),
             None,
             None,
             None,
@@ -4116,10 +12146,8 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::OPTIONAL, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::IDENTIFIER, false)),
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
             None,
             None,
             None,
@@ -4127,10 +12155,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
             None,
             None,
             None,
@@ -4142,9 +12166,8 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
             None,
             None,
             None,
@@ -4153,11 +12176,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::COLON, true)),
             None,
             None,
             None,
@@ -4169,8 +12187,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
         ],
         [
             Some((TK::OPEN_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
@@ -4179,11 +12195,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
-            Some((TK::CLOSE_CURLY, true)),
+            None,
             None,
             None,
             None,
@@ -4194,21 +12206,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::DOUBLE_BAR, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
-            None,
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AT, true)),
-            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
@@ -4220,22 +12226,16 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::AT, true)),
-            Some((TK::IDENTIFIER, false)),
-            None,
-            None,
-            None,
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
             None,
             None,
             None,
@@ -4246,9 +12246,8 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
+            Some((TK::STAR, true)),
+            Some((TK::NUMBER, false)),
             None,
             None,
             None,
@@ -4257,11 +12256,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::OPTIONAL, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
@@ -4272,8 +12266,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::IDENTIFIER, false)),
-            None,
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -4285,20 +12286,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            None,
-            None,
-            None,
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
@@ -4311,9 +12306,9 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
-            None,
-            None,
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
             None,
             None,
             None,
@@ -4322,12 +12317,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::COLON, true)),
-            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
@@ -4337,20 +12326,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_CURLY, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_CURLY, true)),
             None,
             None,
             None,
@@ -4363,7 +12346,9 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
             None,
             None,
             None,
@@ -4374,22 +12359,21 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_CURLY, true)),
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_CURLY, true)),
             None,
             None,
             None,
@@ -4402,24 +12386,18 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::AND, true)),
+            Some((TK::OR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
@@ -4428,9 +12406,16 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
+            Some((TK::AND, true)),
             Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
@@ -4441,8 +12426,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
+            Some((TK::AND, true)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::BAR, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -4454,9 +12446,11 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::OPTIONAL, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::IDENTIFIER, false)),
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -4465,22 +12459,21 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::OPTIONAL, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::COMMA, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::COLON, true)),
             None,
             None,
             None,
@@ -4493,11 +12486,18 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
+            Some((TK::CHECK, true)),
             Some((TK::DOUBLE_BAR, true)),
             Some((TK::CLOSE_PAREN, true)),
+            Some((TK::OPEN_CURLY, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
-            None,
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
             None,
             None,
             None,
@@ -4506,20 +12506,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::COLON, true)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::OPEN_PAREN, true)),
             None,
             None,
             None,
@@ -4532,8 +12526,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
+            Some((TK::VIA, true)),
             None,
             None,
             None,
@@ -4543,11 +12536,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
             None,
             None,
             None,
@@ -4558,23 +12546,17 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_ARROW, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            None,
-            None,
-            None,
+            Some((TK::EXCLUSIVE, true)),
+            Some((TK::OPTIONAL, true)),
+            Some((TK::CHECK, true)),
+            Some((TK::IF, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             None,
             None,
             None,
@@ -4584,10 +12566,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             None,
             None,
             None,
@@ -4595,27 +12574,11 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            None,
-            None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::INTEGER_NAME, true)),
-            Some((TK::STRING_NAME, true)),
-            Some((TK::CHECK, true)),
-            Some((TK::BOOL_NAME, true)),
-            Some((TK::DATE_NAME, true)),
-            Some((TK::ANY, true)),
             None,
             None,
             None,
@@ -4623,8 +12586,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::SEMICOLON, true)),
             Some((TK::CLOSE_CURLY, true)),
             None,
             None,
@@ -4634,90 +12595,8 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::PLUS, true)),
-            Some((TK::STAR, true)),
-            Some((TK::QUESTION, true)),
-            Some((TK::AMPERSAND, true)),
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::PLUS, true)),
-            Some((TK::STAR, true)),
-            Some((TK::QUESTION, true)),
-            Some((TK::AMPERSAND, true)),
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::PLUS, true)),
-            Some((TK::STAR, true)),
-            Some((TK::QUESTION, true)),
-            Some((TK::AMPERSAND, true)),
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::OPEN_CURLY, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::PLUS, true)),
-            Some((TK::STAR, true)),
-            Some((TK::QUESTION, true)),
-            Some((TK::AMPERSAND, true)),
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::REGEX, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::NOT, true)),
-            Some((TK::GE, true)),
-            Some((TK::LE, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::GT, true)),
-            Some((TK::LT, true)),
-            Some((TK::EQUALS, true)),
-            None,
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
-            None,
-            None,
             None,
             None,
-        ],
-        [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             None,
             None,
             None,
@@ -4727,20 +12606,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::NUMBER, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -4753,120 +12626,103 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CHECK, true)),
+            Some((TK::AND, true)),
             Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::CHECK, true)),
+            Some((TK::AND, true)),
             Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::CHECK, true)),
+            Some((TK::AND, true)),
             Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::CHECK, true)),
+            Some((TK::AND, true)),
             Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::CHECK, true)),
+            Some((TK::AND, true)),
             Some((TK::DOUBLE_BAR, true)),
+            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::REGEX, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::NOT, true)),
-            Some((TK::GE, true)),
-            Some((TK::LE, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::GT, true)),
-            Some((TK::LT, true)),
-            Some((TK::EQUALS, true)),
             None,
         ],
         [
@@ -4878,29 +12734,30 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::AND, true)),
+            Some((TK::CHECK, true)),
             Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::DATE, true)),
-            Some((TK::NUMBER, false)),
-            Some((TK::QUOTED_STRING, false)),
             None,
             None,
             None,
@@ -4909,37 +12766,18 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::FALSE, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::DATE, true)),
-            Some((TK::NUMBER, false)),
-            Some((TK::QUOTED_STRING, false)),
-            None,
-            None,
-            None,
+            Some((TK::CARET, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::DATE, true)),
-            Some((TK::NUMBER, false)),
-            Some((TK::QUOTED_STRING, false)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::DATE, true)),
-            Some((TK::NUMBER, false)),
-            Some((TK::QUOTED_STRING, false)),
             None,
             None,
             None,
@@ -4948,20 +12786,13 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::FALSE, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::DATE, true)),
-            Some((TK::NUMBER, false)),
-            Some((TK::QUOTED_STRING, false)),
-            None,
-            None,
+            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::COMMA, true)),
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::QUOTED_STRING, false)),
             None,
             None,
             None,
@@ -4972,44 +12803,26 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::REGEX, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::NOT, true)),
-            Some((TK::GE, true)),
-            Some((TK::LE, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::GT, true)),
-            Some((TK::LT, true)),
-            Some((TK::EQUALS, true)),
             None,
         ],
         [
-            Some((TK::AND, true)),
+            Some((TK::STOP, true)),
+            Some((TK::CHECK, true)),
             Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
+            Some((TK::SEMICOLON, true)),
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::REGEX, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::NOT, true)),
-            Some((TK::GE, true)),
-            Some((TK::LE, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::GT, true)),
-            Some((TK::LT, true)),
-            Some((TK::EQUALS, true)),
             None,
         ],
         [
@@ -5019,6 +12832,13 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
             Some((TK::AMPERSAND, true)),
+            Some((TK::GT, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5026,25 +12846,19 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::INTEGER_NAME, true)),
-            Some((TK::STRING_NAME, true)),
-            Some((TK::CHECK, true)),
-            Some((TK::BOOL_NAME, true)),
-            Some((TK::DATE_NAME, true)),
-            Some((TK::ANY, true)),
             None,
             None,
             None,
@@ -5052,23 +12866,17 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::INTEGER_NAME, true)),
-            Some((TK::STRING_NAME, true)),
-            Some((TK::CHECK, true)),
-            Some((TK::BOOL_NAME, true)),
-            Some((TK::DATE_NAME, true)),
-            Some((TK::ANY, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             None,
             None,
             None,
@@ -5078,10 +12886,17 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
+            Some((TK::CARET, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5091,7 +12906,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::COMMA, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
             None,
             None,
             None,
@@ -5108,21 +12930,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             Some((TK::CLOSE_PAREN, true)),
             Some((TK::CLOSE_CURLY, true)),
             Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5130,12 +12946,19 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5143,12 +12966,19 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5156,9 +12986,13 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::CARET, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5167,61 +13001,59 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::CARET, true)),
+            Some((TK::OPEN_PAREN, true)),
+            Some((TK::IDENTIFIER, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
         ],
         [
-            Some((TK::QUOTED_STRING, false)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
+            Some((TK::CLOSE_PAREN, true)),
+            Some((TK::BAR, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
             None,
             None,
             None,
@@ -5234,155 +13066,79 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::REGEX, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::NOT, true)),
-            Some((TK::GE, true)),
-            Some((TK::LE, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::GT, true)),
-            Some((TK::LT, true)),
-            Some((TK::EQUALS, true)),
             None,
-        ],
-        [
-            Some((TK::FALSE, true)),
-            Some((TK::REGEX, true)),
-            Some((TK::TRUE, true)),
-            Some((TK::NOT, true)),
-            Some((TK::GE, true)),
-            Some((TK::LE, true)),
-            Some((TK::OPEN_PAREN, true)),
-            Some((TK::GT, true)),
-            Some((TK::LT, true)),
-            Some((TK::EQUALS, true)),
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
+            Some((TK::STOP, true)),
+            Some((TK::SEMICOLON, true)),
+            Some((TK::SLASH, true)),
             Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
             Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::PLUS, true)),
+            Some((TK::STAR, true)),
+            Some((TK::QUESTION, true)),
+            None,
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
@@ -5390,21 +13146,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::STOP, true)),
+            Some((TK::START_COMMENT, true)),
+            Some((TK::WS, false)),
+            Some((TK::CommentLine, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STAR, true)),
-            Some((TK::NUMBER, false)),
             None,
             None,
             None,
@@ -5416,62 +13166,57 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::STOP, true)),
+            Some((TK::START_COMMENT, true)),
+            Some((TK::WS, false)),
+            Some((TK::CommentLine, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
         ],
         [
-            Some((TK::AND, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::OR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
+            Some((TK::STOP, true)),
+            Some((TK::START_COMMENT, true)),
+            Some((TK::END_COMMENT, true)),
+            Some((TK::WS, false)),
+            Some((TK::CommentLine, false)),
+            Some((TK::NotComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
+            Some((TK::START_COMMENT, true)),
+            Some((TK::END_COMMENT, true)),
+            Some((TK::WS, false)),
+            Some((TK::CommentLine, false)),
+            Some((TK::NotComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5481,10 +13226,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
+            Some((TK::STOP, false)),
             None,
             None,
             None,
@@ -5492,9 +13234,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CLOSE_CURLY, true)),
             None,
             None,
             None,
@@ -5507,7 +13246,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::STOP, true)),
+            Some((TK::START_COMMENT, true)),
+            Some((TK::WS, false)),
+            Some((TK::CommentLine, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5520,7 +13266,10 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::CLOSE_CURLY, true)),
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5531,15 +13280,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::CHECK, true)),
-            Some((TK::DOUBLE_BAR, true)),
-            Some((TK::CLOSE_PAREN, true)),
-            Some((TK::CLOSE_CURLY, true)),
-            Some((TK::COMMA, true)),
-            Some((TK::BAR, true)),
-            Some((TK::AMPERSAND, true)),
             None,
             None,
             None,
@@ -5557,6 +13297,13 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5570,9 +13317,15 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::START_COMMENT, true)),
             Some((TK::END_COMMENT, true)),
             Some((TK::WS, false)),
@@ -5583,6 +13336,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::START_COMMENT, true)),
@@ -5596,12 +13357,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, false)),
-            None,
-            None,
-            None,
             None,
             None,
             None,
@@ -5611,10 +13366,11 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::START_COMMENT, true)),
+            Some((TK::END_COMMENT, true)),
             Some((TK::WS, false)),
             Some((TK::CommentLine, false)),
+            Some((TK::NotComment, false)),
             None,
             None,
             None,
@@ -5622,25 +13378,18 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, false)),
-            None,
             None,
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::END_COMMENT, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::START_COMMENT, true)),
-            Some((TK::WS, false)),
-            Some((TK::CommentLine, false)),
             None,
             None,
             None,
@@ -5648,12 +13397,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::START_COMMENT, true)),
-            Some((TK::WS, false)),
-            Some((TK::CommentLine, false)),
             None,
             None,
             None,
@@ -5674,13 +13417,7 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::START_COMMENT, true)),
-            Some((TK::END_COMMENT, true)),
-            Some((TK::WS, false)),
-            Some((TK::CommentLine, false)),
-            Some((TK::NotComment, false)),
+            None,
             None,
             None,
             None,
@@ -5689,20 +13426,14 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
-            Some((TK::START_COMMENT, true)),
             Some((TK::END_COMMENT, true)),
-            Some((TK::WS, false)),
-            Some((TK::CommentLine, false)),
-            Some((TK::NotComment, false)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::END_COMMENT, true)),
+            None,
             None,
             None,
             None,
@@ -5726,12 +13457,6 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::END_COMMENT, true)),
-            None,
-            None,
-            None,
             None,
             None,
             None,
@@ -5741,23 +13466,17 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
         ],
         [
+            Some((TK::STOP, true)),
             Some((TK::START_COMMENT, true)),
-            Some((TK::END_COMMENT, true)),
             Some((TK::WS, false)),
             Some((TK::CommentLine, false)),
-            Some((TK::NotComment, false)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::START_COMMENT, true)),
-            Some((TK::WS, false)),
-            Some((TK::CommentLine, false)),
+            None,
             None,
             None,
             None,
@@ -5778,6 +13497,13 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::START_COMMENT, true)),
@@ -5791,6 +13517,13 @@ pub(crate) static PARSER_DEFINITION: PgsParserDefinition = PgsParserDefinition {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
     ],
 };
@@ -5802,10 +13535,7 @@ impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for PgsParserDefi
         PARSER_DEFINITION.gotos[state as usize](nonterm)
     }
     fn expected_token_kinds(&self, state: State) -> Vec<(TokenKind, bool)> {
-        PARSER_DEFINITION.token_kinds[state as usize]
-            .iter()
-            .map_while(|t| *t)
-            .collect()
+        PARSER_DEFINITION.token_kinds[state as usize].iter().map_while(|t| *t).collect()
     }
     fn longest_match() -> bool {
         true
@@ -5835,27 +13565,30 @@ pub struct PgsParser<
     >,
 );
 #[allow(dead_code)]
-impl<'i>
-    PgsParser<
-        'i,
-        Input,
-        StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
-        DefaultBuilder,
-    >
-{
+impl<
+    'i,
+> PgsParser<
+    'i,
+    Input,
+    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
+    DefaultBuilder,
+> {
     pub fn new() -> Self {
-        Self(LRParser::new(
-            &PARSER_DEFINITION,
-            State::default(),
-            false,
-            true,
-            StringLexer::new(false, &RECOGNIZERS),
-            DefaultBuilder::new(),
-        ))
+        Self(
+            LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                true,
+                StringLexer::new(false, &RECOGNIZERS),
+                DefaultBuilder::new(),
+            ),
+        )
     }
 }
 #[allow(dead_code)]
-impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind> for PgsParser<'i, I, L, B>
+impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
+for PgsParser<'i, I, L, B>
 where
     I: InputT + ?Sized + Debug,
     L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
@@ -5872,375 +13605,867 @@ where
     ) -> Result<Self::Output> {
         self.0.parse_with_context(context, input)
     }
-    fn parse_file<'a, F: AsRef<std::path::Path>>(&'a mut self, file: F) -> Result<Self::Output>
+    fn parse_file<'a, F: AsRef<std::path::Path>>(
+        &'a mut self,
+        file: F,
+    ) -> Result<Self::Output>
     where
         'a: 'i,
     {
         self.0.parse_file(file)
     }
 }
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Recognizer {
-    Stop,
-    StrMatch(&'static str),
-    RegexMatch(Lazy<Regex>),
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Recognizer {
+    Stop,
+    StrMatch(&'static str),
+    RegexMatch(Lazy<Regex>),
+}
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct TokenRecognizer(TokenKind, Recognizer);
+impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
+    fn recognize(&self, input: &'i str) -> Option<&'i str> {
+        match &self {
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                if input.starts_with(s) {
+                    log!("{}", "recognized".bold().green());
+                    Some(s)
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                let match_str = r.find(input);
+                match match_str {
+                    Some(x) => {
+                        let x_str = x.as_str();
+                        log!("{} '{}'", "recognized".bold().green(), x_str);
+                        Some(x_str)
+                    }
+                    _ => {
+                        log!("{}", "not recognized".red());
+                        None
+                    }
+                }
+            }
+            TokenRecognizer(_, Recognizer::Stop) => {
+                logn!("{} STOP -- ", "    Recognizing".green());
+                if input.is_empty() {
+                    log!("{}", "recognized".bold().green());
+                    Some("")
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+        }
+    }
+}
+pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
+    TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
+    TokenRecognizer(
+        TokenKind::WS,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\s+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::CommentLine,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "//.*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::NotComment,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "((\\*[^/])|[^\\s*/]|/[^\\*])+")).unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::START_COMMENT, Recognizer::StrMatch("/*")),
+    TokenRecognizer(TokenKind::END_COMMENT, Recognizer::StrMatch("*/")),
+    TokenRecognizer(
+        TokenKind::IDENTIFIER,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "[a-zA-Z_][0-9a-zA-Z_]*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::TYPE_NAME,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(
+                        concat!("^", "[a-zA-Z_][0-9a-zA-Z_]*(::[a-zA-Z_][0-9a-zA-Z_]*)*"),
+                    )
+                    .unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::SEMICOLON, Recognizer::StrMatch(";")),
+    TokenRecognizer(TokenKind::CREATE, Recognizer::StrMatch("CREATE")),
+    TokenRecognizer(TokenKind::NODE, Recognizer::StrMatch("NODE")),
+    TokenRecognizer(TokenKind::EDGE, Recognizer::StrMatch("EDGE")),
+    TokenRecognizer(TokenKind::GRAPH, Recognizer::StrMatch("GRAPH")),
+    TokenRecognizer(TokenKind::TYPE, Recognizer::StrMatch("TYPE")),
+    TokenRecognizer(TokenKind::CONSTRAINT, Recognizer::StrMatch("CONSTRAINT")),
+    TokenRecognizer(TokenKind::FOR, Recognizer::StrMatch("FOR")),
+    TokenRecognizer(TokenKind::OUTGOING, Recognizer::StrMatch("OUTGOING")),
+    TokenRecognizer(TokenKind::INCOMING, Recognizer::StrMatch("INCOMING")),
+    TokenRecognizer(TokenKind::KEY, Recognizer::StrMatch("KEY")),
+    TokenRecognizer(TokenKind::REFERENCES, Recognizer::StrMatch("REFERENCES")),
+    TokenRecognizer(TokenKind::REACHES, Recognizer::StrMatch("REACHES")),
+    TokenRecognizer(TokenKind::VIA, Recognizer::StrMatch("VIA")),
+    TokenRecognizer(TokenKind::SLASH, Recognizer::StrMatch("/")),
+    TokenRecognizer(TokenKind::CARET, Recognizer::StrMatch("^")),
+    TokenRecognizer(TokenKind::OPEN_PAREN, Recognizer::StrMatch("(")),
+    TokenRecognizer(TokenKind::CLOSE_PAREN, Recognizer::StrMatch(")")),
+    TokenRecognizer(TokenKind::OPEN_ARROW, Recognizer::StrMatch("-[")),
+    TokenRecognizer(TokenKind::CLOSE_ARROW, Recognizer::StrMatch("]->")),
+    TokenRecognizer(TokenKind::OPEN_CURLY, Recognizer::StrMatch("{")),
+    TokenRecognizer(TokenKind::CLOSE_CURLY, Recognizer::StrMatch("}")),
+    TokenRecognizer(TokenKind::COLON, Recognizer::StrMatch(":")),
+    TokenRecognizer(TokenKind::COMMA, Recognizer::StrMatch(",")),
+    TokenRecognizer(TokenKind::BAR, Recognizer::StrMatch("|")),
+    TokenRecognizer(TokenKind::DOUBLE_BAR, Recognizer::StrMatch("||")),
+    TokenRecognizer(TokenKind::PLUS, Recognizer::StrMatch("+")),
+    TokenRecognizer(TokenKind::STAR, Recognizer::StrMatch("*")),
+    TokenRecognizer(TokenKind::QUESTION, Recognizer::StrMatch("?")),
+    TokenRecognizer(TokenKind::INTEGER_NAME, Recognizer::StrMatch("INTEGER")),
+    TokenRecognizer(TokenKind::BOOL_NAME, Recognizer::StrMatch("BOOL")),
+    TokenRecognizer(TokenKind::STRING_NAME, Recognizer::StrMatch("STRING")),
+    TokenRecognizer(TokenKind::DATE_NAME, Recognizer::StrMatch("DATE")),
+    TokenRecognizer(TokenKind::LIST, Recognizer::StrMatch("LIST")),
+    TokenRecognizer(TokenKind::ARRAY, Recognizer::StrMatch("ARRAY")),
+    TokenRecognizer(TokenKind::SET, Recognizer::StrMatch("SET")),
+    TokenRecognizer(TokenKind::RECORD, Recognizer::StrMatch("RECORD")),
+    TokenRecognizer(
+        TokenKind::NUMBER,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\d+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::OPTIONAL, Recognizer::StrMatch("OPTIONAL")),
+    TokenRecognizer(TokenKind::AMPERSAND, Recognizer::StrMatch("&")),
+    TokenRecognizer(TokenKind::AT, Recognizer::StrMatch("@")),
+    TokenRecognizer(TokenKind::CHECK, Recognizer::StrMatch("CHECK")),
+    TokenRecognizer(TokenKind::TRUE, Recognizer::StrMatch("TRUE")),
+    TokenRecognizer(TokenKind::FALSE, Recognizer::StrMatch("FALSE")),
+    TokenRecognizer(TokenKind::GT, Recognizer::StrMatch(">")),
+    TokenRecognizer(TokenKind::LT, Recognizer::StrMatch("<")),
+    TokenRecognizer(TokenKind::GE, Recognizer::StrMatch(">=")),
+    TokenRecognizer(TokenKind::LE, Recognizer::StrMatch("<=")),
+    TokenRecognizer(TokenKind::EQUALS, Recognizer::StrMatch("=")),
+    TokenRecognizer(TokenKind::NOT_EQUALS, Recognizer::StrMatch("!=")),
+    TokenRecognizer(
+        TokenKind::QUOTED_STRING,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\"((\\\\\")|[^\"])*\"")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::REGEX, Recognizer::StrMatch("REGEX")),
+    TokenRecognizer(TokenKind::AND, Recognizer::StrMatch("AND")),
+    TokenRecognizer(TokenKind::OR, Recognizer::StrMatch("OR")),
+    TokenRecognizer(TokenKind::NOT, Recognizer::StrMatch("NOT")),
+    TokenRecognizer(TokenKind::IS, Recognizer::StrMatch("IS")),
+    TokenRecognizer(TokenKind::NULL, Recognizer::StrMatch("NULL")),
+    TokenRecognizer(TokenKind::IF, Recognizer::StrMatch("IF")),
+    TokenRecognizer(TokenKind::THEN, Recognizer::StrMatch("THEN")),
+    TokenRecognizer(TokenKind::ELSE, Recognizer::StrMatch("ELSE")),
+    TokenRecognizer(TokenKind::EXCLUSIVE, Recognizer::StrMatch("EXCLUSIVE")),
+    TokenRecognizer(TokenKind::ANY, Recognizer::StrMatch("ANY")),
+    TokenRecognizer(TokenKind::UNIQUE, Recognizer::StrMatch("UNIQUE")),
+    TokenRecognizer(TokenKind::SUM, Recognizer::StrMatch("SUM")),
+    TokenRecognizer(TokenKind::MIN, Recognizer::StrMatch("MIN")),
+    TokenRecognizer(TokenKind::MAX, Recognizer::StrMatch("MAX")),
+    TokenRecognizer(TokenKind::ALL, Recognizer::StrMatch("ALL")),
+    TokenRecognizer(TokenKind::NONE, Recognizer::StrMatch("NONE")),
+    TokenRecognizer(TokenKind::CONTAINS, Recognizer::StrMatch("CONTAINS")),
+    TokenRecognizer(TokenKind::DATE, Recognizer::StrMatch("DATE")),
+    TokenRecognizer(TokenKind::STRICT, Recognizer::StrMatch("STRICT")),
+    TokenRecognizer(TokenKind::LOOSE, Recognizer::StrMatch("LOOSE")),
+    TokenRecognizer(TokenKind::ABSTRACT, Recognizer::StrMatch("ABSTRACT")),
+    TokenRecognizer(TokenKind::EXTENDS, Recognizer::StrMatch("EXTENDS")),
+    TokenRecognizer(TokenKind::IMPORTS, Recognizer::StrMatch("IMPORTS")),
+    TokenRecognizer(TokenKind::AS, Recognizer::StrMatch("AS")),
+];
+pub struct DefaultBuilder {
+    res_stack: Vec<Symbol>,
+}
+impl DefaultBuilder {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self { res_stack: vec![] }
+    }
+}
+impl Builder for DefaultBuilder {
+    type Output = pgs_actions::Pgs;
+    fn get_result(&mut self) -> Self::Output {
+        match self.res_stack.pop().unwrap() {
+            Symbol::NonTerminal(NonTerminal::Pgs(r)) => r,
+            _ => panic!("Invalid result on the parse stack!"),
+        }
+    }
 }
-#[allow(dead_code)]
-#[derive(Debug)]
-pub struct TokenRecognizer(TokenKind, Recognizer);
-impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
-    fn recognize(&self, input: &'i str) -> Option<&'i str> {
-        match &self {
-            #[allow(unused_variables)]
-            TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
-                if input.starts_with(s) {
-                    log!("{}", "recognized".bold().green());
-                    Some(s)
-                } else {
-                    log!("{}", "not recognized".red());
-                    None
+impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
+for DefaultBuilder {
+    #![allow(unused_variables)]
+    fn shift_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        token: Token<'i, Input, TokenKind>,
+    ) {
+        let val = match token.kind {
+            TokenKind::STOP => panic!("Cannot shift STOP token!"),
+            TokenKind::IDENTIFIER => {
+                Terminal::IDENTIFIER(pgs_actions::identifier(context, token))
+            }
+            TokenKind::TYPE_NAME => {
+                Terminal::TYPE_NAME(pgs_actions::type_name(context, token))
+            }
+            TokenKind::SEMICOLON => Terminal::SEMICOLON,
+            TokenKind::CREATE => Terminal::CREATE,
+            TokenKind::NODE => Terminal::NODE,
+            TokenKind::EDGE => Terminal::EDGE,
+            TokenKind::GRAPH => Terminal::GRAPH,
+            TokenKind::TYPE => Terminal::TYPE,
+            TokenKind::CONSTRAINT => Terminal::CONSTRAINT,
+            TokenKind::FOR => Terminal::FOR,
+            TokenKind::OUTGOING => Terminal::OUTGOING,
+            TokenKind::INCOMING => Terminal::INCOMING,
+            TokenKind::KEY => Terminal::KEY,
+            TokenKind::REFERENCES => Terminal::REFERENCES,
+            TokenKind::REACHES => Terminal::REACHES,
+            TokenKind::VIA => Terminal::VIA,
+            TokenKind::SLASH => Terminal::SLASH,
+            TokenKind::CARET => Terminal::CARET,
+            TokenKind::OPEN_PAREN => Terminal::OPEN_PAREN,
+            TokenKind::CLOSE_PAREN => Terminal::CLOSE_PAREN,
+            TokenKind::OPEN_ARROW => Terminal::OPEN_ARROW,
+            TokenKind::CLOSE_ARROW => Terminal::CLOSE_ARROW,
+            TokenKind::OPEN_CURLY => Terminal::OPEN_CURLY,
+            TokenKind::CLOSE_CURLY => Terminal::CLOSE_CURLY,
+            TokenKind::COLON => Terminal::COLON,
+            TokenKind::COMMA => Terminal::COMMA,
+            TokenKind::BAR => Terminal::BAR,
+            TokenKind::DOUBLE_BAR => Terminal::DOUBLE_BAR,
+            TokenKind::PLUS => Terminal::PLUS,
+            TokenKind::STAR => Terminal::STAR,
+            TokenKind::QUESTION => Terminal::QUESTION,
+            TokenKind::INTEGER_NAME => Terminal::INTEGER_NAME,
+            TokenKind::BOOL_NAME => Terminal::BOOL_NAME,
+            TokenKind::STRING_NAME => Terminal::STRING_NAME,
+            TokenKind::DATE_NAME => Terminal::DATE_NAME,
+            TokenKind::LIST => Terminal::LIST,
+            TokenKind::ARRAY => Terminal::ARRAY,
+            TokenKind::SET => Terminal::SET,
+            TokenKind::RECORD => Terminal::RECORD,
+            TokenKind::NUMBER => Terminal::NUMBER(pgs_actions::number(context, token)),
+            TokenKind::OPTIONAL => Terminal::OPTIONAL,
+            TokenKind::AMPERSAND => Terminal::AMPERSAND,
+            TokenKind::AT => Terminal::AT,
+            TokenKind::CHECK => Terminal::CHECK,
+            TokenKind::TRUE => Terminal::TRUE,
+            TokenKind::FALSE => Terminal::FALSE,
+            TokenKind::GT => Terminal::GT,
+            TokenKind::LT => Terminal::LT,
+            TokenKind::GE => Terminal::GE,
+            TokenKind::LE => Terminal::LE,
+            TokenKind::EQUALS => Terminal::EQUALS,
+            TokenKind::NOT_EQUALS => Terminal::NOT_EQUALS,
+            TokenKind::QUOTED_STRING => {
+                Terminal::QUOTED_STRING(pgs_actions::quoted_string(context, token))
+            }
+            TokenKind::REGEX => Terminal::REGEX,
+            TokenKind::AND => Terminal::AND,
+            TokenKind::OR => Terminal::OR,
+            TokenKind::NOT => Terminal::NOT,
+            TokenKind::IS => Terminal::IS,
+            TokenKind::NULL => Terminal::NULL,
+            TokenKind::IF => Terminal::IF,
+            TokenKind::THEN => Terminal::THEN,
+            TokenKind::ELSE => Terminal::ELSE,
+            TokenKind::EXCLUSIVE => Terminal::EXCLUSIVE,
+            TokenKind::ANY => Terminal::ANY,
+            TokenKind::UNIQUE => Terminal::UNIQUE,
+            TokenKind::SUM => Terminal::SUM,
+            TokenKind::MIN => Terminal::MIN,
+            TokenKind::MAX => Terminal::MAX,
+            TokenKind::ALL => Terminal::ALL,
+            TokenKind::NONE => Terminal::NONE,
+            TokenKind::CONTAINS => Terminal::CONTAINS,
+            TokenKind::DATE => Terminal::DATE,
+            TokenKind::STRICT => Terminal::STRICT,
+            TokenKind::LOOSE => Terminal::LOOSE,
+            TokenKind::ABSTRACT => Terminal::ABSTRACT,
+            TokenKind::EXTENDS => Terminal::EXTENDS,
+            TokenKind::IMPORTS => Terminal::IMPORTS,
+            TokenKind::AS => Terminal::AS,
+            _ => panic!("Shift of unreachable terminal!"),
+        };
+        self.res_stack.push(Symbol::Terminal(val));
+    }
+    fn reduce_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        prod: ProdKind,
+        prod_len: usize,
+    ) {
+        let prod = match prod {
+            ProdKind::PgsP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::GraphTypeImportsOpt(p0)),
+                        Symbol::NonTerminal(NonTerminal::CreateType0(p1)),
+                    ) => NonTerminal::Pgs(pgs_actions::pgs_c1(context, p0, p1)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GraphTypeImportsOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::GraphTypeImports(p0)) => {
+                        NonTerminal::GraphTypeImportsOpt(
+                            pgs_actions::graph_type_imports_opt_graph_type_imports(
+                                context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GraphTypeImportsOptP2 => {
+                NonTerminal::GraphTypeImportsOpt(
+                    pgs_actions::graph_type_imports_opt_empty(context),
+                )
+            }
+            ProdKind::CreateType1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::CreateType1(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::CreateType(p1)),
+                    ) => {
+                        NonTerminal::CreateType1(
+                            pgs_actions::create_type1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateType1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CreateType(p0)) => {
+                        NonTerminal::CreateType1(
+                            pgs_actions::create_type1_create_type(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateType0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CreateType1(p0)) => {
+                        NonTerminal::CreateType0(
+                            pgs_actions::create_type0_create_type1(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateType0P2 => {
+                NonTerminal::CreateType0(pgs_actions::create_type0_empty(context))
+            }
+            ProdKind::CreateTypeP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CreateNodeType(p0)) => {
+                        NonTerminal::CreateType(
+                            pgs_actions::create_type_create_node_type(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateTypeP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CreateEdgeType(p0)) => {
+                        NonTerminal::CreateType(
+                            pgs_actions::create_type_create_edge_type(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateTypeP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CreateGraphType(p0)) => {
+                        NonTerminal::CreateType(
+                            pgs_actions::create_type_create_graph_type(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateTypeP4 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CreateConstraint(p0)) => {
+                        NonTerminal::CreateType(
+                            pgs_actions::create_type_create_constraint(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateNodeTypeP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        Symbol::NonTerminal(NonTerminal::ABSTRACTOpt(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::NodeType(p1)),
+                    ) => {
+                        NonTerminal::CreateNodeType(
+                            pgs_actions::create_node_type_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ABSTRACTOptP1 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::ABSTRACTOpt(pgs_actions::abstractopt_abstract(context))
+            }
+            ProdKind::ABSTRACTOptP2 => {
+                NonTerminal::ABSTRACTOpt(pgs_actions::abstractopt_empty(context))
+            }
+            ProdKind::CreateEdgeTypeP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, _, Symbol::NonTerminal(NonTerminal::EdgeType(p0))) => {
+                        NonTerminal::CreateEdgeType(
+                            pgs_actions::create_edge_type_edge_type(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CreateGraphTypeP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, _, Symbol::NonTerminal(NonTerminal::GraphType(p0))) => {
+                        NonTerminal::CreateGraphType(
+                            pgs_actions::create_graph_type_graph_type(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            #[allow(unused_variables)]
-            TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
-                let match_str = r.find(input);
-                match match_str {
-                    Some(x) => {
-                        let x_str = x.as_str();
-                        log!("{} '{}'", "recognized".bold().green(), x_str);
-                        Some(x_str)
+            ProdKind::CreateConstraintP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 9usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeName(p0)),
+                        _,
+                        _,
+                        _,
+                        Symbol::Terminal(Terminal::IDENTIFIER(p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Constraint(p2)),
+                    ) => {
+                        NonTerminal::CreateConstraint(
+                            pgs_actions::create_constraint_c1(context, p0, p1, p2),
+                        )
                     }
-                    _ => {
-                        log!("{}", "not recognized".red());
-                        None
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ConstraintDegree => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Direction(p0)),
+                        _,
+                        Symbol::Terminal(Terminal::IDENTIFIER(p1)),
+                        Symbol::NonTerminal(NonTerminal::Card(p2)),
+                    ) => {
+                        NonTerminal::Constraint(
+                            pgs_actions::constraint_degree(context, p0, p1, p2),
+                        )
                     }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            TokenRecognizer(_, Recognizer::Stop) => {
-                logn!("{} STOP -- ", "    Recognizing".green());
-                if input.is_empty() {
-                    log!("{}", "recognized".bold().green());
-                    Some("")
-                } else {
-                    log!("{}", "not recognized".red());
-                    None
+            ProdKind::ConstraintUnique => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::key1(p0)), _) => {
+                        NonTerminal::Constraint(
+                            pgs_actions::constraint_unique(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-        }
-    }
-}
-pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
-    TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
-    TokenRecognizer(
-        TokenKind::WS,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "\\s+")).unwrap())),
-    ),
-    TokenRecognizer(
-        TokenKind::CommentLine,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "//.*")).unwrap())),
-    ),
-    TokenRecognizer(
-        TokenKind::NotComment,
-        Recognizer::RegexMatch(Lazy::new(|| {
-            Regex::new(concat!("^", "((\\*[^/])|[^\\s*/]|/[^\\*])+")).unwrap()
-        })),
-    ),
-    TokenRecognizer(TokenKind::START_COMMENT, Recognizer::StrMatch("/*")),
-    TokenRecognizer(TokenKind::END_COMMENT, Recognizer::StrMatch("*/")),
-    TokenRecognizer(
-        TokenKind::IDENTIFIER,
-        Recognizer::RegexMatch(Lazy::new(|| {
-            Regex::new(concat!("^", "[a-zA-Z_][0-9a-zA-Z_]*")).unwrap()
-        })),
-    ),
-    TokenRecognizer(TokenKind::SEMICOLON, Recognizer::StrMatch(";")),
-    TokenRecognizer(TokenKind::CREATE, Recognizer::StrMatch("CREATE")),
-    TokenRecognizer(TokenKind::NODE, Recognizer::StrMatch("NODE")),
-    TokenRecognizer(TokenKind::EDGE, Recognizer::StrMatch("EDGE")),
-    TokenRecognizer(TokenKind::GRAPH, Recognizer::StrMatch("GRAPH")),
-    TokenRecognizer(TokenKind::TYPE, Recognizer::StrMatch("TYPE")),
-    TokenRecognizer(TokenKind::OPEN_PAREN, Recognizer::StrMatch("(")),
-    TokenRecognizer(TokenKind::CLOSE_PAREN, Recognizer::StrMatch(")")),
-    TokenRecognizer(TokenKind::OPEN_ARROW, Recognizer::StrMatch("-[")),
-    TokenRecognizer(TokenKind::CLOSE_ARROW, Recognizer::StrMatch("]->")),
-    TokenRecognizer(TokenKind::OPEN_CURLY, Recognizer::StrMatch("{")),
-    TokenRecognizer(TokenKind::CLOSE_CURLY, Recognizer::StrMatch("}")),
-    TokenRecognizer(TokenKind::COLON, Recognizer::StrMatch(":")),
-    TokenRecognizer(TokenKind::COMMA, Recognizer::StrMatch(",")),
-    TokenRecognizer(TokenKind::BAR, Recognizer::StrMatch("|")),
-    TokenRecognizer(TokenKind::DOUBLE_BAR, Recognizer::StrMatch("||")),
-    TokenRecognizer(TokenKind::PLUS, Recognizer::StrMatch("+")),
-    TokenRecognizer(TokenKind::STAR, Recognizer::StrMatch("*")),
-    TokenRecognizer(TokenKind::QUESTION, Recognizer::StrMatch("?")),
-    TokenRecognizer(TokenKind::INTEGER_NAME, Recognizer::StrMatch("INTEGER")),
-    TokenRecognizer(TokenKind::BOOL_NAME, Recognizer::StrMatch("BOOL")),
-    TokenRecognizer(TokenKind::STRING_NAME, Recognizer::StrMatch("STRING")),
-    TokenRecognizer(TokenKind::DATE_NAME, Recognizer::StrMatch("DATE")),
-    TokenRecognizer(
-        TokenKind::NUMBER,
-        Recognizer::RegexMatch(Lazy::new(|| Regex::new(concat!("^", "\\d+")).unwrap())),
-    ),
-    TokenRecognizer(TokenKind::OPTIONAL, Recognizer::StrMatch("OPTIONAL")),
-    TokenRecognizer(TokenKind::AMPERSAND, Recognizer::StrMatch("&")),
-    TokenRecognizer(TokenKind::AT, Recognizer::StrMatch("@")),
-    TokenRecognizer(TokenKind::CHECK, Recognizer::StrMatch("CHECK")),
-    TokenRecognizer(TokenKind::TRUE, Recognizer::StrMatch("TRUE")),
-    TokenRecognizer(TokenKind::FALSE, Recognizer::StrMatch("FALSE")),
-    TokenRecognizer(TokenKind::GT, Recognizer::StrMatch(">")),
-    TokenRecognizer(TokenKind::LT, Recognizer::StrMatch("<")),
-    TokenRecognizer(TokenKind::GE, Recognizer::StrMatch(">=")),
-    TokenRecognizer(TokenKind::LE, Recognizer::StrMatch("<=")),
-    TokenRecognizer(TokenKind::EQUALS, Recognizer::StrMatch("=")),
-    TokenRecognizer(
-        TokenKind::QUOTED_STRING,
-        Recognizer::RegexMatch(Lazy::new(|| {
-            Regex::new(concat!("^", "\"((\\\\\")|[^\"])*\"")).unwrap()
-        })),
-    ),
-    TokenRecognizer(TokenKind::REGEX, Recognizer::StrMatch("REGEX")),
-    TokenRecognizer(TokenKind::AND, Recognizer::StrMatch("AND")),
-    TokenRecognizer(TokenKind::OR, Recognizer::StrMatch("OR")),
-    TokenRecognizer(TokenKind::NOT, Recognizer::StrMatch("NOT")),
-    TokenRecognizer(TokenKind::ANY, Recognizer::StrMatch("ANY")),
-    TokenRecognizer(TokenKind::DATE, Recognizer::StrMatch("DATE")),
-    TokenRecognizer(TokenKind::STRICT, Recognizer::StrMatch("STRICT")),
-    TokenRecognizer(TokenKind::LOOSE, Recognizer::StrMatch("LOOSE")),
-    TokenRecognizer(TokenKind::ABSTRACT, Recognizer::StrMatch("ABSTRACT")),
-    TokenRecognizer(TokenKind::IMPORTS, Recognizer::StrMatch("IMPORTS")),
-];
-pub struct DefaultBuilder {
-    res_stack: Vec<Symbol>,
-}
-impl DefaultBuilder {
-    #[allow(dead_code)]
-    pub fn new() -> Self {
-        Self { res_stack: vec![] }
-    }
-}
-impl Builder for DefaultBuilder {
-    type Output = pgs_actions::Pgs;
-    fn get_result(&mut self) -> Self::Output {
-        match self.res_stack.pop().unwrap() {
-            Symbol::NonTerminal(NonTerminal::Pgs(r)) => r,
-            _ => panic!("Invalid result on the parse stack!"),
-        }
-    }
-}
-impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> for DefaultBuilder {
-    #![allow(unused_variables)]
-    fn shift_action(&mut self, context: &Context<'i, Input>, token: Token<'i, Input, TokenKind>) {
-        let val = match token.kind {
-            TokenKind::STOP => panic!("Cannot shift STOP token!"),
-            TokenKind::IDENTIFIER => Terminal::IDENTIFIER(pgs_actions::identifier(context, token)),
-            TokenKind::SEMICOLON => Terminal::SEMICOLON,
-            TokenKind::CREATE => Terminal::CREATE,
-            TokenKind::NODE => Terminal::NODE,
-            TokenKind::EDGE => Terminal::EDGE,
-            TokenKind::GRAPH => Terminal::GRAPH,
-            TokenKind::TYPE => Terminal::TYPE,
-            TokenKind::OPEN_PAREN => Terminal::OPEN_PAREN,
-            TokenKind::CLOSE_PAREN => Terminal::CLOSE_PAREN,
-            TokenKind::OPEN_ARROW => Terminal::OPEN_ARROW,
-            TokenKind::CLOSE_ARROW => Terminal::CLOSE_ARROW,
-            TokenKind::OPEN_CURLY => Terminal::OPEN_CURLY,
-            TokenKind::CLOSE_CURLY => Terminal::CLOSE_CURLY,
-            TokenKind::COLON => Terminal::COLON,
-            TokenKind::COMMA => Terminal::COMMA,
-            TokenKind::BAR => Terminal::BAR,
-            TokenKind::DOUBLE_BAR => Terminal::DOUBLE_BAR,
-            TokenKind::PLUS => Terminal::PLUS,
-            TokenKind::STAR => Terminal::STAR,
-            TokenKind::QUESTION => Terminal::QUESTION,
-            TokenKind::INTEGER_NAME => Terminal::INTEGER_NAME,
-            TokenKind::BOOL_NAME => Terminal::BOOL_NAME,
-            TokenKind::STRING_NAME => Terminal::STRING_NAME,
-            TokenKind::DATE_NAME => Terminal::DATE_NAME,
-            TokenKind::NUMBER => Terminal::NUMBER(pgs_actions::number(context, token)),
-            TokenKind::OPTIONAL => Terminal::OPTIONAL,
-            TokenKind::AMPERSAND => Terminal::AMPERSAND,
-            TokenKind::AT => Terminal::AT,
-            TokenKind::CHECK => Terminal::CHECK,
-            TokenKind::TRUE => Terminal::TRUE,
-            TokenKind::FALSE => Terminal::FALSE,
-            TokenKind::GT => Terminal::GT,
-            TokenKind::LT => Terminal::LT,
-            TokenKind::GE => Terminal::GE,
-            TokenKind::LE => Terminal::LE,
-            TokenKind::EQUALS => Terminal::EQUALS,
-            TokenKind::QUOTED_STRING => {
-                Terminal::QUOTED_STRING(pgs_actions::quoted_string(context, token))
+            ProdKind::key1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::key1(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::key(p1)),
+                    ) => NonTerminal::key1(pgs_actions::key1_c1(context, p0, p1)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::key1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::key(p0)) => {
+                        NonTerminal::key1(pgs_actions::key1_key(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ConstraintNodeKey => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::key1(p0)), _) => {
+                        NonTerminal::Constraint(
+                            pgs_actions::constraint_node_key(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ConstraintReference => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::key(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeName(p1)),
+                    ) => {
+                        NonTerminal::Constraint(
+                            pgs_actions::constraint_reference(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::REGEX => Terminal::REGEX,
-            TokenKind::AND => Terminal::AND,
-            TokenKind::OR => Terminal::OR,
-            TokenKind::NOT => Terminal::NOT,
-            TokenKind::ANY => Terminal::ANY,
-            TokenKind::DATE => Terminal::DATE,
-            TokenKind::STRICT => Terminal::STRICT,
-            TokenKind::LOOSE => Terminal::LOOSE,
-            _ => panic!("Shift of unreachable terminal!"),
-        };
-        self.res_stack.push(Symbol::Terminal(val));
-    }
-    fn reduce_action(&mut self, context: &Context<'i, Input>, prod: ProdKind, prod_len: usize) {
-        let prod = match prod {
-            ProdKind::PgsP1 => {
+            ProdKind::ConstraintReaches => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 7usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::CreateType1(p0)) => {
-                        NonTerminal::Pgs(pgs_actions::pgs_create_type1(context, p0))
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        _,
+                        _,
+                        Symbol::Terminal(Terminal::IDENTIFIER(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::PathExpr(p1)),
+                    ) => {
+                        NonTerminal::Constraint(
+                            pgs_actions::constraint_reaches(context, p0, p1),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateType1P1 => {
+            ProdKind::PathExprPathSeq => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::CreateType1(p0)),
+                        Symbol::NonTerminal(NonTerminal::PathExpr(p0)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::CreateType(p1)),
-                    ) => NonTerminal::CreateType1(pgs_actions::create_type1_c1(context, p0, p1)),
+                        Symbol::NonTerminal(NonTerminal::PathExpr(p1)),
+                    ) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_seq(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateType1P2 => {
+            ProdKind::PathExprPathAlt => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::CreateType(p0)) => {
-                        NonTerminal::CreateType1(pgs_actions::create_type1_create_type(context, p0))
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::PathExpr(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::PathExpr(p1)),
+                    ) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_alt(context, p0, p1),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateTypeP1 => {
+            ProdKind::PathExprPathStar => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::CreateNodeType(p0)) => {
-                        NonTerminal::CreateType(pgs_actions::create_type_create_node_type(
-                            context, p0,
-                        ))
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (Symbol::NonTerminal(NonTerminal::PathExpr(p0)), _) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_star(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateTypeP2 => {
+            ProdKind::PathExprPathPlus => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::CreateEdgeType(p0)) => {
-                        NonTerminal::CreateType(pgs_actions::create_type_create_edge_type(
-                            context, p0,
-                        ))
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (Symbol::NonTerminal(NonTerminal::PathExpr(p0)), _) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_plus(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateTypeP3 => {
+            ProdKind::PathExprPathOptional => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::CreateGraphType(p0)) => {
-                        NonTerminal::CreateType(pgs_actions::create_type_create_graph_type(
-                            context, p0,
-                        ))
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (Symbol::NonTerminal(NonTerminal::PathExpr(p0)), _) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_optional(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateNodeTypeP1 => {
+            ProdKind::PathExprPathInverse => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
-                    (_, _, _, Symbol::NonTerminal(NonTerminal::NodeType(p0))) => {
-                        NonTerminal::CreateNodeType(pgs_actions::create_node_type_node_type(
-                            context, p0,
-                        ))
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::IDENTIFIER(p0))) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_inverse(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateEdgeTypeP1 => {
+            ProdKind::PathExprPathLabel => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
-                    (_, _, _, Symbol::NonTerminal(NonTerminal::EdgeType(p0))) => {
-                        NonTerminal::CreateEdgeType(pgs_actions::create_edge_type_edge_type(
-                            context, p0,
-                        ))
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::IDENTIFIER(p0)) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_label(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CreateGraphTypeP1 => {
+            ProdKind::PathExprPathParen => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
-                    (_, _, _, Symbol::NonTerminal(NonTerminal::GraphType(p0))) => {
-                        NonTerminal::CreateGraphType(pgs_actions::create_graph_type_graph_type(
-                            context, p0,
-                        ))
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::PathExpr(p0)), _) => {
+                        NonTerminal::PathExpr(
+                            pgs_actions::path_expr_path_paren(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::DirectionP1 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Direction(pgs_actions::direction_outgoing(context))
+            }
+            ProdKind::DirectionP2 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Direction(pgs_actions::direction_incoming(context))
+            }
             ProdKind::NodeTypeP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 5usize)
                     .into_iter();
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
+                    i.next().unwrap(),
                 ) {
                     (
                         _,
                         Symbol::NonTerminal(NonTerminal::TypeNameOpt(p0)),
-                        Symbol::NonTerminal(NonTerminal::LabelPropertySpec(p1)),
+                        Symbol::NonTerminal(NonTerminal::ExtendsOpt(p1)),
+                        Symbol::NonTerminal(NonTerminal::LabelPropertySpec(p2)),
                         _,
-                    ) => NonTerminal::NodeType(pgs_actions::node_type_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::NodeType(
+                            pgs_actions::node_type_c1(context, p0, p1, p2),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6251,7 +14476,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::TypeName(p0)) => {
-                        NonTerminal::TypeNameOpt(pgs_actions::type_name_opt_type_name(context, p0))
+                        NonTerminal::TypeNameOpt(
+                            pgs_actions::type_name_opt_type_name(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6259,6 +14486,69 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
             ProdKind::TypeNameOptP2 => {
                 NonTerminal::TypeNameOpt(pgs_actions::type_name_opt_empty(context))
             }
+            ProdKind::ExtendsOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Extends(p0)) => {
+                        NonTerminal::ExtendsOpt(
+                            pgs_actions::extends_opt_extends(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ExtendsOptP2 => {
+                NonTerminal::ExtendsOpt(pgs_actions::extends_opt_empty(context))
+            }
+            ProdKind::ExtendsP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::TypeName1(p0))) => {
+                        NonTerminal::Extends(
+                            pgs_actions::extends_type_name1(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeName1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::TypeName1(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeName(p1)),
+                    ) => {
+                        NonTerminal::TypeName1(
+                            pgs_actions::type_name1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeName1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::TypeName(p0)) => {
+                        NonTerminal::TypeName1(
+                            pgs_actions::type_name1_type_name(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::EdgeTypeP1 => {
                 let mut i = self
                     .res_stack
@@ -6279,66 +14569,186 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::LabelPropertySpec(p2)),
                         _,
                         Symbol::NonTerminal(NonTerminal::EndpointType(p3)),
-                    ) => NonTerminal::EdgeType(pgs_actions::edge_type_c1(context, p0, p1, p2, p3)),
+                    ) => {
+                        NonTerminal::EdgeType(
+                            pgs_actions::edge_type_c1(context, p0, p1, p2, p3),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GraphTypeP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::TypeName(p0)),
+                        Symbol::NonTerminal(NonTerminal::GraphTypeModeOpt(p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::GraphTypeElementsOpt(p2)),
+                        _,
+                    ) => {
+                        NonTerminal::GraphType(
+                            pgs_actions::graph_type_c1(context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GraphTypeModeOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::GraphTypeMode(p0)) => {
+                        NonTerminal::GraphTypeModeOpt(
+                            pgs_actions::graph_type_mode_opt_graph_type_mode(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GraphTypeModeOptP2 => {
+                NonTerminal::GraphTypeModeOpt(
+                    pgs_actions::graph_type_mode_opt_empty(context),
+                )
+            }
+            ProdKind::GraphTypeElementsOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::GraphTypeElements(p0)) => {
+                        NonTerminal::GraphTypeElementsOpt(
+                            pgs_actions::graph_type_elements_opt_graph_type_elements(
+                                context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GraphTypeElementsOptP2 => {
+                NonTerminal::GraphTypeElementsOpt(
+                    pgs_actions::graph_type_elements_opt_empty(context),
+                )
+            }
+            ProdKind::GraphTypeImportsP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::Import1(p0)), _) => {
+                        NonTerminal::GraphTypeImports(
+                            pgs_actions::graph_type_imports_import1(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Import1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Import1(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Import(p1)),
+                    ) => NonTerminal::Import1(pgs_actions::import1_c1(context, p0, p1)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Import1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Import(p0)) => {
+                        NonTerminal::Import1(pgs_actions::import1_import(context, p0))
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::GraphTypeP1 => {
+            ProdKind::ImportImportPath => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 5usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+                match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::TypeName(p0)),
-                        Symbol::NonTerminal(NonTerminal::GraphTypeModeOpt(p1)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::GraphTypeElementsOpt(p2)),
-                        _,
-                    ) => NonTerminal::GraphType(pgs_actions::graph_type_c1(context, p0, p1, p2)),
+                        Symbol::Terminal(Terminal::QUOTED_STRING(p0)),
+                        Symbol::NonTerminal(NonTerminal::ImportAliasOpt(p1)),
+                    ) => {
+                        NonTerminal::Import(
+                            pgs_actions::import_import_path(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::GraphTypeModeOptP1 => {
+            ProdKind::ImportAliasOptP1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::GraphTypeMode(p0)) => {
-                        NonTerminal::GraphTypeModeOpt(
-                            pgs_actions::graph_type_mode_opt_graph_type_mode(context, p0),
+                    Symbol::NonTerminal(NonTerminal::ImportAlias(p0)) => {
+                        NonTerminal::ImportAliasOpt(
+                            pgs_actions::import_alias_opt_import_alias(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::GraphTypeModeOptP2 => {
-                NonTerminal::GraphTypeModeOpt(pgs_actions::graph_type_mode_opt_empty(context))
+            ProdKind::ImportAliasOptP2 => {
+                NonTerminal::ImportAliasOpt(pgs_actions::import_alias_opt_empty(context))
             }
-            ProdKind::GraphTypeElementsOptP1 => {
+            ProdKind::ImportImportName => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::GraphTypeElements(p0)) => {
-                        NonTerminal::GraphTypeElementsOpt(
-                            pgs_actions::graph_type_elements_opt_graph_type_elements(context, p0),
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::IDENTIFIER(p0)),
+                        Symbol::NonTerminal(NonTerminal::ImportAliasOpt(p1)),
+                    ) => {
+                        NonTerminal::Import(
+                            pgs_actions::import_import_name(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ImportAliasP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::IDENTIFIER(p0))) => {
+                        NonTerminal::ImportAlias(
+                            pgs_actions::import_alias_identifier(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::GraphTypeElementsOptP2 => NonTerminal::GraphTypeElementsOpt(
-                pgs_actions::graph_type_elements_opt_empty(context),
-            ),
             ProdKind::GraphTypeModeP1 => {
                 let _ = self
                     .res_stack
@@ -6360,9 +14770,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::TypeName(p0)) => {
-                        NonTerminal::GraphTypeElements(pgs_actions::graph_type_elements_type_name(
-                            context, p0,
-                        ))
+                        NonTerminal::GraphTypeElements(
+                            pgs_actions::graph_type_elements_type_name(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6374,9 +14784,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::NodeType(p0)) => {
-                        NonTerminal::GraphTypeElements(pgs_actions::graph_type_elements_node_type(
-                            context, p0,
-                        ))
+                        NonTerminal::GraphTypeElements(
+                            pgs_actions::graph_type_elements_node_type(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6388,9 +14798,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::EdgeType(p0)) => {
-                        NonTerminal::GraphTypeElements(pgs_actions::graph_type_elements_edge_type(
-                            context, p0,
-                        ))
+                        NonTerminal::GraphTypeElements(
+                            pgs_actions::graph_type_elements_edge_type(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6401,8 +14811,10 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::IDENTIFIER(p0)) => {
-                        NonTerminal::TypeName(pgs_actions::type_name_identifier(context, p0))
+                    Symbol::Terminal(Terminal::TYPE_NAME(p0)) => {
+                        NonTerminal::TypeName(
+                            pgs_actions::type_name_type_name(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6414,9 +14826,28 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::LabelPropertySpec(p0)), _) => {
-                        NonTerminal::EndpointType(pgs_actions::endpoint_type_label_property_spec(
-                            context, p0,
-                        ))
+                        NonTerminal::EndpointType(
+                            pgs_actions::endpoint_type_label_property_spec(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::EndpointTypeEndpointRef => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::TypeName(p0)), _) => {
+                        NonTerminal::EndpointType(
+                            pgs_actions::endpoint_type_endpoint_ref(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6430,9 +14861,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     (
                         Symbol::NonTerminal(NonTerminal::LabelSpecOpt(p0)),
                         Symbol::NonTerminal(NonTerminal::PropertySpecOpt(p1)),
-                    ) => NonTerminal::LabelPropertySpec(pgs_actions::label_property_spec_c1(
-                        context, p0, p1,
-                    )),
+                    ) => {
+                        NonTerminal::LabelPropertySpec(
+                            pgs_actions::label_property_spec_c1(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6442,9 +14875,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::LabelSpec(p0)) => NonTerminal::LabelSpecOpt(
-                        pgs_actions::label_spec_opt_label_spec(context, p0),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::LabelSpec(p0)) => {
+                        NonTerminal::LabelSpecOpt(
+                            pgs_actions::label_spec_opt_label_spec(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6458,15 +14893,17 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::PropertySpec(p0)) => {
-                        NonTerminal::PropertySpecOpt(pgs_actions::property_spec_opt_property_spec(
-                            context, p0,
-                        ))
+                        NonTerminal::PropertySpecOpt(
+                            pgs_actions::property_spec_opt_property_spec(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
             ProdKind::PropertySpecOptP2 => {
-                NonTerminal::PropertySpecOpt(pgs_actions::property_spec_opt_empty(context))
+                NonTerminal::PropertySpecOpt(
+                    pgs_actions::property_spec_opt_empty(context),
+                )
             }
             ProdKind::LabelSpecP1 => {
                 let mut i = self
@@ -6475,7 +14912,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::Labels(p0))) => {
-                        NonTerminal::LabelSpec(pgs_actions::label_spec_labels(context, p0))
+                        NonTerminal::LabelSpec(
+                            pgs_actions::label_spec_labels(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6499,9 +14938,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::MoreLabels(p0)) => NonTerminal::MoreLabelsOpt(
-                        pgs_actions::more_labels_opt_more_labels(context, p0),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::MoreLabels(p0)) => {
+                        NonTerminal::MoreLabelsOpt(
+                            pgs_actions::more_labels_opt_more_labels(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6518,9 +14959,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         _,
                         Symbol::NonTerminal(NonTerminal::SingleLabel(p0)),
                         Symbol::NonTerminal(NonTerminal::MoreLabelsOpt(p1)),
-                    ) => NonTerminal::MoreLabels(pgs_actions::more_labels_and_labels(
-                        context, p0, p1,
-                    )),
+                    ) => {
+                        NonTerminal::MoreLabels(
+                            pgs_actions::more_labels_and_labels(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6535,7 +14978,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::SingleLabel(p0)),
                         Symbol::NonTerminal(NonTerminal::MoreLabelsOpt(p1)),
                     ) => {
-                        NonTerminal::MoreLabels(pgs_actions::more_labels_or_labels(context, p0, p1))
+                        NonTerminal::MoreLabels(
+                            pgs_actions::more_labels_or_labels(context, p0, p1),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6546,9 +14991,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::IDENTIFIER(p0)) => NonTerminal::SingleLabel(
-                        pgs_actions::single_label_single_label(context, p0),
-                    ),
+                    Symbol::Terminal(Terminal::IDENTIFIER(p0)) => {
+                        NonTerminal::SingleLabel(
+                            pgs_actions::single_label_single_label(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6558,8 +15005,10 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::Terminal(Terminal::IDENTIFIER(p0))) => {
-                        NonTerminal::SingleLabel(pgs_actions::single_label_type_name(context, p0))
+                    (_, Symbol::NonTerminal(NonTerminal::TypeName(p0))) => {
+                        NonTerminal::SingleLabel(
+                            pgs_actions::single_label_type_name(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6571,9 +15020,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::Properties(p0)), _) => {
-                        NonTerminal::PropertySpec(pgs_actions::property_spec_properties(
-                            context, p0,
-                        ))
+                        NonTerminal::PropertySpec(
+                            pgs_actions::property_spec_properties(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6588,7 +15037,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::Properties(p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::Properties(p1)),
-                    ) => NonTerminal::Properties(pgs_actions::properties_each_of(context, p0, p1)),
+                    ) => {
+                        NonTerminal::Properties(
+                            pgs_actions::properties_each_of(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6602,7 +15055,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::Properties(p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::Properties(p1)),
-                    ) => NonTerminal::Properties(pgs_actions::properties_one_of(context, p0, p1)),
+                    ) => {
+                        NonTerminal::Properties(
+                            pgs_actions::properties_one_of(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6613,7 +15070,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::Properties(p0)), _) => {
-                        NonTerminal::Properties(pgs_actions::properties_paren(context, p0))
+                        NonTerminal::Properties(
+                            pgs_actions::properties_paren(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6627,9 +15086,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     (
                         Symbol::NonTerminal(NonTerminal::OPTIONALOpt(p0)),
                         Symbol::NonTerminal(NonTerminal::Property(p1)),
-                    ) => NonTerminal::Properties(pgs_actions::properties_base_property(
-                        context, p0, p1,
-                    )),
+                    ) => {
+                        NonTerminal::Properties(
+                            pgs_actions::properties_base_property(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6643,6 +15104,139 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
             ProdKind::OPTIONALOptP2 => {
                 NonTerminal::OPTIONALOpt(pgs_actions::optionalopt_empty(context))
             }
+            ProdKind::PropertiesRecordCheck => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::RecordCond(p0))) => {
+                        NonTerminal::Properties(
+                            pgs_actions::properties_record_check(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PropertiesIfThen => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 7usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        Symbol::NonTerminal(NonTerminal::RecordCond(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Properties(p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::ElsePropertiesOpt(p2)),
+                    ) => {
+                        NonTerminal::Properties(
+                            pgs_actions::properties_if_then(context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ElsePropertiesOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ElseProperties(p0)) => {
+                        NonTerminal::ElsePropertiesOpt(
+                            pgs_actions::else_properties_opt_else_properties(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ElsePropertiesOptP2 => {
+                NonTerminal::ElsePropertiesOpt(
+                    pgs_actions::else_properties_opt_empty(context),
+                )
+            }
+            ProdKind::PropertiesExclusive => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Property1(p0)), _) => {
+                        NonTerminal::Properties(
+                            pgs_actions::properties_exclusive(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Property1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Property1(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Property(p1)),
+                    ) => {
+                        NonTerminal::Property1(
+                            pgs_actions::property1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Property1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Property(p0)) => {
+                        NonTerminal::Property1(
+                            pgs_actions::property1_property(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ElsePropertiesP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Properties(p0)), _) => {
+                        NonTerminal::ElseProperties(
+                            pgs_actions::else_properties_properties(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::PropertyP1 => {
                 let mut i = self
                     .res_stack
@@ -6678,7 +15272,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     (
                         Symbol::NonTerminal(NonTerminal::SimpleType(p0)),
                         Symbol::NonTerminal(NonTerminal::MoreTypesOpt(p1)),
-                    ) => NonTerminal::TypeSpec(pgs_actions::type_spec_c1(context, p0, p1)),
+                    ) => {
+                        NonTerminal::TypeSpec(pgs_actions::type_spec_c1(context, p0, p1))
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6688,9 +15284,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::MoreTypes(p0)) => NonTerminal::MoreTypesOpt(
-                        pgs_actions::more_types_opt_more_types(context, p0),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::MoreTypes(p0)) => {
+                        NonTerminal::MoreTypesOpt(
+                            pgs_actions::more_types_opt_more_types(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6707,9 +15305,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         _,
                         Symbol::NonTerminal(NonTerminal::SimpleType(p0)),
                         Symbol::NonTerminal(NonTerminal::MoreTypesOpt(p1)),
-                    ) => NonTerminal::MoreTypes(pgs_actions::more_types_intersection_type(
-                        context, p0, p1,
-                    )),
+                    ) => {
+                        NonTerminal::MoreTypes(
+                            pgs_actions::more_types_intersection_type(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6724,7 +15324,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::NonTerminal(NonTerminal::SimpleType(p0)),
                         Symbol::NonTerminal(NonTerminal::MoreTypesOpt(p1)),
                     ) => {
-                        NonTerminal::MoreTypes(pgs_actions::more_types_union_type(context, p0, p1))
+                        NonTerminal::MoreTypes(
+                            pgs_actions::more_types_union_type(context, p0, p1),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6739,9 +15341,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         _,
                         Symbol::NonTerminal(NonTerminal::CardOpt(p0)),
                         Symbol::NonTerminal(NonTerminal::CheckOpt(p1)),
-                    ) => NonTerminal::SimpleType(pgs_actions::simple_type_string_spec(
-                        context, p0, p1,
-                    )),
+                    ) => {
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_string_spec(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6757,7 +15361,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CardOptP2 => NonTerminal::CardOpt(pgs_actions::card_opt_empty(context)),
+            ProdKind::CardOptP2 => {
+                NonTerminal::CardOpt(pgs_actions::card_opt_empty(context))
+            }
             ProdKind::CheckOptP1 => {
                 let mut i = self
                     .res_stack
@@ -6770,7 +15376,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CheckOptP2 => NonTerminal::CheckOpt(pgs_actions::check_opt_empty(context)),
+            ProdKind::CheckOptP2 => {
+                NonTerminal::CheckOpt(pgs_actions::check_opt_empty(context))
+            }
             ProdKind::SimpleTypeInteger => {
                 let mut i = self
                     .res_stack
@@ -6781,7 +15389,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         _,
                         Symbol::NonTerminal(NonTerminal::CardOpt(p0)),
                         Symbol::NonTerminal(NonTerminal::CheckOpt(p1)),
-                    ) => NonTerminal::SimpleType(pgs_actions::simple_type_integer(context, p0, p1)),
+                    ) => {
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_integer(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6795,7 +15407,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         _,
                         Symbol::NonTerminal(NonTerminal::CardOpt(p0)),
                         Symbol::NonTerminal(NonTerminal::CheckOpt(p1)),
-                    ) => NonTerminal::SimpleType(pgs_actions::simple_type_date(context, p0, p1)),
+                    ) => {
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_date(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6809,7 +15425,57 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         _,
                         Symbol::NonTerminal(NonTerminal::CardOpt(p0)),
                         Symbol::NonTerminal(NonTerminal::CheckOpt(p1)),
-                    ) => NonTerminal::SimpleType(pgs_actions::simple_type_bool(context, p0, p1)),
+                    ) => {
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_bool(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::SimpleTypeListSpec => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 6usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::ListName(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeSpec(p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::CardOpt(p2)),
+                        Symbol::NonTerminal(NonTerminal::CheckOpt(p3)),
+                    ) => {
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_list_spec(context, p0, p1, p2, p3),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::SimpleTypeRecordSpec => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        _,
+                        Symbol::NonTerminal(NonTerminal::PropertySpec(p0)),
+                        Symbol::NonTerminal(NonTerminal::CardOpt(p1)),
+                    ) => {
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_record_spec(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -6820,7 +15486,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::CheckOpt(p0))) => {
-                        NonTerminal::SimpleType(pgs_actions::simple_type_any(context, p0))
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_any(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6832,7 +15500,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::Cond(p0))) => {
-                        NonTerminal::SimpleType(pgs_actions::simple_type_cond(context, p0))
+                        NonTerminal::SimpleType(
+                            pgs_actions::simple_type_cond(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -6849,6 +15519,27 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::ListNameP1 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::ListName(pgs_actions::list_name_list(context))
+            }
+            ProdKind::ListNameP2 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::ListName(pgs_actions::list_name_array(context))
+            }
+            ProdKind::ListNameP3 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::ListName(pgs_actions::list_name_set(context))
+            }
             ProdKind::CondP1 => {
                 let _ = self
                     .res_stack
@@ -6987,6 +15678,310 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::CondUnique => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Cond(pgs_actions::cond_unique(context))
+            }
+            ProdKind::CondSum => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Cond(p0)), _) => {
+                        NonTerminal::Cond(pgs_actions::cond_sum(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CondMin => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Cond(p0)), _) => {
+                        NonTerminal::Cond(pgs_actions::cond_min(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CondMax => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Cond(p0)), _) => {
+                        NonTerminal::Cond(pgs_actions::cond_max(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CondAll => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Cond(p0)), _) => {
+                        NonTerminal::Cond(pgs_actions::cond_all(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CondAnyValue => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Cond(p0)), _) => {
+                        NonTerminal::Cond(pgs_actions::cond_any_value(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CondNoneValue => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (_, _, Symbol::NonTerminal(NonTerminal::Cond(p0)), _) => {
+                        NonTerminal::Cond(pgs_actions::cond_none_value(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CondContains => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::SingleValue(p0))) => {
+                        NonTerminal::Cond(pgs_actions::cond_contains(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecordCondRecordAnd => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::RecordCond(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::RecordCond(p1)),
+                    ) => {
+                        NonTerminal::RecordCond(
+                            pgs_actions::record_cond_record_and(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecordCondRecordOr => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::RecordCond(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::RecordCond(p1)),
+                    ) => {
+                        NonTerminal::RecordCond(
+                            pgs_actions::record_cond_record_or(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecordCondRecordNot => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::RecordCond(p0))) => {
+                        NonTerminal::RecordCond(
+                            pgs_actions::record_cond_record_not(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecordCondRecordParen => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::RecordCond(p0)), _) => {
+                        NonTerminal::RecordCond(
+                            pgs_actions::record_cond_record_paren(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecordCondIsNull => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (Symbol::NonTerminal(NonTerminal::Operand(p0)), _, _) => {
+                        NonTerminal::RecordCond(
+                            pgs_actions::record_cond_is_null(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecordCondIsNotNull => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (Symbol::NonTerminal(NonTerminal::Operand(p0)), _, _, _) => {
+                        NonTerminal::RecordCond(
+                            pgs_actions::record_cond_is_not_null(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecordCondComparison => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Operand(p0)),
+                        Symbol::NonTerminal(NonTerminal::Comparator(p1)),
+                        Symbol::NonTerminal(NonTerminal::Operand(p2)),
+                    ) => {
+                        NonTerminal::RecordCond(
+                            pgs_actions::record_cond_comparison(context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::OperandPropertyRef => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::key(p0)) => {
+                        NonTerminal::Operand(
+                            pgs_actions::operand_property_ref(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::OperandLiteral => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::SingleValue(p0)) => {
+                        NonTerminal::Operand(pgs_actions::operand_literal(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ComparatorP1 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Comparator(pgs_actions::comparator_gt(context))
+            }
+            ProdKind::ComparatorP2 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Comparator(pgs_actions::comparator_ge(context))
+            }
+            ProdKind::ComparatorP3 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Comparator(pgs_actions::comparator_lt(context))
+            }
+            ProdKind::ComparatorP4 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Comparator(pgs_actions::comparator_le(context))
+            }
+            ProdKind::ComparatorP5 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Comparator(pgs_actions::comparator_equals(context))
+            }
+            ProdKind::ComparatorP6 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                NonTerminal::Comparator(pgs_actions::comparator_not_equals(context))
+            }
             ProdKind::CardOptional => {
                 let _ = self
                     .res_stack
@@ -7055,9 +16050,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::QUOTED_STRING(p0)) => NonTerminal::SingleValue(
-                        pgs_actions::single_value_string_value(context, p0),
-                    ),
+                    Symbol::Terminal(Terminal::QUOTED_STRING(p0)) => {
+                        NonTerminal::SingleValue(
+                            pgs_actions::single_value_string_value(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -7067,9 +16064,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::NUMBER(p0)) => NonTerminal::SingleValue(
-                        pgs_actions::single_value_number_value(context, p0),
-                    ),
+                    Symbol::Terminal(Terminal::NUMBER(p0)) => {
+                        NonTerminal::SingleValue(
+                            pgs_actions::single_value_number_value(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -7080,7 +16079,9 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::Terminal(Terminal::QUOTED_STRING(p0))) => {
-                        NonTerminal::SingleValue(pgs_actions::single_value_date_value(context, p0))
+                        NonTerminal::SingleValue(
+                            pgs_actions::single_value_date_value(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
@@ -7091,9 +16092,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::BOOL(p0)) => NonTerminal::SingleValue(
-                        pgs_actions::single_value_boolean_value(context, p0),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::BOOL(p0)) => {
+                        NonTerminal::SingleValue(
+                            pgs_actions::single_value_boolean_value(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
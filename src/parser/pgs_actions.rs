@@ -10,6 +10,10 @@ pub type IDENTIFIER = String;
 pub fn identifier(_ctx: &Ctx, token: Token) -> IDENTIFIER {
     token.value.into()
 }
+pub type TYPE_NAME = String;
+pub fn type_name(_ctx: &Ctx, token: Token) -> TYPE_NAME {
+    token.value.into()
+}
 pub type NUMBER = String;
 pub fn number(_ctx: &Ctx, token: Token) -> NUMBER {
     token.value.into()
@@ -18,9 +22,95 @@ pub type QUOTED_STRING = String;
 pub fn quoted_string(_ctx: &Ctx, token: Token) -> QUOTED_STRING {
     token.value.into()
 }
-pub type Pgs = CreateType1;
-pub fn pgs_create_type1(_ctx: &Ctx, create_type1: CreateType1) -> Pgs {
-    create_type1
+#[derive(Debug, Clone)]
+pub struct Pgs {
+    pub graph_type_imports_opt: GraphTypeImportsOpt,
+    pub create_type0: CreateType0,
+}
+pub fn pgs_c1(
+    _ctx: &Ctx,
+    graph_type_imports_opt: GraphTypeImportsOpt,
+    create_type0: CreateType0,
+) -> Pgs {
+    Pgs {
+        graph_type_imports_opt,
+        create_type0,
+    }
+}
+pub type GraphTypeImportsOpt = Option<GraphTypeImports>;
+pub fn graph_type_imports_opt_graph_type_imports(
+    _ctx: &Ctx,
+    graph_type_imports: GraphTypeImports,
+) -> GraphTypeImportsOpt {
+    Some(graph_type_imports)
+}
+pub fn graph_type_imports_opt_empty(_ctx: &Ctx) -> GraphTypeImportsOpt {
+    None
+}
+pub type GraphTypeImports = Import1;
+pub fn graph_type_imports_import1(_ctx: &Ctx, import1: Import1) -> GraphTypeImports {
+    import1
+}
+pub type Import1 = Vec<Import>;
+pub fn import1_c1(_ctx: &Ctx, mut import1: Import1, import: Import) -> Import1 {
+    import1.push(import);
+    import1
+}
+pub fn import1_import(_ctx: &Ctx, import: Import) -> Import1 {
+    vec![import]
+}
+#[derive(Debug, Clone)]
+pub struct ImportPath {
+    pub quoted_string: QUOTED_STRING,
+    pub import_alias_opt: ImportAliasOpt,
+}
+#[derive(Debug, Clone)]
+pub struct ImportName {
+    pub identifier: IDENTIFIER,
+    pub import_alias_opt: ImportAliasOpt,
+}
+#[derive(Debug, Clone)]
+pub enum Import {
+    ImportPath(ImportPath),
+    ImportName(ImportName),
+}
+pub fn import_import_path(
+    _ctx: &Ctx,
+    quoted_string: QUOTED_STRING,
+    import_alias_opt: ImportAliasOpt,
+) -> Import {
+    Import::ImportPath(ImportPath {
+        quoted_string,
+        import_alias_opt,
+    })
+}
+pub fn import_import_name(
+    _ctx: &Ctx,
+    identifier: IDENTIFIER,
+    import_alias_opt: ImportAliasOpt,
+) -> Import {
+    Import::ImportName(ImportName {
+        identifier,
+        import_alias_opt,
+    })
+}
+pub type ImportAliasOpt = Option<ImportAlias>;
+pub fn import_alias_opt_import_alias(_ctx: &Ctx, import_alias: ImportAlias) -> ImportAliasOpt {
+    Some(import_alias)
+}
+pub fn import_alias_opt_empty(_ctx: &Ctx) -> ImportAliasOpt {
+    None
+}
+pub type ImportAlias = IDENTIFIER;
+pub fn import_alias_identifier(_ctx: &Ctx, identifier: IDENTIFIER) -> ImportAlias {
+    identifier
+}
+pub type CreateType0 = Option<CreateType1>;
+pub fn create_type0_create_type1(_ctx: &Ctx, create_type1: CreateType1) -> CreateType0 {
+    Some(create_type1)
+}
+pub fn create_type0_empty(_ctx: &Ctx) -> CreateType0 {
+    None
 }
 pub type CreateType1 = Vec<CreateType>;
 pub fn create_type1_c1(
@@ -39,6 +129,7 @@ pub enum CreateType {
     CreateNodeType(CreateNodeType),
     CreateEdgeType(CreateEdgeType),
     CreateGraphType(CreateGraphType),
+    CreateConstraint(CreateConstraint),
 }
 pub fn create_type_create_node_type(_ctx: &Ctx, create_node_type: CreateNodeType) -> CreateType {
     CreateType::CreateNodeType(create_node_type)
@@ -49,9 +140,37 @@ pub fn create_type_create_edge_type(_ctx: &Ctx, create_edge_type: CreateEdgeType
 pub fn create_type_create_graph_type(_ctx: &Ctx, create_graph_type: CreateGraphType) -> CreateType {
     CreateType::CreateGraphType(create_graph_type)
 }
-pub type CreateNodeType = NodeType;
-pub fn create_node_type_node_type(_ctx: &Ctx, node_type: NodeType) -> CreateNodeType {
-    node_type
+pub fn create_type_create_constraint(
+    _ctx: &Ctx,
+    create_constraint: CreateConstraint,
+) -> CreateType {
+    CreateType::CreateConstraint(create_constraint)
+}
+#[derive(Debug, Clone)]
+pub struct CreateNodeType {
+    pub abstractopt: ABSTRACTOpt,
+    pub node_type: NodeType,
+}
+pub fn create_node_type_c1(
+    _ctx: &Ctx,
+    abstractopt: ABSTRACTOpt,
+    node_type: NodeType,
+) -> CreateNodeType {
+    CreateNodeType {
+        abstractopt,
+        node_type,
+    }
+}
+pub type ABSTRACTOpt = Option<AbstractOptNoO>;
+#[derive(Debug, Clone)]
+pub enum AbstractOptNoO {
+    ABSTRACT,
+}
+pub fn abstractopt_abstract(_ctx: &Ctx) -> ABSTRACTOpt {
+    Some(AbstractOptNoO::ABSTRACT)
+}
+pub fn abstractopt_empty(_ctx: &Ctx) -> ABSTRACTOpt {
+    None
 }
 pub type CreateEdgeType = EdgeType;
 pub fn create_edge_type_edge_type(_ctx: &Ctx, edge_type: EdgeType) -> CreateEdgeType {
@@ -62,17 +181,148 @@ pub fn create_graph_type_graph_type(_ctx: &Ctx, graph_type: GraphType) -> Create
     graph_type
 }
 #[derive(Debug, Clone)]
+pub struct CreateConstraint {
+    pub type_name: TypeName,
+    pub label: IDENTIFIER,
+    pub constraint: Constraint,
+}
+pub fn create_constraint_c1(
+    _ctx: &Ctx,
+    type_name: TypeName,
+    label: IDENTIFIER,
+    constraint: Constraint,
+) -> CreateConstraint {
+    CreateConstraint {
+        type_name,
+        label,
+        constraint,
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Degree {
+    pub direction: Direction,
+    pub edge_label: IDENTIFIER,
+    pub card: Card,
+}
+#[derive(Debug, Clone)]
+pub enum Constraint {
+    Degree(Degree),
+    Unique(key1),
+    NodeKey(key1),
+    Reference(Reference),
+    Reaches(Reaches),
+}
+pub fn constraint_degree(
+    _ctx: &Ctx,
+    direction: Direction,
+    edge_label: IDENTIFIER,
+    card: Card,
+) -> Constraint {
+    Constraint::Degree(Degree {
+        direction,
+        edge_label,
+        card,
+    })
+}
+pub fn constraint_unique(_ctx: &Ctx, key1: key1) -> Constraint {
+    Constraint::Unique(key1)
+}
+pub fn constraint_node_key(_ctx: &Ctx, key1: key1) -> Constraint {
+    Constraint::NodeKey(key1)
+}
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub key: key,
+    pub type_name: IDENTIFIER,
+}
+pub fn constraint_reference(_ctx: &Ctx, key: key, type_name: IDENTIFIER) -> Constraint {
+    Constraint::Reference(Reference { key, type_name })
+}
+#[derive(Debug, Clone)]
+pub struct Reaches {
+    pub target: IDENTIFIER,
+    pub path_expr: PathExpr,
+}
+pub fn constraint_reaches(_ctx: &Ctx, target: IDENTIFIER, path_expr: PathExpr) -> Constraint {
+    Constraint::Reaches(Reaches { target, path_expr })
+}
+#[derive(Debug, Clone)]
+pub enum Direction {
+    OUTGOING,
+    INCOMING,
+}
+pub fn direction_outgoing(_ctx: &Ctx) -> Direction {
+    Direction::OUTGOING
+}
+pub fn direction_incoming(_ctx: &Ctx) -> Direction {
+    Direction::INCOMING
+}
+#[derive(Debug, Clone)]
+pub struct PathSeq {
+    pub left: Box<PathExpr>,
+    pub right: Box<PathExpr>,
+}
+#[derive(Debug, Clone)]
+pub struct PathAlt {
+    pub left: Box<PathExpr>,
+    pub right: Box<PathExpr>,
+}
+#[derive(Debug, Clone)]
+pub enum PathExpr {
+    PathSeq(PathSeq),
+    PathAlt(PathAlt),
+    PathStar(Box<PathExpr>),
+    PathPlus(Box<PathExpr>),
+    PathOptional(Box<PathExpr>),
+    PathInverse(IDENTIFIER),
+    PathLabel(IDENTIFIER),
+    PathParen(Box<PathExpr>),
+}
+pub fn path_expr_path_seq(_ctx: &Ctx, left: PathExpr, right: PathExpr) -> PathExpr {
+    PathExpr::PathSeq(PathSeq {
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+pub fn path_expr_path_alt(_ctx: &Ctx, left: PathExpr, right: PathExpr) -> PathExpr {
+    PathExpr::PathAlt(PathAlt {
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+pub fn path_expr_path_star(_ctx: &Ctx, path_expr: PathExpr) -> PathExpr {
+    PathExpr::PathStar(Box::new(path_expr))
+}
+pub fn path_expr_path_plus(_ctx: &Ctx, path_expr: PathExpr) -> PathExpr {
+    PathExpr::PathPlus(Box::new(path_expr))
+}
+pub fn path_expr_path_optional(_ctx: &Ctx, path_expr: PathExpr) -> PathExpr {
+    PathExpr::PathOptional(Box::new(path_expr))
+}
+pub fn path_expr_path_inverse(_ctx: &Ctx, identifier: IDENTIFIER) -> PathExpr {
+    PathExpr::PathInverse(identifier)
+}
+pub fn path_expr_path_label(_ctx: &Ctx, identifier: IDENTIFIER) -> PathExpr {
+    PathExpr::PathLabel(identifier)
+}
+pub fn path_expr_path_paren(_ctx: &Ctx, path_expr: PathExpr) -> PathExpr {
+    PathExpr::PathParen(Box::new(path_expr))
+}
+#[derive(Debug, Clone)]
 pub struct NodeType {
     pub type_name_opt: TypeNameOpt,
+    pub extends_opt: ExtendsOpt,
     pub label_property_spec: LabelPropertySpec,
 }
 pub fn node_type_c1(
     _ctx: &Ctx,
     type_name_opt: TypeNameOpt,
+    extends_opt: ExtendsOpt,
     label_property_spec: LabelPropertySpec,
 ) -> NodeType {
     NodeType {
         type_name_opt,
+        extends_opt,
         label_property_spec,
     }
 }
@@ -83,6 +333,25 @@ pub fn type_name_opt_type_name(_ctx: &Ctx, type_name: TypeName) -> TypeNameOpt {
 pub fn type_name_opt_empty(_ctx: &Ctx) -> TypeNameOpt {
     None
 }
+pub type ExtendsOpt = Option<Extends>;
+pub fn extends_opt_extends(_ctx: &Ctx, extends: Extends) -> ExtendsOpt {
+    Some(extends)
+}
+pub fn extends_opt_empty(_ctx: &Ctx) -> ExtendsOpt {
+    None
+}
+pub type Extends = TypeName1;
+pub fn extends_type_name1(_ctx: &Ctx, type_name1: TypeName1) -> Extends {
+    type_name1
+}
+pub type TypeName1 = Vec<TypeName>;
+pub fn type_name1_c1(_ctx: &Ctx, mut type_name1: TypeName1, type_name: TypeName) -> TypeName1 {
+    type_name1.push(type_name);
+    type_name1
+}
+pub fn type_name1_type_name(_ctx: &Ctx, type_name: TypeName) -> TypeName1 {
+    vec![type_name]
+}
 #[derive(Debug, Clone)]
 pub struct EdgeType {
     pub source: EndpointType,
@@ -168,16 +437,23 @@ pub fn graph_type_elements_node_type(_ctx: &Ctx, node_type: NodeType) -> GraphTy
 pub fn graph_type_elements_edge_type(_ctx: &Ctx, edge_type: EdgeType) -> GraphTypeElements {
     GraphTypeElements::EdgeType(edge_type)
 }
-pub type TypeName = IDENTIFIER;
-pub fn type_name_identifier(_ctx: &Ctx, identifier: IDENTIFIER) -> TypeName {
-    identifier
+pub type TypeName = TYPE_NAME;
+pub fn type_name_type_name(_ctx: &Ctx, type_name: TYPE_NAME) -> TypeName {
+    type_name
+}
+#[derive(Debug, Clone)]
+pub enum EndpointType {
+    LabelPropertySpec(LabelPropertySpec),
+    EndpointRef(IDENTIFIER),
 }
-pub type EndpointType = LabelPropertySpec;
 pub fn endpoint_type_label_property_spec(
     _ctx: &Ctx,
     label_property_spec: LabelPropertySpec,
 ) -> EndpointType {
-    label_property_spec
+    EndpointType::LabelPropertySpec(label_property_spec)
+}
+pub fn endpoint_type_endpoint_ref(_ctx: &Ctx, identifier: IDENTIFIER) -> EndpointType {
+    EndpointType::EndpointRef(identifier)
 }
 #[derive(Debug, Clone)]
 pub struct LabelPropertySpec {
@@ -296,11 +572,20 @@ pub struct BaseProperty {
     pub property: Property,
 }
 #[derive(Debug, Clone)]
+pub struct IfThen {
+    pub record_cond: RecordCond,
+    pub then: Box<Properties>,
+    pub else_properties_opt: ElsePropertiesOpt,
+}
+#[derive(Debug, Clone)]
 pub enum Properties {
     EachOf(EachOf),
     OneOf(OneOf),
     Paren(Box<Properties>),
     BaseProperty(BaseProperty),
+    RecordCheck(RecordCond),
+    IfThen(IfThen),
+    Exclusive(Property1),
 }
 pub fn properties_each_of(_ctx: &Ctx, left: Properties, right: Properties) -> Properties {
     Properties::EachOf(EachOf {
@@ -327,6 +612,46 @@ pub fn properties_base_property(
         property,
     })
 }
+pub fn properties_record_check(_ctx: &Ctx, record_cond: RecordCond) -> Properties {
+    Properties::RecordCheck(record_cond)
+}
+pub fn properties_if_then(
+    _ctx: &Ctx,
+    record_cond: RecordCond,
+    then: Properties,
+    else_properties_opt: ElsePropertiesOpt,
+) -> Properties {
+    Properties::IfThen(IfThen {
+        record_cond,
+        then: Box::new(then),
+        else_properties_opt,
+    })
+}
+pub fn properties_exclusive(_ctx: &Ctx, property1: Property1) -> Properties {
+    Properties::Exclusive(property1)
+}
+pub type ElsePropertiesOpt = Option<ElseProperties>;
+pub fn else_properties_opt_else_properties(
+    _ctx: &Ctx,
+    else_properties: ElseProperties,
+) -> ElsePropertiesOpt {
+    Some(else_properties)
+}
+pub fn else_properties_opt_empty(_ctx: &Ctx) -> ElsePropertiesOpt {
+    None
+}
+pub type Property1 = Vec<Property>;
+pub fn property1_c1(_ctx: &Ctx, mut property1: Property1, property: Property) -> Property1 {
+    property1.push(property);
+    property1
+}
+pub fn property1_property(_ctx: &Ctx, property: Property) -> Property1 {
+    vec![property]
+}
+pub type ElseProperties = Box<Properties>;
+pub fn else_properties_properties(_ctx: &Ctx, properties: Properties) -> ElseProperties {
+    Box::new(properties)
+}
 pub type OPTIONALOpt = Option<OptionalOptNoO>;
 #[derive(Debug, Clone)]
 pub enum OptionalOptNoO {
@@ -350,6 +675,14 @@ pub type key = IDENTIFIER;
 pub fn key_identifier(_ctx: &Ctx, identifier: IDENTIFIER) -> key {
     identifier
 }
+pub type key1 = Vec<key>;
+pub fn key1_c1(_ctx: &Ctx, mut key1: key1, key: key) -> key1 {
+    key1.push(key);
+    key1
+}
+pub fn key1_key(_ctx: &Ctx, key: key) -> key1 {
+    vec![key]
+}
 #[derive(Debug, Clone)]
 pub struct TypeSpec {
     pub simple_type: SimpleType,
@@ -424,11 +757,25 @@ pub struct Bool {
     pub check_opt: CheckOpt,
 }
 #[derive(Debug, Clone)]
+pub struct ListSpec {
+    pub list_name: ListName,
+    pub type_spec: Box<TypeSpec>,
+    pub card_opt: CardOpt,
+    pub check_opt: CheckOpt,
+}
+#[derive(Debug, Clone)]
+pub struct RecordSpec {
+    pub property_spec: Box<PropertySpec>,
+    pub card_opt: CardOpt,
+}
+#[derive(Debug, Clone)]
 pub enum SimpleType {
     StringSpec(StringSpec),
     Integer(Integer),
     Date(Date),
     Bool(Bool),
+    ListSpec(ListSpec),
+    RecordSpec(RecordSpec),
     Any(CheckOpt),
     Cond(Cond),
 }
@@ -456,6 +803,30 @@ pub fn simple_type_bool(_ctx: &Ctx, card_opt: CardOpt, check_opt: CheckOpt) -> S
         check_opt,
     })
 }
+pub fn simple_type_list_spec(
+    _ctx: &Ctx,
+    list_name: ListName,
+    type_spec: TypeSpec,
+    card_opt: CardOpt,
+    check_opt: CheckOpt,
+) -> SimpleType {
+    SimpleType::ListSpec(ListSpec {
+        list_name,
+        type_spec: Box::new(type_spec),
+        card_opt,
+        check_opt,
+    })
+}
+pub fn simple_type_record_spec(
+    _ctx: &Ctx,
+    property_spec: PropertySpec,
+    card_opt: CardOpt,
+) -> SimpleType {
+    SimpleType::RecordSpec(RecordSpec {
+        property_spec: Box::new(property_spec),
+        card_opt,
+    })
+}
 pub fn simple_type_any(_ctx: &Ctx, check_opt: CheckOpt) -> SimpleType {
     SimpleType::Any(check_opt)
 }
@@ -481,6 +852,21 @@ pub fn check_cond(_ctx: &Ctx, cond: Cond) -> Check {
     cond
 }
 #[derive(Debug, Clone)]
+pub enum ListName {
+    LIST,
+    ARRAY,
+    SET,
+}
+pub fn list_name_list(_ctx: &Ctx) -> ListName {
+    ListName::LIST
+}
+pub fn list_name_array(_ctx: &Ctx) -> ListName {
+    ListName::ARRAY
+}
+pub fn list_name_set(_ctx: &Ctx) -> ListName {
+    ListName::SET
+}
+#[derive(Debug, Clone)]
 pub struct And {
     pub left: Box<Cond>,
     pub right: Box<Cond>,
@@ -504,6 +890,14 @@ pub enum Cond {
     OR(OR),
     Not(Box<Cond>),
     ParenCond(Box<Cond>),
+    Unique,
+    Sum(Box<Cond>),
+    Min(Box<Cond>),
+    Max(Box<Cond>),
+    All(Box<Cond>),
+    AnyValue(Box<Cond>),
+    NoneValue(Box<Cond>),
+    Contains(SingleValue),
 }
 pub fn cond_true(_ctx: &Ctx) -> Cond {
     Cond::TRUE
@@ -547,6 +941,130 @@ pub fn cond_not(_ctx: &Ctx, cond: Cond) -> Cond {
 pub fn cond_paren_cond(_ctx: &Ctx, cond: Cond) -> Cond {
     Cond::ParenCond(Box::new(cond))
 }
+pub fn cond_unique(_ctx: &Ctx) -> Cond {
+    Cond::Unique
+}
+pub fn cond_sum(_ctx: &Ctx, cond: Cond) -> Cond {
+    Cond::Sum(Box::new(cond))
+}
+pub fn cond_min(_ctx: &Ctx, cond: Cond) -> Cond {
+    Cond::Min(Box::new(cond))
+}
+pub fn cond_max(_ctx: &Ctx, cond: Cond) -> Cond {
+    Cond::Max(Box::new(cond))
+}
+pub fn cond_all(_ctx: &Ctx, cond: Cond) -> Cond {
+    Cond::All(Box::new(cond))
+}
+pub fn cond_any_value(_ctx: &Ctx, cond: Cond) -> Cond {
+    Cond::AnyValue(Box::new(cond))
+}
+pub fn cond_none_value(_ctx: &Ctx, cond: Cond) -> Cond {
+    Cond::NoneValue(Box::new(cond))
+}
+pub fn cond_contains(_ctx: &Ctx, single_value: SingleValue) -> Cond {
+    Cond::Contains(single_value)
+}
+#[derive(Debug, Clone)]
+pub struct RecordAnd {
+    pub left: Box<RecordCond>,
+    pub right: Box<RecordCond>,
+}
+#[derive(Debug, Clone)]
+pub struct RecordOr {
+    pub left: Box<RecordCond>,
+    pub right: Box<RecordCond>,
+}
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub left: Operand,
+    pub comparator: Comparator,
+    pub right: Operand,
+}
+#[derive(Debug, Clone)]
+pub enum RecordCond {
+    RecordAnd(RecordAnd),
+    RecordOr(RecordOr),
+    RecordNot(Box<RecordCond>),
+    RecordParen(Box<RecordCond>),
+    IsNull(Operand),
+    IsNotNull(Operand),
+    Comparison(Comparison),
+}
+pub fn record_cond_record_and(_ctx: &Ctx, left: RecordCond, right: RecordCond) -> RecordCond {
+    RecordCond::RecordAnd(RecordAnd {
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+pub fn record_cond_record_or(_ctx: &Ctx, left: RecordCond, right: RecordCond) -> RecordCond {
+    RecordCond::RecordOr(RecordOr {
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+pub fn record_cond_record_not(_ctx: &Ctx, record_cond: RecordCond) -> RecordCond {
+    RecordCond::RecordNot(Box::new(record_cond))
+}
+pub fn record_cond_record_paren(_ctx: &Ctx, record_cond: RecordCond) -> RecordCond {
+    RecordCond::RecordParen(Box::new(record_cond))
+}
+pub fn record_cond_is_null(_ctx: &Ctx, operand: Operand) -> RecordCond {
+    RecordCond::IsNull(operand)
+}
+pub fn record_cond_is_not_null(_ctx: &Ctx, operand: Operand) -> RecordCond {
+    RecordCond::IsNotNull(operand)
+}
+pub fn record_cond_comparison(
+    _ctx: &Ctx,
+    left: Operand,
+    comparator: Comparator,
+    right: Operand,
+) -> RecordCond {
+    RecordCond::Comparison(Comparison {
+        left,
+        comparator,
+        right,
+    })
+}
+#[derive(Debug, Clone)]
+pub enum Operand {
+    PropertyRef(key),
+    Literal(SingleValue),
+}
+pub fn operand_property_ref(_ctx: &Ctx, key: key) -> Operand {
+    Operand::PropertyRef(key)
+}
+pub fn operand_literal(_ctx: &Ctx, single_value: SingleValue) -> Operand {
+    Operand::Literal(single_value)
+}
+#[derive(Debug, Clone)]
+pub enum Comparator {
+    GT,
+    GE,
+    LT,
+    LE,
+    EQUALS,
+    NOT_EQUALS,
+}
+pub fn comparator_gt(_ctx: &Ctx) -> Comparator {
+    Comparator::GT
+}
+pub fn comparator_ge(_ctx: &Ctx) -> Comparator {
+    Comparator::GE
+}
+pub fn comparator_lt(_ctx: &Ctx) -> Comparator {
+    Comparator::LT
+}
+pub fn comparator_le(_ctx: &Ctx) -> Comparator {
+    Comparator::LE
+}
+pub fn comparator_equals(_ctx: &Ctx) -> Comparator {
+    Comparator::EQUALS
+}
+pub fn comparator_not_equals(_ctx: &Ctx) -> Comparator {
+    Comparator::NOT_EQUALS
+}
 #[derive(Debug, Clone)]
 pub struct Range {
     pub number: NUMBER,
@@ -1,25 +1,31 @@
 use super::pgs_actions::CreateType;
+use itertools::Itertools;
 use rustemo::Parser;
 
 use crate::{
     boolean_expr::BooleanExpr,
     card::{Card as PGCard, Max as PGMax},
+    graph_constraint::{Direction as PGDirection, GraphConstraint},
     key::Key,
     label_property_spec::LabelPropertySpec as PGLabelPropertySpec,
     parser::{
         pgs::PgsParser,
         pgs_actions::{
-            BaseProperty, Card, Cond, LabelPropertySpec, Labels, Max, MoreLabels, MoreTypes,
-            Properties, Property, PropertySpec, Range, SimpleType, SingleLabel, SingleValue,
-            TypeSpec,
+            BaseProperty, Card, Comparator, Cond, Constraint, CreateConstraint, Direction,
+            EndpointType, Import, LabelPropertySpec, Labels, ListName, Max, MoreLabels, MoreTypes,
+            Operand, PathExpr, Properties, Property, PropertySpec, Range, RecordCond, SimpleType,
+            SingleLabel, SingleValue, TypeSpec, key1,
         },
+        pgs_resolver::{PgsResolver, SchemaImport},
     },
+    path_expr::PathExpr as PGPathExpr,
     pgs::PropertyGraphSchema,
     pgs_error::PgsError,
     property_value_spec::{
         PropertyValue as PGPropertyValue, PropertyValueSpec as PGPropertyValueSpec,
         TypeSpec as PGTypeSpec,
     },
+    record_expr::{Comparator as PGComparator, Operand as PGOperand, RecordExpr},
     value::Value,
     value_type::ValueType,
 };
@@ -30,54 +36,183 @@ impl PgsBuilder {
     pub fn new() -> Self {
         PgsBuilder {}
     }
+    /// Parses a schema whose imports are resolved from the current directory
     pub fn parse_pgs(&self, input: &str) -> Result<PropertyGraphSchema, PgsError> {
+        self.parse_pgs_with_resolver(input, &mut PgsResolver::new())
+    }
+
+    pub fn parse_pgs_with_resolver(
+        &self,
+        input: &str,
+        resolver: &mut PgsResolver,
+    ) -> Result<PropertyGraphSchema, PgsError> {
         let pgs_content = PgsParser::new()
             .parse(input)
             .map_err(|e| PgsError::ParserError {
                 error: e.to_string(),
             })?;
         let mut schema = PropertyGraphSchema::new();
-        get_create_types(pgs_content, &mut schema)?;
+        for import in pgs_content.graph_type_imports_opt.unwrap_or_default() {
+            let (import, alias) = get_import(import);
+            let imported = resolver.resolve_import(&import)?;
+            match alias {
+                Some(namespace) => schema.add_schema(&imported.qualified(&namespace))?,
+                None => schema.add_schema(&imported)?,
+            }
+        }
+        get_create_types(pgs_content.create_type0.unwrap_or_default(), &mut schema)?;
+        schema.resolve_references()?;
+        schema.check_extends()?;
         Ok(schema)
     }
 }
 
+/// Returns the imported schema and the namespace given to it with AS
+fn get_import(import: Import) -> (SchemaImport, Option<String>) {
+    match import {
+        Import::ImportPath(path) => (
+            SchemaImport::Path(remove_quotes(path.quoted_string.as_str()).to_string()),
+            path.import_alias_opt,
+        ),
+        Import::ImportName(name) => (SchemaImport::Name(name.identifier), name.import_alias_opt),
+    }
+}
+
 fn get_create_types(
     create_types: Vec<CreateType>,
     schema: &mut PropertyGraphSchema,
 ) -> Result<(), PgsError> {
     for create_type in create_types {
         match create_type {
-            CreateType::CreateNodeType(node_type) => {
+            CreateType::CreateNodeType(create_node_type) => {
+                let node_type = create_node_type.node_type;
                 let label_property_spec = get_label_property_spec(node_type.label_property_spec)?;
                 if let Some(type_name) = node_type.type_name_opt {
                     let _ = schema.add_node_spec(type_name.as_str(), label_property_spec)?;
+                    if create_node_type.abstractopt.is_some() {
+                        schema.add_abstract_type(type_name.as_str());
+                    }
+                    if let Some(parents) = node_type.extends_opt {
+                        schema.add_extends(type_name.as_str(), parents);
+                    }
+                } else if create_node_type.abstractopt.is_some() {
+                    return Err(PgsError::UnnamedNodeType {
+                        modifier: "ABSTRACT".to_string(),
+                    });
+                } else if node_type.extends_opt.is_some() {
+                    return Err(PgsError::UnnamedNodeType {
+                        modifier: "EXTENDS".to_string(),
+                    });
                 } else {
                     let _ = schema.add_blank_node_spec(label_property_spec)?;
                 }
             }
             CreateType::CreateEdgeType(edge_type) => {
-                let source_spec = get_label_property_spec(edge_type.source)?;
-                let target_spec = get_label_property_spec(edge_type.target)?;
+                let source_spec = get_endpoint_type(edge_type.source)?;
+                let target_spec = get_endpoint_type(edge_type.target)?;
                 let label_property_spec = get_label_property_spec(edge_type.label_property_spec)?;
                 if let Some(type_name) = edge_type.type_name_opt {
                     let _ = schema.add_edge_spec(
                         type_name.as_str(),
                         source_spec,
-                        target_spec,
                         label_property_spec,
+                        target_spec,
                     )?;
                 } else {
                     let _ =
                         schema.add_blank_edge_spec(source_spec, label_property_spec, target_spec);
                 }
             }
-            CreateType::CreateGraphType(_) => todo!(),
+            CreateType::CreateGraphType(graph_type) => {
+                return Err(PgsError::UnsupportedGraphType {
+                    type_name: graph_type.type_name,
+                });
+            }
+            CreateType::CreateConstraint(create_constraint) => {
+                let constraint = get_constraint(create_constraint)?;
+                schema.add_constraint(constraint)?;
+            }
         }
     }
     Ok(())
 }
 
+fn get_constraint(create_constraint: CreateConstraint) -> Result<GraphConstraint, PgsError> {
+    let name = create_constraint.type_name.as_str();
+    let label = create_constraint.label.as_str();
+    match create_constraint.constraint {
+        Constraint::Degree(degree) => {
+            let direction = match degree.direction {
+                Direction::OUTGOING => PGDirection::Outgoing,
+                Direction::INCOMING => PGDirection::Incoming,
+            };
+            let card = get_card(degree.card)?;
+            Ok(GraphConstraint::degree(
+                name,
+                label,
+                direction,
+                degree.edge_label.as_str(),
+                card,
+            ))
+        }
+        Constraint::Unique(keys) => Ok(GraphConstraint::unique(name, label, get_keys(keys))),
+        Constraint::NodeKey(keys) => Ok(GraphConstraint::node_key(name, label, get_keys(keys))),
+        Constraint::Reference(reference) => Ok(GraphConstraint::reference(
+            name,
+            label,
+            Key::new(reference.key.as_str()),
+            reference.type_name.as_str(),
+        )),
+        Constraint::Reaches(reaches) => Ok(GraphConstraint::reaches(
+            name,
+            label,
+            reaches.target.as_str(),
+            get_path_expr(reaches.path_expr),
+        )),
+    }
+}
+
+fn get_path_expr(path_expr: PathExpr) -> PGPathExpr {
+    match path_expr {
+        PathExpr::PathSeq(seq) => {
+            PGPathExpr::seq(get_path_expr(*seq.left), get_path_expr(*seq.right))
+        }
+        PathExpr::PathAlt(alt) => {
+            PGPathExpr::alt(get_path_expr(*alt.left), get_path_expr(*alt.right))
+        }
+        PathExpr::PathStar(expr) => PGPathExpr::star(get_path_expr(*expr)),
+        PathExpr::PathPlus(expr) => PGPathExpr::plus(get_path_expr(*expr)),
+        PathExpr::PathOptional(expr) => PGPathExpr::optional(get_path_expr(*expr)),
+        PathExpr::PathInverse(label) => PGPathExpr::inverse(label.as_str()),
+        PathExpr::PathLabel(label) => PGPathExpr::label(label.as_str()),
+        PathExpr::PathParen(expr) => get_path_expr(*expr),
+    }
+}
+
+fn get_keys(keys: key1) -> Vec<Key> {
+    keys.iter().map(|key| Key::new(key.as_str())).collect()
+}
+
+fn get_endpoint_type(endpoint_type: EndpointType) -> Result<PGLabelPropertySpec, PgsError> {
+    match endpoint_type {
+        // Inline endpoints without properties only constrain the labels of the node
+        EndpointType::LabelPropertySpec(label_property_spec)
+            if label_property_spec.property_spec_opt.is_none() =>
+        {
+            Ok(PGLabelPropertySpec::content(
+                get_label_property_spec(label_property_spec)?,
+                PGPropertyValueSpec::open(PGPropertyValue::no_properties()),
+            ))
+        }
+        EndpointType::LabelPropertySpec(label_property_spec) => {
+            get_label_property_spec(label_property_spec)
+        }
+        EndpointType::EndpointRef(type_name) => {
+            Ok(PGLabelPropertySpec::ref_(type_name.to_string()))
+        }
+    }
+}
+
 fn get_label_property_spec(
     label_property_spec: LabelPropertySpec,
 ) -> Result<PGLabelPropertySpec, PgsError> {
@@ -158,6 +293,27 @@ fn get_property_value(property_value: Properties) -> Result<PGPropertyValue, Pgs
             Ok(PGPropertyValue::one_of(left, right))
         }
         PropertySpec::BaseProperty(property) => get_base_property(property),
+        PropertySpec::RecordCheck(cond) => {
+            let expr = get_record_cond(cond)?;
+            Ok(PGPropertyValue::check(expr))
+        }
+        PropertySpec::IfThen(if_then) => {
+            let cond = get_record_cond(if_then.record_cond)?;
+            let then = get_property_value_spec(*if_then.then)?;
+            let else_ = match if_then.else_properties_opt {
+                Some(else_properties) => Some(get_property_value_spec(*else_properties)?),
+                None => None,
+            };
+            Ok(PGPropertyValue::if_then_else(cond, then, else_))
+        }
+        PropertySpec::Exclusive(properties) => {
+            let mut exclusive = Vec::new();
+            for property in properties {
+                let (key, type_spec) = get_property(property)?;
+                exclusive.push(PGPropertyValue::property(key, type_spec));
+            }
+            Ok(PGPropertyValue::exclusive(exclusive))
+        }
     }
 }
 
@@ -258,6 +414,36 @@ fn get_simple_type(simple_type: SimpleType) -> Result<PGTypeSpec, PgsError> {
                 Ok(PGTypeSpec::date(card))
             }
         }
+        SimpleType::ListSpec(list) => {
+            let value_type = get_type_spec(*list.type_spec)?.to_value_type();
+            let card = if let Some(card) = list.card_opt {
+                get_card(card)?
+            } else {
+                PGCard::ZeroOrMore
+            };
+            match (list.list_name, list.check_opt) {
+                (ListName::SET, Some(cond)) => Ok(PGTypeSpec::cond(
+                    ValueType::set(value_type, card),
+                    get_cond(cond)?,
+                )),
+                (ListName::SET, None) => Ok(PGTypeSpec::set(value_type, card)),
+                (ListName::LIST | ListName::ARRAY, Some(cond)) => Ok(PGTypeSpec::cond(
+                    ValueType::list(value_type, card),
+                    get_cond(cond)?,
+                )),
+                (ListName::LIST | ListName::ARRAY, None) => Ok(PGTypeSpec::list(value_type, card)),
+            }
+        }
+        SimpleType::RecordSpec(record) => {
+            let property_value = get_property_value_spec(*record.property_spec)?;
+            let record_types = property_value
+                .semantics()
+                .into_iter()
+                .sorted_by_key(|record_type| record_type.to_string())
+                .collect();
+            let card = get_card_opt(record.card_opt)?;
+            Ok(PGTypeSpec::record(record_types, card))
+        }
         SimpleType::Any(cond) => {
             if let Some(cond) = cond {
                 let cond = get_cond(cond)?;
@@ -336,6 +522,90 @@ fn get_cond(cond: Cond) -> Result<BooleanExpr, PgsError> {
             let cleaned = remove_quotes(pattern.as_str());
             Ok(BooleanExpr::Regex(cleaned.to_string()))
         }
+        Cond::Unique => Ok(BooleanExpr::Unique),
+        Cond::Sum(cond) => {
+            let expr = get_cond(*cond)?;
+            Ok(BooleanExpr::Sum(Box::new(expr)))
+        }
+        Cond::Min(cond) => {
+            let expr = get_cond(*cond)?;
+            Ok(BooleanExpr::Min(Box::new(expr)))
+        }
+        Cond::Max(cond) => {
+            let expr = get_cond(*cond)?;
+            Ok(BooleanExpr::Max(Box::new(expr)))
+        }
+        Cond::All(cond) => {
+            let expr = get_cond(*cond)?;
+            Ok(BooleanExpr::All(Box::new(expr)))
+        }
+        Cond::AnyValue(cond) => {
+            let expr = get_cond(*cond)?;
+            Ok(BooleanExpr::Any(Box::new(expr)))
+        }
+        Cond::NoneValue(cond) => {
+            let expr = get_cond(*cond)?;
+            Ok(BooleanExpr::NoneOf(Box::new(expr)))
+        }
+        Cond::Contains(single_value) => {
+            let value = get_value(single_value)?;
+            Ok(BooleanExpr::Contains(value))
+        }
+    }
+}
+
+fn get_record_cond(cond: RecordCond) -> Result<RecordExpr, PgsError> {
+    match cond {
+        RecordCond::RecordAnd(and) => {
+            let left = get_record_cond(*and.left)?;
+            let right = get_record_cond(*and.right)?;
+            Ok(RecordExpr::and(left, right))
+        }
+        RecordCond::RecordOr(or) => {
+            let left = get_record_cond(*or.left)?;
+            let right = get_record_cond(*or.right)?;
+            Ok(RecordExpr::or(left, right))
+        }
+        RecordCond::RecordNot(cond) => {
+            let expr = get_record_cond(*cond)?;
+            Ok(RecordExpr::not_(expr))
+        }
+        RecordCond::RecordParen(cond) => get_record_cond(*cond),
+        RecordCond::IsNull(operand) => {
+            let operand = get_operand(operand)?;
+            Ok(RecordExpr::is_null(operand))
+        }
+        RecordCond::IsNotNull(operand) => {
+            let operand = get_operand(operand)?;
+            Ok(RecordExpr::not_(RecordExpr::is_null(operand)))
+        }
+        RecordCond::Comparison(comparison) => {
+            let left = get_operand(comparison.left)?;
+            let right = get_operand(comparison.right)?;
+            let comparator = get_comparator(comparison.comparator);
+            Ok(RecordExpr::compare(left, comparator, right))
+        }
+    }
+}
+
+fn get_operand(operand: Operand) -> Result<PGOperand, PgsError> {
+    match operand {
+        Operand::PropertyRef(key) => Ok(PGOperand::property(key.as_str())),
+        Operand::Literal(single_value) => {
+            let value = get_value(single_value)?;
+            Ok(PGOperand::value(value))
+        }
+    }
+}
+
+fn get_comparator(comparator: Comparator) -> PGComparator {
+    match comparator {
+        Comparator::GT => PGComparator::GreaterThan,
+        Comparator::GE => PGComparator::GreaterThanOrEqual,
+        Comparator::LT => PGComparator::LessThan,
+        Comparator::LE => PGComparator::LessThanOrEqual,
+        Comparator::EQUALS => PGComparator::Equals,
+        Comparator::NOT_EQUALS => PGComparator::NotEquals,
     }
 }
 
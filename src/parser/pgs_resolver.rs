@@ -0,0 +1,174 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+
+use crate::{parser::pgs_builder::PgsBuilder, pgs::PropertyGraphSchema, pgs_error::PgsError};
+
+/// Schema imported by another schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaImport {
+    /// Path of a file, relative to the importing schema, as in IMPORTS "common/base.pgs"
+    Path(String),
+    /// Name of a schema which is looked up as name.pgs next to the importing schema
+    /// and then in the search paths, as in IMPORTS base
+    Name(String),
+}
+
+impl Display for SchemaImport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaImport::Path(path) => write!(f, "\"{}\"", path),
+            SchemaImport::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Loads schemas from files together with the schemas they import.
+///
+/// Each file is parsed once and cached, so schemas imported through
+/// several paths are shared, and circular imports are reported.
+pub struct PgsResolver {
+    search_paths: Vec<PathBuf>,
+    cache: HashMap<PathBuf, PropertyGraphSchema>,
+    loading: Vec<PathBuf>,
+}
+
+impl Default for PgsResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PgsResolver {
+    pub fn new() -> Self {
+        PgsResolver {
+            search_paths: Vec::new(),
+            cache: HashMap::new(),
+            loading: Vec::new(),
+        }
+    }
+
+    /// Adds a directory where schemas imported by name are looked up
+    pub fn with_search_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.search_paths.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Loads the schema in a file resolving its imports
+    pub fn resolve_path<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<PropertyGraphSchema, PgsError> {
+        let path =
+            std::fs::canonicalize(path.as_ref()).map_err(|e| PgsError::PGSchemaFileReadError {
+                path: path.as_ref().display().to_string(),
+                error: e.to_string(),
+            })?;
+        if let Some(pos) = self.loading.iter().position(|loading| *loading == path) {
+            let cycle = self.loading[pos..]
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|path| path.display())
+                .join(" -> ");
+            return Err(PgsError::CircularImport { cycle });
+        }
+        if let Some(schema) = self.cache.get(&path) {
+            return Ok(schema.clone());
+        }
+        let content =
+            std::fs::read_to_string(&path).map_err(|e| PgsError::PGSchemaFileReadError {
+                path: path.display().to_string(),
+                error: e.to_string(),
+            })?;
+        self.loading.push(path.clone());
+        let result = PgsBuilder::new().parse_pgs_with_resolver(content.as_str(), self);
+        self.loading.pop();
+        let schema = result?;
+        self.cache.insert(path, schema.clone());
+        Ok(schema)
+    }
+
+    /// Loads a schema imported by the schema that is being loaded
+    pub fn resolve_import(
+        &mut self,
+        import: &SchemaImport,
+    ) -> Result<PropertyGraphSchema, PgsError> {
+        let base_dir = self
+            .loading
+            .last()
+            .and_then(|path| path.parent())
+            .map(|dir| dir.to_path_buf())
+            .unwrap_or_default();
+        let candidates: Vec<PathBuf> = match import {
+            SchemaImport::Path(path) => vec![base_dir.join(path)],
+            SchemaImport::Name(name) => {
+                let file_name = format!("{}.pgs", name);
+                std::iter::once(&base_dir)
+                    .chain(self.search_paths.iter())
+                    .map(|dir| dir.join(&file_name))
+                    .collect()
+            }
+        };
+        match candidates.iter().find(|candidate| candidate.is_file()) {
+            Some(path) => self.resolve_path(path),
+            None => Err(PgsError::ImportNotFound {
+                import: import.to_string(),
+                searched: candidates.iter().map(|path| path.display()).join(", "),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circular_import() {
+        let result = PgsResolver::new().resolve_path("tests/imports/cycle_a.pgs");
+        assert!(matches!(result, Err(PgsError::CircularImport { .. })));
+    }
+
+    #[test]
+    fn test_import_by_name_from_search_path() {
+        let mut resolver = PgsResolver::new().with_search_path("tests/imports");
+        let schema = resolver
+            .resolve_import(&SchemaImport::Name("people".to_string()))
+            .unwrap();
+        assert!(schema.is_abstract("PersonType"));
+        assert!(schema.get_node_semantics("StudentType").is_ok());
+        let missing = resolver.resolve_import(&SchemaImport::Name("missing".to_string()));
+        assert!(matches!(missing, Err(PgsError::ImportNotFound { .. })));
+    }
+
+    #[test]
+    fn test_redeclare_imported_type() {
+        let result = PgsResolver::new().resolve_path("tests/imports/redeclare.pgs");
+        assert!(matches!(
+            result,
+            Err(PgsError::DuplicateNodeTypeName { type_name }) if type_name == "PersonType"
+        ));
+    }
+
+    #[test]
+    fn test_import_graph_type() {
+        let result = PgsResolver::new().resolve_path("tests/imports/graph_type_import.pgs");
+        assert!(matches!(
+            result,
+            Err(PgsError::UnsupportedGraphType { type_name }) if type_name == "PeopleGraph"
+        ));
+    }
+
+    #[test]
+    fn test_schema_with_only_imports() {
+        let schema = PgsResolver::new()
+            .resolve_path("tests/imports/bundle.pgs")
+            .unwrap();
+        assert!(schema.is_abstract("PersonType"));
+        assert!(schema.get_node_semantics("StudentType").is_ok());
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::HashSet;
+
+use crate::{
+    key::Key,
+    parser::literal::{unquote, unquoted_value},
+    pg::{PropertyGraph, complete_names},
+    pgs_error::PgsError,
+    record::Record,
+    type_name::LabelName,
+    value::Value,
+};
+
+/// Builds property graphs from the node and edge statements of YARS-PG, as in
+/// `(alice {"Person"}["name":"Alice","age":23])` and
+/// `(alice)-(k1 {"knows"}["since":2020])->(bob)`.
+///
+/// Lists and sets of values are multi-valued properties, dates are written unquoted as
+/// `2020-01-31`, `null` values are missing properties and `#` starts a comment.
+/// Undirected edges (`-(...)-`) are read as edges from the first node to the second. Edges without identifier get the names `e1`, `e2`, ...
+/// Metadata, schema and graph statements are not supported.
+pub struct YarsPgBuilder {}
+
+impl Default for YarsPgBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl YarsPgBuilder {
+    pub fn new() -> Self {
+        YarsPgBuilder {}
+    }
+
+    pub fn parse_yarspg(&self, input: &str) -> Result<PropertyGraph, PgsError> {
+        let mut parser = YarsPgParser {
+            chars: input.chars().collect(),
+            pos: 0,
+            line: 1,
+        };
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        parser.skip_layout();
+        while !parser.at_end() {
+            let line = parser.line;
+            let statement = parser.statement().map_err(|e| yarspg_error(line, e))?;
+            match statement {
+                Statement::Node { id, labels, record } => nodes.push((id, labels, record)),
+                Statement::Edge {
+                    id,
+                    source,
+                    labels,
+                    record,
+                    target,
+                } => edges.push((line, id, source, labels, record, target)),
+            }
+            parser.skip_layout();
+        }
+        let mut pg = PropertyGraph::new();
+        for (id, labels, record) in nodes {
+            pg.add_node(id, labels, record)?;
+        }
+        let names = complete_names(edges.iter().map(|edge| edge.1.clone()).collect(), "e");
+        for ((line, _, source, labels, record, target), name) in edges.into_iter().zip(names) {
+            pg.add_edge(name, source, labels, record, target)
+                .map_err(|e| yarspg_error(line, e.to_string()))?;
+        }
+        Ok(pg)
+    }
+}
+
+enum Statement {
+    Node {
+        id: String,
+        labels: HashSet<LabelName>,
+        record: Record,
+    },
+    Edge {
+        id: Option<String>,
+        source: String,
+        labels: HashSet<LabelName>,
+        record: Record,
+        target: String,
+    },
+}
+
+struct YarsPgParser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl YarsPgParser {
+    fn statement(&mut self) -> Result<Statement, String> {
+        self.expect('(')?;
+        let id = self.identifier()?;
+        self.skip_layout();
+        if self.peek() == Some(')') && self.peek_at(1) == Some('-') {
+            self.expect(')')?;
+            self.expect('-')?;
+            self.expect('(')?;
+            self.skip_layout();
+            let edge_id = if self
+                .peek()
+                .is_some_and(|c| c == '"' || is_identifier_char(c))
+            {
+                Some(self.identifier()?)
+            } else {
+                None
+            };
+            let (labels, record) = self.labels_record()?;
+            self.expect(')')?;
+            self.expect('-')?;
+            if self.peek() == Some('>') {
+                self.pos += 1;
+            }
+            self.expect('(')?;
+            let target = self.identifier()?;
+            self.expect(')')?;
+            Ok(Statement::Edge {
+                id: edge_id,
+                source: id,
+                labels,
+                record,
+                target,
+            })
+        } else {
+            let (labels, record) = self.labels_record()?;
+            self.expect(')')?;
+            Ok(Statement::Node { id, labels, record })
+        }
+    }
+
+    fn labels_record(&mut self) -> Result<(HashSet<LabelName>, Record), String> {
+        let mut labels = HashSet::new();
+        let mut record = Record::new();
+        self.skip_layout();
+        if self.peek() == Some('{') {
+            self.pos += 1;
+            for label in self.separated('}', |parser| parser.string())? {
+                labels.insert(label);
+            }
+        }
+        self.skip_layout();
+        if self.peek() == Some('[') {
+            self.pos += 1;
+            let properties = self.separated(']', |parser| {
+                let key = parser.string()?;
+                parser.expect(':')?;
+                let values = parser
+                    .values()
+                    .map_err(|e| format!("property {}: {}", key, e))?;
+                Ok((key, values))
+            })?;
+            for (key, values) in properties {
+                record.insert_values(Key::new(&key), values);
+            }
+        }
+        Ok((labels, record))
+    }
+
+    /// Values of a property, where lists and sets give several values
+    fn values(&mut self) -> Result<Vec<Value>, String> {
+        self.skip_layout();
+        match self.peek() {
+            Some(open @ ('[' | '{')) => {
+                self.pos += 1;
+                let close = if open == '[' { ']' } else { '}' };
+                let values = self.separated(close, |parser| parser.values())?;
+                Ok(values.into_iter().flatten().collect())
+            }
+            Some('"') => Ok(vec![Value::str(&self.string()?)]),
+            _ => {
+                let token = self.identifier()?;
+                if token == "null" {
+                    return Ok(Vec::new());
+                }
+                match unquoted_value(&token)? {
+                    Some(value) => Ok(vec![value]),
+                    None => Err(format!("unsupported value {}", token)),
+                }
+            }
+        }
+    }
+
+    /// Items separated by commas until the closing character
+    fn separated<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let mut items = Vec::new();
+        self.skip_layout();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_layout();
+            match self.next() {
+                Some(',') => {}
+                Some(c) if c == close => return Ok(items),
+                other => return Err(format!("expected , or {} but found {:?}", close, other)),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.skip_layout();
+        let start = self.pos;
+        self.expect('"')?;
+        while let Some(c) = self.next() {
+            match c {
+                '\\' => {
+                    self.next();
+                }
+                '"' => {
+                    let literal: String = self.chars[start..self.pos].iter().collect();
+                    return unquote(&literal);
+                }
+                _ => {}
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+
+    /// Identifiers of nodes and edges, which can also be quoted strings
+    fn identifier(&mut self) -> Result<String, String> {
+        self.skip_layout();
+        if self.peek() == Some('"') {
+            return self.string();
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(format!("expected identifier but found {:?}", self.peek()));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_layout();
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            other => Err(format!("expected {} but found {:?}", expected, other)),
+        }
+    }
+
+    fn skip_layout(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '#' {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.pos += 1;
+                }
+            } else if c.is_whitespace() {
+                self.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '.'
+}
+
+fn yarspg_error(line: usize, error: String) -> PgsError {
+    PgsError::GraphFormatError {
+        format: "YARS-PG".to_string(),
+        error: format!("line {}: {}", line, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_yarspg() {
+        let input = r#"# People
+(alice {"Person","Student"}["name":"Alice", "age":23, "tags":["a","b"]])
+(bob {"Person"})
+(alice)-({"knows"}["since":2020])->(bob)
+"#;
+        let pg = YarsPgBuilder::new().parse_yarspg(input).unwrap();
+        let alice = pg.get_node_by_label("alice").unwrap();
+        assert_eq!(
+            alice.labels(),
+            &HashSet::from(["Person".to_string(), "Student".to_string()])
+        );
+        assert_eq!(
+            alice.content().get(&Key::new("tags")),
+            Some(&vec![Value::str("a"), Value::str("b")])
+        );
+        let knows = pg.get_edge_by_label("e1").unwrap();
+        assert_eq!(
+            knows.content().get(&Key::new("since")),
+            Some(&vec![Value::int(2020)])
+        );
+    }
+
+    #[test]
+    fn test_unsupported_numbers() {
+        let input = "(alice)\n(bob [\"height\":1.7])\n";
+        let result = YarsPgBuilder::new().parse_yarspg(input);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error reading YARS-PG property graph: line 2: property height: number 1.7 is not supported, only integers can be property values"
+        );
+    }
+}
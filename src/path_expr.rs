@@ -0,0 +1,361 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+use crate::{node_id::NodeId, pg::PropertyGraph, type_name::LabelName};
+
+/// Regular path expressions over the labels of the edges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathExpr {
+    Label(LabelName),
+    /// Traverses an edge with the label from its target to its source, as in ^worksFor
+    Inverse(LabelName),
+    Seq(Box<PathExpr>, Box<PathExpr>),
+    Alt(Box<PathExpr>, Box<PathExpr>),
+    Star(Box<PathExpr>),
+    Plus(Box<PathExpr>),
+    Optional(Box<PathExpr>),
+}
+
+impl PathExpr {
+    pub fn label(label: &str) -> Self {
+        PathExpr::Label(label.to_string())
+    }
+
+    pub fn inverse(label: &str) -> Self {
+        PathExpr::Inverse(label.to_string())
+    }
+
+    pub fn seq(left: PathExpr, right: PathExpr) -> Self {
+        PathExpr::Seq(Box::new(left), Box::new(right))
+    }
+
+    pub fn alt(left: PathExpr, right: PathExpr) -> Self {
+        PathExpr::Alt(Box::new(left), Box::new(right))
+    }
+
+    pub fn star(expr: PathExpr) -> Self {
+        PathExpr::Star(Box::new(expr))
+    }
+
+    pub fn plus(expr: PathExpr) -> Self {
+        PathExpr::Plus(Box::new(expr))
+    }
+
+    pub fn optional(expr: PathExpr) -> Self {
+        PathExpr::Optional(Box::new(expr))
+    }
+
+    /// Looks for the shortest path from the node to some node with the target label
+    /// which matches the expression.
+    ///
+    /// If there is none, the error contains the longest of the shortest paths
+    /// that were explored, which shows how far the search got.
+    pub fn reaches(
+        &self,
+        graph: &PropertyGraph,
+        from: &NodeId,
+        target_label: &str,
+    ) -> Result<Path, Path> {
+        let nfa = Nfa::new(self);
+        let targets: HashSet<&NodeId> = graph
+            .nodes_with_label(target_label)
+            .map(|node| &node.id)
+            .collect();
+        let start = (from.clone(), nfa.start);
+        let mut distances = HashMap::from([(start.clone(), 0)]);
+        let mut previous: Previous = HashMap::new();
+        let mut farthest = start.clone();
+        // 0-1 BFS: epsilon transitions don't add edges to the path
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            let (node, state) = &current;
+            let distance = distances[&current];
+            if *state == nfa.accept && targets.contains(node) {
+                return Ok(path_to(graph, &previous, &current));
+            }
+            if distance > distances[&farthest] {
+                farthest = current.clone();
+            }
+            for (step, next_state) in &nfa.transitions[*state] {
+                let (cost, next_nodes) = match step {
+                    None => (0, vec![node.clone()]),
+                    Some(step) => (1, step.next_nodes(graph, node)),
+                };
+                for next_node in next_nodes {
+                    let next = (next_node, *next_state);
+                    let next_distance = distance + cost;
+                    if distances
+                        .get(&next)
+                        .is_some_and(|known| *known <= next_distance)
+                    {
+                        continue;
+                    }
+                    distances.insert(next.clone(), next_distance);
+                    previous.insert(next.clone(), (current.clone(), step.clone()));
+                    if cost == 0 {
+                        queue.push_front(next);
+                    } else {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        Err(path_to(graph, &previous, &farthest))
+    }
+
+    /// Representation as an operand of a sequence
+    fn operand(&self) -> String {
+        match self {
+            PathExpr::Alt(_, _) => format!("({})", self),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Representation as the operand of a postfix operator
+    fn atom(&self) -> String {
+        match self {
+            PathExpr::Seq(_, _) | PathExpr::Alt(_, _) => format!("({})", self),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for PathExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathExpr::Label(label) => write!(f, "{}", label),
+            PathExpr::Inverse(label) => write!(f, "^{}", label),
+            PathExpr::Seq(left, right) => write!(f, "{}/{}", left.operand(), right.operand()),
+            PathExpr::Alt(left, right) => write!(f, "{} | {}", left, right),
+            PathExpr::Star(expr) => write!(f, "{}*", expr.atom()),
+            PathExpr::Plus(expr) => write!(f, "{}+", expr.atom()),
+            PathExpr::Optional(expr) => write!(f, "{}?", expr.atom()),
+        }
+    }
+}
+
+/// Search state as a node of the graph and a state of the automaton
+type SearchState = (NodeId, usize);
+
+/// Predecessor of each search state with the step that was taken from it
+type Previous = HashMap<SearchState, (SearchState, Option<Step>)>;
+
+/// Path in a graph as the name of the starting node followed by the steps taken
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    start: String,
+    steps: Vec<(Step, String)>,
+}
+
+impl Path {
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.start)?;
+        for (step, node) in &self.steps {
+            if step.inverse {
+                write!(f, " <-[{}]- {}", step.label, node)?;
+            } else {
+                write!(f, " -[{}]-> {}", step.label, node)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    label: LabelName,
+    inverse: bool,
+}
+
+impl Step {
+    fn next_nodes(&self, graph: &PropertyGraph, node: &NodeId) -> Vec<NodeId> {
+        if self.inverse {
+            graph
+                .in_edges(node)
+                .filter(|edge| edge.labels().contains(&self.label))
+                .map(|edge| edge.source.clone())
+                .collect()
+        } else {
+            graph
+                .out_edges(node)
+                .filter(|edge| edge.labels().contains(&self.label))
+                .map(|edge| edge.target.clone())
+                .collect()
+        }
+    }
+}
+
+/// Thompson automaton of a path expression, where None transitions are epsilon transitions
+struct Nfa {
+    transitions: Vec<Vec<(Option<Step>, usize)>>,
+    start: usize,
+    accept: usize,
+}
+
+impl Nfa {
+    fn new(expr: &PathExpr) -> Self {
+        let mut nfa = Nfa {
+            transitions: Vec::new(),
+            start: 0,
+            accept: 0,
+        };
+        nfa.start = nfa.add_state();
+        nfa.accept = nfa.add_state();
+        nfa.compile(expr, nfa.start, nfa.accept);
+        nfa
+    }
+
+    fn add_state(&mut self) -> usize {
+        self.transitions.push(Vec::new());
+        self.transitions.len() - 1
+    }
+
+    fn add_transition(&mut self, from: usize, step: Option<Step>, to: usize) {
+        self.transitions[from].push((step, to));
+    }
+
+    fn compile(&mut self, expr: &PathExpr, from: usize, to: usize) {
+        match expr {
+            PathExpr::Label(label) | PathExpr::Inverse(label) => {
+                let step = Step {
+                    label: label.clone(),
+                    inverse: matches!(expr, PathExpr::Inverse(_)),
+                };
+                self.add_transition(from, Some(step), to);
+            }
+            PathExpr::Seq(left, right) => {
+                let middle = self.add_state();
+                self.compile(left, from, middle);
+                self.compile(right, middle, to);
+            }
+            PathExpr::Alt(left, right) => {
+                self.compile(left, from, to);
+                self.compile(right, from, to);
+            }
+            PathExpr::Star(inner) | PathExpr::Plus(inner) => {
+                let loop_start = self.add_state();
+                let loop_end = self.add_state();
+                self.add_transition(from, None, loop_start);
+                self.compile(inner, loop_start, loop_end);
+                self.add_transition(loop_end, None, loop_start);
+                self.add_transition(loop_end, None, to);
+                if let PathExpr::Star(_) = expr {
+                    self.add_transition(from, None, to);
+                }
+            }
+            PathExpr::Optional(expr) => {
+                self.compile(expr, from, to);
+                self.add_transition(from, None, to);
+            }
+        }
+    }
+}
+
+fn path_to(graph: &PropertyGraph, previous: &Previous, end: &SearchState) -> Path {
+    let name = |node: &NodeId| graph.node_name(node).cloned().unwrap_or(node.to_string());
+    let mut steps = Vec::new();
+    let mut current = end;
+    while let Some((prev, step)) = previous.get(current) {
+        if let Some(step) = step {
+            steps.push((step.clone(), name(&current.0)));
+        }
+        current = prev;
+    }
+    steps.reverse();
+    Path {
+        start: name(&current.0),
+        steps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::record::Record;
+
+    fn graph() -> PropertyGraph {
+        let mut graph = PropertyGraph::new();
+        let nodes = [
+            ("alice", "Employee"),
+            ("sales", "Department"),
+            ("acme", "Company"),
+            ("bob", "Employee"),
+        ];
+        for (name, label) in nodes {
+            graph
+                .add_node(
+                    name.to_string(),
+                    HashSet::from([label.to_string()]),
+                    Record::new(),
+                )
+                .unwrap();
+        }
+        let edges = [
+            ("e1", "alice", "worksFor", "sales"),
+            ("e2", "sales", "partOf", "acme"),
+            ("e3", "bob", "knows", "alice"),
+        ];
+        for (name, source, label, target) in edges {
+            graph
+                .add_edge(
+                    name.to_string(),
+                    source.to_string(),
+                    HashSet::from([label.to_string()]),
+                    Record::new(),
+                    target.to_string(),
+                )
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_reaches_seq_star() {
+        let graph = graph();
+        let alice = graph.get_node_id("alice").unwrap();
+        let expr = PathExpr::seq(
+            PathExpr::label("worksFor"),
+            PathExpr::star(PathExpr::label("partOf")),
+        );
+        let path = expr.reaches(&graph, &alice, "Company").unwrap();
+        assert_eq!(
+            path.to_string(),
+            "alice -[worksFor]-> sales -[partOf]-> acme"
+        );
+        assert!(expr.reaches(&graph, &alice, "Department").is_ok());
+    }
+
+    #[test]
+    fn test_reaches_partial_path() {
+        let graph = graph();
+        let bob = graph.get_node_id("bob").unwrap();
+        let expr = PathExpr::seq(
+            PathExpr::label("knows"),
+            PathExpr::plus(PathExpr::label("partOf")),
+        );
+        let partial = expr.reaches(&graph, &bob, "Company").unwrap_err();
+        assert_eq!(partial.to_string(), "bob -[knows]-> alice");
+        let inverse = PathExpr::seq(PathExpr::label("knows"), PathExpr::inverse("knows"));
+        assert_eq!(
+            inverse
+                .reaches(&graph, &bob, "Employee")
+                .unwrap()
+                .to_string(),
+            "bob -[knows]-> alice <-[knows]- bob"
+        );
+    }
+}
@@ -17,6 +17,12 @@ pub struct PropertyGraph {
     edges: HashMap<EdgeId, Edge>,
     node_names: HashMap<String, NodeId>,
     edge_names: HashMap<String, EdgeId>,
+    node_id_names: HashMap<NodeId, String>,
+    edge_id_names: HashMap<EdgeId, String>,
+    outgoing: HashMap<NodeId, Vec<EdgeId>>,
+    incoming: HashMap<NodeId, Vec<EdgeId>>,
+    node_labels: HashMap<LabelName, Vec<NodeId>>,
+    edge_labels: HashMap<LabelName, Vec<EdgeId>>,
     node_id_counter: usize,
     edge_id_counter: usize,
 }
@@ -29,6 +35,12 @@ impl PropertyGraph {
             edges: HashMap::new(),
             node_names: HashMap::new(),
             edge_names: HashMap::new(),
+            node_id_names: HashMap::new(),
+            edge_id_names: HashMap::new(),
+            outgoing: HashMap::new(),
+            incoming: HashMap::new(),
+            node_labels: HashMap::new(),
+            edge_labels: HashMap::new(),
             node_id_counter: 0,
             edge_id_counter: 0,
         }
@@ -80,25 +92,127 @@ impl PropertyGraph {
         })
     }
 
+    /// Iterates over the nodes of the graph together with their names
+    pub fn nodes(&self) -> impl Iterator<Item = (&String, &Node)> {
+        self.node_names
+            .iter()
+            .filter_map(|(name, id)| self.nodes.get(id).map(|node| (name, node)))
+    }
+
+    /// Iterates over the edges of the graph together with their names
+    pub fn edges(&self) -> impl Iterator<Item = (&String, &Edge)> {
+        self.edge_names
+            .iter()
+            .filter_map(|(name, id)| self.edges.get(id).map(|edge| (name, edge)))
+    }
+
+    /// Node with some id
+    pub fn node(&self, id: &NodeId) -> Option<&Node> {
+        self.nodes.get(id)
+    }
+
+    /// Name of the node with some id
+    pub fn node_name(&self, id: &NodeId) -> Option<&String> {
+        self.node_id_names.get(id)
+    }
+
+    /// Name of the edge with some id
+    pub fn edge_name(&self, id: &EdgeId) -> Option<&String> {
+        self.edge_id_names.get(id)
+    }
+
     pub fn with_nodes(mut self, nodes: HashMap<NodeId, Node>) -> Self {
         self.nodes = nodes;
+        self.node_labels.clear();
+        for (id, node) in &self.nodes {
+            index_labels(&mut self.node_labels, id, node.labels());
+        }
         self
     }
 
     pub fn with_edges(mut self, edges: HashMap<EdgeId, Edge>) -> Self {
         self.edges = edges;
+        self.outgoing.clear();
+        self.incoming.clear();
+        self.edge_labels.clear();
+        for (id, edge) in &self.edges {
+            index_edge(&mut self.outgoing, &mut self.incoming, id, edge);
+            index_labels(&mut self.edge_labels, id, edge.labels());
+        }
         self
     }
 
-    /// Adds a node to the PropertyGraph.
-    pub fn add_node(&mut self, name_id: String, labels: HashSet<LabelName>, record: Record) {
+    /// Nodes which have the label
+    pub fn nodes_with_label(&self, label: &str) -> impl Iterator<Item = &Node> {
+        self.node_labels
+            .get(label)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.nodes.get(id))
+    }
+
+    /// Edges which have the label
+    pub fn edges_with_label(&self, label: &str) -> impl Iterator<Item = &Edge> {
+        self.edge_labels
+            .get(label)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.edges.get(id))
+    }
+
+    /// Edges whose source is the node
+    pub fn out_edges(&self, node: &NodeId) -> impl Iterator<Item = &Edge> {
+        self.adjacent_edges(&self.outgoing, node)
+    }
+
+    /// Edges whose target is the node
+    pub fn in_edges(&self, node: &NodeId) -> impl Iterator<Item = &Edge> {
+        self.adjacent_edges(&self.incoming, node)
+    }
+
+    /// Targets of the outgoing edges of the node that have the label
+    pub fn neighbors<'a>(
+        &'a self,
+        node: &'a NodeId,
+        label: &'a str,
+    ) -> impl Iterator<Item = &'a NodeId> + 'a {
+        self.out_edges(node)
+            .filter(move |edge| edge.labels().contains(label))
+            .map(|edge| &edge.target)
+    }
+
+    fn adjacent_edges<'a>(
+        &'a self,
+        index: &'a HashMap<NodeId, Vec<EdgeId>>,
+        node: &NodeId,
+    ) -> impl Iterator<Item = &'a Edge> + 'a {
+        index
+            .get(node)
+            .into_iter()
+            .flatten()
+            .filter_map(|edge_id| self.edges.get(edge_id))
+    }
+
+    /// Adds a node to the PropertyGraph, failing if there is already a node with that name.
+    pub fn add_node(
+        &mut self,
+        name_id: String,
+        labels: HashSet<LabelName>,
+        record: Record,
+    ) -> Result<(), PgsError> {
+        if self.node_names.contains_key(&name_id) {
+            return Err(PgsError::DuplicateNodeName { name: name_id });
+        }
         let id = NodeId::new(self.node_id_counter);
         self.node_id_counter += 1;
-        self.node_names.insert(name_id, id.clone());
+        self.node_names.insert(name_id.clone(), id.clone());
+        self.node_id_names.insert(id.clone(), name_id);
+        index_labels(&mut self.node_labels, &id, &labels);
         let node = Node::new(id.clone())
             .with_labels(labels)
             .with_content(&record);
         self.nodes.insert(id, node);
+        Ok(())
     }
 
     pub fn get_node_id(&self, label: &str) -> Result<NodeId, PgsError> {
@@ -110,7 +224,7 @@ impl PropertyGraph {
             })
     }
 
-    /// Adds an edge to the PropertyGraph.
+    /// Adds an edge to the PropertyGraph, failing if there is already an edge with that name.
     pub fn add_edge(
         &mut self,
         name_id: String,
@@ -119,9 +233,11 @@ impl PropertyGraph {
         record: Record,
         target: String,
     ) -> Result<(), PgsError> {
+        if self.edge_names.contains_key(&name_id) {
+            return Err(PgsError::DuplicateEdgeName { name: name_id });
+        }
         let id = EdgeId::new(self.edge_id_counter);
         self.edge_id_counter += 1;
-        self.edge_names.insert(name_id, id.clone());
         let source_id = self.get_node_id(&source)?;
         let target_id = self.get_node_id(&target)?;
         let edge = Edge {
@@ -131,33 +247,176 @@ impl PropertyGraph {
             properties: record,
             target: target_id,
         };
+        self.edge_names.insert(name_id.clone(), id.clone());
+        self.edge_id_names.insert(id.clone(), name_id);
+        index_edge(&mut self.outgoing, &mut self.incoming, &id, &edge);
+        index_labels(&mut self.edge_labels, &id, edge.labels());
         self.edges.insert(id, edge);
         Ok(())
     }
 }
 
+/// Completes the names of the elements that don't have one with `{prefix}1`, `{prefix}2`, ...
+/// skipping the names that are already given to other elements
+pub fn complete_names(names: Vec<Option<String>>, prefix: &str) -> Vec<String> {
+    let taken = names.iter().flatten().cloned().collect::<HashSet<_>>();
+    let mut counter = 0;
+    names
+        .into_iter()
+        .map(|name| {
+            name.unwrap_or_else(|| {
+                loop {
+                    counter += 1;
+                    let name = format!("{}{}", prefix, counter);
+                    if !taken.contains(&name) {
+                        break name;
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+fn index_edge(
+    outgoing: &mut HashMap<NodeId, Vec<EdgeId>>,
+    incoming: &mut HashMap<NodeId, Vec<EdgeId>>,
+    id: &EdgeId,
+    edge: &Edge,
+) {
+    outgoing
+        .entry(edge.source.clone())
+        .or_default()
+        .push(id.clone());
+    incoming
+        .entry(edge.target.clone())
+        .or_default()
+        .push(id.clone());
+}
+
+fn index_labels<Id: Clone>(
+    index: &mut HashMap<LabelName, Vec<Id>>,
+    id: &Id,
+    labels: &HashSet<LabelName>,
+) {
+    for label in labels {
+        index.entry(label.clone()).or_default().push(id.clone());
+    }
+}
+
 impl Display for PropertyGraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (node_id, node) in self.nodes.iter() {
             let node_id_str = node_id.to_string();
-            let node_label = self
-                .node_names
-                .iter()
-                .find(|(_, id)| *id == node_id)
-                .map(|(label, _)| label)
-                .unwrap_or(&node_id_str);
+            let node_label = self.node_name(node_id).unwrap_or(&node_id_str);
             writeln!(f, "Node {}: {}", node_label, node)?;
         }
         for (edge_id, edge) in self.edges.iter() {
             let edge_id_str = edge_id.to_string();
-            let edge_label = self
-                .edge_names
-                .iter()
-                .find(|(_, id)| *id == edge_id)
-                .map(|(label, _)| label)
-                .unwrap_or(&edge_id_str);
+            let edge_label = self.edge_name(edge_id).unwrap_or(&edge_id_str);
             writeln!(f, "Edge {}: {}", edge_label, edge)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> PropertyGraph {
+        let mut graph = PropertyGraph::new();
+        for name in ["alice", "bob", "carol"] {
+            graph
+                .add_node(name.to_string(), HashSet::new(), Record::new())
+                .unwrap();
+        }
+        let knows = HashSet::from(["knows".to_string()]);
+        let likes = HashSet::from(["likes".to_string()]);
+        let edges = [
+            ("e1", "alice", &knows, "bob"),
+            ("e2", "alice", &likes, "carol"),
+            ("e3", "bob", &knows, "carol"),
+        ];
+        for (name, source, labels, target) in edges {
+            graph
+                .add_edge(
+                    name.to_string(),
+                    source.to_string(),
+                    labels.clone(),
+                    Record::new(),
+                    target.to_string(),
+                )
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_out_in_edges() {
+        let graph = graph();
+        let alice = graph.get_node_id("alice").unwrap();
+        let carol = graph.get_node_id("carol").unwrap();
+        assert_eq!(graph.out_edges(&alice).count(), 2);
+        assert_eq!(graph.in_edges(&alice).count(), 0);
+        assert_eq!(graph.in_edges(&carol).count(), 2);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let graph = graph();
+        let alice = graph.get_node_id("alice").unwrap();
+        let bob = graph.get_node_id("bob").unwrap();
+        let neighbors: Vec<_> = graph.neighbors(&alice, "knows").collect();
+        assert_eq!(neighbors, vec![&bob]);
+    }
+
+    #[test]
+    fn test_with_label() {
+        let mut graph = graph();
+        graph
+            .add_node(
+                "dave".to_string(),
+                HashSet::from(["Person".to_string(), "Student".to_string()]),
+                Record::new(),
+            )
+            .unwrap();
+        let dave = graph.get_node_id("dave").unwrap();
+        let students: Vec<_> = graph.nodes_with_label("Student").map(|n| &n.id).collect();
+        assert_eq!(students, vec![&dave]);
+        assert_eq!(graph.nodes_with_label("Other").count(), 0);
+        assert_eq!(graph.edges_with_label("knows").count(), 2);
+        assert_eq!(graph.node_name(&dave), Some(&"dave".to_string()));
+    }
+
+    #[test]
+    fn test_complete_names() {
+        let names = vec![Some("e2".to_string()), None, None, Some("e1".to_string())];
+        assert_eq!(complete_names(names, "e"), vec!["e2", "e3", "e4", "e1"]);
+    }
+
+    #[test]
+    fn test_duplicate_names() {
+        let mut graph = graph();
+        let person = HashSet::from(["Person".to_string()]);
+        assert_eq!(
+            graph.add_node("alice".to_string(), person, Record::new()),
+            Err(PgsError::DuplicateNodeName {
+                name: "alice".to_string()
+            })
+        );
+        assert_eq!(graph.nodes_with_label("Person").count(), 0);
+        assert!(
+            graph
+                .add_edge(
+                    "e1".to_string(),
+                    "bob".to_string(),
+                    HashSet::from(["likes".to_string()]),
+                    Record::new(),
+                    "alice".to_string(),
+                )
+                .is_err()
+        );
+        assert_eq!(graph.edges_with_label("likes").count(), 1);
+        assert_eq!(graph.edges().count(), 3);
+    }
+}
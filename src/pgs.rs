@@ -1,11 +1,16 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Display,
+};
 
 use either::Either;
+use itertools::Itertools;
 
 use crate::{
     edge::Edge, edge_id::EdgeId, edge_type::EdgeType, evidence::Evidence,
-    label_property_spec::LabelPropertySpec, node::Node, node_id::NodeId, pgs_error::PgsError,
-    type_name::TypeName,
+    formal_base_type::FormalBaseType, graph_constraint::GraphConstraint,
+    label_property_spec::LabelPropertySpec, node::Node, node_id::NodeId, pg::PropertyGraph,
+    pgs_error::PgsError, type_name::TypeName,
 };
 
 /// Simple representation of a property graph
@@ -15,6 +20,18 @@ pub struct PropertyGraphSchema {
     edge_types: HashMap<EdgeId, EdgeType>,
     node_names: HashMap<String, NodeId>,
     edge_names: HashMap<String, EdgeId>,
+    /// Names of the node types that each name qualified by namespaces can refer to,
+    /// as PersonType and hr::PersonType refer to crm::hr::PersonType
+    node_suffixes: HashMap<String, BTreeSet<TypeName>>,
+    /// Names of the edge types that each name qualified by namespaces can refer to
+    edge_suffixes: HashMap<String, BTreeSet<TypeName>>,
+    /// Node types declared without name, whose names are their numbers
+    blank_node_types: HashSet<NodeId>,
+    /// Edge types declared without name, whose names are their numbers
+    blank_edge_types: HashSet<EdgeId>,
+    constraints: Vec<GraphConstraint>,
+    abstract_types: HashSet<TypeName>,
+    extends: HashMap<TypeName, Vec<TypeName>>,
     node_types_id_counter: usize,
     edge_id_counter: usize,
 }
@@ -26,33 +43,53 @@ impl PropertyGraphSchema {
             edge_types: HashMap::new(),
             node_names: HashMap::new(),
             edge_names: HashMap::new(),
+            node_suffixes: HashMap::new(),
+            edge_suffixes: HashMap::new(),
+            blank_node_types: HashSet::new(),
+            blank_edge_types: HashSet::new(),
+            constraints: Vec::new(),
+            abstract_types: HashSet::new(),
+            extends: HashMap::new(),
             node_types_id_counter: 0,
             edge_id_counter: 0,
         }
     }
 
     pub fn get_node_semantics(&self, type_name: &str) -> Result<&LabelPropertySpec, PgsError> {
-        let node_id = self
-            .node_names
-            .get(type_name)
-            .ok_or(PgsError::MissingNodeLabel {
-                label: type_name.to_string(),
-            })?;
+        let type_name = self.resolve_node_type_name(type_name)?;
+        let node_id = &self.node_names[&type_name];
         self.node_types
-            .get(&node_id)
-            .ok_or(PgsError::MissingType(type_name.to_string()))
+            .get(node_id)
+            .ok_or(PgsError::MissingType(type_name))
     }
 
     pub fn get_edge_semantics(&self, type_name: &str) -> Result<&EdgeType, PgsError> {
-        let edge_id = self
-            .edge_names
-            .get(type_name)
-            .ok_or(PgsError::MissingEdgeLabel {
-                label: type_name.to_string(),
-            })?;
+        let type_name = self.resolve_edge_type_name(type_name)?;
+        let edge_id = &self.edge_names[&type_name];
         self.edge_types
-            .get(&edge_id)
-            .ok_or(PgsError::MissingType(type_name.to_string()))
+            .get(edge_id)
+            .ok_or(PgsError::MissingType(type_name))
+    }
+
+    /// Declared name of the node type that a type name refers to.
+    ///
+    /// Names which are not declared can refer to a type imported in a namespace,
+    /// as PersonType refers to hr::PersonType when no other namespace declares PersonType
+    pub fn resolve_node_type_name(&self, type_name: &str) -> Result<TypeName, PgsError> {
+        resolve_name(type_name, &self.node_names, &self.node_suffixes)?.ok_or(
+            PgsError::MissingNodeLabel {
+                label: type_name.to_string(),
+            },
+        )
+    }
+
+    /// Declared name of the edge type that a type name refers to
+    pub fn resolve_edge_type_name(&self, type_name: &str) -> Result<TypeName, PgsError> {
+        resolve_name(type_name, &self.edge_names, &self.edge_suffixes)?.ok_or(
+            PgsError::MissingEdgeLabel {
+                label: type_name.to_string(),
+            },
+        )
     }
 
     /*pub fn get_semantics_by_label(
@@ -88,16 +125,24 @@ impl PropertyGraphSchema {
         spec: LabelPropertySpec,
     ) -> Result<NodeId, PgsError> {
         let node_id = NodeId::new(self.node_types_id_counter);
+        if self.node_names.contains_key(type_name) {
+            return Err(PgsError::DuplicateNodeTypeName {
+                type_name: type_name.to_string(),
+            });
+        }
         self.node_types.insert(node_id.clone(), spec);
         self.node_names
             .insert(type_name.to_string(), node_id.clone());
+        index_suffixes(&mut self.node_suffixes, type_name);
         self.node_types_id_counter += 1;
         Ok(node_id)
     }
 
     pub fn add_blank_node_spec(&mut self, spec: LabelPropertySpec) -> Result<NodeId, PgsError> {
         let type_name = format!("{}", self.node_types_id_counter);
-        self.add_node_spec(type_name.as_str(), spec)
+        let node_id = self.add_node_spec(type_name.as_str(), spec)?;
+        self.blank_node_types.insert(node_id.clone());
+        Ok(node_id)
     }
 
     pub fn add_blank_edge_spec(
@@ -107,7 +152,9 @@ impl PropertyGraphSchema {
         target: LabelPropertySpec,
     ) -> Result<EdgeId, PgsError> {
         let type_name = format!("{}", self.edge_id_counter);
-        self.add_edge_spec(type_name.as_str(), source, edge, target)
+        let edge_id = self.add_edge_spec(type_name.as_str(), source, edge, target)?;
+        self.blank_edge_types.insert(edge_id.clone());
+        Ok(edge_id)
     }
 
     pub fn add_edge_spec(
@@ -127,53 +174,448 @@ impl PropertyGraphSchema {
             .insert(edge_id.clone(), EdgeType::new(source, edge, target));
         self.edge_names
             .insert(type_name.to_string(), edge_id.clone());
+        index_suffixes(&mut self.edge_suffixes, type_name);
         self.edge_id_counter += 1;
         Ok(edge_id)
     }
 
+    pub fn add_constraint(&mut self, constraint: GraphConstraint) -> Result<(), PgsError> {
+        if self
+            .constraints
+            .iter()
+            .any(|c| c.name() == constraint.name())
+        {
+            return Err(PgsError::DuplicateConstraintName {
+                name: constraint.name().to_string(),
+            });
+        }
+        self.constraints.push(constraint);
+        Ok(())
+    }
+
+    /// Adds the types and constraints of an imported schema.
+    ///
+    /// Types that were already imported through another path are skipped
+    /// if they have the same definition.
+    pub fn add_schema(&mut self, other: &PropertyGraphSchema) -> Result<(), PgsError> {
+        for (type_name, node_id) in other.node_names.iter().sorted_by_key(|(_, id)| id.id) {
+            let spec = other.node_types[node_id].clone();
+            if other.blank_node_types.contains(node_id) {
+                self.add_blank_node_spec(spec)?;
+            } else if let Some(existing) = self.node_names.get(type_name) {
+                if self.node_types[existing] != spec {
+                    return Err(PgsError::ConflictingImportedType {
+                        type_name: type_name.clone(),
+                    });
+                }
+            } else {
+                self.add_node_spec(type_name, spec)?;
+            }
+        }
+        for (type_name, edge_id) in other.edge_names.iter().sorted_by_key(|(_, id)| id.id) {
+            let edge_type = other.edge_types[edge_id].clone();
+            if other.blank_edge_types.contains(edge_id) {
+                self.add_blank_edge_spec(edge_type.source, edge_type.edge, edge_type.target)?;
+            } else if let Some(existing) = self.edge_names.get(type_name) {
+                if self.edge_types[existing] != edge_type {
+                    return Err(PgsError::ConflictingImportedType {
+                        type_name: type_name.clone(),
+                    });
+                }
+            } else {
+                self.add_edge_spec(
+                    type_name,
+                    edge_type.source,
+                    edge_type.edge,
+                    edge_type.target,
+                )?;
+            }
+        }
+        self.abstract_types
+            .extend(other.abstract_types.iter().cloned());
+        for (type_name, parents) in &other.extends {
+            self.add_extends(type_name, parents.clone());
+        }
+        for constraint in &other.constraints {
+            if !self.constraints.contains(constraint) {
+                self.add_constraint(constraint.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy of the schema where the names of types and constraints are qualified by a namespace,
+    /// which is how schemas imported with an alias are added
+    pub fn qualified(&self, namespace: &str) -> PropertyGraphSchema {
+        let qualify = |type_name: &str| match self.resolve_node_type_name(type_name) {
+            Ok(resolved) if !self.is_blank_node_type(&resolved) => {
+                format!("{}::{}", namespace, resolved)
+            }
+            _ => type_name.to_string(),
+        };
+        let qualify_edge = |type_name: &String| {
+            if self.is_blank_edge_type(type_name) {
+                type_name.clone()
+            } else {
+                format!("{}::{}", namespace, type_name)
+            }
+        };
+        let node_names: HashMap<String, NodeId> = self
+            .node_names
+            .iter()
+            .map(|(type_name, id)| (qualify(type_name), id.clone()))
+            .collect();
+        let edge_names: HashMap<String, EdgeId> = self
+            .edge_names
+            .iter()
+            .map(|(type_name, id)| (qualify_edge(type_name), id.clone()))
+            .collect();
+        let mut node_suffixes = HashMap::new();
+        for type_name in node_names.keys() {
+            index_suffixes(&mut node_suffixes, type_name);
+        }
+        let mut edge_suffixes = HashMap::new();
+        for type_name in edge_names.keys() {
+            index_suffixes(&mut edge_suffixes, type_name);
+        }
+        PropertyGraphSchema {
+            node_types: self
+                .node_types
+                .iter()
+                .map(|(id, spec)| (id.clone(), spec.map_refs(&qualify)))
+                .collect(),
+            edge_types: self
+                .edge_types
+                .iter()
+                .map(|(id, edge_type)| (id.clone(), edge_type.map_refs(&qualify)))
+                .collect(),
+            node_names,
+            edge_names,
+            node_suffixes,
+            edge_suffixes,
+            constraints: self
+                .constraints
+                .iter()
+                .map(|constraint| constraint.qualified(namespace, &qualify))
+                .collect(),
+            abstract_types: self
+                .abstract_types
+                .iter()
+                .map(|type_name| qualify(type_name))
+                .collect(),
+            extends: self
+                .extends
+                .iter()
+                .map(|(type_name, parents)| {
+                    let parents = parents.iter().map(|parent| qualify(parent)).collect();
+                    (qualify(type_name), parents)
+                })
+                .collect(),
+            blank_node_types: self.blank_node_types.clone(),
+            blank_edge_types: self.blank_edge_types.clone(),
+            node_types_id_counter: self.node_types_id_counter,
+            edge_id_counter: self.edge_id_counter,
+        }
+    }
+
+    fn is_blank_node_type(&self, type_name: &str) -> bool {
+        self.node_names
+            .get(type_name)
+            .is_some_and(|id| self.blank_node_types.contains(id))
+    }
+
+    fn is_blank_edge_type(&self, type_name: &str) -> bool {
+        self.edge_names
+            .get(type_name)
+            .is_some_and(|id| self.blank_edge_types.contains(id))
+    }
+
+    /// Declares that a node type can't be associated directly with nodes
+    pub fn add_abstract_type(&mut self, type_name: &str) {
+        self.abstract_types.insert(type_name.to_string());
+    }
+
+    pub fn is_abstract(&self, type_name: &str) -> bool {
+        self.abstract_types.contains(type_name)
+    }
+
+    /// Declares the node types that a node type extends
+    pub fn add_extends(&mut self, type_name: &str, parents: Vec<TypeName>) {
+        self.extends.insert(type_name.to_string(), parents);
+    }
+
+    /// Node types that a node type extends
+    pub fn parents(&self, type_name: &str) -> &[TypeName] {
+        self.extends
+            .get(type_name)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Node types that extend a node type directly or through other types, sorted.
+    ///
+    /// The parents are compared by their declared names, as `resolve_references` leaves them
+    pub fn subtypes(&self, type_name: &str) -> Vec<TypeName> {
+        let mut subtypes = Vec::new();
+        let mut pending = vec![type_name.to_string()];
+        while let Some(parent) = pending.pop() {
+            for (child, parents) in &self.extends {
+                if parents.contains(&parent) && child != type_name && !subtypes.contains(child) {
+                    subtypes.push(child.clone());
+                    pending.push(child.clone());
+                }
+            }
+        }
+        subtypes.sort();
+        subtypes
+    }
+
+    /// Replaces the names of the node types referenced by node and edge types, `EXTENDS` and
+    /// `REFERENCES` constraints with their declared names, so unknown or ambiguous names are
+    /// reported with the schema instead of when a graph is validated
+    pub fn resolve_references(&mut self) -> Result<(), PgsError> {
+        let node_refs = self.node_names.iter().flat_map(|(type_name, id)| {
+            self.node_types[id]
+                .refs()
+                .into_iter()
+                .map(move |reference| (type_name, reference))
+        });
+        let edge_refs = self.edge_names.iter().flat_map(|(type_name, id)| {
+            self.edge_types[id]
+                .refs()
+                .into_iter()
+                .map(move |reference| (type_name, reference))
+        });
+        let extends_refs = self
+            .extends
+            .iter()
+            .flat_map(|(type_name, parents)| parents.iter().map(move |parent| (type_name, parent)));
+        let constraint_refs = self.constraints.iter().flat_map(|constraint| {
+            constraint
+                .type_ref()
+                .map(|reference| (constraint.name(), reference))
+        });
+        let mut resolved = HashMap::new();
+        for (type_name, reference) in node_refs
+            .chain(edge_refs)
+            .chain(extends_refs)
+            .map(|(type_name, reference)| (type_name.as_str(), reference))
+            .chain(constraint_refs)
+            .sorted()
+        {
+            let declared = resolve_name(reference, &self.node_names, &self.node_suffixes)?
+                .ok_or_else(|| PgsError::UnknownTypeReference {
+                    type_name: type_name.to_string(),
+                    reference: reference.clone(),
+                })?;
+            resolved.insert(reference.clone(), declared);
+        }
+        let resolve = |type_name: &str| {
+            resolved
+                .get(type_name)
+                .cloned()
+                .unwrap_or_else(|| type_name.to_string())
+        };
+        for spec in self.node_types.values_mut() {
+            *spec = spec.map_refs(&resolve);
+        }
+        for edge_type in self.edge_types.values_mut() {
+            *edge_type = edge_type.map_refs(&resolve);
+        }
+        for parents in self.extends.values_mut() {
+            for parent in parents.iter_mut() {
+                *parent = resolve(parent);
+            }
+        }
+        for constraint in self.constraints.iter_mut() {
+            *constraint = constraint.map_refs(&resolve);
+        }
+        Ok(())
+    }
+
+    /// Checks that the node types which extend other types can be built, so conflicting
+    /// properties in the extended types are reported with the schema
+    pub fn check_extends(&self) -> Result<(), PgsError> {
+        for type_name in self.extends.keys().sorted() {
+            self.node_type_semantics(type_name)?;
+        }
+        Ok(())
+    }
+
+    /// Names of the node types declared with a name, sorted
+    pub fn node_type_names(&self) -> Vec<&TypeName> {
+        self.node_names
+            .iter()
+            .filter(|(_, id)| !self.blank_node_types.contains(id))
+            .map(|(name, _)| name)
+            .sorted()
+            .collect()
+    }
+
+    /// Names of the edge types declared with a name, sorted
+    pub fn edge_type_names(&self) -> Vec<&TypeName> {
+        self.edge_names
+            .iter()
+            .filter(|(_, id)| !self.blank_edge_types.contains(id))
+            .map(|(name, _)| name)
+            .sorted()
+            .collect()
+    }
+
+    /// Node types in the order they were added, where types without name have no name
+    pub fn node_types(&self) -> Vec<(Option<&TypeName>, &LabelPropertySpec)> {
+        self.node_names
+            .iter()
+            .sorted_by_key(|(_, id)| id.id)
+            .map(|(name, id)| {
+                let name = (!self.blank_node_types.contains(id)).then_some(name);
+                (name, &self.node_types[id])
+            })
+            .collect()
+    }
+
+    /// Edge types in the order they were added, where types without name have no name
+    pub fn edge_types(&self) -> Vec<(Option<&TypeName>, &EdgeType)> {
+        self.edge_names
+            .iter()
+            .sorted_by_key(|(_, id)| id.id)
+            .map(|(name, id)| {
+                let name = (!self.blank_edge_types.contains(id)).then_some(name);
+                (name, &self.edge_types[id])
+            })
+            .collect()
+    }
+
+    /// Semantics of a node type including the labels and properties of the types it extends
+    pub fn node_type_semantics(&self, type_name: &str) -> Result<FormalBaseType, PgsError> {
+        self.node_type_semantics_visiting(type_name, &mut Vec::new())
+    }
+
+    fn node_type_semantics_visiting(
+        &self,
+        type_name: &str,
+        visiting: &mut Vec<TypeName>,
+    ) -> Result<FormalBaseType, PgsError> {
+        let type_name = self.resolve_node_type_name(type_name)?;
+        let type_name = type_name.as_str();
+        if visiting.iter().any(|visited| visited == type_name) {
+            return Err(PgsError::CyclicExtension {
+                type_name: type_name.to_string(),
+            });
+        }
+        let semantics = self.get_node_semantics(type_name)?.semantics(self)?;
+        let Some(parents) = self.extends.get(type_name) else {
+            return Ok(semantics);
+        };
+        visiting.push(type_name.to_string());
+        let mut parents_semantics: Option<FormalBaseType> = None;
+        for parent in parents {
+            let parent_semantics = self.node_type_semantics_visiting(parent, visiting)?;
+            parents_semantics = Some(match parents_semantics {
+                None => parent_semantics,
+                Some(combined) => combined.try_combine(&parent_semantics)?,
+            });
+        }
+        visiting.pop();
+        Ok(match parents_semantics {
+            None => semantics,
+            Some(parents_semantics) => semantics.extend(&parents_semantics),
+        })
+    }
+
+    pub fn constraints(&self) -> &[GraphConstraint] {
+        &self.constraints
+    }
+
     pub fn conforms_node(
         &self,
         type_name: &TypeName,
         node: &Node,
     ) -> Either<Vec<PgsError>, Vec<Evidence>> {
-        if let Some(node_id) = self.node_names.get(type_name) {
-            if let Some(spec) = self.node_types.get(node_id) {
-                match spec.semantics(&self) {
-                    Ok(semantics) => semantics.conforms(node.labels(), node.content()),
-                    Err(e) => Either::Left(vec![e]),
-                }
-            } else {
-                Either::Left(vec![PgsError::MissingType(type_name.clone())])
+        let type_name = match self.resolve_node_type_name(type_name) {
+            Ok(type_name) => type_name,
+            Err(e) => return Either::Left(vec![e]),
+        };
+        if self.is_abstract(&type_name) {
+            return Either::Left(vec![PgsError::AbstractTypeAssociation { type_name }]);
+        }
+        match self.node_type_semantics(&type_name) {
+            Ok(semantics) => semantics.conforms(node.labels(), node.content()),
+            Err(e) => Either::Left(vec![e]),
+        }
+    }
+
+    /// Checks that a node conforms to a node type or to some concrete type that extends it,
+    /// which is how nodes conform to abstract types
+    pub fn conforms_node_or_subtype(
+        &self,
+        type_name: &TypeName,
+        node: &Node,
+    ) -> Either<Vec<PgsError>, Vec<Evidence>> {
+        let type_name = match self.resolve_node_type_name(type_name) {
+            Ok(type_name) => type_name,
+            Err(e) => return Either::Left(vec![e]),
+        };
+        let mut errors = Vec::new();
+        let candidates = std::iter::once(type_name.clone()).chain(self.subtypes(&type_name));
+        for candidate in candidates.filter(|candidate| !self.is_abstract(candidate)) {
+            match self.conforms_node(&candidate, node) {
+                Either::Right(evidences) => return Either::Right(evidences),
+                Either::Left(es) => errors.extend(es),
             }
-        } else {
-            Either::Left(vec![PgsError::MissingNodeLabel {
-                label: type_name.to_string(),
-            }])
         }
+        if errors.is_empty() {
+            errors.push(PgsError::AbstractTypeAssociation { type_name });
+        }
+        Either::Left(errors)
     }
 
     pub fn conforms_edge(
         &self,
         type_name: &TypeName,
         edge: &Edge,
+        graph: &PropertyGraph,
     ) -> Either<Vec<PgsError>, Vec<Evidence>> {
-        if let Some(edge_id) = self.edge_names.get(type_name) {
-            if let Some(spec) = self.edge_types.get(edge_id) {
-                match spec.semantics(&self) {
-                    Ok(semantics) => semantics.conforms_edge(type_name, edge),
-                    Err(e) => Either::Left(vec![e]),
-                }
-            } else {
-                Either::Left(vec![PgsError::MissingType(type_name.clone())])
-            }
-        } else {
-            Either::Left(vec![PgsError::MissingEdgeLabel {
-                label: type_name.to_string(),
-            }])
+        match self
+            .get_edge_semantics(type_name)
+            .and_then(|spec| spec.semantics(self))
+        {
+            Ok(semantics) => semantics.conforms_edge(type_name, edge, graph),
+            Err(e) => Either::Left(vec![e]),
         }
     }
 }
 
+/// Finds the declared name that a name refers to, either the same name or
+/// the only declared name which qualifies it with some namespace
+fn resolve_name<T>(
+    type_name: &str,
+    names: &HashMap<String, T>,
+    suffixes: &HashMap<String, BTreeSet<TypeName>>,
+) -> Result<Option<TypeName>, PgsError> {
+    if names.contains_key(type_name) {
+        return Ok(Some(type_name.to_string()));
+    }
+    match suffixes.get(type_name) {
+        None => Ok(None),
+        Some(candidates) if candidates.len() == 1 => Ok(candidates.first().cloned()),
+        Some(candidates) => Err(PgsError::AmbiguousTypeName {
+            type_name: type_name.to_string(),
+            candidates: candidates.iter().join(", "),
+        }),
+    }
+}
+
+/// Adds a declared name under each name that refers to it without some of its namespaces
+fn index_suffixes(suffixes: &mut HashMap<String, BTreeSet<TypeName>>, type_name: &str) {
+    for (index, separator) in type_name.match_indices("::") {
+        suffixes
+            .entry(type_name[index + separator.len()..].to_string())
+            .or_default()
+            .insert(type_name.to_string());
+    }
+}
+
 impl Display for PropertyGraphSchema {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Property Graph Schema:")?;
@@ -185,6 +627,140 @@ impl Display for PropertyGraphSchema {
         for (id, edge) in &self.edge_types {
             writeln!(f, "  {}: {}", id, edge)?;
         }
+        writeln!(
+            f,
+            "Abstract Types: {}",
+            self.abstract_types.iter().sorted().join(", ")
+        )?;
+        writeln!(f, "Extends:")?;
+        for (type_name, parents) in self.extends.iter().sorted() {
+            writeln!(f, "  {} EXTENDS {}", type_name, parents.join(", "))?;
+        }
+        writeln!(f, "Constraints:")?;
+        for constraint in &self.constraints {
+            writeln!(f, "  {}", constraint)?;
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{pg_builder::PgBuilder, pgs_builder::PgsBuilder};
+
+    #[test]
+    fn test_conflicting_extends() {
+        let result = PgsBuilder::new().parse_pgs(
+            r#"CREATE ABSTRACT NODE TYPE ( CodedType : Coded { code: STRING } ) ;
+CREATE ABSTRACT NODE TYPE ( NumberedType : Numbered { code: INTEGER } ) ;
+CREATE NODE TYPE ( ProductType EXTENDS CodedType, NumberedType : Product { name: STRING } )"#,
+        );
+        assert!(matches!(
+            result,
+            Err(PgsError::ConflictingPropertyTypes { .. })
+        ));
+    }
+
+    #[test]
+    fn test_conforms_abstract_type() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE ABSTRACT NODE TYPE ( PersonType : Person { name: STRING } ) ;
+CREATE NODE TYPE ( StudentType EXTENDS PersonType : Student { school: STRING } ) ;
+CREATE NODE TYPE ( MentorType : Mentor { mentee: STRING } ) ;
+CREATE CONSTRAINT MenteeRef FOR (:Mentor) mentee REFERENCES @PersonType"#,
+            )
+            .unwrap();
+        assert_eq!(schema.subtypes("PersonType"), vec!["StudentType"]);
+        let graph = PgBuilder::new()
+            .parse_pg(
+                r#"(alice: Person & Student { name: "Alice", school: "MIT" });
+(bob: Mentor { mentee: "alice" })"#,
+            )
+            .unwrap();
+        let alice = graph.get_node_by_label("alice").unwrap();
+        let person = "PersonType".to_string();
+        assert!(schema.conforms_node(&person, alice).is_left());
+        assert!(schema.conforms_node_or_subtype(&person, alice).is_right());
+        let results = schema.constraints()[0].validate(&schema, &graph);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].conforms);
+    }
+
+    #[test]
+    fn test_blank_types() {
+        let mut schema = PropertyGraphSchema::new();
+        schema
+            .add_blank_node_spec(LabelPropertySpec::label("Person".to_string()))
+            .unwrap();
+        schema
+            .add_node_spec("42", LabelPropertySpec::label("Answer".to_string()))
+            .unwrap();
+        assert_eq!(schema.node_type_names(), vec!["42"]);
+        let names = schema
+            .node_types()
+            .into_iter()
+            .map(|(name, _)| name.cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![None, Some("42".to_string())]);
+        let qualified = schema.qualified("ns");
+        assert_eq!(qualified.node_type_names(), vec!["ns::42"]);
+    }
+
+    #[test]
+    fn test_resolve_qualified_names() {
+        let mut schema = PropertyGraphSchema::new();
+        for type_name in ["crm::hr::PersonType", "hr::TeamType", "crm::TeamType"] {
+            schema
+                .add_node_spec(type_name, LabelPropertySpec::label("Label".to_string()))
+                .unwrap();
+        }
+        let qualified = schema.qualified("org");
+        for schema in [&schema, &qualified] {
+            let person = schema.resolve_node_type_name("PersonType").unwrap();
+            assert!(person.ends_with("crm::hr::PersonType"));
+            assert_eq!(schema.resolve_node_type_name("hr::PersonType"), Ok(person));
+            assert!(matches!(
+                schema.resolve_node_type_name("TeamType"),
+                Err(PgsError::AmbiguousTypeName { .. })
+            ));
+            assert!(matches!(
+                schema.resolve_node_type_name("rm::PersonType"),
+                Err(PgsError::MissingNodeLabel { .. })
+            ));
+        }
+        assert_eq!(
+            qualified.resolve_node_type_name("crm::TeamType"),
+            Ok("org::crm::TeamType".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unknown_references() {
+        let schemas = [
+            (
+                "CREATE NODE TYPE ( StudentType EXTENDS PersonType : Student { school: STRING } )",
+                "StudentType",
+            ),
+            (
+                r#"CREATE NODE TYPE ( PersonType : Person { name: STRING } ) ;
+CREATE EDGE TYPE (@PersonType) -[KnowsType : knows]-> (@FriendType)"#,
+                "KnowsType",
+            ),
+            (
+                r#"CREATE NODE TYPE ( MentorType : Mentor { mentee: STRING } ) ;
+CREATE CONSTRAINT MenteeRef FOR (:Mentor) mentee REFERENCES @PersonType"#,
+                "MenteeRef",
+            ),
+        ];
+        for (schema, referrer) in schemas {
+            match PgsBuilder::new().parse_pgs(schema) {
+                Err(PgsError::UnknownTypeReference { type_name, .. }) => {
+                    assert_eq!(type_name, referrer)
+                }
+                other => panic!("Expected unknown reference from {referrer}, found {other:?}"),
+            }
+        }
+    }
+}
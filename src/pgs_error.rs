@@ -19,6 +19,12 @@ pub enum PgsError {
     #[error("Not found edge with label: {label}")]
     MissingEdgeLabel { label: String },
 
+    #[error("Duplicate node name: {name}")]
+    DuplicateNodeName { name: String },
+
+    #[error("Duplicate edge name: {name}")]
+    DuplicateEdgeName { name: String },
+
     #[error("Not found node/edge with label: {label}")]
     MissingNodeEdgeLabel { label: String },
 
@@ -37,6 +43,9 @@ pub enum PgsError {
         value: Value,
     },
 
+    #[error("Value {value} is repeated in a set of values")]
+    DuplicateValue { value: Value },
+
     #[error("Missing keys in record type: {record_type}, keys: {keys:?}")]
     MissingKeys { keys: String, record_type: String },
 
@@ -49,6 +58,30 @@ pub enum PgsError {
     #[error("Parser error parsing property graph: {error}")]
     PGParserError { error: String },
 
+    #[error("Error reading CSV property graph at line {line}: {error}")]
+    CsvError { line: u64, error: String },
+
+    #[error("Error processing GraphML property graph: {error}")]
+    GraphmlError { error: String },
+
+    #[error("Error reading JSON Lines property graph at line {line}: {error}")]
+    JsonlError { line: usize, error: String },
+
+    #[error("JSON value {value} can't be converted to a property value")]
+    JsonValueError { value: String },
+
+    #[error("Error reading {format} property graph: {error}")]
+    GraphFormatError { format: String, error: String },
+
+    #[error("Error reading {format} schema: {error}")]
+    SchemaFormatError { format: String, error: String },
+
+    #[error("Error writing {format} property graph: {error}")]
+    GraphWriteError { format: String, error: String },
+
+    #[error("Error writing {format} schema: {error}")]
+    SchemaWriteError { format: String, error: String },
+
     #[error("Parser error parsing type map: {error}")]
     MapParserError { error: String },
 
@@ -85,9 +118,15 @@ pub enum PgsError {
     #[error("Condition ({condition}) for value: {value}")]
     ConditionFailed { condition: String, value: String },
 
+    #[error("Record condition ({condition}) failed for record: {record}")]
+    RecordConditionFailed { condition: String, record: String },
+
     #[error("Missing association: node {node}, type {type_name}")]
     MissingAssociation { node: String, type_name: String },
 
+    #[error("Duplicate node type name: {type_name}")]
+    DuplicateNodeTypeName { type_name: String },
+
     #[error("Duplicate edge type name: {type_name}")]
     DuplicateEdgeTypeName { type_name: String },
 
@@ -107,6 +146,118 @@ pub enum PgsError {
         node_semantics: String,
     },
 
+    #[error(
+        "Node {node} has {count} {direction} edges with label {edge_label}, expected {expected}"
+    )]
+    DegreeMismatch {
+        node: String,
+        direction: String,
+        edge_label: String,
+        expected: Card,
+        count: usize,
+    },
+
+    #[error("Node {node} has no values for key properties {keys}")]
+    MissingKeyValues { node: String, keys: String },
+
+    #[error("Node {node} has the same values {values} for {keys} as {others}")]
+    DuplicateKeyValues {
+        node: String,
+        keys: String,
+        values: String,
+        others: String,
+    },
+
+    #[error("Node {node} has value {value} for {key} which is not the name of a node")]
+    DanglingReference {
+        node: String,
+        key: String,
+        value: String,
+    },
+
+    #[error(
+        "Node {node} has value {value} for {key} which refers to a node that doesn't conform to {type_name}: {errors}"
+    )]
+    ReferenceTypeMismatch {
+        node: String,
+        key: String,
+        value: String,
+        type_name: String,
+        errors: String,
+    },
+
+    #[error(
+        "Node {node} doesn't reach any node with label {target} via {path_expr}, the longest path found is {partial_path}"
+    )]
+    NotReachable {
+        node: String,
+        target: String,
+        path_expr: String,
+        partial_path: String,
+    },
+
+    #[error(
+        "The {endpoint} node {node} of an edge doesn't conform to the {endpoint} of {type_name}: {errors}"
+    )]
+    EndpointMismatch {
+        type_name: String,
+        endpoint: String,
+        node: String,
+        errors: String,
+    },
+
+    #[error("The {endpoint} node {node} of an edge of {type_name} is not in the graph")]
+    MissingEndpointNode {
+        type_name: String,
+        endpoint: String,
+        node: String,
+    },
+
+    #[error("Property {key} has conflicting types {left} and {right} in the extended types")]
+    ConflictingPropertyTypes {
+        key: String,
+        left: String,
+        right: String,
+    },
+
+    #[error("Type {type_name} is abstract and can't be associated directly with nodes")]
+    AbstractTypeAssociation { type_name: String },
+
+    #[error("Type {type_name} extends itself")]
+    CyclicExtension { type_name: String },
+
+    #[error("Node types declared as {modifier} must have a name")]
+    UnnamedNodeType { modifier: String },
+
+    #[error(
+        "Graph type {type_name} is not supported, declare its types with CREATE NODE TYPE and CREATE EDGE TYPE"
+    )]
+    UnsupportedGraphType { type_name: String },
+
+    #[error("Type {type_name} has different definitions in the imported schemas")]
+    ConflictingImportedType { type_name: String },
+
+    #[error("Circular import: {cycle}")]
+    CircularImport { cycle: String },
+
+    #[error("Imported schema {import} not found, looked up in: {searched}")]
+    ImportNotFound { import: String, searched: String },
+
+    #[error("Type {type_name} refers to {reference} which is not a declared node type")]
+    UnknownTypeReference {
+        type_name: String,
+        reference: String,
+    },
+
+    #[error("Type name {type_name} is ambiguous, it can refer to: {candidates}")]
+    AmbiguousTypeName {
+        type_name: String,
+        candidates: String,
+    },
+
+    #[error("Duplicate constraint name: {name}")]
+    DuplicateConstraintName { name: String },
+
     #[error("Invalid date value: {date}")]
     InvalidDate { date: String, error: String },
 }
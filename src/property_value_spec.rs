@@ -2,6 +2,7 @@ use crate::boolean_expr::BooleanExpr;
 use crate::card::Card;
 use crate::formal_base_type::FormalBaseType;
 use crate::key::Key;
+use crate::record_expr::RecordExpr;
 use crate::record_type::RecordType;
 
 use crate::pgs_error::PgsError;
@@ -43,6 +44,9 @@ pub enum PropertyValue {
     OneOf(Box<PropertyValue>, Box<PropertyValue>),
     Property(Key, TypeSpec),
     OptionalProperty(Key, TypeSpec),
+    Check(RecordExpr),
+    /// Records without properties
+    NoProperties,
     Empty,
 }
 
@@ -63,10 +67,45 @@ impl PropertyValue {
         PropertyValue::OneOf(Box::new(left), Box::new(right))
     }
 
+    pub fn check(expr: RecordExpr) -> Self {
+        PropertyValue::Check(expr)
+    }
+
     pub fn empty() -> Self {
         PropertyValue::Empty
     }
 
+    pub fn no_properties() -> Self {
+        PropertyValue::NoProperties
+    }
+
+    /// Properties which are required when the condition holds.
+    ///
+    /// When the condition doesn't hold, the record must conform to the `else` branch or,
+    /// if there is none, it may contain all the properties of the `then` branch or none of them.
+    /// Without `else` branch this is written as `then | CHECK NOT cond`, so the alternatives of
+    /// the `then` branch are not repeated and nested conditions grow linearly.
+    pub fn if_then_else(
+        cond: RecordExpr,
+        then: PropertyValue,
+        else_: Option<PropertyValue>,
+    ) -> Self {
+        match else_ {
+            Some(else_) => PropertyValue::one_of(
+                PropertyValue::each_of(PropertyValue::check(cond.clone()), then),
+                PropertyValue::each_of(PropertyValue::check(RecordExpr::not_(cond)), else_),
+            ),
+            None => PropertyValue::one_of(then, PropertyValue::check(RecordExpr::not_(cond))),
+        }
+    }
+
+    /// Mutually exclusive properties, so at most one of them can appear
+    pub fn exclusive(properties: Vec<PropertyValue>) -> Self {
+        properties
+            .into_iter()
+            .fold(PropertyValue::no_properties(), PropertyValue::one_of)
+    }
+
     pub fn semantics(&self) -> HashSet<RecordType> {
         match self {
             PropertyValue::EachOf(left, right) => {
@@ -88,6 +127,10 @@ impl PropertyValue {
                 let record = RecordType::new().with_key_value(p.str(), type_spec.to_value_type());
                 HashSet::from_iter(vec![record, RecordType::empty()])
             }
+            PropertyValue::Check(expr) => {
+                HashSet::from_iter(vec![RecordType::new().with_check(expr.clone())])
+            }
+            PropertyValue::NoProperties => HashSet::from_iter(vec![RecordType::empty()]),
             PropertyValue::Empty => HashSet::new(),
         }
     }
@@ -102,6 +145,8 @@ impl Display for PropertyValue {
             PropertyValue::OptionalProperty(key, type_spec) => {
                 write!(f, "{}: {} (optional)", key, type_spec)
             }
+            PropertyValue::Check(expr) => write!(f, "CHECK {}", expr),
+            PropertyValue::NoProperties => write!(f, "NoProperties"),
             PropertyValue::Empty => write!(f, "Empty"),
         }
     }
@@ -175,6 +220,24 @@ impl TypeSpec {
         }
     }
 
+    pub fn list(value_type: ValueType, card: Card) -> Self {
+        TypeSpec {
+            type_def: Type::Type(ValueType::list(value_type, card)),
+        }
+    }
+
+    pub fn set(value_type: ValueType, card: Card) -> Self {
+        TypeSpec {
+            type_def: Type::Type(ValueType::set(value_type, card)),
+        }
+    }
+
+    pub fn record(record_types: Vec<RecordType>, card: Card) -> Self {
+        TypeSpec {
+            type_def: Type::Type(ValueType::record(record_types, card)),
+        }
+    }
+
     pub fn any() -> Self {
         TypeSpec {
             type_def: Type::Type(ValueType::Any),
@@ -235,3 +298,49 @@ impl Type {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record_expr::Operand;
+
+    fn name() -> PropertyValue {
+        PropertyValue::property(Key::new("name"), TypeSpec::string(Card::One))
+    }
+
+    #[test]
+    fn test_empty_semantics() {
+        assert!(PropertyValue::empty().semantics().is_empty());
+        assert!(
+            PropertyValue::each_of(name(), PropertyValue::empty())
+                .semantics()
+                .is_empty()
+        );
+        assert_eq!(
+            PropertyValue::one_of(name(), PropertyValue::empty()).semantics(),
+            name().semantics()
+        );
+    }
+
+    #[test]
+    fn test_no_properties_semantics() {
+        assert_eq!(
+            PropertyValue::no_properties().semantics(),
+            HashSet::from([RecordType::empty()])
+        );
+        assert_eq!(
+            PropertyValue::each_of(name(), PropertyValue::no_properties()).semantics(),
+            name().semantics()
+        );
+    }
+
+    #[test]
+    fn test_nested_if_is_linear() {
+        let mut property_value = name();
+        for i in 0..10 {
+            let cond = RecordExpr::is_null(Operand::property(&format!("p{}", i)));
+            property_value = PropertyValue::if_then_else(cond, property_value, None);
+        }
+        assert_eq!(property_value.semantics().len(), 11);
+    }
+}
@@ -1,13 +1,16 @@
 use crate::key::Key;
 use crate::value::Value;
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::collections::HashSet as Set;
 use std::fmt::Display;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Properties of a node or edge.
+///
+/// Each key keeps the sequence of values in the order they were written, including duplicates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Record {
-    map: HashMap<Key, Set<Value>>,
+    map: BTreeMap<Key, Vec<Value>>,
 }
 
 impl Record {
@@ -16,7 +19,7 @@ impl Record {
     }
     pub fn new() -> Self {
         Record {
-            map: HashMap::new(),
+            map: BTreeMap::new(),
         }
     }
 
@@ -26,18 +29,18 @@ impl Record {
     }
 
     pub fn insert(&mut self, key: Key, value: Value) {
-        self.map.entry(key).or_insert_with(Set::new).insert(value);
+        self.map.entry(key).or_default().push(value);
     }
 
-    pub fn insert_values(&mut self, key: Key, values: Set<Value>) {
-        self.map.entry(key).or_insert_with(Set::new).extend(values);
+    pub fn insert_values(&mut self, key: Key, values: Vec<Value>) {
+        self.map.entry(key).or_default().extend(values);
     }
 
-    pub fn get(&self, key: &Key) -> Option<&Set<Value>> {
+    pub fn get(&self, key: &Key) -> Option<&Vec<Value>> {
         self.map.get(key)
     }
 
-    /*pub fn remove(&mut self, key: &Key) -> Option<Set<Value>> {
+    /*pub fn remove(&mut self, key: &Key) -> Option<Vec<Value>> {
         self.map.remove(key)
     }*/
 
@@ -45,7 +48,11 @@ impl Record {
         let mut combined = Record::new();
         for (key1, values1) in &self.map {
             if let Some(values2) = other.get(key1) {
-                let combined_values = values1.intersection(values2).cloned().collect::<Set<_>>();
+                let combined_values = values1
+                    .iter()
+                    .filter(|v| values2.contains(v))
+                    .cloned()
+                    .collect::<Vec<_>>();
                 combined.insert_values(key1.clone(), combined_values);
             } else {
                 combined.insert_values(key1.clone(), values1.clone());
@@ -64,7 +71,8 @@ impl Record {
         let mut result = Record::new();
         for (key1, values1) in &self.map {
             if let Some(values2) = other.get(key1) {
-                let combined_values = values1.union(values2).cloned().collect::<Set<_>>();
+                let mut combined_values = values1.clone();
+                combined_values.extend(values2.iter().filter(|v| !values1.contains(v)).cloned());
                 result.insert_values(key1.clone(), combined_values);
             } else {
                 result.insert_values(key1.clone(), values1.clone());
@@ -79,7 +87,7 @@ impl Record {
         result
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Set<Value>)> {
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Vec<Value>)> {
         self.map.iter()
     }
 }
@@ -100,17 +108,16 @@ impl Display for Record {
     }
 }
 
-fn show_values(values: &Set<Value>) -> String {
+fn show_values(values: &[Value]) -> String {
     if values.is_empty() {
         return "[]".to_string();
     }
     if values.len() == 1 {
-        return values.iter().next().unwrap().to_string();
+        return values[0].to_string();
     }
-    let sorted_values: Vec<&Value> = values.iter().sorted().collect();
     format!(
         "[{}]",
-        sorted_values
+        values
             .iter()
             .map(|v| v.to_string())
             .collect::<Vec<_>>()
@@ -136,9 +143,9 @@ mod tests {
 
         let expected = Record {
             map: [
-                (Key::new("k1"), Set::from([Value::str("v2")])),
-                (Key::new("k2"), Set::from([Value::str("v3")])),
-                (Key::new("k3"), Set::from([Value::str("v5")])),
+                (Key::new("k1"), vec![Value::str("v2")]),
+                (Key::new("k2"), vec![Value::str("v3")]),
+                (Key::new("k3"), vec![Value::str("v5")]),
             ]
             .iter()
             .cloned()
@@ -165,10 +172,10 @@ mod tests {
             map: [
                 (
                     Key::new("k1"),
-                    Set::from([Value::str("v2"), Value::str("v1"), Value::str("v4")]),
+                    vec![Value::str("v1"), Value::str("v2"), Value::str("v4")],
                 ),
-                (Key::new("k2"), Set::from([Value::str("v3")])),
-                (Key::new("k3"), Set::from([Value::str("v5")])),
+                (Key::new("k2"), vec![Value::str("v3")]),
+                (Key::new("k3"), vec![Value::str("v5")]),
             ]
             .iter()
             .cloned()
@@ -178,4 +185,17 @@ mod tests {
         let result = record1.union(&record2);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_keeps_order_and_duplicates() {
+        let record = Record::new()
+            .with_key_value("k1", Value::int(1))
+            .with_key_value("k1", Value::int(1))
+            .with_key_value("k1", Value::int(0));
+        assert_eq!(
+            record.get(&Key::new("k1")),
+            Some(&vec![Value::int(1), Value::int(1), Value::int(0)])
+        );
+        assert_eq!(record.to_string(), "{k1: [1, 1, 0]}");
+    }
 }
@@ -0,0 +1,187 @@
+use std::fmt::Display;
+
+use crate::{key::Key, pgs_error::PgsError, record::Record, value::Value};
+
+/// Conditions over a whole record, which can relate the values of several properties
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RecordExpr {
+    And(Box<RecordExpr>, Box<RecordExpr>),
+    Or(Box<RecordExpr>, Box<RecordExpr>),
+    Not(Box<RecordExpr>),
+    IsNull(Operand),
+    Compare(Operand, Comparator, Operand),
+}
+
+impl RecordExpr {
+    pub fn and(left: RecordExpr, right: RecordExpr) -> Self {
+        RecordExpr::And(Box::new(left), Box::new(right))
+    }
+
+    pub fn or(left: RecordExpr, right: RecordExpr) -> Self {
+        RecordExpr::Or(Box::new(left), Box::new(right))
+    }
+
+    pub fn not_(expr: RecordExpr) -> Self {
+        RecordExpr::Not(Box::new(expr))
+    }
+
+    pub fn is_null(operand: Operand) -> Self {
+        RecordExpr::IsNull(operand)
+    }
+
+    pub fn compare(left: Operand, comparator: Comparator, right: Operand) -> Self {
+        RecordExpr::Compare(left, comparator, right)
+    }
+
+    /// Evaluates the condition with the values of the record.
+    ///
+    /// A property is null when it is missing or has no values.
+    /// Comparisons with null operands are false and multi-valued
+    /// operands must satisfy the comparison for every pair of values.
+    pub fn check(&self, record: &Record) -> Result<bool, PgsError> {
+        match self {
+            RecordExpr::And(left, right) => Ok(left.check(record)? && right.check(record)?),
+            RecordExpr::Or(left, right) => Ok(left.check(record)? || right.check(record)?),
+            RecordExpr::Not(expr) => Ok(!expr.check(record)?),
+            RecordExpr::IsNull(operand) => Ok(operand.values(record).is_empty()),
+            RecordExpr::Compare(left, comparator, right) => {
+                let left_values = left.values(record);
+                let right_values = right.values(record);
+                if left_values.is_empty() || right_values.is_empty() {
+                    return Ok(false);
+                }
+                for left_value in &left_values {
+                    for right_value in &right_values {
+                        if !comparator.apply(left_value, right_value)? {
+                            return Ok(false);
+                        }
+                    }
+                }
+                Ok(true)
+            }
+        }
+    }
+}
+
+impl Display for RecordExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordExpr::And(left, right) => write!(f, "({} AND {})", left, right),
+            RecordExpr::Or(left, right) => write!(f, "({} OR {})", left, right),
+            RecordExpr::Not(expr) => write!(f, "NOT {}", expr),
+            RecordExpr::IsNull(operand) => write!(f, "{} IS NULL", operand),
+            RecordExpr::Compare(left, comparator, right) => {
+                write!(f, "{} {} {}", left, comparator, right)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Property(Key),
+    Value(Value),
+}
+
+impl Operand {
+    pub fn property(key: &str) -> Self {
+        Operand::Property(Key::new(key))
+    }
+
+    pub fn value(value: Value) -> Self {
+        Operand::Value(value)
+    }
+
+    fn values(&self, record: &Record) -> Vec<Value> {
+        match self {
+            Operand::Property(key) => record.get(key).cloned().unwrap_or_default(),
+            Operand::Value(value) => vec![value.clone()],
+        }
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Property(key) => write!(f, "{}", key),
+            Operand::Value(Value::String(s)) => write!(f, "\"{}\"", s),
+            Operand::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Comparator {
+    Equals,
+    NotEquals,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl Comparator {
+    pub fn apply(&self, left: &Value, right: &Value) -> Result<bool, PgsError> {
+        match self {
+            Comparator::Equals => Ok(left == right),
+            Comparator::NotEquals => Ok(left != right),
+            Comparator::LessThan => left.less_than(right),
+            Comparator::LessThanOrEqual => left.less_than_or_equal(right),
+            Comparator::GreaterThan => left.greater_than(right),
+            Comparator::GreaterThanOrEqual => left.greater_than_or_equal(right),
+        }
+    }
+}
+
+impl Display for Comparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Comparator::Equals => write!(f, "="),
+            Comparator::NotEquals => write!(f, "!="),
+            Comparator::LessThan => write!(f, "<"),
+            Comparator::LessThanOrEqual => write!(f, "<="),
+            Comparator::GreaterThan => write!(f, ">"),
+            Comparator::GreaterThanOrEqual => write!(f, ">="),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_properties() {
+        let expr = RecordExpr::compare(
+            Operand::property("start"),
+            Comparator::LessThanOrEqual,
+            Operand::property("end"),
+        );
+        let ok = Record::new()
+            .with_key_value("start", Value::int(2020))
+            .with_key_value("end", Value::int(2024));
+        let wrong = Record::new()
+            .with_key_value("start", Value::int(2024))
+            .with_key_value("end", Value::int(2020));
+        assert_eq!(expr.check(&ok), Ok(true));
+        assert_eq!(expr.check(&wrong), Ok(false));
+    }
+
+    #[test]
+    fn test_is_null_or_compare() {
+        let expr = RecordExpr::or(
+            RecordExpr::is_null(Operand::property("end")),
+            RecordExpr::compare(
+                Operand::property("end"),
+                Comparator::GreaterThan,
+                Operand::property("start"),
+            ),
+        );
+        let no_end = Record::new().with_key_value("start", Value::int(2020));
+        let wrong = Record::new()
+            .with_key_value("start", Value::int(2020))
+            .with_key_value("end", Value::int(2020));
+        assert_eq!(expr.check(&no_end), Ok(true));
+        assert_eq!(expr.check(&wrong), Ok(false));
+    }
+}
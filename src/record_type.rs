@@ -5,6 +5,7 @@ use crate::evidence::Evidence;
 use crate::key::Key;
 use crate::pgs_error::PgsError;
 use crate::record::Record;
+use crate::record_expr::RecordExpr;
 use crate::value_type::ValueType;
 use itertools::Itertools;
 use std::collections::{BTreeMap, HashSet};
@@ -14,6 +15,7 @@ use std::fmt::Display;
 pub struct RecordType {
     map: BTreeMap<Key, ValueType>,
     open: bool,
+    checks: Vec<RecordExpr>,
 }
 
 impl RecordType {
@@ -24,6 +26,7 @@ impl RecordType {
         RecordType {
             map: BTreeMap::new(),
             open: false,
+            checks: Vec::new(),
         }
     }
 
@@ -32,6 +35,18 @@ impl RecordType {
         self
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &ValueType)> {
+        self.map.iter()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn checks(&self) -> &[RecordExpr] {
+        &self.checks
+    }
+
     /// Creates an empty RecordType.
     pub fn empty() -> Self {
         RecordType::new()
@@ -43,6 +58,12 @@ impl RecordType {
         self
     }
 
+    /// Adds a condition over the whole record.
+    pub fn with_check(mut self, check: RecordExpr) -> Self {
+        self.checks.push(check);
+        self
+    }
+
     /// Checks if the RecordType conforms to the given Record.
     pub fn conforms(&self, record: &Record) -> Either<Vec<PgsError>, Vec<Evidence>> {
         debug!(
@@ -106,9 +127,25 @@ impl RecordType {
                 }
             }
         }
+        for check in &self.checks {
+            match check.check(record) {
+                Ok(true) => continue,
+                Ok(false) => {
+                    debug!("Record condition failed: {check}");
+                    return Left(vec![PgsError::RecordConditionFailed {
+                        condition: check.to_string(),
+                        record: record.to_string(),
+                    }]);
+                }
+                Err(e) => return Left(vec![e]),
+            }
+        }
         Either::Right(vec![])
     }
 
+    /// Record type with the keys of both record types, which is open when some of them is,
+    /// as the labels in `FormalBaseType::combine`, so an `OPEN` content stays open when it
+    /// is combined with the closed empty record of a label
     pub fn combine(&self, other: &RecordType) -> Self {
         let mut result = RecordType::new();
         for (key, value_type) in &self.map {
@@ -125,6 +162,37 @@ impl RecordType {
                 result.map.insert(key.clone(), value_type.clone());
             }
         }
+        result.open = self.open || other.open;
+        result.checks = [self.checks.clone(), other.checks.clone()].concat();
+        result
+    }
+
+    /// Combines the record types of several extended types, which can't declare
+    /// the same key with different value types
+    pub fn try_combine(&self, other: &RecordType) -> Result<Self, PgsError> {
+        for (key, value_type) in &self.map {
+            if let Some(other_value_type) = other.map.get(key)
+                && value_type != other_value_type
+            {
+                return Err(PgsError::ConflictingPropertyTypes {
+                    key: key.to_string(),
+                    left: value_type.to_string(),
+                    right: other_value_type.to_string(),
+                });
+            }
+        }
+        Ok(self.combine(other))
+    }
+
+    /// Record type of a type that extends another, where the keys of self override the keys of the parent
+    pub fn extend(&self, parent: &RecordType) -> Self {
+        let mut result = self.clone();
+        for (key, value_type) in &parent.map {
+            if !self.map.contains_key(key) {
+                result.map.insert(key.clone(), value_type.clone());
+            }
+        }
+        result.checks = [parent.checks.clone(), self.checks.clone()].concat();
         result
     }
 
@@ -141,6 +209,7 @@ impl Display for RecordType {
             .iter()
             .sorted_by_key(|(k, _)| *k)
             .map(|(k, v)| format!("{}: {}", k, v))
+            .chain(self.checks.iter().map(|check| format!("CHECK {}", check)))
             .collect();
         write!(f, "{{{}}}", entries.join(", "))
     }
@@ -221,4 +290,60 @@ mod tests {
             .with_key_value("age", Value::str("Other"));
         assert!(record_type.conforms(&record).is_left());
     }
+
+    #[test]
+    fn test_record_type_nested_record() {
+        let address_type = RecordType::new()
+            .with_key_value("city", ValueType::string(Card::One))
+            .with_key_value("zip", ValueType::string(Card::One));
+        let record_type = RecordType::new()
+            .with_key_value("address", ValueType::record(vec![address_type], Card::One));
+        let address = Record::new()
+            .with_key_value("city", Value::str("Oviedo"))
+            .with_key_value("zip", Value::str("33003"));
+        let record = Record::new().with_key_value("address", Value::record(address));
+        assert!(record_type.conforms(&record).is_right());
+
+        let wrong_address = Record::new().with_key_value("city", Value::str("Oviedo"));
+        let wrong_record = Record::new().with_key_value("address", Value::record(wrong_address));
+        assert!(record_type.conforms(&wrong_record).is_left());
+    }
+
+    #[test]
+    fn test_record_type_extend_overrides() {
+        let parent = RecordType::new()
+            .with_key_value("name", ValueType::string(Card::One))
+            .with_key_value("age", ValueType::string(Card::One));
+        let child = RecordType::new().with_key_value("age", ValueType::integer(Card::One));
+        let extended = child.extend(&parent);
+        let record = Record::new()
+            .with_key_value("name", Value::str("Alice"))
+            .with_key_value("age", Value::int(42));
+        assert!(extended.conforms(&record).is_right());
+    }
+
+    #[test]
+    fn test_record_type_combine_openness() {
+        let name = RecordType::new().with_key_value("name", ValueType::string(Card::One));
+        let record = Record::new()
+            .with_key_value("name", Value::str("Alice"))
+            .with_key_value("age", Value::int(42));
+        let closed = name.combine(&RecordType::empty());
+        assert!(!closed.is_open());
+        assert!(closed.conforms(&record).is_left());
+        let open = name.combine(&RecordType::empty().with_open());
+        assert!(open.is_open());
+        assert!(open.conforms(&record).is_right());
+    }
+
+    #[test]
+    fn test_record_type_try_combine_conflict() {
+        let coded = RecordType::new().with_key_value("code", ValueType::string(Card::One));
+        let numbered = RecordType::new().with_key_value("code", ValueType::integer(Card::One));
+        assert!(coded.try_combine(&coded).is_ok());
+        assert!(matches!(
+            coded.try_combine(&numbered),
+            Err(PgsError::ConflictingPropertyTypes { .. })
+        ));
+    }
 }
@@ -61,7 +61,7 @@ impl TypeMap {
             })?;
             let conforms_result = match either_node_edge {
                 Left(node) => schema.conforms_node(&type_name, node),
-                Right(edge) => schema.conforms_edge(&type_name, edge),
+                Right(edge) => schema.conforms_edge(&type_name, edge, graph),
             };
             // TODO: Handle when should_conform is false
             result.add_association(ResultAssociation {
@@ -71,6 +71,11 @@ impl TypeMap {
                 details: conforms_result,
             });
         }
+        for constraint in schema.constraints() {
+            for association in constraint.validate(schema, graph) {
+                result.add_association(association);
+            }
+        }
         Ok(result) // Assuming validation passes for now
     }
 
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use regex::Regex;
 use time::{Date, macros::format_description};
 
-use crate::pgs_error::PgsError;
+use crate::{pgs_error::PgsError, record::Record};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Value {
@@ -11,6 +11,8 @@ pub enum Value {
     Integer(i32),
     Date(Date), // Simplified for this example
     Bool(bool),
+    /// Nested record, as in `address: { city: "Oviedo", zip: "33003" }`
+    Record(Record),
 }
 
 impl Value {
@@ -35,6 +37,10 @@ impl Value {
         matches!(self, Value::String(_))
     }
 
+    pub fn record(record: Record) -> Self {
+        Value::Record(record)
+    }
+
     pub fn true_() -> Self {
         Value::Bool(true)
     }
@@ -55,6 +61,10 @@ impl Value {
         matches!(self, Value::Bool(_))
     }
 
+    pub fn is_record(&self) -> bool {
+        matches!(self, Value::Record(_))
+    }
+
     pub fn greater_than(&self, other: &Value) -> Result<bool, PgsError> {
         match (self, other) {
             (Value::Integer(i), Value::Integer(v)) => Ok(i > v),
@@ -129,6 +139,7 @@ impl Display for Value {
             Value::Integer(i) => write!(f, "{}", i),
             Value::Date(d) => write!(f, "{}", d),
             Value::Bool(b) => write!(f, "{}", b),
+            Value::Record(r) => write!(f, "{}", r),
         }
     }
 }
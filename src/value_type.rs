@@ -1,9 +1,11 @@
 use std::{collections::HashSet, fmt::Display};
 
 use either::Either;
+use itertools::Itertools;
 
 use crate::{
-    boolean_expr::BooleanExpr, card::Card, evidence::Evidence, pgs_error::PgsError, value::Value,
+    boolean_expr::BooleanExpr, card::Card, evidence::Evidence, pgs_error::PgsError,
+    record_type::RecordType, value::Value,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -13,6 +15,12 @@ pub enum ValueType {
     IntegerType(Card),
     DateType(Card),
     BoolType(Card),
+    /// Ordered sequence of values of some type whose length is constrained by the cardinality
+    List(Box<ValueType>, Card),
+    /// Values of some type without repetitions whose number is constrained by the cardinality
+    Set(Box<ValueType>, Card),
+    /// Nested records which must conform to one of the alternative record types
+    Record(Vec<RecordType>, Card),
     Intersection(Box<ValueType>, Box<ValueType>),
     Union(Box<ValueType>, Box<ValueType>),
     Cond(BooleanExpr),
@@ -33,6 +41,15 @@ impl ValueType {
     pub fn bool(card: Card) -> Self {
         ValueType::BoolType(card)
     }
+    pub fn list(value_type: ValueType, card: Card) -> Self {
+        ValueType::List(Box::new(value_type), card)
+    }
+    pub fn set(value_type: ValueType, card: Card) -> Self {
+        ValueType::Set(Box::new(value_type), card)
+    }
+    pub fn record(record_types: Vec<RecordType>, card: Card) -> Self {
+        ValueType::Record(record_types, card)
+    }
     pub fn intersection(a: ValueType, b: ValueType) -> Self {
         ValueType::Intersection(Box::new(a), Box::new(b))
     }
@@ -42,35 +59,57 @@ impl ValueType {
     pub fn cond(expr: BooleanExpr) -> Self {
         ValueType::Cond(expr)
     }
-    pub fn conforms(&self, values: &HashSet<Value>) -> Either<Vec<PgsError>, Vec<Evidence>> {
+    pub fn conforms(&self, values: &[Value]) -> Either<Vec<PgsError>, Vec<Evidence>> {
         match self {
             ValueType::StringType(card) => {
-                if !card.contains(values.len()) {
-                    return Either::Left(vec![PgsError::CardinalityMismatch {
-                        expected: card.clone(),
-                        count: values.len(),
-                    }]);
+                if let Some(err) = check_card(card, values) {
+                    return Either::Left(vec![err]);
                 }
                 check_all(values, |v| v.is_string(), "is_string")
             }
             ValueType::IntegerType(card) => {
-                if !card.contains(values.len()) {
-                    return Either::Left(vec![PgsError::CardinalityMismatch {
-                        expected: card.clone(),
-                        count: values.len(),
-                    }]);
+                if let Some(err) = check_card(card, values) {
+                    return Either::Left(vec![err]);
                 }
                 check_all(values, |v| v.is_integer(), "is_integer")
             }
             ValueType::DateType(card) => {
-                if !card.contains(values.len()) {
-                    return Either::Left(vec![PgsError::CardinalityMismatch {
-                        expected: card.clone(),
-                        count: values.len(),
-                    }]);
+                if let Some(err) = check_card(card, values) {
+                    return Either::Left(vec![err]);
                 }
                 check_all(values, |v| v.is_date(), "is_date")
             }
+            ValueType::List(value_type, card) => {
+                if let Some(err) = check_card(card, values) {
+                    return Either::Left(vec![err]);
+                }
+                conforms_each(value_type, values)
+            }
+            ValueType::Set(value_type, card) => {
+                if let Some(err) = check_card(card, values) {
+                    return Either::Left(vec![err]);
+                }
+                let mut seen = HashSet::new();
+                if let Some(value) = values.iter().find(|v| !seen.insert(*v)) {
+                    return Either::Left(vec![PgsError::DuplicateValue {
+                        value: value.clone(),
+                    }]);
+                }
+                conforms_each(value_type, values)
+            }
+            ValueType::Record(record_types, card) => {
+                if let Some(err) = check_card(card, values) {
+                    return Either::Left(vec![err]);
+                }
+                let mut evidences = Vec::new();
+                for value in values {
+                    match conforms_record(record_types, value) {
+                        Either::Left(errs) => return Either::Left(errs),
+                        Either::Right(es) => evidences.extend(es),
+                    }
+                }
+                Either::Right(evidences)
+            }
             ValueType::Intersection(a, b) => {
                 let a_evidence = a.conforms(values);
                 let b_evidence = b.conforms(values);
@@ -99,6 +138,17 @@ impl ValueType {
                     }
                 }
             }
+            ValueType::Cond(cond) if cond.is_aggregate() => match cond.check_values(values) {
+                Ok(true) => Either::Right(vec![Evidence::ConditionPassed {
+                    condition: format!("{}", cond),
+                    values: format!("{:?}", values),
+                }]),
+                Ok(false) => Either::Left(vec![PgsError::ConditionFailed {
+                    condition: format!("{}", cond),
+                    value: format!("{:?}", values),
+                }]),
+                Err(e) => Either::Left(vec![e]),
+            },
             ValueType::Cond(cond) => {
                 for value in values {
                     match cond.check(value) {
@@ -121,11 +171,8 @@ impl ValueType {
                 values: format!("{:?}", values),
             }]),
             ValueType::BoolType(card) => {
-                if !card.contains(values.len()) {
-                    return Either::Left(vec![PgsError::CardinalityMismatch {
-                        expected: card.clone(),
-                        count: values.len(),
-                    }]);
+                if let Some(err) = check_card(card, values) {
+                    return Either::Left(vec![err]);
                 }
                 check_all(values, |v| v.is_bool(), "is_bool")
             }
@@ -133,8 +180,53 @@ impl ValueType {
     }
 }
 
+/// Checks the number of values of a multi-valued property, counting the repeated ones
+fn check_card(card: &Card, values: &[Value]) -> Option<PgsError> {
+    if card.contains(values.len()) {
+        None
+    } else {
+        Some(PgsError::CardinalityMismatch {
+            expected: card.clone(),
+            count: values.len(),
+        })
+    }
+}
+
+/// Checks that each of the values of a list or set conforms to the type of its elements
+fn conforms_each(value_type: &ValueType, values: &[Value]) -> Either<Vec<PgsError>, Vec<Evidence>> {
+    let mut evidences = Vec::new();
+    for value in values {
+        match value_type.conforms(std::slice::from_ref(value)) {
+            Either::Left(errs) => return Either::Left(errs),
+            Either::Right(es) => evidences.extend(es),
+        }
+    }
+    Either::Right(evidences)
+}
+
+/// Checks that a value is a record conforming to some of the record types
+fn conforms_record(
+    record_types: &[RecordType],
+    value: &Value,
+) -> Either<Vec<PgsError>, Vec<Evidence>> {
+    let Value::Record(record) = value else {
+        return Either::Left(vec![PgsError::PredicateFailed {
+            predicate_name: "is_record".to_string(),
+            value: value.clone(),
+        }]);
+    };
+    let mut errors = Vec::new();
+    for record_type in record_types {
+        match record_type.conforms(record) {
+            Either::Left(errs) => errors.extend(errs),
+            Either::Right(es) => return Either::Right(es),
+        }
+    }
+    Either::Left(errors)
+}
+
 fn check_all<F>(
-    values: &HashSet<Value>,
+    values: &[Value],
     predicate: F,
     predicate_name: &str,
 ) -> Either<Vec<PgsError>, Vec<Evidence>>
@@ -166,6 +258,14 @@ impl Display for ValueType {
             ValueType::Cond(expr) => write!(f, "Condition({})", expr),
             ValueType::Any => write!(f, "ANY"),
             ValueType::BoolType(card) => write!(f, "Bool({})", card),
+            ValueType::List(value_type, card) => write!(f, "List({}, {})", value_type, card),
+            ValueType::Set(value_type, card) => write!(f, "Set({}, {})", value_type, card),
+            ValueType::Record(record_types, card) => write!(
+                f,
+                "Record({}, {})",
+                record_types.iter().map(|rt| rt.to_string()).join(" | "),
+                card
+            ),
         }
     }
 }
@@ -0,0 +1,278 @@
+use itertools::Itertools;
+
+use crate::{key::Key, pg::PropertyGraph, pgs_error::PgsError, record::Record, value::Value};
+
+/// Serializes property graphs as a Cypher `CREATE` or a GQL `INSERT` statement that
+/// recreates the graph in a database.
+///
+/// Nodes and edges are bound to variables with their names, except the edges whose names
+/// are also names of nodes, which are written without variable. Properties with several
+/// values are written as lists and dates as `date('...')` in Cypher or `DATE '...'` in GQL.
+pub struct CypherWriter {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dialect {
+    Cypher,
+    Gql,
+}
+
+impl Dialect {
+    fn name(&self) -> &'static str {
+        match self {
+            Dialect::Cypher => "Cypher",
+            Dialect::Gql => "GQL",
+        }
+    }
+}
+
+impl Default for CypherWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CypherWriter {
+    pub fn new() -> Self {
+        CypherWriter {}
+    }
+
+    /// Writes a Cypher script, where each edge must have exactly one label as relationship type
+    pub fn write_cypher(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        write_statement(graph, Dialect::Cypher)
+    }
+
+    /// Writes a GQL script, where nested records are written as `RECORD {...}` values
+    pub fn write_gql(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        write_statement(graph, Dialect::Gql)
+    }
+}
+
+fn write_statement(graph: &PropertyGraph, dialect: Dialect) -> Result<String, PgsError> {
+    let mut patterns = Vec::new();
+    for (name, node) in graph.nodes().sorted_by_key(|(_, node)| node.id.id) {
+        patterns.push(format!(
+            "({}{}{})",
+            identifier(name),
+            labels(node.labels().iter(), dialect),
+            properties(node.content(), dialect)?
+        ));
+    }
+    for (name, edge) in graph.edges().sorted_by_key(|(_, edge)| edge.id.id) {
+        if dialect == Dialect::Cypher && edge.labels().len() != 1 {
+            return Err(writer_error(
+                dialect,
+                format!(
+                    "edge {} must have exactly one label as relationship type",
+                    name
+                ),
+            ));
+        }
+        let source = graph.node_name(&edge.source).cloned().unwrap_or_default();
+        let target = graph.node_name(&edge.target).cloned().unwrap_or_default();
+        let variable = if graph.get_node_id(name).is_ok() {
+            String::new()
+        } else {
+            identifier(name)
+        };
+        patterns.push(format!(
+            "({})-[{}{}{}]->({})",
+            identifier(&source),
+            variable,
+            labels(edge.labels().iter(), dialect),
+            properties(edge.content(), dialect)?,
+            identifier(&target)
+        ));
+    }
+    if patterns.is_empty() {
+        return Ok(String::new());
+    }
+    let keyword = match dialect {
+        Dialect::Cypher => "CREATE",
+        Dialect::Gql => "INSERT",
+    };
+    let indent = " ".repeat(keyword.len() + 1);
+    Ok(format!(
+        "{} {};\n",
+        keyword,
+        patterns.join(&format!(",\n{}", indent))
+    ))
+}
+
+/// Labels as `:A:B` in Cypher and as `:A&B` in GQL
+fn labels<'a>(labels: impl Iterator<Item = &'a String>, dialect: Dialect) -> String {
+    let labels = labels.sorted().map(|label| identifier(label)).collect_vec();
+    if labels.is_empty() {
+        return String::new();
+    }
+    match dialect {
+        Dialect::Cypher => format!(":{}", labels.join(":")),
+        Dialect::Gql => format!(":{}", labels.join("&")),
+    }
+}
+
+fn properties(record: &Record, dialect: Dialect) -> Result<String, PgsError> {
+    if record.iter().next().is_none() {
+        return Ok(String::new());
+    }
+    Ok(format!(" {}", map(record, dialect)?))
+}
+
+fn map(record: &Record, dialect: Dialect) -> Result<String, PgsError> {
+    let entries = record
+        .iter()
+        .map(|(key, values)| {
+            let values = values
+                .iter()
+                .map(|value| value_str(key, value, dialect))
+                .collect::<Result<Vec<_>, _>>()?;
+            let values = match values.as_slice() {
+                [value] => value.clone(),
+                _ => format!("[{}]", values.join(", ")),
+            };
+            Ok(format!("{}: {}", identifier(key.str()), values))
+        })
+        .collect::<Result<Vec<_>, PgsError>>()?;
+    Ok(format!("{{{}}}", entries.join(", ")))
+}
+
+fn value_str(key: &Key, value: &Value, dialect: Dialect) -> Result<String, PgsError> {
+    match (value, dialect) {
+        (Value::String(s), _) => Ok(quote(s)),
+        (Value::Integer(i), _) => Ok(i.to_string()),
+        (Value::Bool(b), _) => Ok(b.to_string()),
+        (Value::Date(_), Dialect::Cypher) => Ok(format!("date({})", quote(&value.to_string()))),
+        (Value::Date(_), Dialect::Gql) => Ok(format!("DATE {}", quote(&value.to_string()))),
+        (Value::Record(record), Dialect::Gql) => Ok(format!("RECORD {}", map(record, dialect)?)),
+        (Value::Record(_), Dialect::Cypher) => Err(writer_error(
+            dialect,
+            format!("nested record in property {} can't be written", key),
+        )),
+    }
+}
+
+/// Names that are not plain identifiers are quoted with backticks
+pub(crate) fn identifier(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+    {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn writer_error(dialect: Dialect, error: String) -> PgsError {
+    PgsError::GraphWriteError {
+        format: dialect.name().to_string(),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::parser::{cypher_builder::CypherBuilder, pg_builder::PgBuilder};
+
+    fn graph() -> PropertyGraph {
+        let mut graph = PropertyGraph::new();
+        let alice = Record::new()
+            .with_key_value("name", Value::str("Alice O'Hara"))
+            .with_key_value("born", Value::date("2000-01-31").unwrap())
+            .with_key_value("tags", Value::str("a"))
+            .with_key_value("tags", Value::str("b"));
+        graph
+            .add_node(
+                "alice".to_string(),
+                HashSet::from(["Person".to_string(), "Student".to_string()]),
+                alice,
+            )
+            .unwrap();
+        graph
+            .add_node(
+                "bob `smith`".to_string(),
+                HashSet::from(["Person".to_string()]),
+                Record::new(),
+            )
+            .unwrap();
+        graph
+            .add_edge(
+                "k1".to_string(),
+                "alice".to_string(),
+                HashSet::from(["knows".to_string()]),
+                Record::new().with_key_value("since", Value::int(2020)),
+                "bob `smith`".to_string(),
+            )
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_cypher_round_trip() {
+        let graph = graph();
+        let cypher = CypherWriter::new().write_cypher(&graph).unwrap();
+        let parsed = CypherBuilder::new().parse_cypher(&cypher).unwrap();
+        for name in ["alice", "bob `smith`"] {
+            let expected = graph.get_node_by_label(name).unwrap();
+            let node = parsed.get_node_by_label(name).unwrap();
+            assert_eq!(node.labels(), expected.labels());
+            assert_eq!(node.content(), expected.content());
+        }
+        let edge = parsed.get_edge_by_label("k1").unwrap();
+        assert_eq!(
+            edge.content(),
+            graph.get_edge_by_label("k1").unwrap().content()
+        );
+    }
+
+    #[test]
+    fn test_list_fixture_round_trip() {
+        let graph = PgBuilder::new()
+            .parse_pg(include_str!("../../tests/list.pg"))
+            .unwrap();
+        let cypher = CypherWriter::new().write_cypher(&graph).unwrap();
+        let parsed = CypherBuilder::new().parse_cypher(&cypher).unwrap();
+        for (name, node) in graph.nodes() {
+            let parsed_node = parsed.get_node_by_label(name).unwrap();
+            assert_eq!(parsed_node.content(), node.content(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_edge_named_as_node() {
+        let mut graph = PropertyGraph::new();
+        graph
+            .add_node("e0".to_string(), HashSet::new(), Record::new())
+            .unwrap();
+        graph
+            .add_edge(
+                "e0".to_string(),
+                "e0".to_string(),
+                HashSet::from(["self".to_string()]),
+                Record::new(),
+                "e0".to_string(),
+            )
+            .unwrap();
+        let cypher = CypherWriter::new().write_cypher(&graph).unwrap();
+        assert_eq!(cypher, "CREATE (e0),\n       (e0)-[:self]->(e0);\n");
+        let parsed = CypherBuilder::new().parse_cypher(&cypher).unwrap();
+        assert_eq!(parsed.nodes().count(), 1);
+        assert_eq!(parsed.edges().count(), 1);
+    }
+
+    #[test]
+    fn test_gql() {
+        let gql = CypherWriter::new().write_gql(&graph()).unwrap();
+        assert_eq!(
+            gql,
+            "INSERT (alice:Person&Student {born: DATE '2000-01-31', name: 'Alice O\\'Hara', tags: ['a', 'b']}),\n       \
+             (`bob ``smith```:Person),\n       \
+             (alice)-[k1:knows {since: 2020}]->(`bob ``smith```);\n"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use itertools::Itertools;
+
+use crate::{
+    card::Card,
+    key::Key,
+    label_property_spec::LabelPropertySpec,
+    pgs::PropertyGraphSchema,
+    pgs_error::PgsError,
+    record_type::RecordType,
+    value_type::ValueType,
+    writer::{
+        cypher_writer::identifier,
+        type_content::{TypeContent, record_fields},
+    },
+};
+
+/// Prints schemas as ISO GQL `CREATE GRAPH TYPE` statements, which can be read by `GqlBuilder`.
+///
+/// Node types include the labels and properties of the types they reference or extend, and
+/// properties with several values are written as lists. The constructs that GQL graph types
+/// can't express, like alternatives, checks, open records and graph constraints, are listed
+/// in comments before the statement. The properties of alternatives are written as optional,
+/// so the graph type accepts the properties of every alternative.
+pub struct GqlWriter {}
+
+impl Default for GqlWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GqlWriter {
+    pub fn new() -> Self {
+        GqlWriter {}
+    }
+
+    pub fn write_graph_type(
+        &self,
+        name: &str,
+        schema: &PropertyGraphSchema,
+    ) -> Result<String, PgsError> {
+        let mut notes = Vec::new();
+        let mut elements = Vec::new();
+        for (type_name, spec) in schema.node_types() {
+            let content = match type_name {
+                Some(type_name) => {
+                    if schema.is_abstract(type_name) {
+                        notes.push(format!("{}: abstract type", type_name));
+                    }
+                    TypeContent::node_type(schema, type_name)?
+                }
+                None => TypeContent::spec(schema, "node type", spec)?,
+            };
+            add_notes(&content, &mut notes);
+            let context = type_name.map(|name| name.as_str()).unwrap_or("node type");
+            let filler = filler(&content, context, &mut notes);
+            elements.push(match type_name {
+                Some(type_name) => format!(
+                    "NODE TYPE {} ({}{})",
+                    identifier(type_name),
+                    identifier(type_name),
+                    filler
+                ),
+                None => format!("({})", filler.trim_start()),
+            });
+        }
+        for (type_name, edge_type) in schema.edge_types() {
+            let context = type_name.map(|name| name.as_str()).unwrap_or("edge type");
+            let content = TypeContent::spec(schema, context, &edge_type.edge)?;
+            add_notes(&content, &mut notes);
+            let pattern = format!(
+                "{}-[{}]->{}",
+                endpoint(schema, context, &edge_type.source, &mut notes)?,
+                filler(&content, context, &mut notes).trim_start(),
+                endpoint(schema, context, &edge_type.target, &mut notes)?
+            );
+            elements.push(match type_name {
+                Some(type_name) => {
+                    format!("DIRECTED EDGE TYPE {} {}", identifier(type_name), pattern)
+                }
+                None => pattern,
+            });
+        }
+        for constraint in schema.constraints() {
+            notes.push(format!("{}: {}", constraint.name(), constraint));
+        }
+        let mut out = String::new();
+        let notes = notes.into_iter().unique().collect::<Vec<_>>();
+        if !notes.is_empty() {
+            out.push_str("// Not expressible in GQL:\n");
+            for note in notes {
+                out.push_str(&format!("//   {}\n", note));
+            }
+        }
+        out.push_str(&format!("CREATE GRAPH TYPE {} {{\n", identifier(name)));
+        out.push_str(
+            &elements
+                .iter()
+                .map(|element| format!("  {}", element))
+                .join(",\n"),
+        );
+        if !elements.is_empty() {
+            out.push('\n');
+        }
+        out.push_str("}\n");
+        Ok(out)
+    }
+}
+
+fn add_notes(content: &TypeContent, notes: &mut Vec<String>) {
+    for type_name in &content.open {
+        notes.push(format!("{}: open record", type_name));
+    }
+    notes.extend(content.notes.iter().cloned());
+}
+
+/// Endpoints are references to node types or inline labels and properties
+fn endpoint(
+    schema: &PropertyGraphSchema,
+    context: &str,
+    spec: &LabelPropertySpec,
+    notes: &mut Vec<String>,
+) -> Result<String, PgsError> {
+    if let LabelPropertySpec::Ref(type_name) = spec {
+        let type_name = schema.resolve_node_type_name(type_name)?;
+        return Ok(format!("({})", identifier(&type_name)));
+    }
+    let content = TypeContent::spec(schema, context, spec)?;
+    notes.extend(content.notes.iter().cloned());
+    Ok(format!(
+        "({})",
+        filler(&content, context, notes).trim_start()
+    ))
+}
+
+/// Labels and property types, as in ` :Person&Student {name :: STRING NOT NULL}`
+fn filler(content: &TypeContent, context: &str, notes: &mut Vec<String>) -> String {
+    let mut filler = String::new();
+    if !content.labels.is_empty() {
+        filler.push_str(&format!(
+            " :{}",
+            content
+                .labels
+                .iter()
+                .map(|label| identifier(label))
+                .join("&")
+        ));
+    }
+    if !content.properties.is_empty() {
+        let properties = content
+            .properties
+            .iter()
+            .map(|(key, (value_type, required))| {
+                let context = format!("{}.{}", context, key);
+                property_type(key, gql_type(value_type, &context, notes), *required)
+            })
+            .join(", ");
+        filler.push_str(&format!(" {{{}}}", properties));
+    }
+    filler
+}
+
+fn property_type(key: &Key, value_type: String, required: bool) -> String {
+    let not_null = if required { " NOT NULL" } else { "" };
+    format!("{} :: {}{}", identifier(key.str()), value_type, not_null)
+}
+
+/// GQL type of the values of a property, where several values are written as a list
+fn gql_type(value_type: &ValueType, context: &str, notes: &mut Vec<String>) -> String {
+    let (base, card) = match value_type {
+        ValueType::Any => return "ANY".to_string(),
+        ValueType::StringType(card) => ("STRING".to_string(), card),
+        ValueType::IntegerType(card) => ("INTEGER".to_string(), card),
+        ValueType::DateType(card) => ("DATE".to_string(), card),
+        ValueType::BoolType(card) => ("BOOLEAN".to_string(), card),
+        ValueType::List(value_type, card) => (gql_type(value_type, context, notes), card),
+        ValueType::Set(value_type, card) => {
+            notes.push(format!(
+                "{}: values without repetitions {}",
+                context, value_type
+            ));
+            (gql_type(value_type, context, notes), card)
+        }
+        ValueType::Record(record_types, card) => (record_type(record_types, context, notes), card),
+        ValueType::Intersection(left, right) => {
+            let left = gql_type(left, context, notes);
+            let right = gql_type(right, context, notes);
+            return match (left.as_str(), right.as_str()) {
+                ("ANY", _) => right,
+                (_, "ANY") => left,
+                _ if left == right => left,
+                _ => {
+                    notes.push(format!(
+                        "{}: values of types {} and {}",
+                        context, left, right
+                    ));
+                    left
+                }
+            };
+        }
+        ValueType::Cond(expr) => {
+            notes.push(format!("{}: condition {}", context, expr));
+            return "ANY".to_string();
+        }
+        ValueType::Union(_, _) => {
+            notes.push(format!("{}: union type {}", context, value_type));
+            return "ANY".to_string();
+        }
+    };
+    match card {
+        Card::One | Card::ZeroOrOne => base,
+        Card::ZeroOrMore => format!("LIST<{} NOT NULL>", base),
+        _ => {
+            notes.push(format!(
+                "{}: cardinality {} written as a list",
+                context, card
+            ));
+            format!("LIST<{} NOT NULL>", base)
+        }
+    }
+}
+
+/// Alternative record types, as the ones of optional fields, are written as one record
+/// where the fields that are not in all the alternatives can be null
+fn record_type(record_types: &[RecordType], context: &str, notes: &mut Vec<String>) -> String {
+    for record_type in record_types {
+        if record_type.is_open() {
+            notes.push(format!("{}: open record", context));
+        }
+        for check in record_type.checks() {
+            notes.push(format!("{}: CHECK {}", context, check));
+        }
+    }
+    let (fields, combinations) = record_fields(record_types);
+    if !combinations {
+        notes.push(format!("{}: alternative records", context));
+    }
+    let fields = fields
+        .into_iter()
+        .map(|(key, (value_type, required))| {
+            let context = format!("{}.{}", context, key);
+            let value_type = gql_type(value_type, &context, notes);
+            property_type(key, value_type, required)
+        })
+        .join(", ");
+    format!("RECORD {{{}}}", fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::pgs_builder::PgsBuilder;
+
+    #[test]
+    fn test_write_graph_type() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( PersonType : Person {
+    name: STRING,
+    OPTIONAL age: INTEGER CHECK > 0,
+    OPTIONAL emails: STRING*,
+    address: RECORD { city: STRING, OPTIONAL zip: STRING }
+}) ;
+CREATE NODE TYPE ( StudentType : Student & @PersonType ) ;
+CREATE EDGE TYPE (@PersonType) -[KnowsType : knows { since: INTEGER }]-> (:Person) ;
+CREATE CONSTRAINT UniqueName FOR (:Person) UNIQUE (name)"#,
+            )
+            .unwrap();
+        let gql = GqlWriter::new()
+            .write_graph_type("People", &schema)
+            .unwrap();
+        assert_eq!(
+            gql,
+            r#"// Not expressible in GQL:
+//   PersonType.age: condition (> 0)
+//   StudentType.age: condition (> 0)
+//   UniqueName: UniqueName FOR (:Person) UNIQUE (name)
+CREATE GRAPH TYPE People {
+  NODE TYPE PersonType (PersonType :Person {address :: RECORD {city :: STRING NOT NULL, zip :: STRING} NOT NULL, age :: INTEGER, emails :: LIST<STRING NOT NULL>, name :: STRING NOT NULL}),
+  NODE TYPE StudentType (StudentType :Person&Student {address :: RECORD {city :: STRING NOT NULL, zip :: STRING} NOT NULL, age :: INTEGER, emails :: LIST<STRING NOT NULL>, name :: STRING NOT NULL}),
+  DIRECTED EDGE TYPE KnowsType (PersonType)-[:knows {since :: INTEGER NOT NULL}]->(:Person)
+}
+"#
+        );
+    }
+    #[test]
+    fn test_alternative_properties_are_optional() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( ContactType : Contact {
+    name: STRING,
+    email: STRING || phone: STRING || phone: INTEGER
+})"#,
+            )
+            .unwrap();
+        let gql = GqlWriter::new()
+            .write_graph_type("Contacts", &schema)
+            .unwrap();
+        assert_eq!(
+            gql.lines().skip_while(|line| line.starts_with("//")).join("\n"),
+            "CREATE GRAPH TYPE Contacts {
+  NODE TYPE ContactType (ContactType :Contact {email :: STRING, name :: STRING NOT NULL, phone :: ANY})
+}"
+        );
+        assert!(gql.contains("ContactType: alternative properties"));
+    }
+}
@@ -0,0 +1,359 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use itertools::Itertools;
+use quick_xml::escape::{escape, partial_escape};
+
+use crate::{
+    key::Key, pg::PropertyGraph, pgs_error::PgsError, record::Record, value::Value,
+    writer::pg_json_writer::json_value,
+};
+
+/// Serializes property graphs as GraphML documents that can be read by `GraphmlBuilder`.
+///
+/// Dates are written as strings because GraphML has no date type. Properties whose values
+/// have different types get a key for each type, and properties with several values or
+/// without values are written as JSON arrays with `attr.list`.
+pub struct GraphmlWriter {}
+
+/// Whether the values of each property and type are written as lists
+type KeyTypes = BTreeMap<(Key, &'static str), bool>;
+
+impl Default for GraphmlWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphmlWriter {
+    pub fn new() -> Self {
+        GraphmlWriter {}
+    }
+
+    pub fn write_graphml(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        let nodes = graph
+            .nodes()
+            .sorted_by_key(|(_, node)| node.id.id)
+            .collect::<Vec<_>>();
+        let edges = graph
+            .edges()
+            .sorted_by_key(|(_, edge)| edge.id.id)
+            .collect::<Vec<_>>();
+        let node_keys = key_types(nodes.iter().map(|(_, node)| node.content()))?;
+        let edge_keys = key_types(edges.iter().map(|(_, edge)| edge.content()))?;
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        let mut key_ids = BTreeMap::new();
+        for (domain, keys) in [("node", &node_keys), ("edge", &edge_keys)] {
+            for ((key, attr_type), list) in keys {
+                let id = format!("d{}", key_ids.len());
+                let list = if *list {
+                    format!(" attr.list=\"{}\"", attr_type)
+                } else {
+                    String::new()
+                };
+                out.push_str(&format!(
+                    "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"{}/>\n",
+                    id,
+                    domain,
+                    escape(key.str()),
+                    attr_type,
+                    list
+                ));
+                key_ids.insert((domain, key.clone(), *attr_type), id);
+            }
+        }
+        out.push_str("  <graph id=\"G\" edgedefault=\"directed\">\n");
+        for (name, node) in nodes {
+            let labels = node
+                .labels()
+                .iter()
+                .sorted()
+                .map(|l| format!(":{}", l))
+                .join("");
+            out.push_str(&format!(
+                "    <node id=\"{}\" labels=\"{}\">\n",
+                escape(name.as_str()),
+                escape(labels.as_str())
+            ));
+            write_data(&mut out, node.content(), "node", &node_keys, &key_ids)?;
+            out.push_str("    </node>\n");
+        }
+        for (name, edge) in edges {
+            let source = graph.node_name(&edge.source).cloned().unwrap_or_default();
+            let target = graph.node_name(&edge.target).cloned().unwrap_or_default();
+            out.push_str(&format!(
+                "    <edge id=\"{}\" source=\"{}\" target=\"{}\" label=\"{}\">\n",
+                escape(name.as_str()),
+                escape(source.as_str()),
+                escape(target.as_str()),
+                escape(edge.labels().iter().sorted().join(":").as_str())
+            ));
+            write_data(&mut out, edge.content(), "edge", &edge_keys, &key_ids)?;
+            out.push_str("    </edge>\n");
+        }
+        out.push_str("  </graph>\n");
+        out.push_str("</graphml>\n");
+        Ok(out)
+    }
+}
+
+/// Collects the types of the values of each property, and which ones need lists
+fn key_types<'a>(records: impl Iterator<Item = &'a Record>) -> Result<KeyTypes, PgsError> {
+    let mut keys = KeyTypes::new();
+    let mut empty = BTreeSet::new();
+    for record in records {
+        for (key, values) in record.iter() {
+            if values.is_empty() {
+                empty.insert(key.clone());
+            }
+            for (attr_type, group) in typed_values(key, values)? {
+                let list = keys.entry((key.clone(), attr_type)).or_default();
+                *list = *list || group.len() > 1;
+            }
+        }
+    }
+    for key in empty {
+        let attr_type = empty_list_type(&keys, &key);
+        keys.insert((key, attr_type), true);
+    }
+    Ok(keys)
+}
+
+/// Type of the list key that holds a property without values, which is the first type
+/// of the property or string
+fn empty_list_type(keys: &KeyTypes, key: &Key) -> &'static str {
+    keys.keys()
+        .find(|(name, _)| name == key)
+        .map(|(_, attr_type)| *attr_type)
+        .unwrap_or("string")
+}
+
+/// Values of a property grouped by their types, in the order the types appear
+fn typed_values<'a>(
+    key: &Key,
+    values: &'a [Value],
+) -> Result<Vec<(&'static str, Vec<&'a Value>)>, PgsError> {
+    let mut groups: Vec<(&'static str, Vec<&Value>)> = Vec::new();
+    for value in values {
+        let attr_type = attr_type(key, value)?;
+        match groups
+            .iter_mut()
+            .find(|(group_type, _)| *group_type == attr_type)
+        {
+            Some((_, group)) => group.push(value),
+            None => groups.push((attr_type, vec![value])),
+        }
+    }
+    Ok(groups)
+}
+
+fn attr_type(key: &Key, value: &Value) -> Result<&'static str, PgsError> {
+    match value {
+        Value::String(_) | Value::Date(_) => Ok("string"),
+        Value::Integer(_) => Ok("int"),
+        Value::Bool(_) => Ok("boolean"),
+        Value::Record(_) => Err(PgsError::GraphWriteError {
+            format: "GraphML".to_string(),
+            error: format!("nested record in property {} can't be written", key),
+        }),
+    }
+}
+
+fn write_data(
+    out: &mut String,
+    record: &Record,
+    domain: &'static str,
+    keys: &KeyTypes,
+    key_ids: &BTreeMap<(&'static str, Key, &'static str), String>,
+) -> Result<(), PgsError> {
+    for (key, values) in record.iter() {
+        let groups = if values.is_empty() {
+            vec![(empty_list_type(keys, key), Vec::new())]
+        } else {
+            typed_values(key, values)?
+        };
+        for (attr_type, group) in groups {
+            let id = &key_ids[&(domain, key.clone(), attr_type)];
+            let text = if keys[&(key.clone(), attr_type)] {
+                let items = group.into_iter().map(json_value).collect();
+                serde_json::Value::Array(items).to_string()
+            } else {
+                group.iter().map(|value| value.to_string()).join("")
+            };
+            out.push_str(&format!(
+                "      <data key=\"{}\">{}</data>\n",
+                id,
+                partial_escape(text.as_str())
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::parser::{graphml_builder::GraphmlBuilder, pg_builder::PgBuilder};
+
+    #[test]
+    fn test_write_graphml() {
+        let mut graph = PropertyGraph::new();
+        let alice = Record::new()
+            .with_key_value("name", Value::str("Alice & co"))
+            .with_key_value("tags", Value::str("a"))
+            .with_key_value("tags", Value::str("b"));
+        graph
+            .add_node(
+                "alice".to_string(),
+                HashSet::from(["Student".to_string(), "Person".to_string()]),
+                alice,
+            )
+            .unwrap();
+        graph
+            .add_node("bob".to_string(), HashSet::new(), Record::new())
+            .unwrap();
+        graph
+            .add_edge(
+                "k1".to_string(),
+                "alice".to_string(),
+                HashSet::from(["knows".to_string()]),
+                Record::new().with_key_value("since", Value::int(2020)),
+                "bob".to_string(),
+            )
+            .unwrap();
+        let graphml = GraphmlWriter::new().write_graphml(&graph).unwrap();
+        assert_eq!(
+            graphml,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="name" attr.type="string"/>
+  <key id="d1" for="node" attr.name="tags" attr.type="string" attr.list="string"/>
+  <key id="d2" for="edge" attr.name="since" attr.type="int"/>
+  <graph id="G" edgedefault="directed">
+    <node id="alice" labels=":Person:Student">
+      <data key="d0">Alice &amp; co</data>
+      <data key="d1">["a","b"]</data>
+    </node>
+    <node id="bob" labels="">
+    </node>
+    <edge id="k1" source="alice" target="bob" label="knows">
+      <data key="d2">2020</data>
+    </edge>
+  </graph>
+</graphml>
+"#
+        );
+        let parsed = GraphmlBuilder::new().parse_graphml(&graphml).unwrap();
+        assert_eq!(
+            parsed.get_node_by_label("alice").unwrap().content(),
+            graph.get_node_by_label("alice").unwrap().content()
+        );
+    }
+
+    #[test]
+    fn test_mixed_key_types() {
+        let mut graph = PropertyGraph::new();
+        for (name, code) in [("a", Value::int(1)), ("b", Value::str("x"))] {
+            graph
+                .add_node(
+                    name.to_string(),
+                    HashSet::new(),
+                    Record::new().with_key_value("code", code),
+                )
+                .unwrap();
+        }
+        let graphml = GraphmlWriter::new().write_graphml(&graph).unwrap();
+        assert!(graphml.contains(r#"<key id="d0" for="node" attr.name="code" attr.type="int"/>"#));
+        assert!(
+            graphml.contains(r#"<key id="d1" for="node" attr.name="code" attr.type="string"/>"#)
+        );
+        let parsed = GraphmlBuilder::new().parse_graphml(&graphml).unwrap();
+        for name in ["a", "b"] {
+            assert_eq!(
+                parsed.get_node_by_label(name).unwrap().content(),
+                graph.get_node_by_label(name).unwrap().content()
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_list() {
+        let mut graph = PropertyGraph::new();
+        for (name, record) in [
+            ("a", Record::new().with_key_value("code", Value::int(1))),
+            ("b", Record::new()),
+        ] {
+            graph
+                .add_node(name.to_string(), HashSet::new(), record)
+                .unwrap();
+        }
+        let mut record = Record::new();
+        record.insert_values(Key::new("code"), Vec::new());
+        record.insert_values(Key::new("tags"), Vec::new());
+        graph
+            .add_node("c".to_string(), HashSet::new(), record)
+            .unwrap();
+        let graphml = GraphmlWriter::new().write_graphml(&graph).unwrap();
+        assert!(graphml.contains(
+            r#"<key id="d0" for="node" attr.name="code" attr.type="int" attr.list="int"/>"#
+        ));
+        assert!(graphml.contains(
+            r#"<key id="d1" for="node" attr.name="tags" attr.type="string" attr.list="string"/>"#
+        ));
+        let parsed = GraphmlBuilder::new().parse_graphml(&graphml).unwrap();
+        for name in ["a", "b", "c"] {
+            assert_eq!(
+                parsed.get_node_by_label(name).unwrap().content(),
+                graph.get_node_by_label(name).unwrap().content()
+            );
+        }
+    }
+
+    #[test]
+    fn test_list_fixture_round_trip() {
+        let graph = PgBuilder::new()
+            .parse_pg(include_str!("../../tests/list.pg"))
+            .unwrap();
+        let graphml = GraphmlWriter::new().write_graphml(&graph).unwrap();
+        let parsed = GraphmlBuilder::new().parse_graphml(&graphml).unwrap();
+        for (name, node) in graph.nodes() {
+            let parsed_node = parsed.get_node_by_label(name).unwrap();
+            assert_eq!(parsed_node.content(), node.content(), "{name}");
+            assert_eq!(parsed_node.labels(), node.labels(), "{name}");
+        }
+    }
+
+    /// GraphML has no date type, so dates are read back as strings
+    #[test]
+    fn test_dates_as_strings() {
+        let mut graph = PropertyGraph::new();
+        let record = Record::new().with_key_value("born", Value::date("2000-01-31").unwrap());
+        graph
+            .add_node("alice".to_string(), HashSet::new(), record)
+            .unwrap();
+        let graphml = GraphmlWriter::new().write_graphml(&graph).unwrap();
+        let parsed = GraphmlBuilder::new().parse_graphml(&graphml).unwrap();
+        assert_eq!(
+            parsed.get_node_by_label("alice").unwrap().content(),
+            &Record::new().with_key_value("born", Value::str("2000-01-31"))
+        );
+    }
+    #[test]
+    fn test_nested_record() {
+        let mut graph = PropertyGraph::new();
+        let record = Record::new().with_key_value("address", Value::record(Record::new()));
+        graph
+            .add_node("alice".to_string(), HashSet::new(), record)
+            .unwrap();
+        assert_eq!(
+            GraphmlWriter::new()
+                .write_graphml(&graph)
+                .unwrap_err()
+                .to_string(),
+            "Error writing GraphML property graph: nested record in property address can't be written"
+        );
+    }
+}
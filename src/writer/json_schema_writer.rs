@@ -0,0 +1,462 @@
+use itertools::Itertools;
+use serde_json::{Map, Value as JsonValue, json};
+
+use crate::{
+    boolean_expr::BooleanExpr,
+    card::{Card, Max},
+    key::Key,
+    pgs::PropertyGraphSchema,
+    pgs_error::PgsError,
+    record_type::RecordType,
+    value::Value,
+    value_type::ValueType,
+    writer::{pg_json_writer::json_value, type_content::record_fields},
+};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Translates the records of node types to JSON Schema, to validate node payloads
+/// whose properties are JSON members.
+///
+/// Properties with at most one value are written as single values and the rest as arrays
+/// whose `minItems` and `maxItems` come from the cardinality. A property with several
+/// values also accepts a single value when its cardinality allows one, as in the graphs. Optional properties are not
+/// `required`, closed records don't allow additional properties, regular expressions are
+/// `pattern`s and integer comparisons are `minimum` and `maximum` keywords. The constructs
+/// that can't be translated, as record checks or comparisons of dates, are listed
+/// in a `$comment`.
+pub struct JsonSchemaWriter {}
+
+/// Keywords of the values of a property and of the array that contains them
+#[derive(Default)]
+struct ValuesSchema {
+    item: Map<String, JsonValue>,
+    array: Map<String, JsonValue>,
+    card: Option<Card>,
+}
+
+impl Default for JsonSchemaWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonSchemaWriter {
+    pub fn new() -> Self {
+        JsonSchemaWriter {}
+    }
+
+    /// Writes a document with the schemas of the named node types in `$defs`
+    pub fn write_json_schema(&self, schema: &PropertyGraphSchema) -> Result<String, PgsError> {
+        let mut defs = Map::new();
+        for type_name in schema.node_type_names() {
+            let mut node_schema = self.node_type_schema(schema, type_name)?;
+            if let JsonValue::Object(node_schema) = &mut node_schema {
+                node_schema.remove("$schema");
+            }
+            defs.insert(type_name.clone(), node_schema);
+        }
+        let document = json!({ "$schema": DRAFT, "$defs": defs });
+        serde_json::to_string_pretty(&document).map_err(|e| PgsError::SchemaWriteError {
+            format: "JSON Schema".to_string(),
+            error: e.to_string(),
+        })
+    }
+
+    /// JSON Schema of the records of the nodes of a type, including the types it extends
+    pub fn node_type_schema(
+        &self,
+        schema: &PropertyGraphSchema,
+        type_name: &str,
+    ) -> Result<JsonValue, PgsError> {
+        let semantics = schema.node_type_semantics(type_name)?;
+        let record_types = semantics
+            .content()
+            .iter()
+            .sorted_by_key(|record_type| record_type.to_string())
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut untranslated = Vec::new();
+        let mut node_schema = Map::new();
+        node_schema.insert("$schema".to_string(), json!(DRAFT));
+        node_schema.insert("title".to_string(), json!(type_name));
+        node_schema.extend(records_schema(&record_types, type_name, &mut untranslated));
+        if !untranslated.is_empty() {
+            let untranslated = untranslated.into_iter().unique().join("; ");
+            node_schema.insert(
+                "$comment".to_string(),
+                json!(format!("Not translated: {}", untranslated)),
+            );
+        }
+        Ok(JsonValue::Object(node_schema))
+    }
+}
+
+/// Alternative record types are written as one object when they are the combinations
+/// of some optional properties and as `anyOf` alternatives otherwise
+fn records_schema(
+    record_types: &[RecordType],
+    context: &str,
+    untranslated: &mut Vec<String>,
+) -> Map<String, JsonValue> {
+    let (fields, combinations) = record_fields(record_types);
+    if combinations || record_types.is_empty() {
+        let open = record_types.iter().any(|record_type| record_type.is_open());
+        let checks = record_types
+            .iter()
+            .flat_map(|record_type| record_type.checks());
+        for check in checks {
+            untranslated.push(format!("{}: CHECK {}", context, check));
+        }
+        object_schema(fields.into_iter(), open, context, untranslated)
+    } else {
+        let alternatives = record_types
+            .iter()
+            .map(|record_type| {
+                for check in record_type.checks() {
+                    untranslated.push(format!("{}: CHECK {}", context, check));
+                }
+                let fields = record_type
+                    .iter()
+                    .map(|(key, value_type)| (key, (value_type, true)));
+                JsonValue::Object(object_schema(
+                    fields,
+                    record_type.is_open(),
+                    context,
+                    untranslated,
+                ))
+            })
+            .collect::<Vec<_>>();
+        let mut schema = Map::new();
+        schema.insert("anyOf".to_string(), JsonValue::Array(alternatives));
+        schema
+    }
+}
+
+fn object_schema<'a>(
+    fields: impl Iterator<Item = (&'a Key, (&'a ValueType, bool))>,
+    open: bool,
+    context: &str,
+    untranslated: &mut Vec<String>,
+) -> Map<String, JsonValue> {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (key, (value_type, is_required)) in fields {
+        let context = format!("{}.{}", context, key);
+        properties.insert(
+            key.str().to_string(),
+            JsonValue::Object(property_schema(value_type, &context, untranslated)),
+        );
+        if is_required {
+            required.push(json!(key.str()));
+        }
+    }
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("object"));
+    schema.insert("properties".to_string(), JsonValue::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".to_string(), JsonValue::Array(required));
+    }
+    if !open {
+        schema.insert("additionalProperties".to_string(), json!(false));
+    }
+    schema
+}
+
+/// Properties with several values are arrays of their values, or `anyOf` a single value
+/// and an array when they can have only one value
+fn property_schema(
+    value_type: &ValueType,
+    context: &str,
+    untranslated: &mut Vec<String>,
+) -> Map<String, JsonValue> {
+    let values = values_schema(value_type, context, untranslated);
+    let (min, max) = match &values.card {
+        None | Some(Card::One) | Some(Card::ZeroOrOne) => (0, Some(1)),
+        Some(Card::ZeroOrMore) => (0, None),
+        Some(Card::OneOrMore) => (1, None),
+        Some(Card::Range(min, Max::Bounded(max))) => (*min, Some(*max)),
+        Some(Card::Range(min, Max::Unbounded)) => (*min, None),
+    };
+    if max.is_some_and(|max| max <= 1) {
+        if !values.array.is_empty() {
+            untranslated.push(format!("{}: conditions on several values", context));
+        }
+        return values.item;
+    }
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("array"));
+    schema.insert("items".to_string(), JsonValue::Object(values.item.clone()));
+    if min > 0 {
+        schema.insert("minItems".to_string(), json!(min));
+    }
+    if let Some(max) = max {
+        schema.insert("maxItems".to_string(), json!(max));
+    }
+    for (keyword, value) in values.array {
+        add_keyword(&mut schema, &keyword, value);
+    }
+    if min > 1 {
+        return schema;
+    }
+    let mut single_or_array = Map::new();
+    single_or_array.insert(
+        "anyOf".to_string(),
+        json!([JsonValue::Object(values.item), JsonValue::Object(schema)]),
+    );
+    single_or_array
+}
+
+/// Schema of the values of a JSON type with an optional format
+fn type_schema(json_type: &str, format: Option<&str>) -> Map<String, JsonValue> {
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!(json_type));
+    if let Some(format) = format {
+        schema.insert("format".to_string(), json!(format));
+    }
+    schema
+}
+
+fn values_schema(
+    value_type: &ValueType,
+    context: &str,
+    untranslated: &mut Vec<String>,
+) -> ValuesSchema {
+    let (item, card) = match value_type {
+        ValueType::Any => return ValuesSchema::default(),
+        ValueType::StringType(card) => (type_schema("string", None), card),
+        ValueType::IntegerType(card) => (type_schema("integer", None), card),
+        ValueType::DateType(card) => (type_schema("string", Some("date")), card),
+        ValueType::BoolType(card) => (type_schema("boolean", None), card),
+        ValueType::List(value_type, card) => {
+            (values_schema(value_type, context, untranslated).item, card)
+        }
+        ValueType::Set(value_type, card) => {
+            let mut array = Map::new();
+            array.insert("uniqueItems".to_string(), json!(true));
+            return ValuesSchema {
+                item: values_schema(value_type, context, untranslated).item,
+                array,
+                card: Some(card.clone()),
+            };
+        }
+        ValueType::Record(record_types, card) => {
+            (records_schema(record_types, context, untranslated), card)
+        }
+        ValueType::Intersection(left, right) => {
+            let mut values = values_schema(left, context, untranslated);
+            let right = values_schema(right, context, untranslated);
+            for (keyword, value) in right.item {
+                add_keyword(&mut values.item, &keyword, value);
+            }
+            for (keyword, value) in right.array {
+                add_keyword(&mut values.array, &keyword, value);
+            }
+            values.card = match (values.card, right.card) {
+                (Some(left), Some(right)) => Some(left.intersection(&right)),
+                (left, right) => left.or(right),
+            };
+            return values;
+        }
+        ValueType::Union(left, right) => {
+            let left = values_schema(left, context, untranslated);
+            let right = values_schema(right, context, untranslated);
+            if left.card != right.card || !left.array.is_empty() || !right.array.is_empty() {
+                untranslated.push(format!("{}: union type {}", context, value_type));
+            }
+            let mut item = Map::new();
+            item.insert(
+                "anyOf".to_string(),
+                json!([JsonValue::Object(left.item), JsonValue::Object(right.item)]),
+            );
+            return ValuesSchema {
+                item,
+                array: Map::new(),
+                card: left.card.or(right.card),
+            };
+        }
+        ValueType::Cond(expr) => {
+            let mut values = ValuesSchema::default();
+            condition(expr, &mut values, context, untranslated);
+            return values;
+        }
+    };
+    ValuesSchema {
+        item,
+        array: Map::new(),
+        card: Some(card.clone()),
+    }
+}
+
+/// Adds the keywords of a condition on the values of a property
+fn condition(
+    expr: &BooleanExpr,
+    values: &mut ValuesSchema,
+    context: &str,
+    untranslated: &mut Vec<String>,
+) {
+    match expr {
+        BooleanExpr::True => {}
+        BooleanExpr::False => add_keyword(&mut values.item, "not", json!({})),
+        BooleanExpr::And(left, right) => {
+            condition(left, values, context, untranslated);
+            condition(right, values, context, untranslated);
+        }
+        BooleanExpr::Or(left, right) => {
+            let left = item_condition(left, context, untranslated);
+            let right = item_condition(right, context, untranslated);
+            add_keyword(&mut values.item, "anyOf", json!([left, right]));
+        }
+        BooleanExpr::Not(expr) | BooleanExpr::NoneOf(expr) => {
+            let expr = item_condition(expr, context, untranslated);
+            add_keyword(&mut values.item, "not", expr);
+        }
+        BooleanExpr::All(expr) => condition(expr, values, context, untranslated),
+        BooleanExpr::Equals(value) => add_keyword(&mut values.item, "const", json_value(value)),
+        BooleanExpr::Regex(pattern) => add_keyword(&mut values.item, "pattern", json!(pattern)),
+        BooleanExpr::GreaterThan(Value::Integer(n)) => {
+            add_keyword(&mut values.item, "exclusiveMinimum", json!(n))
+        }
+        BooleanExpr::GreaterThanOrEqual(Value::Integer(n)) => {
+            add_keyword(&mut values.item, "minimum", json!(n))
+        }
+        BooleanExpr::LessThan(Value::Integer(n)) => {
+            add_keyword(&mut values.item, "exclusiveMaximum", json!(n))
+        }
+        BooleanExpr::LessThanOrEqual(Value::Integer(n)) => {
+            add_keyword(&mut values.item, "maximum", json!(n))
+        }
+        BooleanExpr::Unique => add_keyword(&mut values.array, "uniqueItems", json!(true)),
+        BooleanExpr::Contains(value) => add_keyword(
+            &mut values.array,
+            "contains",
+            json!({ "const": json_value(value) }),
+        ),
+        BooleanExpr::Any(expr) => {
+            let expr = item_condition(expr, context, untranslated);
+            add_keyword(&mut values.array, "contains", expr);
+        }
+        _ => untranslated.push(format!("{}: condition {}", context, expr)),
+    }
+}
+
+/// Schema of a condition nested in another one, which can only constrain single values
+fn item_condition(expr: &BooleanExpr, context: &str, untranslated: &mut Vec<String>) -> JsonValue {
+    let mut values = ValuesSchema::default();
+    condition(expr, &mut values, context, untranslated);
+    if !values.array.is_empty() {
+        untranslated.push(format!("{}: condition {}", context, expr));
+    }
+    JsonValue::Object(values.item)
+}
+
+/// Keywords that are already in the schema are added in an `allOf` to keep both
+fn add_keyword(schema: &mut Map<String, JsonValue>, keyword: &str, value: JsonValue) {
+    if !schema.contains_key(keyword) {
+        schema.insert(keyword.to_string(), value);
+        return;
+    }
+    let mut other = Map::new();
+    other.insert(keyword.to_string(), value);
+    match schema.get_mut("allOf") {
+        Some(JsonValue::Array(all_of)) => all_of.push(JsonValue::Object(other)),
+        _ => {
+            schema.insert("allOf".to_string(), json!([other]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::pgs_builder::PgsBuilder;
+
+    #[test]
+    fn test_node_type_schema() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( PersonType : Person {
+    name: STRING CHECK REGEX "^[A-Z]",
+    OPTIONAL age: INTEGER CHECK >= 0 AND < 150,
+    emails: STRING {1,3},
+    OPTIONAL born: DATE CHECK > DATE "1900-01-01"
+})"#,
+            )
+            .unwrap();
+        let json_schema = JsonSchemaWriter::new()
+            .node_type_schema(&schema, "PersonType")
+            .unwrap();
+        assert_eq!(
+            json_schema,
+            json!({
+                "$schema": DRAFT,
+                "title": "PersonType",
+                "type": "object",
+                "properties": {
+                    "age": {"type": "integer", "minimum": 0, "exclusiveMaximum": 150},
+                    "born": {"type": "string", "format": "date"},
+                    "emails": {
+                        "anyOf": [
+                            {"type": "string"},
+                            {
+                                "type": "array",
+                                "items": {"type": "string"},
+                                "minItems": 1,
+                                "maxItems": 3
+                            }
+                        ]
+                    },
+                    "name": {"type": "string", "pattern": "^[A-Z]"}
+                },
+                "required": ["emails", "name"],
+                "additionalProperties": false,
+                "$comment": "Not translated: PersonType.born: condition (> 1900-01-01)"
+            })
+        );
+    }
+
+    #[test]
+    fn test_several_values() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( SeriesType : Series {
+    measures: INTEGER {0,5},
+    pair: STRING {2,2}
+})"#,
+            )
+            .unwrap();
+        let json_schema = JsonSchemaWriter::new()
+            .node_type_schema(&schema, "SeriesType")
+            .unwrap();
+        assert_eq!(
+            json_schema["properties"]["measures"],
+            json!({"anyOf": [
+                {"type": "integer"},
+                {"type": "array", "items": {"type": "integer"}, "maxItems": 5}
+            ]})
+        );
+        assert_eq!(
+            json_schema["properties"]["pair"],
+            json!({"type": "array", "items": {"type": "string"}, "minItems": 2, "maxItems": 2})
+        );
+    }
+
+    #[test]
+    fn test_alternative_records() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( ContactType : Contact { email: STRING || phone: STRING })"#,
+            )
+            .unwrap();
+        let json_schema = JsonSchemaWriter::new()
+            .node_type_schema(&schema, "ContactType")
+            .unwrap();
+        assert_eq!(
+            json_schema["anyOf"],
+            json!([
+                {"type": "object", "properties": {"email": {"type": "string"}}, "required": ["email"], "additionalProperties": false},
+                {"type": "object", "properties": {"phone": {"type": "string"}}, "required": ["phone"], "additionalProperties": false}
+            ])
+        );
+    }
+}
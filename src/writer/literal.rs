@@ -0,0 +1,41 @@
+use crate::{key::Key, pgs_error::PgsError, record::Record, value::Value};
+
+/// Writes a value as a literal of the PG format and YARS-PG, which are read back by
+/// `parser::literal::unquoted_value`.
+///
+/// Strings are always quoted so they are not read back as numbers, booleans or dates,
+/// and dates are written unquoted as `2020-01-31`.
+fn value_str(format: &str, key: &Key, value: &Value) -> Result<String, PgsError> {
+    match value {
+        Value::String(s) => Ok(quote(s)),
+        Value::Integer(i) => Ok(i.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Date(d) => Ok(d.to_string()),
+        Value::Record(_) => Err(PgsError::GraphWriteError {
+            format: format.to_string(),
+            error: format!("nested record in property {} can't be written", key),
+        }),
+    }
+}
+
+/// Literals of the values of each property of a record, in the order of the keys
+pub(crate) fn property_literals<'a>(
+    format: &str,
+    record: &'a Record,
+) -> Result<Vec<(&'a Key, Vec<String>)>, PgsError> {
+    record
+        .iter()
+        .map(|(key, values)| {
+            let literals = values
+                .iter()
+                .map(|value| value_str(format, key, value))
+                .collect::<Result<_, _>>()?;
+            Ok((key, literals))
+        })
+        .collect()
+}
+
+/// Double quoted string with the escapes of JSON
+pub(crate) fn quote(s: &str) -> String {
+    serde_json::Value::String(s.to_string()).to_string()
+}
@@ -0,0 +1,12 @@
+// Serializers of property graphs and schemas to formats used by other tools
+pub mod cypher_writer;
+pub mod gql_writer;
+pub mod graphml_writer;
+pub mod json_schema_writer;
+pub(crate) mod literal;
+pub mod neo4j_constraint_writer;
+pub mod pg_format_writer;
+pub mod pg_json_writer;
+pub mod rdf_writer;
+pub(crate) mod type_content;
+pub mod yarspg_writer;
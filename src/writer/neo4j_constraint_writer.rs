@@ -0,0 +1,382 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::{
+    card::{Card, Max},
+    graph_constraint::ConstraintKind,
+    key::Key,
+    label_property_spec::LabelPropertySpec,
+    pgs::PropertyGraphSchema,
+    pgs_error::PgsError,
+    type_name::{LabelName, TypeName},
+    value_type::ValueType,
+    writer::{cypher_writer::identifier, type_content::TypeContent},
+};
+
+/// Generates the Neo4j 5 `CREATE CONSTRAINT` statements that enforce the parts of a schema
+/// that Neo4j supports: property existence, property types of single values,
+/// and the `UNIQUE` and `KEY` constraints.
+///
+/// Neo4j constraints apply to a single label, so only node and edge types with one label
+/// are translated, and only the properties that all the types which may have the label
+/// require with the same type. The rest of the constructs, like alternatives, closed
+/// records, checks and endpoints, are listed in the report.
+pub struct Neo4jConstraintWriter {}
+
+/// Constraint statements and the constructs of the schema that could not be translated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neo4jConstraints {
+    pub statements: Vec<String>,
+    pub untranslated: Vec<String>,
+}
+
+#[derive(Clone, Copy)]
+enum Element {
+    Node,
+    Relationship,
+}
+
+impl Default for Neo4jConstraintWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Neo4jConstraintWriter {
+    pub fn new() -> Self {
+        Neo4jConstraintWriter {}
+    }
+
+    pub fn write_constraints(
+        &self,
+        schema: &PropertyGraphSchema,
+    ) -> Result<Neo4jConstraints, PgsError> {
+        let mut constraints = Neo4jConstraints {
+            statements: Vec::new(),
+            untranslated: Vec::new(),
+        };
+        let mut node_types = Vec::new();
+        for type_name in schema.node_type_names() {
+            let content = TypeContent::node_type(schema, type_name)?;
+            add_notes(&content, &mut constraints);
+            node_types.push((type_name, content));
+        }
+        let mut edge_types = Vec::new();
+        for type_name in schema.edge_type_names() {
+            let edge_type = schema.get_edge_semantics(type_name)?;
+            let content = TypeContent::spec(schema, type_name, &edge_type.edge)?;
+            add_notes(&content, &mut constraints);
+            if edge_type.source != LabelPropertySpec::Empty
+                || edge_type.target != LabelPropertySpec::Empty
+            {
+                constraints
+                    .untranslated
+                    .push(format!("{}: endpoints of the edge type", type_name));
+            }
+            edge_types.push((type_name, content));
+        }
+        add_type_constraints(&node_types, Element::Node, &mut constraints);
+        add_type_constraints(&edge_types, Element::Relationship, &mut constraints);
+        for constraint in schema.constraints() {
+            let pattern = pattern(Element::Node, constraint.label());
+            let (keys, kind) = match constraint.kind() {
+                ConstraintKind::Unique { keys } => (keys, "UNIQUE"),
+                ConstraintKind::NodeKey { keys } => (keys, "NODE KEY"),
+                _ => {
+                    constraints
+                        .untranslated
+                        .push(format!("{}: {}", constraint.name(), constraint));
+                    continue;
+                }
+            };
+            let properties = match keys.as_slice() {
+                [key] => property(Element::Node, key),
+                _ => format!(
+                    "({})",
+                    keys.iter()
+                        .map(|key| property(Element::Node, key))
+                        .join(", ")
+                ),
+            };
+            constraints.statements.push(format!(
+                "CREATE CONSTRAINT {} IF NOT EXISTS FOR {} REQUIRE {} IS {};",
+                constraint_name(&[constraint.name()]),
+                pattern,
+                properties,
+                kind
+            ));
+        }
+        // Types referenced or extended by several types report the same constructs
+        constraints.untranslated = constraints.untranslated.into_iter().unique().collect();
+        Ok(constraints)
+    }
+}
+
+/// Closed records can't be enforced by Neo4j, which always allows other properties
+fn add_notes(content: &TypeContent, constraints: &mut Neo4jConstraints) {
+    for type_name in &content.closed {
+        constraints
+            .untranslated
+            .push(format!("{}: closed record", type_name));
+    }
+    constraints
+        .untranslated
+        .extend(content.notes.iter().cloned());
+}
+
+fn add_type_constraints(
+    types: &[(&TypeName, TypeContent)],
+    element: Element,
+    constraints: &mut Neo4jConstraints,
+) {
+    for (type_name, content) in types {
+        let label = match content.labels.iter().collect::<Vec<_>>().as_slice() {
+            [label] => *label,
+            labels => {
+                constraints.untranslated.push(format!(
+                    "{}: types with {} labels",
+                    type_name,
+                    labels.len()
+                ));
+                continue;
+            }
+        };
+        let pattern = pattern(element, label);
+        for (key, (value_type, required)) in &content.properties {
+            if !shared_property(types, label, key, (value_type, *required)) {
+                constraints.untranslated.push(format!(
+                    "{}.{}: label {} is shared with types with other properties",
+                    type_name, key, label
+                ));
+                continue;
+            }
+            let mut reasons = Vec::new();
+            let (neo4j_type, min) = neo4j_type(value_type, &mut reasons);
+            for reason in reasons {
+                constraints
+                    .untranslated
+                    .push(format!("{}.{}: {}", type_name, key, reason));
+            }
+            if *required && min > 0 {
+                constraints.statements.push(format!(
+                    "CREATE CONSTRAINT {} IF NOT EXISTS FOR {} REQUIRE {} IS NOT NULL;",
+                    constraint_name(&[type_name, key.str(), "exists"]),
+                    pattern,
+                    property(element, key)
+                ));
+            }
+            if let Some(neo4j_type) = neo4j_type {
+                constraints.statements.push(format!(
+                    "CREATE CONSTRAINT {} IF NOT EXISTS FOR {} REQUIRE {} IS :: {};",
+                    constraint_name(&[type_name, key.str(), "type"]),
+                    pattern,
+                    property(element, key),
+                    neo4j_type
+                ));
+            }
+        }
+    }
+}
+
+/// Checks that all the types which may have the label, as the types which extend the type
+/// or have the label as an alternative, have the same property, so its constraints hold
+/// for all the elements with the label
+fn shared_property(
+    types: &[(&TypeName, TypeContent)],
+    label: &LabelName,
+    key: &Key,
+    property: (&ValueType, bool),
+) -> bool {
+    types
+        .iter()
+        .filter(|(_, other)| {
+            other.labels.contains(label) || other.alternative_labels.contains(label)
+        })
+        .all(|(_, other)| {
+            other
+                .properties
+                .get(key)
+                .is_some_and(|(value_type, required)| (value_type, *required) == property)
+        })
+}
+
+/// Neo4j type of the values of a property and minimum number of values,
+/// adding the reasons why parts of the type can't be translated
+fn neo4j_type(value_type: &ValueType, reasons: &mut Vec<String>) -> (Option<&'static str>, usize) {
+    let (neo4j_type, card) = match value_type {
+        ValueType::Any => return (None, 0),
+        ValueType::StringType(card) => ("STRING", card),
+        ValueType::IntegerType(card) => ("INTEGER", card),
+        ValueType::DateType(card) => ("DATE", card),
+        ValueType::BoolType(card) => ("BOOLEAN", card),
+        ValueType::Intersection(left, right) => {
+            let (left_type, left_min) = neo4j_type(left, reasons);
+            let (right_type, right_min) = neo4j_type(right, reasons);
+            let neo4j_type = match (left_type, right_type) {
+                (Some(left), Some(right)) if left != right => {
+                    reasons.push(format!("values of types {} and {}", left, right));
+                    None
+                }
+                (left, right) => left.or(right),
+            };
+            return (neo4j_type, left_min.max(right_min));
+        }
+        ValueType::Cond(expr) => {
+            reasons.push(format!("condition {}", expr));
+            return (None, 0);
+        }
+        ValueType::List(_, _)
+        | ValueType::Set(_, _)
+        | ValueType::Record(_, _)
+        | ValueType::Union(_, _) => {
+            reasons.push(format!("type {}", value_type));
+            return (None, 0);
+        }
+    };
+    let (min, max) = card_bounds(card);
+    if max.is_some_and(|max| max <= 1) {
+        (Some(neo4j_type), min)
+    } else {
+        reasons.push(format!("several values with cardinality {}", card));
+        (None, min)
+    }
+}
+
+fn card_bounds(card: &Card) -> (usize, Option<usize>) {
+    match card {
+        Card::ZeroOrOne => (0, Some(1)),
+        Card::One => (1, Some(1)),
+        Card::ZeroOrMore => (0, None),
+        Card::OneOrMore => (1, None),
+        Card::Range(min, Max::Bounded(max)) => (*min, Some(*max)),
+        Card::Range(min, Max::Unbounded) => (*min, None),
+    }
+}
+
+fn pattern(element: Element, label: &str) -> String {
+    match element {
+        Element::Node => format!("(n:{})", identifier(label)),
+        Element::Relationship => format!("()-[r:{}]-()", identifier(label)),
+    }
+}
+
+fn property(element: Element, key: &Key) -> String {
+    match element {
+        Element::Node => format!("n.{}", identifier(key.str())),
+        Element::Relationship => format!("r.{}", identifier(key.str())),
+    }
+}
+
+/// Constraint names with the characters that are not allowed in identifiers replaced
+fn constraint_name(parts: &[&str]) -> String {
+    parts
+        .join("_")
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+impl Display for Neo4jConstraints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for statement in &self.statements {
+            writeln!(f, "{}", statement)?;
+        }
+        if !self.untranslated.is_empty() {
+            writeln!(f, "// Not translated:")?;
+            for untranslated in &self.untranslated {
+                writeln!(f, "//   {}", untranslated)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::pgs_builder::PgsBuilder;
+
+    #[test]
+    fn test_constraints() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( PersonType : Person {
+    name: STRING,
+    OPTIONAL age: INTEGER CHECK > 0,
+    OPTIONAL emails: STRING*
+}) ;
+CREATE EDGE TYPE (@PersonType) -[KnowsType : knows { since: INTEGER }]-> (@PersonType) ;
+CREATE CONSTRAINT PersonKey FOR (:Person) KEY (name, age)"#,
+            )
+            .unwrap();
+        let constraints = Neo4jConstraintWriter::new()
+            .write_constraints(&schema)
+            .unwrap();
+        assert_eq!(
+            constraints.statements,
+            vec![
+                "CREATE CONSTRAINT PersonType_age_type IF NOT EXISTS FOR (n:Person) REQUIRE n.age IS :: INTEGER;",
+                "CREATE CONSTRAINT PersonType_name_exists IF NOT EXISTS FOR (n:Person) REQUIRE n.name IS NOT NULL;",
+                "CREATE CONSTRAINT PersonType_name_type IF NOT EXISTS FOR (n:Person) REQUIRE n.name IS :: STRING;",
+                "CREATE CONSTRAINT KnowsType_since_exists IF NOT EXISTS FOR ()-[r:knows]-() REQUIRE r.since IS NOT NULL;",
+                "CREATE CONSTRAINT KnowsType_since_type IF NOT EXISTS FOR ()-[r:knows]-() REQUIRE r.since IS :: INTEGER;",
+                "CREATE CONSTRAINT PersonKey IF NOT EXISTS FOR (n:Person) REQUIRE (n.name, n.age) IS NODE KEY;",
+            ]
+        );
+        assert_eq!(
+            constraints.untranslated,
+            vec![
+                "PersonType: closed record",
+                "KnowsType: closed record",
+                "KnowsType: endpoints of the edge type",
+                "PersonType.age: condition (> 0)",
+                "PersonType.emails: several values with cardinality 0..*",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let schema = PgsBuilder::new()
+            .parse_pgs(
+                r#"CREATE NODE TYPE ( AType : A { name: STRING, age: INTEGER } ) ;
+CREATE NODE TYPE ( BType : A | B { name: STRING, age: STRING } )"#,
+            )
+            .unwrap();
+        let constraints = Neo4jConstraintWriter::new()
+            .write_constraints(&schema)
+            .unwrap();
+        assert_eq!(
+            constraints.statements,
+            vec![
+                "CREATE CONSTRAINT AType_name_exists IF NOT EXISTS FOR (n:A) REQUIRE n.name IS NOT NULL;",
+                "CREATE CONSTRAINT AType_name_type IF NOT EXISTS FOR (n:A) REQUIRE n.name IS :: STRING;",
+            ]
+        );
+        assert!(constraints.untranslated.contains(
+            &"AType.age: label A is shared with types with other properties".to_string()
+        ));
+        assert!(
+            constraints
+                .untranslated
+                .iter()
+                .any(|untranslated| untranslated.starts_with("BType: alternative labels"))
+        );
+    }
+
+    #[test]
+    fn test_optional_label() {
+        let mut schema = PgsBuilder::new()
+            .parse_pgs("CREATE NODE TYPE ( AType : A { name: STRING } )")
+            .unwrap();
+        let label = LabelPropertySpec::label("A".to_string());
+        schema
+            .add_node_spec("OtherType", LabelPropertySpec::optional(label))
+            .unwrap();
+        let constraints = Neo4jConstraintWriter::new()
+            .write_constraints(&schema)
+            .unwrap();
+        assert!(constraints.statements.is_empty());
+    }
+}
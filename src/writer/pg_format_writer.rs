@@ -0,0 +1,173 @@
+use itertools::Itertools;
+
+use crate::{
+    pg::PropertyGraph,
+    pgs_error::PgsError,
+    record::Record,
+    writer::literal::{property_literals, quote},
+};
+
+/// Serializes property graphs in the PG format of the pg-format tools, which can be read
+/// by `PgFormatBuilder`.
+///
+/// Properties with several values repeat their key and dates are written unquoted.
+/// The format has no edge identifiers, so the names of the edges are not kept, and it
+/// can't express empty lists, so properties without values give an error.
+pub struct PgFormatWriter {}
+
+impl Default for PgFormatWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PgFormatWriter {
+    pub fn new() -> Self {
+        PgFormatWriter {}
+    }
+
+    pub fn write_pg_format(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        let mut out = String::new();
+        for (name, node) in graph.nodes().sorted_by_key(|(_, node)| node.id.id) {
+            out.push_str(&identifier(name));
+            write_labels_record(&mut out, node.labels().iter(), node.content())?;
+            out.push('\n');
+        }
+        for (_, edge) in graph.edges().sorted_by_key(|(_, edge)| edge.id.id) {
+            let source = graph.node_name(&edge.source).cloned().unwrap_or_default();
+            let target = graph.node_name(&edge.target).cloned().unwrap_or_default();
+            out.push_str(&format!(
+                "{} -> {}",
+                identifier(&source),
+                identifier(&target)
+            ));
+            write_labels_record(&mut out, edge.labels().iter(), edge.content())?;
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+fn write_labels_record<'a>(
+    out: &mut String,
+    labels: impl Iterator<Item = &'a String>,
+    record: &Record,
+) -> Result<(), PgsError> {
+    for label in labels.sorted() {
+        out.push_str(&format!(" :{}", identifier(label)));
+    }
+    for (key, literals) in property_literals("PG format", record)? {
+        if literals.is_empty() {
+            return Err(PgsError::GraphWriteError {
+                format: "PG format".to_string(),
+                error: format!("property {} is an empty list, which can't be written", key),
+            });
+        }
+        for literal in literals {
+            out.push_str(&format!(" {}:{}", identifier(key.str()), literal));
+        }
+    }
+    Ok(())
+}
+
+fn identifier(name: &str) -> String {
+    if name.is_empty()
+        || name.starts_with('#')
+        || name.contains(|c: char| c.is_whitespace() || c == '"' || c == ':')
+    {
+        quote(name)
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{key::Key, parser::pg_format_builder::PgFormatBuilder, value::Value};
+
+    #[test]
+    fn test_write_pg_format() {
+        let mut graph = PropertyGraph::new();
+        let alice = Record::new()
+            .with_key_value("name", Value::str("Alice: \"the first\""))
+            .with_key_value("born", Value::date("2000-01-31").unwrap())
+            .with_key_value("tags", Value::str("a"))
+            .with_key_value("tags", Value::str("b"));
+        graph
+            .add_node(
+                "alice smith".to_string(),
+                HashSet::from(["Student".to_string(), "Person".to_string()]),
+                alice,
+            )
+            .unwrap();
+        graph
+            .add_node("#1".to_string(), HashSet::new(), Record::new())
+            .unwrap();
+        graph
+            .add_edge(
+                "k1".to_string(),
+                "alice smith".to_string(),
+                HashSet::from(["knows".to_string()]),
+                Record::new().with_key_value("since", Value::int(2020)),
+                "#1".to_string(),
+            )
+            .unwrap();
+        let written = PgFormatWriter::new().write_pg_format(&graph).unwrap();
+        assert_eq!(
+            written,
+            r##""alice smith" :Person :Student born:2000-01-31 name:"Alice: \"the first\"" tags:"a" tags:"b"
+"#1"
+"alice smith" -> "#1" :knows since:2020
+"##
+        );
+    }
+
+    #[test]
+    fn test_literals_keep_their_type() {
+        let mut graph = PropertyGraph::new();
+        let record = Record::new()
+            .with_key_value("born", Value::date("2000-01-31").unwrap())
+            .with_key_value("code", Value::str("42"))
+            .with_key_value("day", Value::str("2000-01-31"))
+            .with_key_value("flag", Value::str("true"))
+            .with_key_value("active", Value::false_());
+        graph
+            .add_node("alice".to_string(), HashSet::new(), record)
+            .unwrap();
+        let written = PgFormatWriter::new().write_pg_format(&graph).unwrap();
+        let parsed = PgFormatBuilder::new().parse_pg_format(&written).unwrap();
+        assert_eq!(
+            parsed.get_node_by_label("alice").unwrap().content(),
+            graph.get_node_by_label("alice").unwrap().content()
+        );
+    }
+
+    #[test]
+    fn test_nested_record() {
+        let mut graph = PropertyGraph::new();
+        let record = Record::new().with_key_value("address", Value::record(Record::new()));
+        graph
+            .add_node("alice".to_string(), HashSet::new(), record)
+            .unwrap();
+        let result = PgFormatWriter::new().write_pg_format(&graph);
+        assert!(matches!(result, Err(PgsError::GraphWriteError { .. })));
+    }
+
+    #[test]
+    fn test_empty_list() {
+        let mut graph = PropertyGraph::new();
+        let mut record = Record::new();
+        record.insert_values(Key::new("tags"), Vec::new());
+        graph
+            .add_node("alice".to_string(), HashSet::new(), record)
+            .unwrap();
+        let result = PgFormatWriter::new().write_pg_format(&graph);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error writing PG format property graph: property tags is an empty list, which can't be written"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use itertools::Itertools;
+use serde_json::{Map, Value as JsonValue, json};
+
+use crate::{pg::PropertyGraph, pgs_error::PgsError, record::Record, value::Value};
+
+/// Serializes property graphs as PG-JSON documents, which can be read by `PgJsonBuilder`.
+///
+/// Property values are always arrays, dates are written as strings and nested records
+/// as objects. Edges keep their names in an `id` field.
+pub struct PgJsonWriter {}
+
+impl Default for PgJsonWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PgJsonWriter {
+    pub fn new() -> Self {
+        PgJsonWriter {}
+    }
+
+    pub fn write_pg_json(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        let nodes = graph
+            .nodes()
+            .sorted_by_key(|(_, node)| node.id.id)
+            .map(|(name, node)| {
+                json!({
+                    "id": name,
+                    "labels": node.labels().iter().sorted().collect::<Vec<_>>(),
+                    "properties": json_properties(node.content()),
+                })
+            })
+            .collect::<Vec<_>>();
+        let edges = graph
+            .edges()
+            .sorted_by_key(|(_, edge)| edge.id.id)
+            .map(|(name, edge)| {
+                json!({
+                    "id": name,
+                    "from": graph.node_name(&edge.source),
+                    "to": graph.node_name(&edge.target),
+                    "undirected": false,
+                    "labels": edge.labels().iter().sorted().collect::<Vec<_>>(),
+                    "properties": json_properties(edge.content()),
+                })
+            })
+            .collect::<Vec<_>>();
+        let document = json!({ "nodes": nodes, "edges": edges });
+        serde_json::to_string_pretty(&document).map_err(|e| PgsError::GraphWriteError {
+            format: "PG-JSON".to_string(),
+            error: e.to_string(),
+        })
+    }
+}
+
+fn json_properties(record: &Record) -> Map<String, JsonValue> {
+    record
+        .iter()
+        .map(|(key, values)| {
+            let values = values.iter().map(json_value).collect();
+            (key.str().to_string(), JsonValue::Array(values))
+        })
+        .collect()
+}
+
+pub(crate) fn json_value(value: &Value) -> JsonValue {
+    match value {
+        Value::Integer(i) => JsonValue::from(*i),
+        Value::Bool(b) => JsonValue::Bool(*b),
+        Value::Record(record) => JsonValue::Object(json_properties(record)),
+        other => JsonValue::String(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::parser::pg_json_builder::PgJsonBuilder;
+
+    #[test]
+    fn test_write_pg_json() {
+        let mut graph = PropertyGraph::new();
+        let alice = Record::new()
+            .with_key_value("born", Value::date("2000-01-31").unwrap())
+            .with_key_value(
+                "address",
+                Value::record(Record::new().with_key_value("city", Value::str("Oviedo"))),
+            );
+        graph
+            .add_node(
+                "alice".to_string(),
+                HashSet::from(["Person".to_string()]),
+                alice,
+            )
+            .unwrap();
+        graph
+            .add_edge(
+                "k1".to_string(),
+                "alice".to_string(),
+                HashSet::from(["knows".to_string()]),
+                Record::new().with_key_value("since", Value::int(2020)),
+                "alice".to_string(),
+            )
+            .unwrap();
+        let written = PgJsonWriter::new().write_pg_json(&graph).unwrap();
+        let document: JsonValue = serde_json::from_str(&written).unwrap();
+        assert_eq!(
+            document,
+            json!({
+                "nodes": [{
+                    "id": "alice",
+                    "labels": ["Person"],
+                    "properties": {"address": [{"city": ["Oviedo"]}], "born": ["2000-01-31"]},
+                }],
+                "edges": [{
+                    "id": "k1",
+                    "from": "alice",
+                    "to": "alice",
+                    "undirected": false,
+                    "labels": ["knows"],
+                    "properties": {"since": [2020]},
+                }],
+            })
+        );
+        let parsed = PgJsonBuilder::new().parse_pg_json(&written).unwrap();
+        let edge = parsed.get_edge_by_label("k1").unwrap();
+        assert_eq!(
+            edge.content(),
+            graph.get_edge_by_label("k1").unwrap().content()
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use itertools::Itertools;
+
+use crate::{
+    key::Key, pg::PropertyGraph, pgs_error::PgsError, record::Record, type_name::LabelName,
+    value::Value,
+};
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Serializes property graphs as RDF 1.2 in Turtle or N-Triples, with triple terms for
+/// the edges.
+///
+/// Nodes are IRIs `<base>node/<name>` whose labels are `rdf:type`s of the classes
+/// `<base>label/<label>`, and properties are predicates `<base>property/<key>` with typed
+/// literals. Each label of an edge gives a triple between its nodes with the label as
+/// predicate, which is the triple term `<<( s p o )>>` reified by the IRI `<base>edge/<name>`
+/// of the edge with `rdf:reifies`. The properties of the edge are written for that IRI, so parallel edges with
+/// the same label keep their own properties. Nested records are blank nodes.
+pub struct RdfWriter {
+    base: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Iri(String),
+    BlankNode(usize),
+    Literal { lexical: String, datatype: String },
+    Triple(Box<Triple>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Triple {
+    subject: Term,
+    predicate: String,
+    object: Term,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Syntax {
+    Turtle,
+    NTriples,
+}
+
+impl Default for RdfWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RdfWriter {
+    pub fn new() -> Self {
+        RdfWriter {
+            base: "http://example.org/".to_string(),
+        }
+    }
+
+    /// Base IRI of the nodes, labels and properties, `http://example.org/` by default.
+    ///
+    /// A `/` is appended when the base doesn't end with `/` or `#`, so the IRIs are
+    /// `<base>/node/<name>` instead of being glued to the last segment of the base.
+    pub fn with_base(mut self, base: &str) -> Self {
+        self.base = if base.ends_with(['/', '#']) {
+            base.to_string()
+        } else {
+            format!("{}/", base)
+        };
+        self
+    }
+
+    /// Writes Turtle with prefixes for the nodes, labels and properties
+    pub fn write_turtle(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        let triples = self.triples(graph)?;
+        let mut out = String::new();
+        for (prefix, namespace) in self.prefixes() {
+            out.push_str(&format!("@prefix {}: <{}> .\n", prefix, namespace));
+        }
+        let mut previous_subject = None;
+        for triple in &triples {
+            let subject = self.term(&triple.subject, Syntax::Turtle);
+            let predicate = self.iri(&triple.predicate, Syntax::Turtle);
+            let object = self.term(&triple.object, Syntax::Turtle);
+            if previous_subject.as_ref() == Some(&subject) {
+                out.push_str(&format!(" ;\n    {} {}", predicate, object));
+            } else {
+                if previous_subject.is_some() {
+                    out.push_str(" .\n");
+                }
+                out.push_str(&format!("\n{} {} {}", subject, predicate, object));
+                previous_subject = Some(subject);
+            }
+        }
+        if previous_subject.is_some() {
+            out.push_str(" .\n");
+        }
+        Ok(out)
+    }
+
+    /// Writes N-Triples with a triple per line and full IRIs
+    pub fn write_ntriples(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        let triples = self.triples(graph)?;
+        Ok(triples
+            .iter()
+            .map(|triple| format!("{} .\n", self.triple(triple, Syntax::NTriples)))
+            .collect())
+    }
+
+    fn prefixes(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("rdf", RDF.to_string()),
+            ("xsd", XSD.to_string()),
+            ("node", format!("{}node/", self.base)),
+            ("edge", format!("{}edge/", self.base)),
+            ("label", format!("{}label/", self.base)),
+            ("property", format!("{}property/", self.base)),
+        ]
+    }
+
+    fn triples(&self, graph: &PropertyGraph) -> Result<Vec<Triple>, PgsError> {
+        let mut triples = Vec::new();
+        let mut blank_nodes = 0;
+        for (name, node) in graph.nodes().sorted_by_key(|(_, node)| node.id.id) {
+            let subject = self.node_iri(name);
+            for label in node.labels().iter().sorted() {
+                triples.push(Triple {
+                    subject: subject.clone(),
+                    predicate: format!("{}type", RDF),
+                    object: Term::Iri(self.label_iri(label)),
+                });
+            }
+            self.properties(&subject, node.content(), &mut triples, &mut blank_nodes);
+        }
+        for (name, edge) in graph.edges().sorted_by_key(|(_, edge)| edge.id.id) {
+            if edge.labels().is_empty() {
+                return Err(PgsError::GraphWriteError {
+                    format: "RDF".to_string(),
+                    error: format!("edge {} has no label to be written as a predicate", name),
+                });
+            }
+            let source = graph.node_name(&edge.source).cloned().unwrap_or_default();
+            let target = graph.node_name(&edge.target).cloned().unwrap_or_default();
+            let reifier = self.edge_iri(name);
+            for label in edge.labels().iter().sorted() {
+                let triple = Triple {
+                    subject: self.node_iri(&source),
+                    predicate: self.label_iri(label),
+                    object: self.node_iri(&target),
+                };
+                triples.push(triple.clone());
+                triples.push(Triple {
+                    subject: reifier.clone(),
+                    predicate: format!("{}reifies", RDF),
+                    object: Term::Triple(Box::new(triple)),
+                });
+            }
+            self.properties(&reifier, edge.content(), &mut triples, &mut blank_nodes);
+        }
+        Ok(triples)
+    }
+
+    /// Adds a triple for each value of the properties, where nested records are blank nodes
+    fn properties(
+        &self,
+        subject: &Term,
+        record: &Record,
+        triples: &mut Vec<Triple>,
+        blank_nodes: &mut usize,
+    ) {
+        let mut nested = Vec::new();
+        for (key, values) in record.iter() {
+            for value in values {
+                let object = match value {
+                    Value::Record(record) => {
+                        *blank_nodes += 1;
+                        let blank_node = Term::BlankNode(*blank_nodes);
+                        nested.push((blank_node.clone(), record));
+                        blank_node
+                    }
+                    Value::String(_) => literal(value, "string"),
+                    Value::Integer(_) => literal(value, "integer"),
+                    Value::Date(_) => literal(value, "date"),
+                    Value::Bool(_) => literal(value, "boolean"),
+                };
+                triples.push(Triple {
+                    subject: subject.clone(),
+                    predicate: self.property_iri(key),
+                    object,
+                });
+            }
+        }
+        for (blank_node, record) in nested {
+            self.properties(&blank_node, record, triples, blank_nodes);
+        }
+    }
+
+    fn node_iri(&self, name: &str) -> Term {
+        Term::Iri(format!("{}node/{}", self.base, iri_segment(name)))
+    }
+
+    fn edge_iri(&self, name: &str) -> Term {
+        Term::Iri(format!("{}edge/{}", self.base, iri_segment(name)))
+    }
+
+    fn label_iri(&self, label: &LabelName) -> String {
+        format!("{}label/{}", self.base, iri_segment(label))
+    }
+
+    fn property_iri(&self, key: &Key) -> String {
+        format!("{}property/{}", self.base, iri_segment(key.str()))
+    }
+
+    fn triple(&self, triple: &Triple, syntax: Syntax) -> String {
+        format!(
+            "{} {} {}",
+            self.term(&triple.subject, syntax),
+            self.iri(&triple.predicate, syntax),
+            self.term(&triple.object, syntax)
+        )
+    }
+
+    fn term(&self, term: &Term, syntax: Syntax) -> String {
+        match term {
+            Term::Iri(iri) => self.iri(iri, syntax),
+            Term::BlankNode(n) => format!("_:b{}", n),
+            Term::Literal { lexical, datatype } if datatype == &format!("{}string", XSD) => {
+                quote(lexical)
+            }
+            Term::Literal { lexical, datatype } => {
+                format!("{}^^{}", quote(lexical), self.iri(datatype, syntax))
+            }
+            Term::Triple(triple) => format!("<<( {} )>>", self.triple(triple, syntax)),
+        }
+    }
+
+    /// IRIs are written as prefixed names in Turtle when their local name is simple
+    fn iri(&self, iri: &str, syntax: Syntax) -> String {
+        if syntax == Syntax::Turtle {
+            if iri == format!("{}type", RDF) {
+                return "a".to_string();
+            }
+            for (prefix, namespace) in self.prefixes() {
+                if let Some(local) = iri.strip_prefix(namespace.as_str())
+                    && !local.is_empty()
+                    && local.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return format!("{}:{}", prefix, local);
+                }
+            }
+        }
+        format!("<{}>", iri)
+    }
+}
+
+/// Literal with a datatype of XML Schema
+fn literal(value: &Value, datatype: &str) -> Term {
+    Term::Literal {
+        lexical: value.to_string(),
+        datatype: format!("{}{}", XSD, datatype),
+    }
+}
+
+/// Percent-encodes the characters of names which are not unreserved in IRIs
+fn iri_segment(name: &str) -> String {
+    name.bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect()
+}
+
+fn quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn graph() -> PropertyGraph {
+        let mut graph = PropertyGraph::new();
+        let alice = Record::new()
+            .with_key_value("name", Value::str("Alice \"Al\""))
+            .with_key_value("born", Value::date("2000-01-31").unwrap())
+            .with_key_value(
+                "address",
+                Value::record(Record::new().with_key_value("city", Value::str("Oviedo"))),
+            );
+        graph
+            .add_node(
+                "alice".to_string(),
+                HashSet::from(["Person".to_string(), "Student".to_string()]),
+                alice,
+            )
+            .unwrap();
+        graph
+            .add_node(
+                "bob smith".to_string(),
+                HashSet::from(["Person".to_string()]),
+                Record::new().with_key_value("active", Value::true_()),
+            )
+            .unwrap();
+        graph
+            .add_edge(
+                "k1".to_string(),
+                "alice".to_string(),
+                HashSet::from(["knows".to_string()]),
+                Record::new().with_key_value("since", Value::int(2020)),
+                "bob smith".to_string(),
+            )
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_turtle() {
+        let turtle = RdfWriter::new()
+            .with_base("http://example.org/people")
+            .write_turtle(&graph())
+            .unwrap();
+        assert_eq!(
+            turtle,
+            r#"@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix node: <http://example.org/people/node/> .
+@prefix edge: <http://example.org/people/edge/> .
+@prefix label: <http://example.org/people/label/> .
+@prefix property: <http://example.org/people/property/> .
+
+node:alice a label:Person ;
+    a label:Student ;
+    property:address _:b1 ;
+    property:born "2000-01-31"^^xsd:date ;
+    property:name "Alice \"Al\"" .
+
+_:b1 property:city "Oviedo" .
+
+<http://example.org/people/node/bob%20smith> a label:Person ;
+    property:active "true"^^xsd:boolean .
+
+node:alice label:knows <http://example.org/people/node/bob%20smith> .
+
+edge:k1 rdf:reifies <<( node:alice label:knows <http://example.org/people/node/bob%20smith> )>> ;
+    property:since "2020"^^xsd:integer .
+"#
+        );
+    }
+
+    #[test]
+    fn test_ntriples() {
+        let ntriples = RdfWriter::new().write_ntriples(&graph()).unwrap();
+        let lines = ntriples.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(
+            lines[9],
+            "<http://example.org/edge/k1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#reifies> <<( <http://example.org/node/alice> <http://example.org/label/knows> <http://example.org/node/bob%20smith> )>> ."
+        );
+        assert_eq!(
+            lines[10],
+            "<http://example.org/edge/k1> <http://example.org/property/since> \"2020\"^^<http://www.w3.org/2001/XMLSchema#integer> ."
+        );
+    }
+
+    #[test]
+    fn test_parallel_edges() {
+        let mut graph = graph();
+        graph
+            .add_edge(
+                "k2".to_string(),
+                "alice".to_string(),
+                HashSet::from(["knows".to_string()]),
+                Record::new().with_key_value("since", Value::int(2024)),
+                "bob smith".to_string(),
+            )
+            .unwrap();
+        let ntriples = RdfWriter::new().write_ntriples(&graph).unwrap();
+        for (edge, since) in [("k1", 2020), ("k2", 2024)] {
+            let line = format!(
+                "<http://example.org/edge/{}> <http://example.org/property/since> \"{}\"^^<http://www.w3.org/2001/XMLSchema#integer> .",
+                edge, since
+            );
+            assert!(ntriples.lines().any(|l| l == line));
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    key::Key,
+    label_property_spec::LabelPropertySpec,
+    pgs::PropertyGraphSchema,
+    pgs_error::PgsError,
+    property_value_spec::{PropertyValue, PropertyValueSpec},
+    record_type::RecordType,
+    type_name::{LabelName, TypeName},
+    value_type::ValueType,
+};
+
+/// Labels and properties that a node or edge type requires, collected through the types
+/// it references or extends, for the translations to languages that only have plain
+/// sets of labels and properties
+#[derive(Debug, Default)]
+pub(crate) struct TypeContent {
+    pub labels: BTreeSet<LabelName>,
+    /// Labels that the type may have, as the ones in alternatives or optional labels
+    pub alternative_labels: BTreeSet<LabelName>,
+    /// Value type of each property and whether the property is required
+    pub properties: BTreeMap<Key, (ValueType, bool)>,
+    /// Types whose records are closed
+    pub closed: Vec<TypeName>,
+    /// Types whose records are open
+    pub open: Vec<TypeName>,
+    /// Constructs that can't be represented as labels and properties
+    pub notes: Vec<String>,
+}
+
+impl TypeContent {
+    /// Collects the content of a named node type and the node types it extends
+    pub fn node_type(schema: &PropertyGraphSchema, type_name: &str) -> Result<Self, PgsError> {
+        let mut content = TypeContent::default();
+        content.add_node_type(schema, type_name, &mut Vec::new())?;
+        Ok(content)
+    }
+
+    /// Collects the content of a spec which is part of the type with the name
+    pub fn spec(
+        schema: &PropertyGraphSchema,
+        type_name: &str,
+        spec: &LabelPropertySpec,
+    ) -> Result<Self, PgsError> {
+        let mut content = TypeContent::default();
+        content.add_spec(schema, type_name, spec, &mut Vec::new())?;
+        Ok(content)
+    }
+
+    fn add_node_type(
+        &mut self,
+        schema: &PropertyGraphSchema,
+        type_name: &str,
+        visiting: &mut Vec<TypeName>,
+    ) -> Result<(), PgsError> {
+        let type_name = schema.resolve_node_type_name(type_name)?;
+        if visiting.contains(&type_name) {
+            return Err(PgsError::CyclicExtension { type_name });
+        }
+        visiting.push(type_name.clone());
+        let spec = schema.get_node_semantics(&type_name)?;
+        self.add_spec(schema, &type_name, spec, visiting)?;
+        for parent in schema.parents(&type_name) {
+            self.add_node_type(schema, parent, visiting)?;
+        }
+        visiting.pop();
+        Ok(())
+    }
+
+    fn add_spec(
+        &mut self,
+        schema: &PropertyGraphSchema,
+        type_name: &str,
+        spec: &LabelPropertySpec,
+        visiting: &mut Vec<TypeName>,
+    ) -> Result<(), PgsError> {
+        match spec {
+            LabelPropertySpec::Empty => {}
+            LabelPropertySpec::Label(label) => {
+                self.labels.insert(label.clone());
+            }
+            LabelPropertySpec::Ref(referenced) => {
+                self.add_node_type(schema, referenced, visiting)?;
+            }
+            LabelPropertySpec::And(left, right) => {
+                self.add_spec(schema, type_name, left, visiting)?;
+                self.add_spec(schema, type_name, right, visiting)?;
+            }
+            LabelPropertySpec::Open(spec) => {
+                self.add_spec(schema, type_name, spec, visiting)?;
+            }
+            LabelPropertySpec::Optional(alternative) => {
+                self.notes
+                    .push(format!("{}: alternative labels {}", type_name, spec));
+                self.add_alternative_labels(schema, type_name, alternative, visiting)?;
+            }
+            LabelPropertySpec::Or(left, right) => {
+                self.notes
+                    .push(format!("{}: alternative labels {}", type_name, spec));
+                self.add_alternative_labels(schema, type_name, left, visiting)?;
+                self.add_alternative_labels(schema, type_name, right, visiting)?;
+            }
+            LabelPropertySpec::Content(spec, value_spec) => {
+                self.add_spec(schema, type_name, spec, visiting)?;
+                let property_value = match value_spec {
+                    PropertyValueSpec::Closed(property_value) => {
+                        self.closed.push(type_name.to_string());
+                        property_value
+                    }
+                    PropertyValueSpec::Open(property_value) => {
+                        self.open.push(type_name.to_string());
+                        property_value
+                    }
+                };
+                self.add_properties(type_name, property_value);
+            }
+        }
+        Ok(())
+    }
+
+    fn add_alternative_labels(
+        &mut self,
+        schema: &PropertyGraphSchema,
+        type_name: &str,
+        spec: &LabelPropertySpec,
+        visiting: &mut Vec<TypeName>,
+    ) -> Result<(), PgsError> {
+        let mut alternative = TypeContent::default();
+        alternative.add_spec(schema, type_name, spec, visiting)?;
+        self.alternative_labels.extend(alternative.labels);
+        self.alternative_labels
+            .extend(alternative.alternative_labels);
+        Ok(())
+    }
+
+    fn add_properties(&mut self, type_name: &str, property_value: &PropertyValue) {
+        match property_value {
+            PropertyValue::EachOf(left, right) => {
+                self.add_properties(type_name, left);
+                self.add_properties(type_name, right);
+            }
+            PropertyValue::Property(key, type_spec) => {
+                self.properties
+                    .insert(key.clone(), (type_spec.to_value_type(), true));
+            }
+            PropertyValue::OptionalProperty(key, type_spec) => {
+                self.properties
+                    .insert(key.clone(), (type_spec.to_value_type(), false));
+            }
+            PropertyValue::OneOf(left, right) => {
+                self.notes.push(format!(
+                    "{}: alternative properties {}",
+                    type_name, property_value
+                ));
+                self.add_alternative_properties(type_name, left);
+                self.add_alternative_properties(type_name, right);
+            }
+            PropertyValue::Check(expr) => self.notes.push(format!("{}: CHECK {}", type_name, expr)),
+            PropertyValue::NoProperties | PropertyValue::Empty => {}
+        }
+    }
+
+    /// The properties of an alternative are optional, and their type is any value when
+    /// other alternatives give them a different type
+    fn add_alternative_properties(&mut self, type_name: &str, property_value: &PropertyValue) {
+        let mut alternative = TypeContent::default();
+        alternative.add_properties(type_name, property_value);
+        for (key, (value_type, _)) in alternative.properties {
+            match self.properties.get_mut(&key) {
+                Some((other, false)) if *other != value_type => *other = ValueType::Any,
+                Some(_) => {}
+                None => {
+                    self.properties.insert(key, (value_type, false));
+                }
+            }
+        }
+    }
+}
+
+/// Fields of alternative record types with whether each field is in all the alternatives.
+///
+/// The flag returned with the fields is false when the alternatives are not just the
+/// combinations of some optional fields, as the ones of `OPTIONAL` properties, so they
+/// can't be written as one record without losing information.
+pub(crate) fn record_fields(
+    record_types: &[RecordType],
+) -> (BTreeMap<&Key, (&ValueType, bool)>, bool) {
+    let mut fields: BTreeMap<&Key, (&ValueType, usize)> = BTreeMap::new();
+    let mut same_types = true;
+    for record_type in record_types {
+        for (key, value_type) in record_type.iter() {
+            let field = fields.entry(key).or_insert((value_type, 0));
+            same_types &= field.0 == value_type;
+            field.1 += 1;
+        }
+    }
+    let optional = fields
+        .values()
+        .filter(|(_, count)| *count < record_types.len())
+        .count();
+    let combinations = record_types.len().is_power_of_two()
+        && record_types.len().trailing_zeros() as usize == optional;
+    let fields = fields
+        .into_iter()
+        .map(|(key, (value_type, count))| (key, (value_type, count == record_types.len())))
+        .collect();
+    (fields, same_types && combinations)
+}
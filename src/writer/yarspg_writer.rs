@@ -0,0 +1,126 @@
+use itertools::Itertools;
+
+use crate::{
+    pg::PropertyGraph,
+    pgs_error::PgsError,
+    record::Record,
+    writer::literal::{property_literals, quote},
+};
+
+/// Serializes property graphs as YARS-PG node and edge statements, which can be read
+/// by `YarsPgBuilder`.
+///
+/// Properties with several values are written as lists and dates unquoted.
+pub struct YarsPgWriter {}
+
+impl Default for YarsPgWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl YarsPgWriter {
+    pub fn new() -> Self {
+        YarsPgWriter {}
+    }
+
+    pub fn write_yarspg(&self, graph: &PropertyGraph) -> Result<String, PgsError> {
+        let mut out = String::new();
+        for (name, node) in graph.nodes().sorted_by_key(|(_, node)| node.id.id) {
+            out.push_str(&format!(
+                "({}{})\n",
+                identifier(name),
+                labels_record(node.labels().iter(), node.content())?
+            ));
+        }
+        for (name, edge) in graph.edges().sorted_by_key(|(_, edge)| edge.id.id) {
+            let source = graph.node_name(&edge.source).cloned().unwrap_or_default();
+            let target = graph.node_name(&edge.target).cloned().unwrap_or_default();
+            out.push_str(&format!(
+                "({})-({}{})->({})\n",
+                identifier(&source),
+                identifier(name),
+                labels_record(edge.labels().iter(), edge.content())?,
+                identifier(&target)
+            ));
+        }
+        Ok(out)
+    }
+}
+
+fn labels_record<'a>(
+    labels: impl Iterator<Item = &'a String>,
+    record: &Record,
+) -> Result<String, PgsError> {
+    let labels = labels.sorted().map(|label| quote(label)).join(",");
+    let properties = property_literals("YARS-PG", record)?
+        .into_iter()
+        .map(|(key, literals)| match literals.as_slice() {
+            [literal] => format!("{}:{}", quote(key.str()), literal),
+            _ => format!("{}:[{}]", quote(key.str()), literals.join(",")),
+        })
+        .collect::<Vec<_>>();
+    Ok(format!(" {{{}}}[{}]", labels, properties.join(",")))
+}
+
+fn identifier(name: &str) -> String {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+    {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{parser::yarspg_builder::YarsPgBuilder, value::Value};
+
+    #[test]
+    fn test_write_yarspg() {
+        let mut graph = PropertyGraph::new();
+        let alice = Record::new()
+            .with_key_value("name", Value::str("Alice (\"the first\")"))
+            .with_key_value("born", Value::date("2000-01-31").unwrap())
+            .with_key_value("tags", Value::str("a"))
+            .with_key_value("tags", Value::str("b"));
+        graph
+            .add_node(
+                "alice smith".to_string(),
+                HashSet::from(["Student".to_string(), "Person".to_string()]),
+                alice,
+            )
+            .unwrap();
+        graph
+            .add_node("bob".to_string(), HashSet::new(), Record::new())
+            .unwrap();
+        graph
+            .add_edge(
+                "k1".to_string(),
+                "alice smith".to_string(),
+                HashSet::from(["knows".to_string()]),
+                Record::new().with_key_value("since", Value::int(2020)),
+                "bob".to_string(),
+            )
+            .unwrap();
+        let written = YarsPgWriter::new().write_yarspg(&graph).unwrap();
+        assert_eq!(
+            written,
+            r#"("alice smith" {"Person","Student"}["born":2000-01-31,"name":"Alice (\"the first\")","tags":["a","b"]])
+(bob {}[])
+("alice smith")-(k1 {"knows"}["since":2020])->(bob)
+"#
+        );
+        let parsed = YarsPgBuilder::new().parse_yarspg(&written).unwrap();
+        assert_eq!(
+            parsed.get_node_by_label("alice smith").unwrap().content(),
+            graph.get_node_by_label("alice smith").unwrap().content()
+        );
+    }
+}
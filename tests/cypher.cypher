@@ -0,0 +1,6 @@
+// People who know each other
+CREATE (alice:Person {name: 'Alice', age: 23, emails: ['alice@example.org', 'alice@work.org']}),
+       (bob:Person {name: "Bob Smith"}),
+       (carol_wrong:Person {name: 'Carol', age: 'thirty'})
+CREATE (alice)-[e1:knows {since: 2020}]->(bob)
+MERGE (carol_wrong)<-[e2:knows {since: 2021}]-(bob);